@@ -7,8 +7,10 @@
 //! - 全局反馈API使用
 
 use adui_dioxus::{
-    App, Button, ButtonType, ComponentSize, ConfigProvider, ThemeMode, ThemeProvider, Title,
-    TitleLevel, use_message, use_modal, use_notification, use_theme,
+    App, Button, ButtonType, ComponentSize, ConfigProvider, MessageConfig, MessageType,
+    ModalConfig, ModalOk, NotificationConfig, NotificationGlobalConfig, NotificationStack,
+    ThemeMode, ThemeProvider, Title, TitleLevel, use_message, use_modal, use_notification,
+    use_theme,
 };
 use dioxus::prelude::*;
 
@@ -24,6 +26,10 @@ fn app() -> Element {
                 disabled: Some(false),
                 App {
                     class: Some("demo-app-root".into()),
+                    notification: NotificationGlobalConfig {
+                        max_count: Some(6),
+                        stack: Some(NotificationStack::default()),
+                    },
                     AppDemo {}
                 }
             }
@@ -118,6 +124,42 @@ fn AppDemo() -> Element {
                         },
                         "调用 message.error()"
                     }
+                    Button {
+                        r#type: ButtonType::Default,
+                        onclick: {
+                            let api = msg.clone();
+                            move |_| {
+                                if let Some(api) = api.clone() {
+                                    api.open(MessageConfig {
+                                        content: "保存中...".into(),
+                                        r#type: MessageType::Loading,
+                                        duration: 0.0,
+                                        key: Some("save".into()),
+                                        ..Default::default()
+                                    });
+                                }
+                            }
+                        },
+                        "按 key 打开：保存中"
+                    }
+                    Button {
+                        r#type: ButtonType::Default,
+                        onclick: {
+                            let api = msg.clone();
+                            move |_| {
+                                if let Some(api) = api.clone() {
+                                    api.open(MessageConfig {
+                                        content: "已保存".into(),
+                                        r#type: MessageType::Success,
+                                        duration: 2.0,
+                                        key: Some("save".into()),
+                                        ..Default::default()
+                                    });
+                                }
+                            }
+                        },
+                        "按 key 更新：已保存"
+                    }
                 }
             }
 
@@ -174,6 +216,23 @@ fn AppDemo() -> Element {
                         },
                         "调用 notification.error()"
                     }
+                    Button {
+                        r#type: ButtonType::Default,
+                        onclick: {
+                            let api = notice.clone();
+                            move |_| {
+                                if let Some(api) = api.clone() {
+                                    api.open(NotificationConfig {
+                                        title: "带进度条的通知".into(),
+                                        description: Some("悬停时暂停倒计时".into()),
+                                        show_progress: true,
+                                        ..Default::default()
+                                    });
+                                }
+                            }
+                        },
+                        "调用 notification.open()（进度条）"
+                    }
                 }
             }
 
@@ -190,16 +249,60 @@ fn AppDemo() -> Element {
                             let api = modal.clone();
                             move |_| {
                                 if let Some(api) = api.clone() {
-                                    api.open();
+                                    api.confirm(ModalConfig {
+                                        title: Some("确认删除该条目？".into()),
+                                        content: Some("删除后无法恢复。".into()),
+                                        ..Default::default()
+                                    });
                                 }
                             }
                         },
-                        "调用 modal.open()"
+                        "调用 modal.confirm()"
                     }
-                    p {
-                        style: "margin-top: 8px; color: var(--adui-color-text-secondary); font-size: 12px;",
-                        "当前阶段 Message/Notification/Modal 仅完成 API 与 Overlay 管理接线，"
-                        "视觉层与更完整的行为将在后续补充。"
+                    Button {
+                        r#type: ButtonType::Default,
+                        onclick: {
+                            let api = modal.clone();
+                            move |_| {
+                                if let Some(api) = api.clone() {
+                                    api.success(ModalConfig {
+                                        title: Some("提交成功".into()),
+                                        content: Some("数据已保存。".into()),
+                                        ..Default::default()
+                                    });
+                                }
+                            }
+                        },
+                        "调用 modal.success()"
+                    }
+                    Button {
+                        r#type: ButtonType::Default,
+                        onclick: {
+                            let api = modal.clone();
+                            move |_| {
+                                if let Some(api) = api.clone() {
+                                    api.confirm(ModalConfig {
+                                        title: Some("保存更改？".into()),
+                                        // 在 future 中发起请求，完成前 OK 按钮保持 loading。
+                                        on_ok: Some(ModalOk::future(|| async { Ok(()) })),
+                                        ..Default::default()
+                                    });
+                                }
+                            }
+                        },
+                        "异步 on_ok"
+                    }
+                    Button {
+                        r#type: ButtonType::Default,
+                        onclick: {
+                            let api = modal.clone();
+                            move |_| {
+                                if let Some(api) = api.clone() {
+                                    api.destroy_all();
+                                }
+                            }
+                        },
+                        "调用 modal.destroy_all()"
                     }
                 }
             }
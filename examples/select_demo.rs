@@ -6,14 +6,17 @@
 //! - 分组选项
 //! - 禁用状态
 //! - 与 Form 集成
+//! - 远程搜索与滚动加载
 
 use adui_dioxus::{
-    Button, ButtonHtmlType, ButtonType, Form, FormItem, Select, SelectOption, ThemeMode,
-    ThemeProvider, Title, TitleLevel,
+    Button, ButtonHtmlType, ButtonType, Form, FormItem, RemotePage, RemoteQuery,
+    RemoteSearchFuture, Select, SelectMode, SelectOption, ThemeMode, ThemeProvider, Title,
+    TitleLevel,
     components::form::{FormFinishEvent, FormFinishFailedEvent, FormRule},
     use_form, use_theme,
 };
 use dioxus::prelude::*;
+use std::rc::Rc;
 
 fn main() {
     dioxus::launch(app);
@@ -88,6 +91,9 @@ fn SelectDemo() -> Element {
 
             // 与 Form 集成
             FormSelectSection {}
+
+            // 远程搜索
+            RemoteSelectSection {}
         }
     }
 }
@@ -233,6 +239,55 @@ fn FormSelectSection() -> Element {
 }
 
 // 统一的demo section组件
+/// 模拟的远程用户列表：每页 20 条，共 95 条。
+fn search_users(request: RemoteQuery) -> RemoteSearchFuture<SelectOption> {
+    Box::pin(async move {
+        const PAGE_SIZE: usize = 20;
+        let matches: Vec<SelectOption> = (1..=95)
+            .map(|id| SelectOption {
+                key: format!("user-{id}"),
+                label: format!("用户 {id:03}"),
+                disabled: false,
+            })
+            .filter(|opt| opt.label.contains(request.query.trim()))
+            .collect();
+        let start = (request.page - 1) * PAGE_SIZE;
+        let options: Vec<SelectOption> = matches
+            .iter()
+            .skip(start)
+            .take(PAGE_SIZE)
+            .cloned()
+            .collect();
+        Ok(RemotePage {
+            has_more: start + options.len() < matches.len(),
+            options,
+        })
+    })
+}
+
+#[component]
+fn RemoteSelectSection() -> Element {
+    let values = use_signal(Vec::<String>::new);
+
+    rsx! {
+        DemoSection {
+            title: "远程搜索（防抖 + 滚动加载 + 分隔符）",
+            Select {
+                mode: SelectMode::Multiple,
+                options: vec![],
+                values: Some(values.read().clone()),
+                remote_search: Some(Rc::new(search_users) as Rc<_>),
+                token_separators: Some(vec![",".to_string()]),
+                placeholder: Some("输入编号搜索用户，或粘贴以逗号分隔的用户名".into()),
+                on_change: {
+                    let mut sig = values;
+                    move |next: Vec<String>| sig.set(next)
+                },
+            }
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 struct DemoSectionProps {
     title: &'static str,
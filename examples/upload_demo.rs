@@ -1,5 +1,6 @@
 use adui_dioxus::{
-    Button, ButtonType, Space, SpaceDirection, ThemeProvider, Upload, UploadChangeInfo, UploadFile,
+    Button, ButtonType, ChunkedUploadConfig, ImageCropConfig, ImageResizeConfig, Space,
+    SpaceDirection, ThemeProvider, Upload, UploadChangeInfo, UploadDragger, UploadFile,
     UploadListConfig, UploadListType,
 };
 use dioxus::prelude::*;
@@ -56,10 +57,43 @@ fn UploadDemo() -> Element {
                 }
             }
 
-            h2 { "拖拽上传" }
+            h2 { "头像裁剪" }
+            Upload {
+                list_type: UploadListType::PictureCard,
+                accept: Some("image/*".to_string()),
+                max_count: Some(1),
+                image_crop: Some(ImageCropConfig::default()),
+                image_resize: Some(ImageResizeConfig {
+                    max_width: Some(256),
+                    max_height: Some(256),
+                    ..Default::default()
+                }),
+                show_upload_list: Some(UploadListConfig { show_remove_icon: true }),
+                "+ 上传头像"
+            }
+
+            h2 { "分片上传" }
             Upload {
-                dragger: true,
-                description: Some(rsx!(p { "支持点击或拖拽文件至区域，配合 dragger 样式展示。" })),
+                action: Some("/api/upload/chunks".to_string()),
+                multiple: true,
+                chunked: Some(ChunkedUploadConfig {
+                    chunk_size: 2 * 1024 * 1024,
+                    concurrency: 2,
+                    ..Default::default()
+                }),
+                show_upload_list: Some(UploadListConfig { show_remove_icon: true }),
+                description: Some(rsx!(p { "大文件按 2 MiB 分片上传，最多同时上传 2 个文件，可暂停/继续。" })),
+                Button {
+                    r#type: ButtonType::Default,
+                    "上传大文件"
+                }
+            }
+
+            h2 { "拖拽上传" }
+            UploadDragger {
+                multiple: true,
+                pastable: true,
+                description: Some(rsx!(p { "支持点击、拖拽文件或文件夹至区域，也可以直接粘贴截图。" })),
                 Space {
                     direction: SpaceDirection::Vertical,
                     style: "width:100%;align-items:center;padding:16px 0;",
@@ -102,7 +102,7 @@ pub fn Affix(props: AffixProps) -> Element {
     let fixed_id = use_signal(|| format!("adui-affix-fixed-{}", rand_id()));
 
     // Set up scroll/resize listeners
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         let placeholder_id_for_effect = placeholder_id.read().clone();
         let offset_top_val = effective_offset_top;
@@ -275,13 +275,13 @@ pub fn Affix(props: AffixProps) -> Element {
 /// Generate a simple random ID for element identification.
 fn rand_id() -> u32 {
     // Simple pseudo-random based on current time
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         use js_sys::Math;
         (Math::random() * 1_000_000.0) as u32
     }
 
-    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
     {
         use std::time::{SystemTime, UNIX_EPOCH};
         SystemTime::now()
@@ -209,7 +209,7 @@ pub fn Anchor(props: AnchorProps) -> Element {
     );
 
     // Set up scroll listener to update active link
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         let items_for_effect = items.clone();
         let target_offset_val = effective_target_offset;
@@ -451,13 +451,13 @@ fn AnchorLink(props: AnchorLinkProps) -> Element {
                     }
 
                     // Handle scroll to target
-                    #[cfg(target_arch = "wasm32")]
+                    #[cfg(all(feature = "web", target_arch = "wasm32"))]
                     {
                         handle_anchor_click(&href_for_click, replace_for_click);
                     }
 
                     // Silence warning for non-wasm targets
-                    #[cfg(not(target_arch = "wasm32"))]
+                    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
                     let _ = replace_for_click;
 
                     // Prevent default for internal anchors
@@ -513,7 +513,7 @@ fn collect_hrefs(items: &[AnchorLinkItem]) -> Vec<String> {
 }
 
 /// Get the currently active anchor based on scroll position.
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 fn get_internal_current_anchor(
     document: &web_sys::Document,
     hrefs: &[String],
@@ -551,7 +551,7 @@ fn get_internal_current_anchor(
 }
 
 /// Handle anchor click - scroll to target and update history.
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 fn handle_anchor_click(href: &str, replace: bool) {
     use wasm_bindgen::JsCast;
 
@@ -1,27 +1,12 @@
 use crate::components::message::{MessageApi, MessageHost, use_message_entries_provider};
+pub use crate::components::modal::ModalApi;
+use crate::components::modal::{ModalHost, use_modal_entries_provider};
 use crate::components::notification::{
-    NotificationApi, NotificationHost, use_notification_entries_provider,
+    NotificationApi, NotificationGlobalConfig, NotificationHost, use_notification_entries_provider,
 };
-use crate::components::overlay::{OverlayHandle, OverlayKind, use_overlay_provider};
+use crate::components::overlay::use_overlay_provider;
 use dioxus::prelude::*;
 
-/// Minimal modal API exposed by `App`.
-#[derive(Clone)]
-pub struct ModalApi {
-    overlay: OverlayHandle,
-}
-
-impl ModalApi {
-    pub fn new(overlay: OverlayHandle) -> Self {
-        Self { overlay }
-    }
-
-    /// Placeholder for future `confirm`/`open` helpers.
-    pub fn open(&self) {
-        let _ = self.overlay.open(OverlayKind::Modal, true);
-    }
-}
-
 /// Context value shared by `App` and consumed by `use_app` / `use_message` /
 /// `use_notification` / `use_modal`.
 #[derive(Clone, Default)]
@@ -38,6 +23,9 @@ pub struct AppProps {
     pub class: Option<String>,
     #[props(optional)]
     pub style: Option<String>,
+    /// App-wide notification options such as `max_count` and `stack`.
+    #[props(optional)]
+    pub notification: Option<NotificationGlobalConfig>,
     pub children: Element,
 }
 
@@ -48,20 +36,26 @@ pub fn App(props: AppProps) -> Element {
     let AppProps {
         class,
         style,
+        notification,
         children,
     } = props;
+    let notification_config = notification.unwrap_or_default();
 
     // Install a single overlay manager for this App subtree.
     let overlay = use_overlay_provider();
 
-    // Install per-App message & notification queues in context.
+    // Install per-App message, notification & modal queues in context.
     let message_entries = use_message_entries_provider();
     let notification_entries = use_notification_entries_provider();
+    let modal_entries = use_modal_entries_provider();
 
     let ctx = AppContextValue {
         message: Some(MessageApi::new(overlay.clone(), message_entries)),
-        notification: Some(NotificationApi::new(overlay.clone(), notification_entries)),
-        modal: Some(ModalApi::new(overlay)),
+        notification: Some(
+            NotificationApi::new(overlay.clone(), notification_entries)
+                .with_config(notification_config),
+        ),
+        modal: Some(ModalApi::new(overlay, modal_entries)),
     };
 
     use_context_provider(|| ctx.clone());
@@ -77,7 +71,8 @@ pub fn App(props: AppProps) -> Element {
         }
         // Global feedback hosts
         MessageHost {}
-        NotificationHost {}
+        NotificationHost { stack: notification_config.stack }
+        ModalHost {}
     }
 }
 
@@ -146,8 +141,8 @@ mod app_tests {
         // Note: Creating an actual instance requires runtime context with Signal
         // But we can verify the type structure and method signatures
         fn assert_modal_api_methods() {
-            // ModalApi::new takes OverlayHandle and returns ModalApi
-            // ModalApi::open takes &self and returns ()
+            // ModalApi::new takes OverlayHandle and ModalEntriesSignal
+            // ModalApi::confirm takes ModalConfig and returns ModalRef
             // These are verified by compilation
         }
         assert_modal_api_methods();
@@ -159,7 +154,8 @@ mod app_tests {
         // Note: Creating actual Element requires runtime context
         // But we can verify the structure
         fn assert_app_props_structure() {
-            // AppProps has optional class and style, and required children
+            // AppProps has optional class, style and notification config,
+            // and required children
             // This is verified by compilation
         }
         assert_app_props_structure();
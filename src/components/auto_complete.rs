@@ -1,8 +1,10 @@
 use crate::components::config_provider::{ComponentSize, use_config};
 use crate::components::control::{ControlStatus, push_status_class};
 use crate::components::form::{form_value_to_string, use_form_item_control};
+use crate::components::locale::use_locale;
 use crate::components::select_base::{
-    DropdownLayer, SelectOption, filter_options_by_query, use_dropdown_layer,
+    DropdownLayer, RemoteQuery, RemoteSearchFn, SelectOption, filter_options_by_query,
+    same_remote_search, use_dropdown_layer, use_remote_search,
 };
 use dioxus::events::KeyboardEvent;
 use dioxus::prelude::*;
@@ -13,8 +15,9 @@ use dioxus::prelude::*;
 /// - 基于 Input 的受控/非受控输入；
 /// - 本地 options 过滤；
 /// - 选择候选项时将其 label 写回输入框，并触发 on_select/on_change；
-/// - 与 Form 集成时，直接以字符串形式读写字段值。
-#[derive(Props, Clone, PartialEq)]
+/// - 与 Form 集成时，直接以字符串形式读写字段值；
+/// - 通过 `remote_search` 远程加载候选项（防抖、丢弃过期请求、滚动到底部加载下一页）。
+#[derive(Props, Clone)]
 pub struct AutoCompleteProps {
     /// 候选列表（建议使用较小集合），使用 SelectOption 复用模型。
     #[props(optional)]
@@ -53,14 +56,55 @@ pub struct AutoCompleteProps {
     /// 输入变化回调（Form 场景下也会在写回字段后触发）。
     #[props(optional)]
     pub on_change: Option<EventHandler<String>>,
-    /// 输入变化时的搜索回调，可用于外部异步更新 options。
+    /// 输入变化时的搜索回调，可用于外部异步更新 options；在 `search_debounce` 之后触发。
     #[props(optional)]
     pub on_search: Option<EventHandler<String>>,
+    /// 最后一次输入到触发 `on_search` / `remote_search` 的间隔（毫秒）。
+    /// 设置了 `remote_search` 时默认 300，否则默认 0。
+    #[props(optional)]
+    pub search_debounce: Option<u32>,
+    /// 远程候选项来源，设置后不再在本地过滤 `options`。
+    #[props(optional)]
+    pub remote_search: Option<RemoteSearchFn<SelectOption>>,
+    /// 在弹层中显示加载状态，适用于通过 `on_search` 自行更新 options 的场景。
+    #[props(default)]
+    pub loading: bool,
+    /// 无匹配项时显示的内容；远程模式下默认使用 locale 中的空状态文案。
+    #[props(optional)]
+    pub not_found_content: Option<Element>,
+    /// 候选列表滚动时触发。
+    #[props(optional)]
+    pub on_popup_scroll: Option<EventHandler<ScrollEvent>>,
     /// 选择某个候选项时触发，参数为该项的 key。
     #[props(optional)]
     pub on_select: Option<EventHandler<String>>,
 }
 
+impl PartialEq for AutoCompleteProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.options == other.options
+            && self.value == other.value
+            && self.default_value == other.default_value
+            && self.placeholder == other.placeholder
+            && self.allow_clear == other.allow_clear
+            && self.disabled == other.disabled
+            && self.status == other.status
+            && self.size == other.size
+            && self.class == other.class
+            && self.style == other.style
+            && self.dropdown_class == other.dropdown_class
+            && self.dropdown_style == other.dropdown_style
+            && self.on_change == other.on_change
+            && self.on_search == other.on_search
+            && self.on_select == other.on_select
+            && self.search_debounce == other.search_debounce
+            && self.loading == other.loading
+            && self.not_found_content == other.not_found_content
+            && self.on_popup_scroll == other.on_popup_scroll
+            && same_remote_search(&self.remote_search, &other.remote_search)
+    }
+}
+
 /// Ant Design flavored AutoComplete (MVP).
 #[component]
 pub fn AutoComplete(props: AutoCompleteProps) -> Element {
@@ -80,10 +124,17 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
         on_change,
         on_search,
         on_select,
+        search_debounce,
+        remote_search,
+        loading,
+        not_found_content,
+        on_popup_scroll,
     } = props;
 
     let config = use_config();
+    let locale = use_locale();
     let form_control = use_form_item_control();
+    let remote = use_remote_search(remote_search, search_debounce);
 
     let final_size = size.unwrap_or(config.size);
 
@@ -111,7 +162,7 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
     let open_state: Signal<bool> = use_signal(|| false);
     let internal_click_flag: Signal<bool> = use_signal(|| false);
 
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         let mut open_for_global = open_state;
         let mut internal_flag = internal_click_flag;
@@ -146,13 +197,18 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
 
     let placeholder_str = placeholder.unwrap_or_default();
 
+    // 远程模式下候选项在输入后才会到达，因此同样允许打开弹层。
     let has_any_options = options
         .as_ref()
         .map(|opts| !opts.is_empty())
-        .unwrap_or(false);
-
-    // Filter options by current input value.
-    let filtered_options: Vec<SelectOption> = if let Some(opts) = options.as_ref() {
+        .unwrap_or(false)
+        || remote.enabled()
+        || loading;
+
+    // Filter options by current input value; remote results are used as-is.
+    let filtered_options: Vec<SelectOption> = if remote.enabled() {
+        remote.options()
+    } else if let Some(opts) = options.as_ref() {
         if current_value.is_empty() {
             opts.clone()
         } else {
@@ -162,6 +218,13 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
         Vec::new()
     };
 
+    let list_loading = loading || remote.loading();
+    let remote_error = remote.error();
+    let show_not_found =
+        filtered_options.is_empty() && (remote.not_found() || not_found_content.is_some());
+    let show_popup =
+        !filtered_options.is_empty() || list_loading || remote_error.is_some() || show_not_found;
+
     let open_flag = *open_state.read();
     let DropdownLayer { z_index, .. } = use_dropdown_layer(open_flag);
     let current_z = *z_index.read();
@@ -219,9 +282,11 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
         if let Some(cb) = on_change_cb {
             cb.call(next.clone());
         }
-        if let Some(cb) = on_search_cb {
-            cb.call(next);
-        }
+        remote.search(next, None, move |request: &RemoteQuery| {
+            if let Some(cb) = on_search_cb {
+                cb.call(request.query.clone());
+            }
+        });
     };
 
     rsx! {
@@ -306,11 +371,17 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
                     }
                 }
             }
-            if open_flag && !filtered_options.is_empty() {
+            if open_flag && show_popup {
                 div {
                     class: "{dropdown_class_attr}",
                     style: "{dropdown_style_attr}",
                     role: "listbox",
+                    onscroll: move |evt| {
+                        remote.handle_scroll(&evt);
+                        if let Some(cb) = on_popup_scroll {
+                            cb.call(evt);
+                        }
+                    },
                     ul { class: "adui-select-item-list",
                         {filtered_options.iter().map(|opt| {
                             let key = opt.key.clone();
@@ -359,6 +430,15 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
                             }
                         })}
                     }
+                    if list_loading {
+                        div { class: "adui-select-item-loading", "{locale.global.loading}" }
+                    } else if let Some(message) = remote_error {
+                        div { class: "adui-select-item-empty adui-select-item-error", "{message}" }
+                    } else if show_not_found {
+                        div { class: "adui-select-item-empty",
+                            {not_found_content.unwrap_or_else(|| rsx! { "{locale.empty.description}" })}
+                        }
+                    }
                 }
             }
         }
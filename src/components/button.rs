@@ -477,7 +477,7 @@ pub fn Button(props: ButtonProps) -> Element {
     let button_id = use_signal(|| format!("adui-btn-{}", rand_id()));
 
     // Set data-* attributes via JavaScript interop if provided
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         if let Some(data_attrs) = data_attributes.as_ref() {
             let id = button_id.read().clone();
@@ -499,7 +499,7 @@ pub fn Button(props: ButtonProps) -> Element {
             }
         }
     }
-    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
     {
         // Suppress unused variable warning on non-wasm32 targets
         let _ = data_attributes;
@@ -667,6 +667,11 @@ fn solid_visuals(tokens: &ThemeTokens, color: ButtonColor, ghost: bool) -> Butto
         | ButtonColor::Warning
         | ButtonColor::Danger => {
             let (accent, hover, active) = tone_palette(tokens, color);
+            let shadow_token = if color == ButtonColor::Danger {
+                "danger-shadow"
+            } else {
+                "primary-shadow"
+            };
             ButtonVisuals {
                 bg: accent.clone(),
                 bg_hover: hover.clone(),
@@ -678,22 +683,22 @@ fn solid_visuals(tokens: &ThemeTokens, color: ButtonColor, ghost: bool) -> Butto
                 border_hover: hover.clone(),
                 border_active: active.clone(),
                 border_style: "solid".into(),
-                shadow: tokens.shadow.clone(),
+                shadow: button_token_var(shadow_token, &tokens.shadow),
                 focus_shadow: focus_ring(color, "0 0 0 2px rgba(22, 119, 255, 0.28)"),
             }
         }
         ButtonColor::Default => ButtonVisuals {
-            bg: tokens.color_bg_container.clone(),
+            bg: button_token_var("default-bg", &tokens.color_bg_container),
             bg_hover: tokens.color_bg_base.clone(),
             bg_active: tokens.color_bg_base.clone(),
-            color: tokens.color_text.clone(),
-            color_hover: tokens.color_text.clone(),
-            color_active: tokens.color_text.clone(),
-            border: tokens.color_border.clone(),
+            color: button_token_var("default-color", &tokens.color_text),
+            color_hover: button_token_var("default-color", &tokens.color_text),
+            color_active: button_token_var("default-color", &tokens.color_text),
+            border: button_token_var("default-border-color", &tokens.color_border),
             border_hover: tokens.color_border_hover.clone(),
             border_active: tokens.color_border_hover.clone(),
             border_style: "solid".into(),
-            shadow: tokens.shadow.clone(),
+            shadow: button_token_var("default-shadow", &tokens.shadow),
             focus_shadow: "0 0 0 2px rgba(0, 0, 0, 0.08)".into(),
         },
     };
@@ -772,13 +777,13 @@ fn outline_visuals(
 ) -> ButtonVisuals {
     let mut visuals = match color {
         ButtonColor::Default => ButtonVisuals {
-            bg: tokens.color_bg_container.clone(),
-            bg_hover: tokens.color_bg_container.clone(),
-            bg_active: tokens.color_bg_container.clone(),
-            color: tokens.color_text.clone(),
+            bg: button_token_var("default-bg", &tokens.color_bg_container),
+            bg_hover: button_token_var("default-bg", &tokens.color_bg_container),
+            bg_active: button_token_var("default-bg", &tokens.color_bg_container),
+            color: button_token_var("default-color", &tokens.color_text),
             color_hover: tokens.color_primary.clone(),
             color_active: tokens.color_primary_active.clone(),
-            border: tokens.color_border.clone(),
+            border: button_token_var("default-border-color", &tokens.color_border),
             border_hover: tokens.color_primary.clone(),
             border_active: tokens.color_primary_active.clone(),
             border_style: if dashed {
@@ -821,6 +826,12 @@ fn outline_visuals(
     visuals
 }
 
+/// Reference a `--adui-button-*` component token, falling back to the value
+/// derived from global tokens.
+fn button_token_var(name: &str, fallback: &str) -> String {
+    format!("var(--adui-button-{name}, {fallback})")
+}
+
 fn tone_palette(tokens: &ThemeTokens, color: ButtonColor) -> (String, String, String) {
     match color {
         ButtonColor::Primary => (
@@ -864,13 +875,13 @@ fn focus_ring(color: ButtonColor, fallback: &str) -> String {
 /// Generate a simple random ID for element identification.
 fn rand_id() -> u32 {
     // Simple pseudo-random based on current time
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         use js_sys::Math;
         (Math::random() * 1_000_000.0) as u32
     }
 
-    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
     {
         use std::time::{SystemTime, UNIX_EPOCH};
         SystemTime::now()
@@ -1095,6 +1106,24 @@ mod tests {
         assert_ne!(default.color, "#ffffff");
     }
 
+    #[test]
+    fn visuals_reference_component_tokens() {
+        let tokens = ThemeTokens::light();
+        let primary = visuals(&tokens, ButtonVariant::Solid, ButtonColor::Primary, false);
+        assert_eq!(
+            primary.shadow,
+            format!("var(--adui-button-primary-shadow, {})", tokens.shadow)
+        );
+        let danger = visuals(&tokens, ButtonVariant::Solid, ButtonColor::Danger, false);
+        assert!(
+            danger
+                .shadow
+                .starts_with("var(--adui-button-danger-shadow,")
+        );
+        let default = visuals(&tokens, ButtonVariant::Solid, ButtonColor::Default, false);
+        assert!(default.bg.starts_with("var(--adui-button-default-bg,"));
+    }
+
     #[test]
     fn visuals_text_variant() {
         let tokens = ThemeTokens::light();
@@ -1,8 +1,10 @@
 use dioxus::events::MouseEvent;
 use dioxus::prelude::*;
-use time::{Date, Month};
+use time::{Date, Month, UtcOffset};
 
-use crate::components::config_provider::{Locale, use_config};
+use crate::components::date_panel::{PanelView, PickerMode, panel_cells};
+use crate::components::date_picker::DateValue;
+use crate::components::locale::use_locale;
 
 /// Internal value type for Calendar (date without time).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -39,6 +41,16 @@ pub enum CalendarMode {
     Year,
 }
 
+/// The panel a calendar mode shows: days in `Month` mode, months in `Year`.
+impl From<CalendarMode> for PickerMode {
+    fn from(mode: CalendarMode) -> Self {
+        match mode {
+            CalendarMode::Month => PickerMode::Date,
+            CalendarMode::Year => PickerMode::Month,
+        }
+    }
+}
+
 /// Props for the Calendar component (MVP subset).
 #[derive(Props, Clone, PartialEq)]
 pub struct CalendarProps {
@@ -66,6 +78,10 @@ pub struct CalendarProps {
     /// Inline style for the root element.
     #[props(optional)]
     pub style: Option<String>,
+    /// Timezone of "today": when set, the calendar opens on today and
+    /// highlights it.
+    #[props(optional)]
+    pub utc_offset: Option<UtcOffset>,
 }
 
 pub(crate) fn days_in_month(year: i32, month: u8) -> u8 {
@@ -106,40 +122,36 @@ pub fn Calendar(props: CalendarProps) -> Element {
         on_panel_change,
         class,
         style,
+        utc_offset,
     } = props;
 
-    let config = use_config();
-    let locale = config.locale;
+    let locale = use_locale();
 
-    let initial_date = value.or(default_value).unwrap_or_else(|| {
-        // Fixed reference date to keep behaviour deterministic across
-        // environments without relying on system time.
-        CalendarDate::from_ymd(2024, 1, 1).expect("valid date")
-    });
+    let today = utc_offset.map(DateValue::today);
+    let initial_date = value
+        .or(default_value)
+        .or(today.map(|t| CalendarDate { inner: t.inner }))
+        .unwrap_or_else(|| {
+            // Without `utc_offset`, a fixed reference date keeps behaviour
+            // deterministic across environments.
+            CalendarDate::from_ymd(2024, 1, 1).expect("valid date")
+        });
 
     let selected: Signal<CalendarDate> = use_signal(|| initial_date);
     let current_mode: Signal<CalendarMode> = use_signal(|| mode.unwrap_or_default());
 
-    let view_year: Signal<i32> = use_signal(|| initial_date.year());
-    let view_month: Signal<u8> = use_signal(|| initial_date.month());
-
-    // For month view.
-    let year_now = *view_year.read();
-    let month_now = *view_month.read();
-    let days_in_month_now = days_in_month(year_now, month_now);
-    let first_weekday = weekday_index_monday(year_now, month_now, 1) as usize;
-    let total_cells = first_weekday + days_in_month_now as usize;
-    let padded_cells = total_cells.div_ceil(7) * 7;
-
-    let locale_for_header = locale;
-    let header_label = match locale_for_header {
-        Locale::ZhCN => format!("{year_now}年{month_now}月"),
-        Locale::EnUS => format!("{year_now}-{month_now:02}"),
-    };
-    let weekday_labels: [&str; 7] = match locale_for_header {
-        Locale::ZhCN => ["一", "二", "三", "四", "五", "六", "日"],
-        Locale::EnUS => ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
-    };
+    let view: Signal<PanelView> = use_signal(|| PanelView {
+        year: initial_date.year(),
+        month: initial_date.month(),
+    });
+
+    // A controlled `mode` wins over the internal one.
+    let mode_now = mode.unwrap_or(*current_mode.read());
+    let panel_mode = PickerMode::from(mode_now);
+    let view_now = *view.read();
+
+    let header_label = view_now.title(panel_mode, &locale.date_picker);
+    let weekday_labels = locale.date_picker.weekday_labels();
 
     let fullscreen_flag = fullscreen.unwrap_or(false);
 
@@ -152,47 +164,45 @@ pub fn Calendar(props: CalendarProps) -> Element {
     }
     let class_attr = class_list.join(" ");
     let style_attr = style.unwrap_or_default();
+    let body_class = match mode_now {
+        CalendarMode::Month => "adui-calendar-body",
+        CalendarMode::Year => "adui-calendar-body adui-calendar-body-year",
+    };
 
-    let mode_signal = current_mode;
     let on_panel_change_cb = on_panel_change;
 
     let selected_for_click = selected;
     let on_select_cb = on_select;
 
-    // Build date cells for month mode.
+    // Build the cells: days in month mode, months in year mode.
     let mut date_cells: Vec<Element> = Vec::new();
     let selected_now = *selected_for_click.read();
+    let selected_period = panel_mode.normalize(DateValue {
+        inner: selected_now.inner,
+    });
 
-    for index in 0..padded_cells {
-        let cell_day =
-            if index < first_weekday || index >= first_weekday + days_in_month_now as usize {
-                None
-            } else {
-                Some((index - first_weekday + 1) as u8)
-            };
-
-        let is_outside = cell_day.is_none();
+    for cell in panel_cells(panel_mode, view_now, &locale.date_picker) {
+        let cell_value = cell.value;
         let mut cell_classes = vec!["adui-calendar-date".to_string()];
-        if is_outside {
+        if cell_value.is_none() {
             cell_classes.push("adui-calendar-date-empty".to_string());
         } else {
             cell_classes.push("adui-calendar-date-cell".to_string());
         }
-        if let (Some(day), CalendarDate { inner }) = (cell_day, selected_now)
-            && inner.year() == year_now
-            && inner.month() as u8 == month_now
-            && inner.day() == day
-        {
+        if cell_value.is_some() && cell_value == today.map(|t| panel_mode.normalize(t)) {
+            cell_classes.push("adui-calendar-date-today".to_string());
+        }
+        if cell_value == Some(selected_period) {
             cell_classes.push("adui-calendar-date-selected".to_string());
         }
         let cell_class_attr = cell_classes.join(" ");
+        let label = cell.label;
 
         let on_click_day = {
             let mut selected_state = selected_for_click;
             move |_: MouseEvent| {
-                if let Some(day) = cell_day
-                    && let Some(date) = CalendarDate::from_ymd(year_now, month_now, day)
-                {
+                if let Some(value) = cell_value {
+                    let date = CalendarDate { inner: value.inner };
                     selected_state.set(date);
                     if let Some(cb) = on_select_cb {
                         cb.call(date);
@@ -205,69 +215,30 @@ pub fn Calendar(props: CalendarProps) -> Element {
             div {
                 class: "{cell_class_attr}",
                 onclick: on_click_day,
-                match cell_day {
-                    Some(day) => rsx!{ span { class: "adui-calendar-date-value", "{day}" } },
-                    None => rsx!{ span { class: "adui-calendar-date-value", "" } },
-                }
+                span { class: "adui-calendar-date-value", "{label}" }
             }
         };
 
         date_cells.push(cell_node);
     }
 
-    let on_prev_month = {
-        let mut vy = view_year;
-        let mut vm = view_month;
-        move |_| {
-            if *mode_signal.read() != CalendarMode::Month {
-                return;
-            }
-            let mut year = *vy.read();
-            let mut month = *vm.read();
-            if month == 1 {
-                month = 12;
-                year -= 1;
-            } else {
-                month -= 1;
-            }
-            vy.set(year);
-            vm.set(month);
-            if let Some(cb) = on_panel_change_cb
-                && let Some(date) = CalendarDate::from_ymd(year, month, 1)
-            {
-                cb.call((date, CalendarMode::Month));
-            }
-        }
-    };
-
-    let on_next_month = {
-        let mut vy = view_year;
-        let mut vm = view_month;
-        move |_| {
-            if *mode_signal.read() != CalendarMode::Month {
-                return;
-            }
-            let mut year = *vy.read();
-            let mut month = *vm.read();
-            if month == 12 {
-                month = 1;
-                year += 1;
-            } else {
-                month += 1;
-            }
-            vy.set(year);
-            vm.set(month);
-            if let Some(cb) = on_panel_change_cb
-                && let Some(date) = CalendarDate::from_ymd(year, month, 1)
-            {
-                cb.call((date, CalendarMode::Month));
-            }
+    // Month mode pages by month, year mode by year.
+    let shift_view = move |steps: i32| {
+        let mut view = view;
+        let next = view.read().shift(panel_mode, steps);
+        view.set(next);
+        if let Some(cb) = on_panel_change_cb
+            && let Some(date) = CalendarDate::from_ymd(next.year, next.month, 1)
+        {
+            cb.call((date, mode_now));
         }
     };
+    let on_prev_month = move |_: MouseEvent| shift_view(-1);
+    let on_next_month = move |_: MouseEvent| shift_view(1);
 
     rsx! {
         div { class: "{class_attr}", style: "{style_attr}",
-            // Header with month navigation for month mode.
+            // Header with month / year navigation.
             div { class: "adui-calendar-header",
                 button { class: "adui-calendar-nav-btn", onclick: on_prev_month, "<" }
                 span { class: "adui-calendar-header-view", "{header_label}" }
@@ -275,14 +246,16 @@ pub fn Calendar(props: CalendarProps) -> Element {
             }
 
             // Weekday header.
-            div { class: "adui-calendar-week-row",
-                for label in weekday_labels {
-                    span { class: "adui-calendar-week-cell", "{label}" }
+            if mode_now == CalendarMode::Month {
+                div { class: "adui-calendar-week-row",
+                    for label in weekday_labels {
+                        span { class: "adui-calendar-week-cell", "{label}" }
+                    }
                 }
             }
 
-            // Date grid.
-            div { class: "adui-calendar-body",
+            // Date or month grid.
+            div { class: "{body_class}",
                 for cell in date_cells { {cell} }
             }
         }
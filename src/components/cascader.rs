@@ -1,8 +1,10 @@
 use crate::components::config_provider::{ComponentSize, use_config};
 use crate::components::control::{ControlStatus, push_status_class};
 use crate::components::form::{FormItemControlContext, use_form_item_control};
+use crate::components::icon::{Icon, IconKind};
 use crate::components::select_base::{
-    CascaderNode, DropdownLayer, OptionKey, path_to_value, use_dropdown_layer, value_to_path,
+    CascaderNode, DropdownLayer, LoadDataFn, NodeLoadStatus, OptionKey, path_to_value,
+    use_dropdown_layer, use_lazy_node_loader, value_to_path,
 };
 use dioxus::events::KeyboardEvent;
 use dioxus::prelude::*;
@@ -12,8 +14,9 @@ use serde_json::Value;
 ///
 /// 当前版本重点支持单选路径，不实现多选逻辑：
 /// - `value` 表示选中的路径，如 `Some(vec!["zhejiang", "hangzhou"])`；
-/// - `multiple` 字段预留，暂未生效（仅作为将来扩展的 API 占位）。
-#[derive(Props, Clone, PartialEq)]
+/// - `multiple` 字段预留，暂未生效（仅作为将来扩展的 API 占位）；
+/// - `load_data` 用于异步加载无子节点选项的下一级（与 Tree / TreeSelect 共用加载机制）。
+#[derive(Props, Clone)]
 pub struct CascaderProps {
     /// 级联选项树。
     pub options: Vec<CascaderNode>,
@@ -51,6 +54,37 @@ pub struct CascaderProps {
     /// 选中路径变更回调。
     #[props(optional)]
     pub on_change: Option<EventHandler<Vec<String>>>,
+    /// 异步加载选项的下一级，点击尚未加载的无子节点选项时触发。
+    #[props(optional)]
+    pub load_data: Option<LoadDataFn>,
+    /// 受控的已加载选项 key 集合。
+    #[props(optional)]
+    pub loaded_keys: Option<Vec<String>>,
+    /// `load_data` 成功后回调全部已加载 key。
+    #[props(optional)]
+    pub on_load: Option<EventHandler<Vec<String>>>,
+}
+
+impl PartialEq for CascaderProps {
+    fn eq(&self, other: &Self) -> bool {
+        // Compare all fields except function pointers
+        self.options == other.options
+            && self.value == other.value
+            && self.multiple == other.multiple
+            && self.placeholder == other.placeholder
+            && self.allow_clear == other.allow_clear
+            && self.disabled == other.disabled
+            && self.status == other.status
+            && self.size == other.size
+            && self.class == other.class
+            && self.style == other.style
+            && self.dropdown_class == other.dropdown_class
+            && self.dropdown_style == other.dropdown_style
+            && self.on_change == other.on_change
+            && self.loaded_keys == other.loaded_keys
+            && self.on_load == other.on_load
+        // Function pointers cannot be compared for equality
+    }
 }
 
 /// Ant Design flavored Cascader (MVP).
@@ -70,6 +104,9 @@ pub fn Cascader(props: CascaderProps) -> Element {
         dropdown_class,
         dropdown_style,
         on_change,
+        load_data,
+        loaded_keys,
+        on_load,
     } = props;
 
     // `multiple` 目前只作为占位参数，尚未在内部实现多选逻辑。
@@ -78,6 +115,10 @@ pub fn Cascader(props: CascaderProps) -> Element {
     let config = use_config();
     let form_control = use_form_item_control();
 
+    // 异步加载的子级会被合并进 options。
+    let loader = use_lazy_node_loader(load_data, loaded_keys, on_load);
+    let options = loader.merge(&options);
+
     let final_size = size.unwrap_or(config.size);
 
     let is_disabled =
@@ -112,7 +153,7 @@ pub fn Cascader(props: CascaderProps) -> Element {
     let internal_click_flag: Signal<bool> = use_signal(|| false);
 
     // document 级别点击监听，仅 wasm 目标生效。
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         let mut open_for_global = open_state;
         let mut internal_flag = internal_click_flag;
@@ -365,6 +406,15 @@ pub fn Cascader(props: CascaderProps) -> Element {
                                         let label = node.label.clone();
                                         let disabled_opt = node.disabled || is_disabled_flag;
                                         let has_children = !node.children.is_empty();
+                                        // 无子节点但尚未加载时，点击触发 load_data 而非提交。
+                                        let lazy = !has_children && loader.can_load(&key);
+                                        let load_status = if lazy { loader.status(&key) } else { None };
+                                        let is_loading = load_status == Some(NodeLoadStatus::Loading);
+                                        let load_error = match &load_status {
+                                            Some(NodeLoadStatus::Failed(message)) => Some(message.clone()),
+                                            _ => None,
+                                        };
+                                        let node_for_load = node.clone();
 
                                         let is_in_path = ui_path_snapshot_col
                                             .get(col_index)
@@ -420,6 +470,12 @@ pub fn Cascader(props: CascaderProps) -> Element {
                                                         return;
                                                     }
 
+                                                    // 尚未加载的节点：发起（或重试）加载，完成后展开下一列。
+                                                    if lazy {
+                                                        loader.load(&node_for_load);
+                                                        return;
+                                                    }
+
                                                     // 叶子节点：提交选中路径。
                                                     apply_selected_path(
                                                         &form_for_click_item,
@@ -433,7 +489,21 @@ pub fn Cascader(props: CascaderProps) -> Element {
                                                     let mut open_signal = open_for_click_col;
                                                     open_signal.set(false);
                                                 },
-                                                "{label}"
+                                                "aria-busy": is_loading,
+                                                span { class: "adui-cascader-menu-item-content", "{label}" }
+                                                if is_loading {
+                                                    span { class: "adui-cascader-menu-item-loading-icon",
+                                                        Icon { kind: IconKind::Loading, size: 12.0 }
+                                                    }
+                                                } else if let Some(message) = load_error.clone() {
+                                                    span {
+                                                        class: "adui-cascader-menu-item-error-icon",
+                                                        title: "{message}",
+                                                        "!"
+                                                    }
+                                                } else if has_children || lazy {
+                                                    span { class: "adui-cascader-menu-item-expand-icon", "›" }
+                                                }
                                             }
                                         }
                                     })}
@@ -1,12 +1,13 @@
 use crate::components::config_provider::use_config;
 use crate::components::form::use_form_item_control;
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use crate::components::interaction::as_pointer_event;
+use crate::components::locale::use_locale;
 use dioxus::events::PointerData;
 use dioxus::prelude::*;
 use serde_json::Value;
 use std::rc::Rc;
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use wasm_bindgen::JsCast;
 
 /// HSVA color model for internal state.
@@ -57,6 +58,7 @@ pub fn ColorPicker(props: ColorPickerProps) -> Element {
     } = props;
 
     let config = use_config();
+    let locale = use_locale();
     let form_control = use_form_item_control();
     let controlled = value.is_some();
 
@@ -302,7 +304,7 @@ pub fn ColorPicker(props: ColorPickerProps) -> Element {
                         oninput: handle_input,
                     }
                     if allow_clear {
-                        button { class: "adui-color-picker-clear", disabled: is_disabled, onclick: handle_clear, "Clear" }
+                        button { class: "adui-color-picker-clear", disabled: is_disabled, onclick: handle_clear, "{locale.color_picker.clear}" }
                     }
                 }
             }
@@ -1,3 +1,6 @@
+use std::rc::Rc;
+
+use crate::components::locale::{LocaleBundle, LocaleContext, find_locale_bundle};
 use crate::theme::{Theme, ThemeProvider, ThemeTokens};
 use dioxus::prelude::*;
 
@@ -10,7 +13,7 @@ pub enum ComponentSize {
     Large,
 }
 
-/// Simple locale flag for components that need basic language switching.
+/// Locale flag selecting one of the built-in message catalogs.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum Locale {
     /// Simplified Chinese.
@@ -18,6 +21,58 @@ pub enum Locale {
     ZhCN,
     /// English (US).
     EnUS,
+    /// Japanese.
+    JaJP,
+    /// German.
+    DeDE,
+    /// French.
+    FrFR,
+    /// Spanish.
+    EsES,
+    /// Korean.
+    KoKR,
+    /// Traditional Chinese (Taiwan).
+    ZhTW,
+}
+
+impl Locale {
+    /// BCP 47 code of this locale, e.g. `"ja-JP"`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::ZhCN => "zh-CN",
+            Locale::EnUS => "en-US",
+            Locale::JaJP => "ja-JP",
+            Locale::DeDE => "de-DE",
+            Locale::FrFR => "fr-FR",
+            Locale::EsES => "es-ES",
+            Locale::KoKR => "ko-KR",
+            Locale::ZhTW => "zh-TW",
+        }
+    }
+
+    /// Parse a locale code. Matching is case-insensitive and accepts `_` as
+    /// separator (`"ja_jp"`).
+    pub fn from_code(code: &str) -> Option<Locale> {
+        let normalized = code.trim().replace('_', "-").to_ascii_lowercase();
+        [
+            Locale::ZhCN,
+            Locale::EnUS,
+            Locale::JaJP,
+            Locale::DeDE,
+            Locale::FrFR,
+            Locale::EsES,
+            Locale::KoKR,
+            Locale::ZhTW,
+        ]
+        .into_iter()
+        .find(|locale| locale.code().to_ascii_lowercase() == normalized)
+    }
+
+    /// Message catalog for this locale. Bundles registered via
+    /// `register_locale_bundle` under the same code take precedence.
+    pub fn bundle(self) -> Rc<LocaleBundle> {
+        find_locale_bundle(self.code()).unwrap_or_default()
+    }
 }
 
 /// Global configuration shared by components.
@@ -60,6 +115,14 @@ pub struct ConfigProviderProps {
     /// integrate with date/time or other text-heavy features.
     #[props(optional)]
     pub locale: Option<Locale>,
+    /// Full message catalog for descendants. Takes precedence over the
+    /// bundle selected by `locale`; use it for custom or registered locales.
+    #[props(optional)]
+    pub locale_bundle: Option<LocaleBundle>,
+    /// First day of the week for date panels, Sunday = 0 .. Saturday = 6.
+    /// Overrides `week_start` of the resolved locale bundle.
+    #[props(optional)]
+    pub week_start: Option<u8>,
     /// Optional initial theme. If omitted, the current ThemeProvider behaviour
     /// is preserved.
     #[props(optional)]
@@ -85,6 +148,35 @@ pub fn ConfigProvider(props: ConfigProviderProps) -> Element {
     // above, it will simply override this one.
     use_context_provider(|| value.clone());
 
+    // Locale bundle: explicit prop, then the `locale` flag, then the parent's
+    // bundle so nested providers keep an inherited custom catalog.
+    let parent_locale = try_use_context::<LocaleContext>();
+    let resolved = match (props.locale_bundle.clone(), props.locale, parent_locale) {
+        (Some(bundle), _, _) => Rc::new(bundle),
+        (None, Some(locale), _) => locale.bundle(),
+        (None, None, Some(ctx)) => ctx.bundle.read().clone(),
+        (None, None, None) => value.locale.bundle(),
+    };
+    let resolved = match props.week_start {
+        Some(week_start) if week_start != resolved.date_picker.week_start => {
+            let mut bundle = (*resolved).clone();
+            bundle.date_picker.week_start = week_start % 7;
+            Rc::new(bundle)
+        }
+        _ => resolved,
+    };
+    let locale_ctx = use_context_provider(|| LocaleContext {
+        bundle: Signal::new(resolved.clone()),
+    });
+    // Later changes reach descendants from an effect; writing the signal
+    // while rendering would re-render subscribers mid-pass.
+    use_effect(use_reactive!(|resolved| {
+        let mut bundle = locale_ctx.bundle;
+        if *bundle.peek() != resolved {
+            bundle.set(resolved);
+        }
+    }));
+
     rsx! {
         ThemeProvider { theme: props.theme.clone(), {props.children} }
     }
@@ -146,6 +238,13 @@ mod tests {
         assert_eq!(Locale::ZhCN, Locale::ZhCN);
         assert_eq!(Locale::EnUS, Locale::EnUS);
         assert_ne!(Locale::ZhCN, Locale::EnUS);
+        assert_ne!(Locale::JaJP, Locale::ZhTW);
+    }
+
+    #[test]
+    fn locale_bundle_matches_code() {
+        assert_eq!(Locale::KoKR.bundle().locale, "ko-KR");
+        assert_eq!(Locale::ZhTW.bundle().modal.ok_text, "確定");
     }
 
     #[test]
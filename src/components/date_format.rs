@@ -0,0 +1,498 @@
+//! Token-based date/time formatting and parsing for the pickers.
+//!
+//! Patterns follow the dayjs tokens used by Ant Design:
+//!
+//! | Token | Output |
+//! |-------|--------|
+//! | `YYYY` / `YY` | 4-digit / 2-digit year |
+//! | `gggg` / `GGGG` | Week-numbering year of `w` / of `W` |
+//! | `Q` | Quarter, 1-4 |
+//! | `M` / `MM` / `MMM` | Month, 1-12 / 01-12 / locale name |
+//! | `D` / `DD` / `Do` | Day of month, 1-31 / 01-31 / locale ordinal |
+//! | `d` / `dd` / `ddd` | Weekday, 0 (Sunday)-6 / short name / name |
+//! | `w` / `ww` / `wo` | Locale week number / zero padded / locale ordinal |
+//! | `W` / `WW` | ISO week number / zero padded |
+//! | `H` / `HH` | Hour, 0-23 / 00-23 |
+//! | `h` / `hh` | Hour, 1-12 / 01-12 |
+//! | `m` / `mm`, `s` / `ss` | Minute and second |
+//! | `A` / `a` | Locale meridiem / lowercase meridiem |
+//!
+//! Locale weeks start on the locale's `week_start` and are numbered by its
+//! `week_min_days` (see [`WeekRule`]); with the default Monday / 4 they are
+//! ISO weeks. Text inside `[...]` is copied verbatim. Names come from the
+//! [`DatePickerLocale`] of the active `ConfigProvider`, so the functions here
+//! are pure and can be tested without a DOM.
+
+use crate::components::date_panel::WeekRule;
+use crate::components::date_picker::DateValue;
+use crate::components::locale::DatePickerLocale;
+use crate::components::time_picker::TimeValue;
+
+/// Default pattern of `DatePicker` and `RangePicker`.
+pub const DEFAULT_DATE_FORMAT: &str = "YYYY-MM-DD";
+/// Default pattern of `TimePicker`.
+pub const DEFAULT_TIME_FORMAT: &str = "HH:mm:ss";
+
+const TOKENS: [&str; 29] = [
+    "YYYY", "GGGG", "gggg", "MMM", "ddd", "YY", "MM", "DD", "Do", "dd", "HH", "hh", "mm", "ss",
+    "ww", "wo", "WW", "Q", "M", "D", "d", "H", "h", "m", "s", "w", "A", "a", "W",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Part {
+    Token(&'static str),
+    Literal(String),
+}
+
+fn tokenize(pattern: &str) -> Vec<Part> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut rest = pattern;
+    while !rest.is_empty() {
+        if let Some(stripped) = rest.strip_prefix('[')
+            && let Some(end) = stripped.find(']')
+        {
+            literal.push_str(&stripped[..end]);
+            rest = &stripped[end + 1..];
+            continue;
+        }
+        if let Some(token) = TOKENS.iter().find(|token| rest.starts_with(**token)) {
+            if !literal.is_empty() {
+                parts.push(Part::Literal(std::mem::take(&mut literal)));
+            }
+            parts.push(Part::Token(token));
+            rest = &rest[token.len()..];
+            continue;
+        }
+        let ch = rest.chars().next().unwrap_or_default();
+        literal.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+    if !literal.is_empty() {
+        parts.push(Part::Literal(literal));
+    }
+    parts
+}
+
+/// Whether `pattern` contains any of the given tokens (outside `[...]`).
+pub fn format_has_token(pattern: &str, tokens: &[&str]) -> bool {
+    tokenize(pattern)
+        .iter()
+        .any(|part| matches!(part, Part::Token(t) if tokens.contains(t)))
+}
+
+fn english_suffix(n: u32) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+fn ordinal(template: &str, n: u32) -> String {
+    template
+        .replace("{n}", &n.to_string())
+        .replace("{th}", english_suffix(n))
+}
+
+/// Format a date and/or time with `pattern`. Tokens whose value is missing
+/// render as empty text.
+pub fn format_date_time(
+    date: Option<DateValue>,
+    time: Option<TimeValue>,
+    pattern: &str,
+    locale: &DatePickerLocale,
+) -> String {
+    let rule = WeekRule::of(locale);
+    let mut out = String::new();
+    for part in tokenize(pattern) {
+        let token = match part {
+            Part::Literal(text) => {
+                out.push_str(&text);
+                continue;
+            }
+            Part::Token(token) => token,
+        };
+        let d = date.map(|value| value.inner);
+        let text = match (token, d, time) {
+            ("YYYY", Some(d), _) => format!("{:04}", d.year()),
+            ("GGGG", Some(d), _) => format!("{:04}", d.to_iso_week_date().0),
+            ("gggg", Some(d), _) => format!("{:04}", rule.week_of(d).0),
+            ("YY", Some(d), _) => format!("{:02}", d.year().rem_euclid(100)),
+            ("Q", Some(d), _) => ((d.month() as u8 - 1) / 3 + 1).to_string(),
+            ("M", Some(d), _) => (d.month() as u8).to_string(),
+            ("MM", Some(d), _) => format!("{:02}", d.month() as u8),
+            ("MMM", Some(d), _) => locale.month_names[d.month() as usize - 1].clone(),
+            ("D", Some(d), _) => d.day().to_string(),
+            ("DD", Some(d), _) => format!("{:02}", d.day()),
+            ("Do", Some(d), _) => ordinal(&locale.day_ordinal, d.day() as u32),
+            ("d", Some(d), _) => d.weekday().number_days_from_sunday().to_string(),
+            ("dd", Some(d), _) => {
+                locale.short_weekdays[d.weekday().number_days_from_monday() as usize].clone()
+            }
+            ("ddd", Some(d), _) => {
+                locale.weekdays[d.weekday().number_days_from_monday() as usize].clone()
+            }
+            ("w", Some(d), _) => rule.week_of(d).1.to_string(),
+            ("ww", Some(d), _) => format!("{:02}", rule.week_of(d).1),
+            ("wo", Some(d), _) => ordinal(&locale.week_ordinal, rule.week_of(d).1 as u32),
+            ("W", Some(d), _) => d.iso_week().to_string(),
+            ("WW", Some(d), _) => format!("{:02}", d.iso_week()),
+            ("H", _, Some(t)) => t.hour.to_string(),
+            ("HH", _, Some(t)) => format!("{:02}", t.hour),
+            ("h", _, Some(t)) => hour12(t.hour).to_string(),
+            ("hh", _, Some(t)) => format!("{:02}", hour12(t.hour)),
+            ("m", _, Some(t)) => t.minute.to_string(),
+            ("mm", _, Some(t)) => format!("{:02}", t.minute),
+            ("s", _, Some(t)) => t.second.to_string(),
+            ("ss", _, Some(t)) => format!("{:02}", t.second),
+            ("A", _, Some(t)) => locale.meridiem[usize::from(t.hour >= 12)].clone(),
+            ("a", _, Some(t)) => locale.meridiem[usize::from(t.hour >= 12)].to_lowercase(),
+            _ => String::new(),
+        };
+        out.push_str(&text);
+    }
+    out
+}
+
+fn hour12(hour: u8) -> u8 {
+    match hour % 12 {
+        0 => 12,
+        h => h,
+    }
+}
+
+/// Format a date with `pattern`.
+pub fn format_date(date: DateValue, pattern: &str, locale: &DatePickerLocale) -> String {
+    format_date_time(Some(date), None, pattern, locale)
+}
+
+/// Format a time with `pattern`.
+pub fn format_time(time: TimeValue, pattern: &str, locale: &DatePickerLocale) -> String {
+    format_date_time(None, Some(time), pattern, locale)
+}
+
+/// Fields recovered by [`parse_date_time`]. Absent fields were not part of
+/// the pattern.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParsedDateTime {
+    pub year: Option<i32>,
+    pub quarter: Option<u8>,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+    pub week: Option<u8>,
+    /// Numbering of `week`: the locale's for `w`, ISO for `W`.
+    pub week_rule: WeekRule,
+    pub hour: Option<u8>,
+    pub minute: Option<u8>,
+    pub second: Option<u8>,
+    /// `Some(true)` after noon, from the `A` / `a` token.
+    pub pm: Option<bool>,
+}
+
+impl ParsedDateTime {
+    /// Date described by the parsed fields. A year is required; a missing
+    /// month and day fall back to the start of the quarter, week or year.
+    pub fn date(&self) -> Option<DateValue> {
+        let year = self.year?;
+        if self.month.is_none()
+            && self.day.is_none()
+            && let Some(week) = self.week
+        {
+            return self
+                .week_rule
+                .from_week(year, week)
+                .map(|inner| DateValue { inner });
+        }
+        let month = self
+            .month
+            .or_else(|| self.quarter.map(|q| (q - 1) * 3 + 1))
+            .unwrap_or(1);
+        DateValue::from_ymd(year, month, self.day.unwrap_or(1))
+    }
+
+    /// Time described by the parsed fields; `None` when no time token was
+    /// present.
+    pub fn time(&self) -> Option<TimeValue> {
+        if self.hour.is_none() && self.minute.is_none() && self.second.is_none() {
+            return None;
+        }
+        let mut hour = self.hour.unwrap_or(0);
+        match self.pm {
+            Some(true) if hour < 12 => hour += 12,
+            Some(false) if hour == 12 => hour = 0,
+            _ => {}
+        }
+        let (minute, second) = (self.minute.unwrap_or(0), self.second.unwrap_or(0));
+        (hour < 24 && minute < 60 && second < 60).then(|| TimeValue::new(hour, minute, second))
+    }
+}
+
+struct Cursor<'a> {
+    rest: &'a str,
+}
+
+impl Cursor<'_> {
+    /// Read between `min` and `max` ASCII digits.
+    fn digits(&mut self, min: usize, max: usize) -> Option<u32> {
+        let len = self
+            .rest
+            .bytes()
+            .take(max)
+            .take_while(u8::is_ascii_digit)
+            .count();
+        if len < min {
+            return None;
+        }
+        let value = self.rest[..len].parse().ok()?;
+        self.rest = &self.rest[len..];
+        Some(value)
+    }
+
+    /// Consume `text` if the input starts with it, ignoring ASCII case.
+    fn eat(&mut self, text: &str) -> bool {
+        if text.is_empty() {
+            return true;
+        }
+        match self.rest.get(..text.len()) {
+            Some(head) if head.eq_ignore_ascii_case(text) => {
+                self.rest = &self.rest[text.len()..];
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Consume the longest of `names`, returning its index.
+    fn one_of(&mut self, names: &[String]) -> Option<usize> {
+        let (index, name) = names
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| {
+                !name.is_empty()
+                    && self
+                        .rest
+                        .get(..name.len())
+                        .is_some_and(|head| head.eq_ignore_ascii_case(name))
+            })
+            .max_by_key(|(_, name)| name.len())?;
+        self.rest = &self.rest[name.len()..];
+        Some(index)
+    }
+
+    /// Read a number followed by the text `template` puts after it.
+    fn ordinal(&mut self, template: &str) -> Option<u32> {
+        let (prefix, _) = template.split_once("{n}").unwrap_or(("", ""));
+        if !self.eat(prefix) {
+            return None;
+        }
+        let n = self.digits(1, 2)?;
+        let rendered = ordinal(template, n);
+        let suffix = rendered
+            .split_once(&n.to_string())
+            .map(|(_, suffix)| suffix)
+            .unwrap_or("");
+        self.eat(suffix);
+        Some(n)
+    }
+}
+
+/// Parse `text` with `pattern`. The whole input must match; names are
+/// matched case-insensitively.
+pub fn parse_date_time(
+    text: &str,
+    pattern: &str,
+    locale: &DatePickerLocale,
+) -> Option<ParsedDateTime> {
+    let mut parsed = ParsedDateTime::default();
+    let mut cursor = Cursor { rest: text.trim() };
+    for part in tokenize(pattern) {
+        let token = match part {
+            Part::Literal(literal) => {
+                if !cursor.eat(&literal) {
+                    return None;
+                }
+                continue;
+            }
+            Part::Token(token) => token,
+        };
+        match token {
+            "YYYY" | "GGGG" | "gggg" => parsed.year = Some(cursor.digits(4, 4)? as i32),
+            "YY" => parsed.year = Some(2000 + cursor.digits(2, 2)? as i32),
+            "Q" => {
+                let quarter = cursor.digits(1, 1)? as u8;
+                if !(1..=4).contains(&quarter) {
+                    return None;
+                }
+                parsed.quarter = Some(quarter);
+            }
+            "M" | "MM" => parsed.month = Some(cursor.digits(1, 2)? as u8),
+            "MMM" => parsed.month = Some(cursor.one_of(&locale.month_names)? as u8 + 1),
+            "D" | "DD" => parsed.day = Some(cursor.digits(1, 2)? as u8),
+            "Do" => parsed.day = Some(cursor.ordinal(&locale.day_ordinal)? as u8),
+            "d" => {
+                cursor.digits(1, 1)?;
+            }
+            "dd" => {
+                cursor.one_of(&locale.short_weekdays)?;
+            }
+            "ddd" => {
+                cursor.one_of(&locale.weekdays)?;
+            }
+            "w" | "ww" => {
+                parsed.week = Some(cursor.digits(1, 2)? as u8);
+                parsed.week_rule = WeekRule::of(locale);
+            }
+            "wo" => {
+                parsed.week = Some(cursor.ordinal(&locale.week_ordinal)? as u8);
+                parsed.week_rule = WeekRule::of(locale);
+            }
+            "W" | "WW" => {
+                parsed.week = Some(cursor.digits(1, 2)? as u8);
+                parsed.week_rule = WeekRule::ISO;
+            }
+            "H" | "HH" | "h" | "hh" => parsed.hour = Some(cursor.digits(1, 2)? as u8),
+            "m" | "mm" => parsed.minute = Some(cursor.digits(1, 2)? as u8),
+            "s" | "ss" => parsed.second = Some(cursor.digits(1, 2)? as u8),
+            "A" | "a" => parsed.pm = Some(cursor.one_of(&locale.meridiem)? == 1),
+            _ => {}
+        }
+    }
+    cursor.rest.is_empty().then_some(parsed)
+}
+
+/// Parse a date with `pattern`.
+pub fn parse_date(text: &str, pattern: &str, locale: &DatePickerLocale) -> Option<DateValue> {
+    parse_date_time(text, pattern, locale)?.date()
+}
+
+/// Parse a time with `pattern`.
+pub fn parse_time(text: &str, pattern: &str, locale: &DatePickerLocale) -> Option<TimeValue> {
+    parse_date_time(text, pattern, locale)?.time()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::locale::LocaleBundle;
+
+    fn en() -> DatePickerLocale {
+        LocaleBundle::builtin("en-US").unwrap().date_picker
+    }
+
+    fn zh() -> DatePickerLocale {
+        LocaleBundle::builtin("zh-CN").unwrap().date_picker
+    }
+
+    #[test]
+    fn formats_numeric_tokens() {
+        let date = DateValue::from_ymd(2024, 3, 5).unwrap();
+        assert_eq!(format_date(date, "YYYY-MM-DD", &en()), "2024-03-05");
+        assert_eq!(format_date(date, "D/M/YY", &en()), "5/3/24");
+        assert_eq!(format_date(date, "YYYY-[Q]Q", &en()), "2024-Q1");
+        assert_eq!(format_date(date, "YYYY-wo", &en()), "2024-10th");
+        let new_year = DateValue::from_ymd(2024, 12, 30).unwrap();
+        assert_eq!(format_date(new_year, "GGGG-WW", &en()), "2025-01");
+        assert_eq!(format_date(new_year, "gggg-ww", &zh()), "2025-01");
+    }
+
+    #[test]
+    fn formats_locale_names() {
+        let date = DateValue::from_ymd(2024, 3, 1).unwrap();
+        assert_eq!(
+            format_date(date, "ddd, MMM Do YYYY", &en()),
+            "Fri, Mar 1st 2024"
+        );
+        assert_eq!(
+            format_date(date, "YYYY年M月Do ddd", &zh()),
+            "2024年3月1日 周五"
+        );
+
+        let time = TimeValue::new(13, 5, 0);
+        assert_eq!(format_time(time, "hh:mm A", &en()), "01:05 PM");
+        assert_eq!(format_time(time, "A h:mm", &zh()), "下午 1:05");
+        assert_eq!(format_time(TimeValue::new(0, 0, 0), "h a", &en()), "12 am");
+    }
+
+    #[test]
+    fn parses_back_what_it_formats() {
+        let locale = en();
+        let date = DateValue::from_ymd(2023, 11, 22).unwrap();
+        for pattern in ["YYYY-MM-DD", "DD/MM/YYYY", "MMM Do, YYYY", "ddd D MMM YYYY"] {
+            let text = format_date(date, pattern, &locale);
+            assert_eq!(parse_date(&text, pattern, &locale), Some(date), "{pattern}");
+        }
+
+        let time = TimeValue::new(18, 30, 15);
+        for pattern in ["HH:mm:ss", "hh:mm:ss A", "h:mm:ss a"] {
+            let text = format_time(time, pattern, &locale);
+            assert_eq!(parse_time(&text, pattern, &locale), Some(time), "{pattern}");
+        }
+    }
+
+    #[test]
+    fn parsing_is_lenient_on_padding_but_strict_on_shape() {
+        let locale = en();
+        assert_eq!(
+            parse_date("2024-3-5", "YYYY-MM-DD", &locale),
+            DateValue::from_ymd(2024, 3, 5)
+        );
+        assert_eq!(parse_date("2024-02-30", "YYYY-MM-DD", &locale), None);
+        assert_eq!(parse_date("2024-03-05x", "YYYY-MM-DD", &locale), None);
+        assert_eq!(parse_date("2024/03/05", "YYYY-MM-DD", &locale), None);
+        assert_eq!(parse_time("25:00:00", "HH:mm:ss", &locale), None);
+    }
+
+    #[test]
+    fn partial_patterns_pick_the_period_start() {
+        let locale = en();
+        assert_eq!(
+            parse_date("2024-07", "YYYY-MM", &locale),
+            DateValue::from_ymd(2024, 7, 1)
+        );
+        assert_eq!(
+            parse_date("2024-Q3", "YYYY-[Q]Q", &locale),
+            DateValue::from_ymd(2024, 7, 1)
+        );
+        // en-US weeks start on Sunday.
+        assert_eq!(
+            parse_date("2024-10th", "YYYY-wo", &locale),
+            DateValue::from_ymd(2024, 3, 3)
+        );
+        assert_eq!(
+            parse_date("2024-W10", "YYYY-[W]W", &locale),
+            DateValue::from_ymd(2024, 3, 4)
+        );
+        assert_eq!(
+            parse_date("2024年10周", "YYYY年wo", &zh()),
+            DateValue::from_ymd(2024, 3, 4)
+        );
+    }
+
+    #[test]
+    fn locale_weeks_differ_at_year_boundaries() {
+        // Sunday 2023-01-01: ISO week 52 of 2022, but week 1 of 2023 in
+        // en-US where the week holding January 1st comes first.
+        let date = DateValue::from_ymd(2023, 1, 1).unwrap();
+        assert_eq!(format_date(date, "GGGG-WW", &en()), "2022-52");
+        assert_eq!(format_date(date, "gggg-ww", &en()), "2023-01");
+        assert_eq!(format_date(date, "gggg-ww", &zh()), "2022-52");
+        assert_eq!(
+            parse_date("2023-01", "gggg-ww", &en()),
+            DateValue::from_ymd(2023, 1, 1)
+        );
+        assert_eq!(
+            parse_date("2023-01", "gggg-ww", &zh()),
+            DateValue::from_ymd(2023, 1, 2)
+        );
+    }
+
+    #[test]
+    fn detects_tokens_outside_literals() {
+        assert!(format_has_token("HH:mm", &["mm"]));
+        assert!(!format_has_token("HH:mm", &["ss", "s"]));
+        assert!(!format_has_token("[ss] HH", &["ss"]));
+    }
+}
@@ -0,0 +1,386 @@
+//! Picker modes and the panel grids shared by `DatePicker`, `RangePicker`
+//! and `Calendar`.
+//!
+//! A [`PickerMode`] decides what one value stands for (a day, an ISO week, a
+//! month, a quarter or a year), how it is displayed by default, and which
+//! grid the dropdown shows. Values are always stored as the first day of
+//! their period, so a week value is the first day of that week and a quarter
+//! value the first day of its first month.
+//!
+//! Where weeks start and how they are numbered comes from a [`WeekRule`],
+//! read from the `week_start` / `week_min_days` fields of the locale.
+
+use crate::components::calendar::{days_in_month, weekday_index_monday};
+use crate::components::date_picker::DateValue;
+use crate::components::locale::DatePickerLocale;
+use time::{Date, Duration, Month, Weekday};
+
+/// How weeks are laid out and numbered: the first day of the week, and the
+/// number of days the first week of a year must have in that year (CLDR
+/// `firstDay` / `minDays`). ISO 8601 weeks start on Monday and week 1
+/// contains January 4th; US weeks start on Sunday and week 1 contains
+/// January 1st.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WeekRule {
+    pub first_day: Weekday,
+    pub min_days: u8,
+}
+
+impl Default for WeekRule {
+    fn default() -> Self {
+        WeekRule::ISO
+    }
+}
+
+impl WeekRule {
+    pub const ISO: WeekRule = WeekRule {
+        first_day: Weekday::Monday,
+        min_days: 4,
+    };
+
+    /// Rule of a locale. `week_start` counts from Sunday = 0, as in dayjs.
+    pub fn of(locale: &DatePickerLocale) -> Self {
+        WeekRule {
+            first_day: Weekday::Sunday.nth_next(locale.week_start % 7),
+            min_days: locale.week_min_days.clamp(1, 7),
+        }
+    }
+
+    /// Column of a date in a grid starting on `first_day`, 0-6.
+    pub fn column(self, date: Date) -> u8 {
+        (date.weekday().number_days_from_monday() + 7 - self.first_day.number_days_from_monday())
+            % 7
+    }
+
+    /// First day of the week containing `date`.
+    pub fn week_start(self, date: Date) -> Date {
+        date - Duration::days(self.column(date) as i64)
+    }
+
+    fn first_week_start(self, year: i32) -> Option<Date> {
+        Date::from_calendar_date(year, Month::January, self.min_days)
+            .ok()
+            .map(|date| self.week_start(date))
+    }
+
+    /// Week-numbering year and week number (1-53) of `date`.
+    pub fn week_of(self, date: Date) -> (i32, u8) {
+        let start = self.week_start(date);
+        let year = date.year();
+        let week_year = match self.first_week_start(year + 1) {
+            Some(next) if start >= next => year + 1,
+            _ => match self.first_week_start(year) {
+                Some(first) if start < first => year - 1,
+                _ => year,
+            },
+        };
+        let first = self.first_week_start(week_year).unwrap_or(start);
+        (week_year, ((start - first).whole_weeks() + 1) as u8)
+    }
+
+    /// First day of `week` of the week-numbering `year`.
+    pub fn from_week(self, year: i32, week: u8) -> Option<Date> {
+        if week == 0 {
+            return None;
+        }
+        let start = self
+            .first_week_start(year)?
+            .checked_add(Duration::weeks(week as i64 - 1))?;
+        (self.week_of(start).0 == year).then_some(start)
+    }
+}
+
+/// Granularity of a date picker, like Ant Design's `picker` prop.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PickerMode {
+    #[default]
+    Date,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl PickerMode {
+    /// Pattern used when the picker has no explicit `format`.
+    pub fn default_format(self) -> &'static str {
+        match self {
+            PickerMode::Date => "YYYY-MM-DD",
+            PickerMode::Week => "gggg-wo",
+            PickerMode::Month => "YYYY-MM",
+            PickerMode::Quarter => "YYYY-[Q]Q",
+            PickerMode::Year => "YYYY",
+        }
+    }
+
+    /// First day of the period containing `value`, with ISO weeks.
+    pub fn normalize(self, value: DateValue) -> DateValue {
+        self.normalize_in(value, WeekRule::ISO)
+    }
+
+    /// First day of the period containing `value`, with weeks laid out by
+    /// `rule`.
+    pub fn normalize_in(self, value: DateValue, rule: WeekRule) -> DateValue {
+        let date = value.inner;
+        let (year, month) = (date.year(), date.month() as u8);
+        let start = match self {
+            PickerMode::Date => Some(date),
+            PickerMode::Week => Some(rule.week_start(date)),
+            PickerMode::Month => DateValue::from_ymd(year, month, 1).map(|v| v.inner),
+            PickerMode::Quarter => {
+                DateValue::from_ymd(year, (month - 1) / 3 * 3 + 1, 1).map(|v| v.inner)
+            }
+            PickerMode::Year => DateValue::from_ymd(year, 1, 1).map(|v| v.inner),
+        };
+        DateValue {
+            inner: start.unwrap_or(date),
+        }
+    }
+
+    /// Whether both values fall in the same period.
+    pub fn same_period(self, a: DateValue, b: DateValue) -> bool {
+        self.same_period_in(a, b, WeekRule::ISO)
+    }
+
+    /// Whether both values fall in the same period, with weeks laid out by
+    /// `rule`.
+    pub fn same_period_in(self, a: DateValue, b: DateValue, rule: WeekRule) -> bool {
+        self.normalize_in(a, rule) == self.normalize_in(b, rule)
+    }
+
+    /// Whether the dropdown shows a day grid (`Date` and `Week`).
+    pub fn has_day_grid(self) -> bool {
+        matches!(self, PickerMode::Date | PickerMode::Week)
+    }
+
+    /// Class suffix of the panel, e.g. `adui-date-picker-month-panel`.
+    pub fn as_class(self) -> &'static str {
+        match self {
+            PickerMode::Date => "date",
+            PickerMode::Week => "week",
+            PickerMode::Month => "month",
+            PickerMode::Quarter => "quarter",
+            PickerMode::Year => "year",
+        }
+    }
+}
+
+/// The visible page of a panel: a month for day grids, a year for month and
+/// quarter grids, and the decade containing `year` for the year grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PanelView {
+    pub year: i32,
+    pub month: u8,
+}
+
+impl PanelView {
+    pub fn of(value: DateValue) -> Self {
+        PanelView {
+            year: value.inner.year(),
+            month: value.inner.month() as u8,
+        }
+    }
+
+    /// Move by `steps` pages of `mode`'s panel.
+    pub fn shift(self, mode: PickerMode, steps: i32) -> Self {
+        match mode {
+            PickerMode::Date | PickerMode::Week => {
+                let index = self.year * 12 + self.month as i32 - 1 + steps;
+                PanelView {
+                    year: index.div_euclid(12),
+                    month: (index.rem_euclid(12) + 1) as u8,
+                }
+            }
+            PickerMode::Month | PickerMode::Quarter => PanelView {
+                year: self.year + steps,
+                ..self
+            },
+            PickerMode::Year => PanelView {
+                year: self.year + steps * 10,
+                ..self
+            },
+        }
+    }
+
+    /// Panel header text.
+    pub fn title(self, mode: PickerMode, locale: &DatePickerLocale) -> String {
+        match mode {
+            PickerMode::Date | PickerMode::Week => {
+                locale.format_year_month(self.year, self.month as u32)
+            }
+            PickerMode::Month | PickerMode::Quarter => self.year.to_string(),
+            PickerMode::Year => {
+                let start = decade_start(self.year);
+                format!("{}-{}", start, start + 9)
+            }
+        }
+    }
+}
+
+fn decade_start(year: i32) -> i32 {
+    year.div_euclid(10) * 10
+}
+
+/// One cell of a panel grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PanelCell {
+    /// Start of the period the cell picks; `None` for padding cells.
+    pub value: Option<DateValue>,
+    pub label: String,
+    /// Belongs to a neighbouring page (the years around a decade).
+    pub outside: bool,
+}
+
+/// Cells of `mode`'s panel for `view`. Day grids are padded to full weeks
+/// starting on the locale's first weekday; month grids hold 12 cells, quarter grids 4 and year
+/// grids the decade plus one year on either side.
+pub fn panel_cells(mode: PickerMode, view: PanelView, locale: &DatePickerLocale) -> Vec<PanelCell> {
+    match mode {
+        PickerMode::Date | PickerMode::Week => {
+            let days = days_in_month(view.year, view.month) as usize;
+            let rule = WeekRule::of(locale);
+            let first = (weekday_index_monday(view.year, view.month, 1) + 7
+                - rule.first_day.number_days_from_monday()) as usize
+                % 7;
+            let total = (first + days).div_ceil(7) * 7;
+            (0..total)
+                .map(|index| {
+                    let day =
+                        (index >= first && index < first + days).then(|| (index - first + 1) as u8);
+                    PanelCell {
+                        value: day.and_then(|d| DateValue::from_ymd(view.year, view.month, d)),
+                        label: day.map(|d| d.to_string()).unwrap_or_default(),
+                        outside: false,
+                    }
+                })
+                .collect()
+        }
+        PickerMode::Month => (1..=12u8)
+            .map(|month| PanelCell {
+                value: DateValue::from_ymd(view.year, month, 1),
+                label: locale.month_names[month as usize - 1].clone(),
+                outside: false,
+            })
+            .collect(),
+        PickerMode::Quarter => (0..4u8)
+            .map(|q| PanelCell {
+                value: DateValue::from_ymd(view.year, q * 3 + 1, 1),
+                label: format!("Q{}", q + 1),
+                outside: false,
+            })
+            .collect(),
+        PickerMode::Year => {
+            let start = decade_start(view.year);
+            (start - 1..=start + 10)
+                .map(|year| PanelCell {
+                    value: DateValue::from_ymd(year, 1, 1),
+                    label: year.to_string(),
+                    outside: year < start || year > start + 9,
+                })
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::locale::LocaleBundle;
+
+    fn date(y: i32, m: u8, d: u8) -> DateValue {
+        DateValue::from_ymd(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn normalize_snaps_to_period_start() {
+        let value = date(2024, 8, 15);
+        assert_eq!(PickerMode::Date.normalize(value), value);
+        assert_eq!(PickerMode::Week.normalize(value), date(2024, 8, 12));
+        assert_eq!(PickerMode::Month.normalize(value), date(2024, 8, 1));
+        assert_eq!(PickerMode::Quarter.normalize(value), date(2024, 7, 1));
+        assert_eq!(PickerMode::Year.normalize(value), date(2024, 1, 1));
+        // ISO week 1 of 2025 starts in 2024.
+        assert_eq!(
+            PickerMode::Week.normalize(date(2025, 1, 2)),
+            date(2024, 12, 30)
+        );
+    }
+
+    #[test]
+    fn week_rules_at_year_boundaries() {
+        let us = WeekRule {
+            first_day: Weekday::Sunday,
+            min_days: 1,
+        };
+        let iso = WeekRule::ISO;
+        // Monday 2024-12-30 already belongs to week 1 of 2025.
+        assert_eq!(iso.week_of(date(2024, 12, 30).inner), (2025, 1));
+        assert_eq!(us.week_of(date(2024, 12, 30).inner), (2025, 1));
+        // Sunday 2021-01-03 closes ISO week 53 of 2020 but opens US week 2.
+        assert_eq!(iso.week_of(date(2021, 1, 3).inner), (2020, 53));
+        assert_eq!(us.week_of(date(2021, 1, 3).inner), (2021, 2));
+        // Saturday 2022-01-01: ISO week 52 of 2021, US week 1 of 2022.
+        assert_eq!(iso.week_of(date(2022, 1, 1).inner), (2021, 52));
+        assert_eq!(us.week_of(date(2022, 1, 1).inner), (2022, 1));
+
+        assert_eq!(us.from_week(2022, 1), Some(date(2021, 12, 26).inner));
+        assert_eq!(iso.from_week(2020, 53), Some(date(2020, 12, 28).inner));
+        assert_eq!(iso.from_week(2021, 53), None);
+        assert_eq!(
+            PickerMode::Week.normalize_in(date(2024, 8, 15), us),
+            date(2024, 8, 11)
+        );
+    }
+
+    #[test]
+    fn view_shifts_by_panel_page() {
+        let view = PanelView {
+            year: 2024,
+            month: 1,
+        };
+        assert_eq!(
+            view.shift(PickerMode::Date, -1),
+            PanelView {
+                year: 2023,
+                month: 12
+            }
+        );
+        assert_eq!(view.shift(PickerMode::Quarter, 1).year, 2025);
+        assert_eq!(view.shift(PickerMode::Year, -1).year, 2014);
+    }
+
+    #[test]
+    fn grids_per_mode() {
+        let locale = LocaleBundle::builtin("en-US").unwrap().date_picker;
+        let view = PanelView {
+            year: 2024,
+            month: 2,
+        };
+        // February 2024 starts on a Thursday: four padding cells in the
+        // Sunday-first en-US grid, three in a Monday-first one.
+        let days = panel_cells(PickerMode::Date, view, &locale);
+        assert_eq!(days.len(), 35);
+        assert!(days[..4].iter().all(|c| c.value.is_none()));
+        assert_eq!(days[4].value, Some(date(2024, 2, 1)));
+        let monday_first = DatePickerLocale {
+            week_start: 1,
+            ..locale.clone()
+        };
+        let days = panel_cells(PickerMode::Date, view, &monday_first);
+        assert_eq!(days[3].value, Some(date(2024, 2, 1)));
+        assert_eq!(monday_first.weekday_labels()[0], "Mo");
+        assert_eq!(locale.weekday_labels()[0], "Su");
+
+        let months = panel_cells(PickerMode::Month, view, &locale);
+        assert_eq!(months[2].label, "Mar");
+
+        let quarters = panel_cells(PickerMode::Quarter, view, &locale);
+        assert_eq!(quarters[3].value, Some(date(2024, 10, 1)));
+
+        let years = panel_cells(PickerMode::Year, view, &locale);
+        assert_eq!(years.first().unwrap().label, "2019");
+        assert!(years.first().unwrap().outside);
+        assert_eq!(years.last().unwrap().label, "2030");
+        assert_eq!(view.title(PickerMode::Year, &locale), "2020-2029");
+    }
+}
@@ -1,11 +1,13 @@
-use crate::components::config_provider::{Locale, use_config};
-use crate::components::floating::use_floating_close_handle;
+use crate::components::date_format::{format_date, parse_date};
+use crate::components::date_panel::{PanelCell, PanelView, PickerMode, WeekRule, panel_cells};
+use crate::components::floating::{FloatingCloseHandle, use_floating_close_handle};
+use crate::components::locale::{DatePickerLocale, use_locale};
 use crate::components::select_base::use_dropdown_layer;
 use dioxus::events::KeyboardEvent;
 use dioxus::prelude::*;
 use std::collections::HashMap;
 use std::rc::Rc;
-use time::Date;
+use time::{Date, OffsetDateTime, UtcOffset};
 
 // Internal value used by RangePicker to represent a possibly-partial range.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -52,36 +54,55 @@ impl DateValue {
             self.inner.day()
         )
     }
-}
 
-/// Compute the number of days in a given month of a given year.
-fn days_in_month(year: i32, month: u8) -> u8 {
-    match month {
-        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-        4 | 6 | 9 | 11 => 30,
-        2 => {
-            // Gregorian leap year rules.
-            let is_leap = (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0);
-            if is_leap { 29 } else { 28 }
+    /// Parse a `YYYY-MM-DD` string, the inverse of [`Self::to_ymd_string`].
+    /// Month and day may be unpadded (`2024-1-5`), and a time part after `T`
+    /// or a space is ignored.
+    pub fn parse_ymd(text: &str) -> Option<Self> {
+        let mut parts = text.trim().splitn(3, '-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let rest = parts.next()?;
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if !(rest[digits..].is_empty() || rest[digits..].starts_with(['T', 't', ' '])) {
+            return None;
         }
-        _ => 30,
+        let day = rest[..digits].parse().ok()?;
+        Self::from_ymd(year, month, day)
+    }
+
+    /// Date at `unix_seconds` in the timezone `offset` away from UTC.
+    pub fn from_unix_timestamp(unix_seconds: i64, offset: UtcOffset) -> Option<Self> {
+        OffsetDateTime::from_unix_timestamp(unix_seconds)
+            .ok()
+            .map(|at| DateValue {
+                inner: at.to_offset(offset).date(),
+            })
+    }
+
+    /// Today's date in the timezone `offset` away from UTC.
+    pub fn today(offset: UtcOffset) -> Self {
+        Self::from_unix_timestamp(unix_now_seconds(), offset)
+            .expect("current time is within the supported range")
     }
 }
 
-/// Compute weekday index for a given date, with Monday = 0 .. Sunday = 6.
-fn weekday_index_monday(year: i32, month: u8, day: u8) -> u8 {
-    // Tomohiko Sakamoto's algorithm, returning 0 = Sunday .. 6 = Saturday.
-    let m = month as i32;
-    let d = day as i32;
-    let mut y = year;
-    let t = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
-    if m < 3 {
-        y -= 1;
-    }
-    let w = (y + y / 4 - y / 100 + y / 400 + t[(m - 1) as usize] + d) % 7;
-    let sunday_based = w as u8; // 0 = Sunday
-    // Convert to Monday-based index: Monday = 0 .. Sunday = 6.
-    (sunday_based + 6) % 7
+/// Seconds since the Unix epoch, from the browser clock on web targets.
+pub(crate) fn unix_now_seconds() -> i64 {
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
+    {
+        (js_sys::Date::now() / 1000.0) as i64
+    }
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default()
+    }
 }
 
 /// Props for the DatePicker component (MVP subset for single date picker).
@@ -96,9 +117,13 @@ pub struct DatePickerProps {
     /// Placeholder text shown when no value is selected.
     #[props(optional)]
     pub placeholder: Option<String>,
-    /// Display/parse format. MVP: primarily `YYYY-MM-DD`, reserved for future extension.
+    /// Display/parse pattern, e.g. `DD/MM/YYYY` or `MMM Do, YYYY`. Defaults
+    /// to the pattern of `picker`; see `date_format` for the supported tokens.
     #[props(optional)]
     pub format: Option<String>,
+    /// Granularity of the value: date, ISO week, month, quarter or year.
+    #[props(default)]
+    pub picker: PickerMode,
     /// Whether the picker is disabled.
     #[props(optional)]
     pub disabled: Option<bool>,
@@ -114,6 +139,26 @@ pub struct DatePickerProps {
     /// Change callback fired when the selected date changes.
     #[props(optional)]
     pub on_change: Option<EventHandler<Option<DateValue>>>,
+    /// Select several discrete dates instead of one; the selection is
+    /// `values` and changes are reported through `on_values_change`.
+    #[props(default)]
+    pub multiple: bool,
+    /// Controlled dates in `multiple` mode.
+    #[props(optional)]
+    pub values: Option<Vec<DateValue>>,
+    /// Initial dates in uncontrolled `multiple` mode.
+    #[props(optional)]
+    pub default_values: Option<Vec<DateValue>>,
+    /// Change callback of `multiple` mode, with the dates sorted.
+    #[props(optional)]
+    pub on_values_change: Option<EventHandler<Vec<DateValue>>>,
+    /// Quick picks listed beside the panel, e.g. `("Today", date)`.
+    #[props(optional)]
+    pub presets: Option<Vec<(String, DateValue)>>,
+    /// Timezone of "today": when set, the panel opens on today's page and
+    /// highlights today's cell.
+    #[props(optional)]
+    pub utc_offset: Option<UtcOffset>,
     /// Show time picker in addition to date picker.
     #[props(optional)]
     pub show_time: Option<ShowTimeConfig>,
@@ -141,10 +186,16 @@ impl PartialEq for DatePickerProps {
             && self.default_value == other.default_value
             && self.placeholder == other.placeholder
             && self.format == other.format
+            && self.picker == other.picker
             && self.disabled == other.disabled
             && self.allow_clear == other.allow_clear
             && self.class == other.class
             && self.style == other.style
+            && self.multiple == other.multiple
+            && self.values == other.values
+            && self.default_values == other.default_values
+            && self.presets == other.presets
+            && self.utc_offset == other.utc_offset
             && self.show_time == other.show_time
             && self.ranges == other.ranges
             && self.generate_config == other.generate_config
@@ -168,6 +219,9 @@ pub struct ShowTimeConfig {
 }
 
 /// Date generation configuration for custom date libraries.
+///
+/// When set, `format` and `parse` replace the built-in `date_format` engine
+/// for the picker input; both receive the picker's `format` pattern.
 #[derive(Clone)]
 pub struct DateGenerateConfig {
     /// Generate date from year, month, day.
@@ -187,26 +241,166 @@ impl PartialEq for DateGenerateConfig {
     }
 }
 
-/// Ant Design flavored DatePicker (MVP: single-date picker with dropdown
-/// calendar). RangePicker and richer behaviours will be built on top in later
-/// steps.
+/// Formats and parses the input text of a picker with its pattern, through
+/// `DateGenerateConfig` when one is given.
+#[derive(Clone)]
+struct DateTextCodec {
+    pattern: String,
+    locale: DatePickerLocale,
+    generate_config: Option<DateGenerateConfig>,
+}
+
+impl DateTextCodec {
+    fn format(&self, value: DateValue) -> String {
+        match &self.generate_config {
+            Some(config) => (config.format)(value, &self.pattern),
+            None => format_date(value, &self.pattern, &self.locale),
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<DateValue> {
+        match &self.generate_config {
+            Some(config) => (config.parse)(text, &self.pattern),
+            None => parse_date(text, &self.pattern, &self.locale),
+        }
+    }
+}
+
+/// Header, weekday row and grid of a picker dropdown for `mode`. `cells` are
+/// the rendered cells of the current `view`.
+fn render_panel(
+    mode: PickerMode,
+    view: Signal<PanelView>,
+    locale: &DatePickerLocale,
+    close_handle: FloatingCloseHandle,
+    mut hover: Signal<Option<DateValue>>,
+    cells: Vec<Element>,
+) -> Element {
+    let title = view.read().title(mode, locale);
+    let weekday_labels = locale.weekday_labels();
+    let numbered = mode == PickerMode::Week;
+    let body_class = format!(
+        "adui-date-picker-body adui-date-picker-{}-panel",
+        mode.as_class()
+    );
+    let shift = move |steps: i32| {
+        close_handle.mark_internal_click();
+        let mut view = view;
+        let next = view.read().shift(mode, steps);
+        view.set(next);
+    };
+
+    rsx! {
+        div { class: "adui-date-picker-header",
+            button {
+                class: "adui-date-picker-nav-btn adui-date-picker-prev-month",
+                onclick: move |_| shift(-1),
+                "<"
+            }
+            span { class: "adui-date-picker-header-view", "{title}" }
+            button {
+                class: "adui-date-picker-nav-btn adui-date-picker-next-month",
+                onclick: move |_| shift(1),
+                ">"
+            }
+        }
+
+        if mode.has_day_grid() {
+            div {
+                class: if numbered { "adui-date-picker-week-row adui-date-picker-week-row-numbered" } else { "adui-date-picker-week-row" },
+                if numbered {
+                    span { class: "adui-date-picker-week-cell" }
+                }
+                for label in weekday_labels {
+                    span { class: "adui-date-picker-week-cell", "{label}" }
+                }
+            }
+        }
+
+        div {
+            class: "{body_class}",
+            onmouseleave: move |_| hover.set(None),
+            for cell in cells { {cell} }
+        }
+    }
+}
+
+/// Week number shown in front of each row of the week panel.
+fn week_number_cell(row: &[PanelCell], rule: WeekRule) -> Element {
+    let week = row
+        .iter()
+        .find_map(|cell| cell.value)
+        .map(|value| rule.week_of(value.inner).1.to_string())
+        .unwrap_or_default();
+    rsx! {
+        span { class: "adui-date-picker-cell adui-date-picker-cell-week-number", "{week}" }
+    }
+}
+
+/// Whether two dates fit in one range of at most `max_days` days, both ends
+/// included.
+fn within_span(a: DateValue, b: DateValue, max_days: Option<u32>) -> bool {
+    max_days.is_none_or(|max| (b.inner - a.inner).whole_days().unsigned_abs() < max as u64)
+}
+
+/// `values` with `value` added, or removed when already present; kept sorted.
+fn toggle_date(values: &[DateValue], value: DateValue) -> Vec<DateValue> {
+    let mut next: Vec<DateValue> = values.iter().copied().filter(|v| *v != value).collect();
+    if next.len() == values.len() {
+        next.push(value);
+        next.sort_by_key(|v| v.inner);
+    }
+    next
+}
+
+/// Quick picks listed on the left of the dropdown.
+fn render_presets(items: Vec<Element>) -> Element {
+    rsx! {
+        ul { class: "adui-date-picker-presets",
+            for item in items { {item} }
+        }
+    }
+}
+
+/// Ant Design flavored DatePicker: a single date, week, month, quarter or
+/// year picked from a dropdown panel, or typed into the input. With
+/// `multiple`, cells toggle dates in and out of `values`.
 #[component]
 pub fn DatePicker(props: DatePickerProps) -> Element {
     let DatePickerProps {
         value,
         default_value,
+        multiple,
+        values,
+        default_values,
         placeholder,
-        format: _format,
+        format,
+        picker,
         disabled,
         allow_clear,
         class,
         style,
         on_change,
+        on_values_change,
+        presets,
+        disabled_date,
+        utc_offset,
+        generate_config,
         ..
     } = props;
 
-    let config = use_config();
-    let locale = config.locale;
+    let locale = use_locale();
+    let codec = DateTextCodec {
+        pattern: format.unwrap_or_else(|| picker.default_format().to_string()),
+        locale: locale.date_picker.clone(),
+        generate_config,
+    };
+    let week_rule = WeekRule::of(&locale.date_picker);
+    let normalize = move |value: DateValue| picker.normalize_in(value, week_rule);
+    let is_date_disabled = {
+        let disabled_date = disabled_date.clone();
+        move |value: DateValue| disabled_date.as_ref().is_some_and(|f| f(value))
+    };
 
     let is_disabled = disabled.unwrap_or(false);
     let allow_clear_flag = allow_clear.unwrap_or(false);
@@ -218,13 +412,19 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
     } else {
         *selected_state.read()
     };
-
-    let display_text = current_value.map(|v| v.to_ymd_string()).unwrap_or_default();
-
-    let default_placeholder = match locale {
-        Locale::ZhCN => "请选择日期".to_string(),
-        Locale::EnUS => "Select date".to_string(),
+    let multi_state: Signal<Vec<DateValue>> = use_signal(|| default_values.unwrap_or_default());
+    let values_controlled = values.is_some();
+    let current_values: Vec<DateValue> = values.unwrap_or_else(|| multi_state.read().clone());
+
+    // Text being typed into the input; replaces the formatted value until it
+    // is committed on blur or Enter.
+    let draft: Signal<Option<String>> = use_signal(|| None);
+    let display_text = match draft.read().clone() {
+        Some(text) => text,
+        None => current_value.map(|v| codec.format(v)).unwrap_or_default(),
     };
+
+    let default_placeholder = locale.date_picker.placeholder.clone();
     let placeholder_str = placeholder.unwrap_or(default_placeholder);
 
     let controlled = value.is_some();
@@ -239,33 +439,31 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
     let dropdown_layer = use_dropdown_layer(open_flag);
     let current_z = *dropdown_layer.z_index.read();
 
-    // Visible calendar month/year. Default to current value if present, else a
-    // fixed reference (2024-01) to keep behaviour deterministic across
-    // environments without relying on system time.
-    let initial_year = current_value.map(|v| v.inner.year()).unwrap_or(2024);
-    let initial_month = current_value.map(|v| v.inner.month() as u8).unwrap_or(1);
-    let view_year: Signal<i32> = use_signal(|| initial_year);
-    let view_month: Signal<u8> = use_signal(|| initial_month);
-
-    let year_now = *view_year.read();
-    let month_now = *view_month.read();
-    let days_in_month_now = days_in_month(year_now, month_now);
-    let first_weekday = weekday_index_monday(year_now, month_now, 1) as usize;
-    let total_cells = first_weekday + days_in_month_now as usize;
-    let padded_cells = total_cells.div_ceil(7) * 7; // round up to full weeks
-
-    let locale_for_header = locale;
-    let month_label = match locale_for_header {
-        Locale::ZhCN => format!("{year_now}年{month_now}月"),
-        Locale::EnUS => format!("{year_now}-{month_now:02}"),
-    };
-    let weekday_labels: [&str; 7] = match locale_for_header {
-        Locale::ZhCN => ["一", "二", "三", "四", "五", "六", "日"],
-        Locale::EnUS => ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
-    };
+    // Today is only known when a timezone is given, which keeps rendering
+    // deterministic across environments otherwise.
+    let today = utc_offset.map(DateValue::today);
+
+    // Visible panel page. Default to current value if present, else today,
+    // else a fixed reference (2024-01).
+    let view: Signal<PanelView> = use_signal(|| {
+        current_value
+            .or(current_values.first().copied())
+            .or(today)
+            .map(PanelView::of)
+            .unwrap_or(PanelView {
+                year: 2024,
+                month: 1,
+            })
+    });
+    // Hovered cell; the week panel highlights the whole hovered week.
+    let hover: Signal<Option<DateValue>> = use_signal(|| None);
+    let hovered = *hover.read();
 
     // Build root/control classes.
     let mut control_classes = vec!["adui-date-picker".to_string()];
+    if multiple {
+        control_classes.push("adui-date-picker-multiple".to_string());
+    }
     if is_disabled {
         control_classes.push("adui-date-picker-disabled".to_string());
     }
@@ -283,75 +481,193 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
     let open_for_keydown = open_for_toggle;
 
     // Derived flags.
-    let has_value = current_value.is_some();
+    let has_value = if multiple {
+        !current_values.is_empty()
+    } else {
+        current_value.is_some()
+    };
 
-    // Precompute calendar cells for the current view.
-    let mut date_cells: Vec<Element> = Vec::new();
-    for index in 0..padded_cells {
-        let cell_day =
-            if index < first_weekday || index >= first_weekday + days_in_month_now as usize {
-                None
-            } else {
-                Some((index - first_weekday + 1) as u8)
+    let commit = move |next: Option<DateValue>| {
+        let next = next.map(normalize);
+        if !controlled_flag {
+            let mut state = selected_for_day_click;
+            state.set(next);
+        }
+        if let Some(cb) = on_change_cb {
+            cb.call(next);
+        }
+    };
+    // Commit the typed text if it parses to an enabled date, then drop the
+    // draft so the input shows the (possibly unchanged) formatted value.
+    let commit_draft = {
+        let codec = codec.clone();
+        let is_date_disabled = is_date_disabled.clone();
+        move || {
+            let mut draft = draft;
+            let Some(text) = draft.take() else {
+                return;
             };
+            if let Some(parsed) = codec.parse(&text)
+                && !is_date_disabled(parsed)
+                && Some(normalize(parsed)) != current_value.map(normalize)
+            {
+                commit(Some(parsed));
+            }
+        }
+    };
+    let commit_values = move |next: Vec<DateValue>| {
+        if !values_controlled {
+            let mut state = multi_state;
+            state.set(next.clone());
+        }
+        if let Some(cb) = on_values_change {
+            cb.call(next);
+        }
+    };
+
+    // Precompute panel cells for the current view.
+    let cells = panel_cells(picker, *view.read(), &locale.date_picker);
+    let mut date_cells: Vec<Element> = Vec::new();
+    for (index, cell) in cells.iter().enumerate() {
+        if picker == PickerMode::Week && index % 7 == 0 {
+            date_cells.push(week_number_cell(
+                &cells[index..(index + 7).min(cells.len())],
+                week_rule,
+            ));
+        }
 
-        let is_outside = cell_day.is_none();
-        let is_selected = if let (Some(day), Some(current)) = (cell_day, current_value) {
-            current.inner.year() == year_now
-                && current.inner.month() as u8 == month_now
-                && current.inner.day() == day
+        let cell_value = cell.value;
+        let in_period = |other: Option<DateValue>| match (cell_value, other) {
+            (Some(v), Some(o)) => normalize(v) == normalize(o),
+            _ => false,
+        };
+        let is_selected = if multiple {
+            current_values.iter().any(|v| in_period(Some(*v)))
         } else {
-            false
+            in_period(current_value)
         };
+        let cell_disabled = cell_value.is_some_and(&is_date_disabled);
 
         let mut cell_classes = vec!["adui-date-picker-cell".to_string()];
-        if is_outside {
+        if cell_value.is_none() {
             cell_classes.push("adui-date-picker-cell-empty".to_string());
         } else {
             cell_classes.push("adui-date-picker-cell-date".to_string());
         }
+        if cell.outside {
+            cell_classes.push("adui-date-picker-cell-outside".to_string());
+        }
+        if cell_disabled {
+            cell_classes.push("adui-date-picker-cell-disabled".to_string());
+        }
+        if in_period(today) {
+            cell_classes.push("adui-date-picker-cell-today".to_string());
+        }
         if is_selected {
             cell_classes.push("adui-date-picker-cell-selected".to_string());
         }
+        if picker == PickerMode::Week && in_period(hovered) {
+            cell_classes.push("adui-date-picker-cell-hover".to_string());
+        }
         let cell_class_attr = cell_classes.join(" ");
+        let label = cell.label.clone();
 
-        let selected_state_for_cell = selected_for_day_click;
-        let on_change_cb_for_cell = on_change_cb;
-        let controlled_flag_for_cell = controlled_flag;
         let close_for_cell = close_handle;
+        let values_for_cell = current_values.clone();
 
         let cell_node = rsx! {
             span {
                 class: "{cell_class_attr}",
+                onmouseenter: move |_| {
+                    if picker == PickerMode::Week {
+                        let mut hover = hover;
+                        hover.set(cell_value);
+                    }
+                },
                 onclick: move |_| {
                     close_for_cell.mark_internal_click();
-                    if let Some(day) = cell_day
-                        && let Some(value) = DateValue::from_ymd(year_now, month_now, day)
-                    {
-                        if controlled_flag_for_cell {
-                            if let Some(cb) = on_change_cb_for_cell {
-                                cb.call(Some(value));
-                            }
-                        } else {
-                            let mut state = selected_state_for_cell;
-                            state.set(Some(value));
-                            if let Some(cb) = on_change_cb_for_cell {
-                                cb.call(Some(value));
-                            }
+                    if cell_disabled {
+                        return;
+                    }
+                    if let Some(value) = cell_value {
+                        if multiple {
+                            // 多选模式下保持面板打开，便于继续选择。
+                            commit_values(toggle_date(&values_for_cell, normalize(value)));
+                            return;
                         }
+                        let mut draft = draft;
+                        draft.set(None);
+                        commit(Some(value));
                         // 选择后关闭面板。
                         close_for_cell.close();
                     }
                 },
-                match cell_day {
-                    Some(day) => rsx!{ "{day}" },
-                    None => rsx!{ "" },
-                }
+                "{label}"
             }
         };
 
         date_cells.push(cell_node);
     }
+    let panel = render_panel(
+        picker,
+        view,
+        &locale.date_picker,
+        close_handle,
+        hover,
+        date_cells,
+    );
+
+    let mut preset_nodes: Vec<Element> = Vec::new();
+    for (label, preset) in presets.unwrap_or_default() {
+        let values_for_preset = current_values.clone();
+        preset_nodes.push(rsx! {
+            li {
+                class: "adui-date-picker-preset",
+                onclick: move |_| {
+                    close_handle.mark_internal_click();
+                    let mut view = view;
+                    view.set(PanelView::of(preset));
+                    if multiple {
+                        let value = normalize(preset);
+                        if !values_for_preset.contains(&value) {
+                            commit_values(toggle_date(&values_for_preset, value));
+                        }
+                        return;
+                    }
+                    let mut draft = draft;
+                    draft.set(None);
+                    commit(Some(preset));
+                    close_handle.close();
+                },
+                "{label}"
+            }
+        });
+    }
+    let has_presets = !preset_nodes.is_empty();
+
+    // Selected dates shown as removable tags in multiple mode.
+    let mut tag_nodes: Vec<Element> = Vec::new();
+    if multiple {
+        for value in current_values.iter().copied() {
+            let text = codec.format(value);
+            let values_for_tag = current_values.clone();
+            tag_nodes.push(rsx! {
+                span { class: "adui-date-picker-tag",
+                    "{text}"
+                    if !is_disabled {
+                        span {
+                            class: "adui-date-picker-tag-remove",
+                            onclick: move |evt: MouseEvent| {
+                                evt.stop_propagation();
+                                commit_values(toggle_date(&values_for_tag, value));
+                            },
+                            "×"
+                        }
+                    }
+                }
+            });
+        }
+    }
 
     rsx! {
         div {
@@ -390,28 +706,56 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
                         _ => {}
                     }
                 },
-                input {
-                    class: "adui-date-picker-input",
-                    readonly: true,
-                    disabled: is_disabled,
-                    value: "{display_text}",
-                    placeholder: "{placeholder_str}",
+                if multiple {
+                    div { class: "adui-date-picker-selection",
+                        if tag_nodes.is_empty() {
+                            span { class: "adui-date-picker-placeholder", "{placeholder_str}" }
+                        }
+                        for tag in tag_nodes { {tag} }
+                    }
+                } else {
+                    input {
+                        class: "adui-date-picker-input",
+                        disabled: is_disabled,
+                        value: "{display_text}",
+                        placeholder: "{placeholder_str}",
+                        oninput: move |evt| {
+                            let text = evt.value();
+                            // Only move the panel while typing; the value is
+                            // committed on blur or Enter.
+                            if let Some(parsed) = codec.parse(&text) {
+                                let mut view = view;
+                                view.set(PanelView::of(parsed));
+                            }
+                            let mut draft = draft;
+                            draft.set(Some(text));
+                        },
+                        onkeydown: {
+                            let commit_draft = commit_draft.clone();
+                            move |evt: KeyboardEvent| {
+                                if evt.key() == Key::Enter && draft.read().is_some() {
+                                    evt.prevent_default();
+                                    evt.stop_propagation();
+                                    commit_draft();
+                                    close_handle.close();
+                                }
+                            }
+                        },
+                        onblur: move |_| commit_draft(),
+                    }
                 }
                 if allow_clear_flag && has_value && !is_disabled {
                     span {
                         class: "adui-date-picker-clear",
-                        onclick: move |_| {
-                            if controlled_flag {
-                                if let Some(cb) = on_change_cb {
-                                    cb.call(None);
-                                }
-                            } else {
-                                let mut state = selected_for_day_click;
-                                state.set(None);
-                                if let Some(cb) = on_change_cb {
-                                    cb.call(None);
-                                }
+                        onclick: move |evt: MouseEvent| {
+                            if multiple {
+                                evt.stop_propagation();
+                                commit_values(Vec::new());
+                                return;
                             }
+                            let mut draft = draft;
+                            draft.set(None);
+                            commit(None);
                         },
                         "×"
                     }
@@ -419,64 +763,13 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
             }
 
             if open_flag {
-                // Simple calendar dropdown.
                 div {
-                    class: "adui-date-picker-dropdown",
+                    class: if has_presets { "adui-date-picker-dropdown adui-date-picker-dropdown-presets" } else { "adui-date-picker-dropdown" },
                     style: "position: absolute; top: 100%; left: 0; min-width: 100%; z-index: {current_z};",
-                    // Header with month navigation.
-                    div { class: "adui-date-picker-header",
-                        button {
-                            class: "adui-date-picker-nav-btn adui-date-picker-prev-month",
-                            onclick: move |_| {
-                                close_handle.mark_internal_click();
-                                let mut year = *view_year.read();
-                                let mut month = *view_month.read();
-                                if month == 1 {
-                                    month = 12;
-                                    year -= 1;
-                                } else {
-                                    month -= 1;
-                                }
-                                let mut y = view_year;
-                                let mut m = view_month;
-                                y.set(year);
-                                m.set(month);
-                            },
-                            "<"
-                        }
-                        span { class: "adui-date-picker-header-view", "{month_label}" }
-                        button {
-                            class: "adui-date-picker-nav-btn adui-date-picker-next-month",
-                            onclick: move |_| {
-                                close_handle.mark_internal_click();
-                                let mut year = *view_year.read();
-                                let mut month = *view_month.read();
-                                if month == 12 {
-                                    month = 1;
-                                    year += 1;
-                                } else {
-                                    month += 1;
-                                }
-                                let mut y = view_year;
-                                let mut m = view_month;
-                                y.set(year);
-                                m.set(month);
-                            },
-                            ">"
-                        }
-                    }
-
-                    // Weekday header row.
-                    div { class: "adui-date-picker-week-row",
-                        for label in weekday_labels {
-                            span { class: "adui-date-picker-week-cell", "{label}" }
-                        }
-                    }
-
-                    // Date grid.
-                    div { class: "adui-date-picker-body",
-                        for cell in date_cells { {cell} }
+                    if has_presets {
+                        {render_presets(preset_nodes)}
                     }
+                    div { class: "adui-date-picker-panel", {panel} }
                 }
             }
         }
@@ -484,7 +777,7 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
 }
 
 /// Props for the RangePicker component (MVP subset).
-#[derive(Props, Clone, PartialEq)]
+#[derive(Props, Clone)]
 pub struct RangePickerProps {
     /// Controlled range value. Each side may be `None` to represent a
     /// partially selected range.
@@ -496,9 +789,13 @@ pub struct RangePickerProps {
     /// Placeholders for the start and end inputs.
     #[props(optional)]
     pub placeholder: Option<(String, String)>,
-    /// Display/parse format. MVP: primarily `YYYY-MM-DD`.
+    /// Display/parse pattern of both inputs. Defaults to the pattern of
+    /// `picker`.
     #[props(optional)]
     pub format: Option<String>,
+    /// Granularity of both ends of the range.
+    #[props(default)]
+    pub picker: PickerMode,
     /// Whether the picker is disabled.
     #[props(optional)]
     pub disabled: Option<bool>,
@@ -514,25 +811,70 @@ pub struct RangePickerProps {
     /// Change callback fired when the range changes.
     #[props(optional)]
     pub on_change: Option<EventHandler<DateRangeValue>>,
+    /// Quick picks listed beside the panel, e.g. `("This month", range)`.
+    #[props(optional)]
+    pub presets: Option<Vec<(String, DateRangeValue)>>,
+    /// Disable specific dates: (date) -> bool
+    #[props(optional)]
+    pub disabled_date: Option<Rc<dyn Fn(DateValue) -> bool>>,
+    /// Longest range in days, both ends included. Once the first end is
+    /// picked, cells further away are disabled.
+    #[props(optional)]
+    pub max_span: Option<u32>,
+    /// Timezone of "today", as on `DatePicker`.
+    #[props(optional)]
+    pub utc_offset: Option<UtcOffset>,
+}
+
+impl PartialEq for RangePickerProps {
+    fn eq(&self, other: &Self) -> bool {
+        // Compare all fields except function pointers
+        self.value == other.value
+            && self.default_value == other.default_value
+            && self.placeholder == other.placeholder
+            && self.format == other.format
+            && self.picker == other.picker
+            && self.disabled == other.disabled
+            && self.allow_clear == other.allow_clear
+            && self.class == other.class
+            && self.style == other.style
+            && self.on_change == other.on_change
+            && self.presets == other.presets
+            && self.max_span == other.max_span
+            && self.utc_offset == other.utc_offset
+    }
 }
 
-/// MVP RangePicker: single-month range selection with basic highlighting.
+/// RangePicker: single-panel range selection. While only the start is
+/// picked, the cells up to the hovered one are highlighted.
 #[component]
 pub fn RangePicker(props: RangePickerProps) -> Element {
     let RangePickerProps {
         value,
         default_value,
         placeholder,
-        format: _format,
+        format,
+        picker,
         disabled,
         allow_clear,
         class,
         style,
         on_change,
+        presets,
+        disabled_date,
+        max_span,
+        utc_offset,
     } = props;
 
-    let config = use_config();
-    let locale = config.locale;
+    let locale = use_locale();
+    let codec = DateTextCodec {
+        pattern: format.unwrap_or_else(|| picker.default_format().to_string()),
+        locale: locale.date_picker.clone(),
+        generate_config: None,
+    };
+    let week_rule = WeekRule::of(&locale.date_picker);
+    let normalize = move |value: DateValue| picker.normalize_in(value, week_rule);
+    let is_date_disabled = move |value: DateValue| disabled_date.as_ref().is_some_and(|f| f(value));
 
     let is_disabled = disabled.unwrap_or(false);
     let allow_clear_flag = allow_clear.unwrap_or(false);
@@ -541,13 +883,23 @@ pub fn RangePicker(props: RangePickerProps) -> Element {
     let range_state: Signal<DateRangeValue> = use_signal(|| initial_range);
     let range = value.unwrap_or_else(|| *range_state.read());
 
-    let start_text = range.start.map(|d| d.to_ymd_string()).unwrap_or_default();
-    let end_text = range.end.map(|d| d.to_ymd_string()).unwrap_or_default();
-
-    let default_placeholder = match locale {
-        Locale::ZhCN => ("开始日期".to_string(), "结束日期".to_string()),
-        Locale::EnUS => ("Start date".to_string(), "End date".to_string()),
-    };
+    // Texts being typed into the start / end inputs, committed on blur or
+    // Enter.
+    let start_draft: Signal<Option<String>> = use_signal(|| None);
+    let end_draft: Signal<Option<String>> = use_signal(|| None);
+    let start_text = start_draft
+        .read()
+        .clone()
+        .unwrap_or_else(|| range.start.map(|d| codec.format(d)).unwrap_or_default());
+    let end_text = end_draft
+        .read()
+        .clone()
+        .unwrap_or_else(|| range.end.map(|d| codec.format(d)).unwrap_or_default());
+
+    let default_placeholder = (
+        locale.date_picker.range_start_placeholder.clone(),
+        locale.date_picker.range_end_placeholder.clone(),
+    );
     let (start_ph, end_ph) = placeholder.unwrap_or(default_placeholder);
 
     let controlled = value.is_some();
@@ -557,38 +909,23 @@ pub fn RangePicker(props: RangePickerProps) -> Element {
     let dropdown_layer = use_dropdown_layer(open_flag);
     let current_z = *dropdown_layer.z_index.read();
 
-    // Visible calendar month/year. Use start date if present, otherwise end,
-    // otherwise a fixed reference.
-    let initial_year = range
-        .start
-        .or(range.end)
-        .map(|v| v.inner.year())
-        .unwrap_or(2024);
-    let initial_month = range
-        .start
-        .or(range.end)
-        .map(|v| v.inner.month() as u8)
-        .unwrap_or(1);
-
-    let view_year: Signal<i32> = use_signal(|| initial_year);
-    let view_month: Signal<u8> = use_signal(|| initial_month);
-
-    let year_now = *view_year.read();
-    let month_now = *view_month.read();
-    let days_in_month_now = days_in_month(year_now, month_now);
-    let first_weekday = weekday_index_monday(year_now, month_now, 1) as usize;
-    let total_cells = first_weekday + days_in_month_now as usize;
-    let padded_cells = total_cells.div_ceil(7) * 7;
-
-    let locale_for_header = locale;
-    let month_label = match locale_for_header {
-        Locale::ZhCN => format!("{year_now}年{month_now}月"),
-        Locale::EnUS => format!("{year_now}-{month_now:02}"),
-    };
-    let weekday_labels: [&str; 7] = match locale_for_header {
-        Locale::ZhCN => ["一", "二", "三", "四", "五", "六", "日"],
-        Locale::EnUS => ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
-    };
+    let today = utc_offset.map(DateValue::today);
+
+    // Visible panel page. Use start date if present, otherwise end, otherwise
+    // today, otherwise a fixed reference.
+    let view: Signal<PanelView> = use_signal(|| {
+        range
+            .start
+            .or(range.end)
+            .or(today)
+            .map(PanelView::of)
+            .unwrap_or(PanelView {
+                year: 2024,
+                month: 1,
+            })
+    });
+    let hover: Signal<Option<DateValue>> = use_signal(|| None);
+    let hovered = *hover.read();
 
     let mut control_classes = vec!["adui-date-picker adui-date-picker-range".to_string()];
     if is_disabled {
@@ -602,59 +939,88 @@ pub fn RangePicker(props: RangePickerProps) -> Element {
 
     let open_for_toggle = open_state;
 
-    let on_change_cb = on_change;
-    let range_for_click = range_state;
-
     // Shared floating close handle for range picker dropdown.
     let close_handle = use_floating_close_handle(open_for_toggle);
 
-    // Precompute date cells with simple range highlighting.
+    let apply_range = move |next: DateRangeValue| {
+        if !controlled {
+            let mut state = range_state;
+            state.set(next);
+        }
+        if let Some(cb) = on_change {
+            cb.call(next);
+        }
+    };
+
+    // First picked end while the other one is still open; `max_span` is
+    // measured from it.
+    let anchor = match (range.start, range.end) {
+        (Some(start), None) => Some(normalize(start)),
+        _ => None,
+    };
+    // Range being previewed: the picked start up to the hovered cell.
+    let hover_span = match (anchor, hovered) {
+        (Some(a), Some(hovered)) => {
+            let b = normalize(hovered);
+            Some((a.inner.min(b.inner), a.inner.max(b.inner)))
+        }
+        _ => None,
+    };
+
+    // Precompute panel cells with range highlighting.
+    let cells = panel_cells(picker, *view.read(), &locale.date_picker);
     let mut date_cells: Vec<Element> = Vec::new();
-    for index in 0..padded_cells {
-        let cell_day =
-            if index < first_weekday || index >= first_weekday + days_in_month_now as usize {
-                None
-            } else {
-                Some((index - first_weekday + 1) as u8)
-            };
+    for (index, cell) in cells.iter().enumerate() {
+        if picker == PickerMode::Week && index % 7 == 0 {
+            date_cells.push(week_number_cell(
+                &cells[index..(index + 7).min(cells.len())],
+                week_rule,
+            ));
+        }
 
-        let is_outside = cell_day.is_none();
+        let cell_value = cell.value;
+        let period = cell_value.map(normalize);
         let mut is_selected_start = false;
         let mut is_selected_end = false;
         let mut in_range = false;
+        let mut in_hover_range = false;
+        let mut cell_disabled = false;
 
-        if let Some(day) = cell_day {
-            if let Some(start) = range.start
-                && start.inner.year() == year_now
-                && start.inner.month() as u8 == month_now
-                && start.inner.day() == day
-            {
-                is_selected_start = true;
-            }
-            if let Some(end) = range.end
-                && end.inner.year() == year_now
-                && end.inner.month() as u8 == month_now
-                && end.inner.day() == day
-            {
-                is_selected_end = true;
-            }
+        if let (Some(value), Some(period)) = (cell_value, period) {
+            let date = period.inner;
+            is_selected_start = range.start.is_some_and(|s| normalize(s).inner == date);
+            is_selected_end = range.end.is_some_and(|e| normalize(e).inner == date);
             if let (Some(start), Some(end)) = (range.start, range.end) {
-                let date = DateValue::from_ymd(year_now, month_now, day).unwrap();
-                if date.inner >= start.inner && date.inner <= end.inner {
-                    in_range = true;
-                }
+                in_range = date >= normalize(start).inner && date <= normalize(end).inner;
             }
+            if let Some((lo, hi)) = hover_span {
+                in_hover_range = date >= lo && date <= hi;
+            }
+            cell_disabled = is_date_disabled(value)
+                || anchor.is_some_and(|a| !within_span(a, period, max_span));
         }
 
         let mut cell_classes = vec!["adui-date-picker-cell".to_string()];
-        if is_outside {
+        if cell_value.is_none() {
             cell_classes.push("adui-date-picker-cell-empty".to_string());
         } else {
             cell_classes.push("adui-date-picker-cell-date".to_string());
         }
+        if cell.outside {
+            cell_classes.push("adui-date-picker-cell-outside".to_string());
+        }
+        if cell_disabled {
+            cell_classes.push("adui-date-picker-cell-disabled".to_string());
+        }
+        if period.is_some() && period == today.map(normalize) {
+            cell_classes.push("adui-date-picker-cell-today".to_string());
+        }
         if in_range {
             cell_classes.push("adui-date-picker-cell-in-range".to_string());
         }
+        if in_hover_range && !cell_disabled {
+            cell_classes.push("adui-date-picker-cell-in-range-hover".to_string());
+        }
         if is_selected_start {
             cell_classes.push("adui-date-picker-cell-range-start".to_string());
         }
@@ -662,22 +1028,26 @@ pub fn RangePicker(props: RangePickerProps) -> Element {
             cell_classes.push("adui-date-picker-cell-range-end".to_string());
         }
         let cell_class_attr = cell_classes.join(" ");
+        let label = cell.label.clone();
 
-        let on_change_for_cell = on_change_cb;
-        let controlled_for_cell = controlled;
         let close_for_cell = close_handle;
 
         let cell_node = rsx! {
             span {
                 class: "{cell_class_attr}",
+                onmouseenter: move |_| {
+                    let mut hover = hover;
+                    hover.set(cell_value);
+                },
                 onclick: move |_| {
                     if is_disabled {
                         return;
                     }
                     close_for_cell.mark_internal_click();
-                    if let Some(day) = cell_day
-                        && let Some(clicked) = DateValue::from_ymd(year_now, month_now, day)
-                    {
+                    if cell_disabled {
+                        return;
+                    }
+                    if let Some(clicked) = period {
                         let mut next = range;
                         match (next.start, next.end) {
                             (None, _) => {
@@ -700,31 +1070,104 @@ pub fn RangePicker(props: RangePickerProps) -> Element {
                             }
                         }
 
-                        if controlled_for_cell {
-                            if let Some(cb) = on_change_for_cell {
-                                cb.call(next);
-                            }
-                        } else {
-                            let mut state = range_for_click;
-                            state.set(next);
-                            if let Some(cb) = on_change_for_cell {
-                                cb.call(next);
-                            }
-                        }
+                        apply_range(next);
                     }
                 },
-                match cell_day {
-                    Some(day) => rsx!{ "{day}" },
-                    None => rsx!{ "" },
-                }
+                "{label}"
             }
         };
 
         date_cells.push(cell_node);
     }
+    let panel = render_panel(
+        picker,
+        view,
+        &locale.date_picker,
+        close_handle,
+        hover,
+        date_cells,
+    );
+
+    let mut preset_nodes: Vec<Element> = Vec::new();
+    for (label, preset) in presets.unwrap_or_default() {
+        let next = DateRangeValue {
+            start: preset.start.map(normalize),
+            end: preset.end.map(normalize),
+        };
+        preset_nodes.push(rsx! {
+            li {
+                class: "adui-date-picker-preset",
+                onclick: move |_| {
+                    close_handle.mark_internal_click();
+                    if let Some(first) = next.start.or(next.end) {
+                        let mut view = view;
+                        view.set(PanelView::of(first));
+                    }
+                    let (mut start, mut end) = (start_draft, end_draft);
+                    start.set(None);
+                    end.set(None);
+                    apply_range(next);
+                    close_handle.close();
+                },
+                "{label}"
+            }
+        });
+    }
+    let has_presets = !preset_nodes.is_empty();
 
     let has_any_value = range.start.is_some() || range.end.is_some();
 
+    // Only move the panel while a side is being typed.
+    let type_side = {
+        let codec = codec.clone();
+        move |text: String, is_start: bool| {
+            let mut draft = if is_start { start_draft } else { end_draft };
+            if let Some(parsed) = codec.parse(&text) {
+                let mut view = view;
+                view.set(PanelView::of(parsed));
+            }
+            draft.set(Some(text));
+        }
+    };
+    // Commit the typed text of one side; a range typed backwards is swapped.
+    // Disabled dates and ranges longer than `max_span` are not committed.
+    let commit_side = move |is_start: bool| {
+        let mut draft = if is_start { start_draft } else { end_draft };
+        let Some(text) = draft.take() else {
+            return;
+        };
+        if let Some(parsed) = codec.parse(&text).map(normalize)
+            && !is_date_disabled(parsed)
+        {
+            let mut next = range;
+            if is_start {
+                next.start = Some(parsed);
+            } else {
+                next.end = Some(parsed);
+            }
+            if let (Some(start), Some(end)) = (next.start, next.end)
+                && start.inner > end.inner
+            {
+                next = DateRangeValue {
+                    start: Some(end),
+                    end: Some(start),
+                };
+            }
+            let fits = match (next.start, next.end) {
+                (Some(start), Some(end)) => within_span(start, end, max_span),
+                _ => true,
+            };
+            if fits && next != range {
+                apply_range(next);
+            }
+        }
+    };
+    let clear_drafts = move || {
+        let (mut start, mut end) = (start_draft, end_draft);
+        start.set(None);
+        end.set(None);
+    };
+
     rsx! {
         div {
             class: "adui-date-picker-root",
@@ -762,34 +1205,53 @@ pub fn RangePicker(props: RangePickerProps) -> Element {
                 },
                 input {
                     class: "adui-date-picker-input adui-date-picker-input-start",
-                    readonly: true,
                     disabled: is_disabled,
                     value: "{start_text}",
                     placeholder: "{start_ph}",
+                    oninput: {
+                        let type_side = type_side.clone();
+                        move |evt: FormEvent| type_side(evt.value(), true)
+                    },
+                    onkeydown: {
+                        let commit_side = commit_side.clone();
+                        move |evt: KeyboardEvent| {
+                            if evt.key() == Key::Enter && start_draft.read().is_some() {
+                                evt.prevent_default();
+                                evt.stop_propagation();
+                                commit_side(true);
+                            }
+                        }
+                    },
+                    onblur: {
+                        let commit_side = commit_side.clone();
+                        move |_| commit_side(true)
+                    },
                 }
                 span { class: "adui-date-picker-range-separator", " ~ " }
                 input {
                     class: "adui-date-picker-input adui-date-picker-input-end",
-                    readonly: true,
                     disabled: is_disabled,
                     value: "{end_text}",
                     placeholder: "{end_ph}",
+                    oninput: move |evt: FormEvent| type_side(evt.value(), false),
+                    onkeydown: {
+                        let commit_side = commit_side.clone();
+                        move |evt: KeyboardEvent| {
+                            if evt.key() == Key::Enter && end_draft.read().is_some() {
+                                evt.prevent_default();
+                                evt.stop_propagation();
+                                commit_side(false);
+                            }
+                        }
+                    },
+                    onblur: move |_| commit_side(false),
                 }
                 if allow_clear_flag && has_any_value && !is_disabled {
                     span {
                         class: "adui-date-picker-clear",
                         onclick: move |_| {
-                            if controlled {
-                                if let Some(cb) = on_change {
-                                    cb.call(DateRangeValue::empty());
-                                }
-                            } else {
-                                let mut state = range_state;
-                                state.set(DateRangeValue::empty());
-                                if let Some(cb) = on_change {
-                                    cb.call(DateRangeValue::empty());
-                                }
-                            }
+                            clear_drafts();
+                            apply_range(DateRangeValue::empty());
                         },
                         "×"
                     }
@@ -798,59 +1260,12 @@ pub fn RangePicker(props: RangePickerProps) -> Element {
 
             if open_flag {
                 div {
-                    class: "adui-date-picker-dropdown",
+                    class: if has_presets { "adui-date-picker-dropdown adui-date-picker-dropdown-presets" } else { "adui-date-picker-dropdown" },
                     style: "position: absolute; top: 100%; left: 0; min-width: 100%; z-index: {current_z};",
-                    div { class: "adui-date-picker-header",
-                        button {
-                            class: "adui-date-picker-nav-btn adui-date-picker-prev-month",
-                            onclick: move |_| {
-                                close_handle.mark_internal_click();
-                                let mut year = *view_year.read();
-                                let mut month = *view_month.read();
-                                if month == 1 {
-                                    month = 12;
-                                    year -= 1;
-                                } else {
-                                    month -= 1;
-                                }
-                                let mut y = view_year;
-                                let mut m = view_month;
-                                y.set(year);
-                                m.set(month);
-                            },
-                            "<"
-                        }
-                        span { class: "adui-date-picker-header-view", "{month_label}" }
-                        button {
-                            class: "adui-date-picker-nav-btn adui-date-picker-next-month",
-                            onclick: move |_| {
-                                close_handle.mark_internal_click();
-                                let mut year = *view_year.read();
-                                let mut month = *view_month.read();
-                                if month == 12 {
-                                    month = 1;
-                                    year += 1;
-                                } else {
-                                    month += 1;
-                                }
-                                let mut y = view_year;
-                                let mut m = view_month;
-                                y.set(year);
-                                m.set(month);
-                            },
-                            ">"
-                        }
-                    }
-
-                    div { class: "adui-date-picker-week-row",
-                        for label in weekday_labels {
-                            span { class: "adui-date-picker-week-cell", "{label}" }
-                        }
-                    }
-
-                    div { class: "adui-date-picker-body",
-                        for cell in date_cells { {cell} }
+                    if has_presets {
+                        {render_presets(preset_nodes)}
                     }
+                    div { class: "adui-date-picker-panel", {panel} }
                 }
             }
         }
@@ -860,6 +1275,7 @@ pub fn RangePicker(props: RangePickerProps) -> Element {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::calendar::{days_in_month, weekday_index_monday};
 
     #[test]
     fn date_value_from_ymd_and_to_string_are_consistent() {
@@ -901,6 +1317,16 @@ mod tests {
         assert_eq!(value4.to_ymd_string(), "1999-03-15");
     }
 
+    #[test]
+    fn parse_ymd_accepts_unpadded_and_timed_values() {
+        let expected = DateValue::from_ymd(2024, 1, 5);
+        assert_eq!(DateValue::parse_ymd("2024-1-5"), expected);
+        assert_eq!(DateValue::parse_ymd("2024-01-05"), expected);
+        assert_eq!(DateValue::parse_ymd("2024-01-05T08:30:00Z"), expected);
+        assert_eq!(DateValue::parse_ymd("2024-1-5x"), None);
+        assert_eq!(DateValue::parse_ymd("2024-1-"), None);
+    }
+
     #[test]
     fn date_value_leap_year_handling() {
         // Test leap years
@@ -963,6 +1389,41 @@ mod tests {
         assert_eq!(weekday_index_monday(2024, 1, 8), base); // Same weekday next week
     }
 
+    #[test]
+    fn date_value_from_unix_timestamp_applies_offset() {
+        // 2024-03-01T23:30:00Z
+        let at = 1_709_335_800;
+        assert_eq!(
+            DateValue::from_unix_timestamp(at, UtcOffset::UTC),
+            DateValue::from_ymd(2024, 3, 1)
+        );
+        let shanghai = UtcOffset::from_hms(8, 0, 0).unwrap();
+        assert_eq!(
+            DateValue::from_unix_timestamp(at, shanghai),
+            DateValue::from_ymd(2024, 3, 2)
+        );
+    }
+
+    #[test]
+    fn within_span_counts_both_ends() {
+        let start = DateValue::from_ymd(2024, 1, 1).unwrap();
+        let day_31 = DateValue::from_ymd(2024, 1, 31).unwrap();
+        let day_32 = DateValue::from_ymd(2024, 2, 1).unwrap();
+        assert!(within_span(start, day_31, Some(31)));
+        assert!(!within_span(start, day_32, Some(31)));
+        assert!(!within_span(day_32, start, Some(31)));
+        assert!(within_span(start, day_32, None));
+    }
+
+    #[test]
+    fn toggle_date_adds_sorted_and_removes() {
+        let a = DateValue::from_ymd(2024, 1, 5).unwrap();
+        let b = DateValue::from_ymd(2024, 1, 2).unwrap();
+        let values = toggle_date(&toggle_date(&[], a), b);
+        assert_eq!(values, vec![b, a]);
+        assert_eq!(toggle_date(&values, a), vec![b]);
+    }
+
     #[test]
     fn date_range_value_empty() {
         let range = DateRangeValue::empty();
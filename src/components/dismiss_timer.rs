@@ -0,0 +1,213 @@
+//! Auto-dismiss countdown shared by message and notification entries.
+//!
+//! Timeouts are fire-and-forget, so every entry carries a generation that is
+//! bumped whenever its countdown is restarted or paused. A timeout only
+//! closes the entry when its generation still matches.
+
+use crate::components::overlay::{OverlayHandle, OverlayKey};
+use dioxus::prelude::*;
+
+/// Countdown state of a single entry, in milliseconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct DismissTimer {
+    /// Bumped on every restart, pause and resume.
+    pub generation: u64,
+    /// Bumped on restart only; keys the progress bar so its animation
+    /// starts over after a keyed update.
+    pub epoch: u64,
+    /// Full duration; 0 disables auto-dismiss.
+    pub duration_ms: f64,
+    /// Time left when the current run started or the countdown paused.
+    pub remaining_ms: f64,
+    /// Clock time of the current run, `None` while paused.
+    pub started_at: Option<f64>,
+}
+
+impl DismissTimer {
+    pub fn new(duration_secs: f32, now: f64) -> Self {
+        let duration_ms = f64::from(duration_secs.max(0.0)) * 1000.0;
+        Self {
+            generation: 0,
+            epoch: 0,
+            duration_ms,
+            remaining_ms: duration_ms,
+            started_at: Some(now),
+        }
+    }
+
+    /// Start over with a new duration, e.g. after an update by key.
+    pub fn restart(&mut self, duration_secs: f32, now: f64) {
+        let next = Self::new(duration_secs, now);
+        self.generation += 1;
+        self.epoch += 1;
+        self.duration_ms = next.duration_ms;
+        self.remaining_ms = next.remaining_ms;
+        self.started_at = next.started_at;
+    }
+
+    /// Stop the countdown, keeping the time left.
+    pub fn pause(&mut self, now: f64) {
+        if let Some(started) = self.started_at.take() {
+            self.remaining_ms = (self.remaining_ms - (now - started)).max(0.0);
+            self.generation += 1;
+        }
+    }
+
+    /// Continue a paused countdown.
+    pub fn resume(&mut self, now: f64) {
+        if self.started_at.is_none() {
+            self.started_at = Some(now);
+            self.generation += 1;
+        }
+    }
+
+    /// Delay after which the current run should close the entry, or `None`
+    /// when paused or auto-dismiss is disabled.
+    pub fn pending_delay(&self) -> Option<f64> {
+        if self.duration_ms <= 0.0 {
+            return None;
+        }
+        self.started_at.map(|_| self.remaining_ms)
+    }
+}
+
+/// Entries that close themselves through a [`DismissTimer`].
+pub(crate) trait TimedEntry: Clone + 'static {
+    fn overlay_key(&self) -> OverlayKey;
+    /// Caller-provided key used to update an entry in place.
+    fn user_key(&self) -> Option<&str>;
+    fn timer(&self) -> &DismissTimer;
+    fn timer_mut(&mut self) -> &mut DismissTimer;
+}
+
+/// Index of the open entry with caller key `key`, if any.
+pub(crate) fn find_keyed<E: TimedEntry>(entries: &[E], key: Option<&str>) -> Option<usize> {
+    let key = key?;
+    entries.iter().position(|e| e.user_key() == Some(key))
+}
+
+/// Schedule the close of `key` for the current run of its timer.
+pub(crate) fn schedule_dismiss<E: TimedEntry>(
+    entries: Signal<Vec<E>>,
+    overlay: OverlayHandle,
+    key: OverlayKey,
+) {
+    let Some((generation, delay)) = entries
+        .peek()
+        .iter()
+        .find(|e| e.overlay_key() == key)
+        .and_then(|e| e.timer().pending_delay().map(|d| (e.timer().generation, d)))
+    else {
+        return;
+    };
+    set_timeout(delay, move || {
+        let mut entries = entries;
+        let current = entries
+            .peek()
+            .iter()
+            .any(|e| e.overlay_key() == key && e.timer().generation == generation);
+        if current {
+            entries.write().retain(|e| e.overlay_key() != key);
+            overlay.close(key);
+        }
+    });
+}
+
+/// Pause the countdown of `key`, e.g. while it is hovered.
+pub(crate) fn pause_dismiss<E: TimedEntry>(entries: Signal<Vec<E>>, key: OverlayKey) {
+    let mut entries = entries;
+    let now = now_ms();
+    if let Some(entry) = entries.write().iter_mut().find(|e| e.overlay_key() == key) {
+        entry.timer_mut().pause(now);
+    }
+}
+
+/// Resume the countdown of `key` and schedule its close.
+pub(crate) fn resume_dismiss<E: TimedEntry>(
+    entries: Signal<Vec<E>>,
+    overlay: OverlayHandle,
+    key: OverlayKey,
+) {
+    let mut writable = entries;
+    let now = now_ms();
+    if let Some(entry) = writable.write().iter_mut().find(|e| e.overlay_key() == key) {
+        entry.timer_mut().resume(now);
+    }
+    schedule_dismiss(entries, overlay, key);
+}
+
+/// Milliseconds on the host clock.
+pub(crate) fn now_ms() -> f64 {
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
+    {
+        js_sys::Date::now()
+    }
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs_f64() * 1000.0)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn set_timeout(delay_ms: f64, f: impl FnOnce() + 'static) {
+    use wasm_bindgen::{JsCast, closure::Closure};
+
+    if let Some(window) = web_sys::window() {
+        let callback = Closure::once(f);
+        let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+            callback.as_ref().unchecked_ref(),
+            delay_ms as i32,
+        );
+        callback.forget();
+    }
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+fn set_timeout(_delay_ms: f64, _f: impl FnOnce() + 'static) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pause_keeps_remaining_time() {
+        let mut timer = DismissTimer::new(3.0, 1_000.0);
+        assert_eq!(timer.pending_delay(), Some(3_000.0));
+
+        timer.pause(2_000.0);
+        assert_eq!(timer.pending_delay(), None);
+        assert_eq!(timer.remaining_ms, 2_000.0);
+        assert_eq!(timer.generation, 1);
+
+        // Pausing twice does not count the paused time.
+        timer.pause(5_000.0);
+        assert_eq!(timer.remaining_ms, 2_000.0);
+
+        timer.resume(9_000.0);
+        assert_eq!(timer.pending_delay(), Some(2_000.0));
+        assert_eq!(timer.generation, 2);
+    }
+
+    #[test]
+    fn restart_invalidates_pending_run() {
+        let mut timer = DismissTimer::new(3.0, 0.0);
+        timer.pause(2_500.0);
+        timer.restart(1.5, 4_000.0);
+        assert_eq!(timer.pending_delay(), Some(1_500.0));
+        assert_eq!(timer.epoch, 1);
+        assert_eq!(timer.generation, 2);
+    }
+
+    #[test]
+    fn zero_duration_never_schedules() {
+        let mut timer = DismissTimer::new(0.0, 0.0);
+        assert_eq!(timer.pending_delay(), None);
+        timer.pause(10.0);
+        timer.resume(20.0);
+        assert_eq!(timer.pending_delay(), None);
+    }
+}
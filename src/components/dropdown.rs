@@ -1,5 +1,8 @@
 use crate::components::config_provider::{ComponentSize, use_config};
 use crate::components::floating::use_floating_close_handle;
+use crate::components::floating_position::{
+    FloatingAlign, FloatingPlacement, FloatingSide, PositionOptions,
+};
 use crate::components::overlay::OverlayKind;
 use crate::components::select_base::use_floating_layer;
 use dioxus::events::{KeyboardEvent, MouseEvent};
@@ -40,6 +43,16 @@ pub enum DropdownPlacement {
     BottomRight,
 }
 
+impl From<DropdownPlacement> for FloatingPlacement {
+    fn from(placement: DropdownPlacement) -> Self {
+        let align = match placement {
+            DropdownPlacement::BottomLeft => FloatingAlign::Start,
+            DropdownPlacement::BottomRight => FloatingAlign::End,
+        };
+        FloatingPlacement::new(FloatingSide::Bottom, align)
+    }
+}
+
 /// Props for the lightweight Dropdown component (MVP).
 #[derive(Props, Clone, PartialEq)]
 pub struct DropdownProps {
@@ -110,6 +123,10 @@ pub fn Dropdown(props: DropdownProps) -> Element {
 
     let floating = use_floating_layer(OverlayKind::Dropdown, current_open);
     let current_z = *floating.z_index.read();
+    let positioner = floating.position;
+    let measured = positioner.position();
+    let anchor_id = positioner.anchor_id();
+    let overlay_id = positioner.overlay_id();
 
     let close_handle = if !is_controlled && matches!(trigger, DropdownTrigger::Click) {
         Some(use_floating_close_handle(open_state))
@@ -152,7 +169,16 @@ pub fn Dropdown(props: DropdownProps) -> Element {
         DropdownPlacement::BottomRight => "right: 0;",
     };
 
-    let overlay_style_attr = {
+    let overlay_style_attr = if let Some(pos) = measured {
+        let extra = overlay_style.unwrap_or_default();
+        format!(
+            "{} z-index: {}; {} {}",
+            pos.to_style(),
+            current_z,
+            width_style,
+            extra
+        )
+    } else {
         let extra = overlay_style.unwrap_or_default();
         format!(
             "position: absolute; top: 100%; margin-top: 4px; z-index: {}; {}; {} {}",
@@ -160,6 +186,11 @@ pub fn Dropdown(props: DropdownProps) -> Element {
         )
     };
 
+    let position_options = PositionOptions {
+        offset: 4.0,
+        ..PositionOptions::default()
+    };
+
     let size_class = match global_size {
         ComponentSize::Small => "adui-dropdown-sm",
         ComponentSize::Large => "adui-dropdown-lg",
@@ -170,6 +201,7 @@ pub fn Dropdown(props: DropdownProps) -> Element {
 
     rsx! {
         span {
+            id: "{anchor_id}",
             class: "{class_attr}",
             style: "position: relative; display: inline-block;",
             onmouseenter: move |_evt: MouseEvent| {
@@ -224,8 +256,12 @@ pub fn Dropdown(props: DropdownProps) -> Element {
             {children}
             if current_open {
                 div {
+                    id: "{overlay_id}",
                     class: "{overlay_class_attr} {size_class}",
                     style: "{overlay_style_attr}",
+                    onmounted: move |_| {
+                        positioner.update(placement.into(), position_options);
+                    },
                     onclick: move |_evt| {
                         if let Some(handle) = close_handle_for_menu {
                             handle.mark_internal_click();
@@ -345,6 +381,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dropdown_placement_aligns_to_trigger_edges() {
+        let left: FloatingPlacement = DropdownPlacement::BottomLeft.into();
+        let right: FloatingPlacement = DropdownPlacement::BottomRight.into();
+        assert_eq!(left.as_class_suffix(), "bottom-start");
+        assert_eq!(right.as_class_suffix(), "bottom-end");
+    }
+
     #[test]
     fn dropdown_placement_clone() {
         let original = DropdownPlacement::BottomRight;
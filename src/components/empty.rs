@@ -1,3 +1,4 @@
+use crate::components::locale::use_locale;
 use dioxus::prelude::*;
 
 /// Built-in image presets for the Empty component.
@@ -57,7 +58,8 @@ pub fn Empty(props: EmptyProps) -> Element {
     let class_attr = classes.join(" ");
     let style_attr = style.unwrap_or_default();
 
-    let description_text = description.unwrap_or_else(|| "暂无数据".to_string());
+    let locale = use_locale();
+    let description_text = description.unwrap_or_else(|| locale.empty.description.clone());
 
     // Render image content based on preset.
     let image_node = match image.unwrap_or(EmptyImage::Default) {
@@ -1,6 +1,5 @@
 use crate::theme::{ThemeTokens, use_theme};
 use dioxus::prelude::*;
-use web_sys::window;
 
 /// Visual style for the floating action button.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -308,7 +307,8 @@ pub fn BackTop(props: BackTopProps) -> Element {
                 if let Some(h) = handler.as_ref() {
                     h.call(evt.clone());
                 }
-                if let Some(win) = window() {
+                #[cfg(all(feature = "web", target_arch = "wasm32"))]
+                if let Some(win) = web_sys::window() {
                     win.scroll_to_with_x_and_y(0.0, 0.0);
                 }
             }
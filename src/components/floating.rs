@@ -35,7 +35,7 @@ impl FloatingCloseHandle {
 pub fn use_floating_close_handle(open: Signal<bool>) -> FloatingCloseHandle {
     let internal_click_flag: Signal<bool> = use_signal(|| false);
 
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         let mut flag_for_global = internal_click_flag;
         let mut open_for_global = open;
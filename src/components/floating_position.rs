@@ -0,0 +1,657 @@
+//! Collision-aware positioning for floating overlays.
+//!
+//! The geometry lives in [`compute_floating_position`], a pure function over
+//! measured rects that implements three steps:
+//!
+//! - **flip**: move to the opposite side when the preferred side overflows
+//!   the viewport and the opposite side has more room;
+//! - **shift**: slide along the cross axis so the overlay stays inside the
+//!   viewport padding;
+//! - **arrow**: report where an arrow should point so it keeps targeting the
+//!   trigger centre after shifting.
+//!
+//! [`use_floating_positioner`] wires it to the DOM on wasm32: it measures the
+//! trigger and overlay by element id, writes the result to a signal, and
+//! recomputes on scroll (capture phase, so nested scroll containers count) and
+//! resize. On other targets the position stays `None` and components keep
+//! their CSS placement.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use dioxus::core::{current_scope_id, has_context};
+use dioxus::prelude::*;
+
+/// Number of positioners created so far in one component scope.
+#[derive(Clone, Default)]
+struct FloatingIdCounter(Rc<Cell<usize>>);
+
+/// Element id for the next positioner of the current scope. Built from the
+/// scope id and a per-scope index, so server and client renders of the same
+/// tree agree and ids do not grow across requests.
+fn next_floating_id() -> String {
+    let counter = has_context::<FloatingIdCounter>()
+        .unwrap_or_else(|| provide_context(FloatingIdCounter::default()));
+    let index = counter.0.get();
+    counter.0.set(index + 1);
+    format!("adui-floating-anchor-{}-{index}", current_scope_id().0)
+}
+
+/// Rectangle in viewport coordinates (as returned by `getBoundingClientRect`).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FloatingRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl FloatingRect {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    pub fn center_x(&self) -> f64 {
+        self.x + self.width / 2.0
+    }
+
+    pub fn center_y(&self) -> f64 {
+        self.y + self.height / 2.0
+    }
+}
+
+/// Side of the trigger the overlay is placed on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FloatingSide {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+impl FloatingSide {
+    pub fn opposite(self) -> Self {
+        match self {
+            FloatingSide::Top => FloatingSide::Bottom,
+            FloatingSide::Bottom => FloatingSide::Top,
+            FloatingSide::Left => FloatingSide::Right,
+            FloatingSide::Right => FloatingSide::Left,
+        }
+    }
+
+    /// Whether the overlay sits above or below the trigger (cross axis is x).
+    pub fn is_vertical(self) -> bool {
+        matches!(self, FloatingSide::Top | FloatingSide::Bottom)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FloatingSide::Top => "top",
+            FloatingSide::Bottom => "bottom",
+            FloatingSide::Left => "left",
+            FloatingSide::Right => "right",
+        }
+    }
+}
+
+/// Alignment along the cross axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FloatingAlign {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+/// Requested placement: a side plus an alignment on that side.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FloatingPlacement {
+    pub side: FloatingSide,
+    pub align: FloatingAlign,
+}
+
+impl FloatingPlacement {
+    pub const fn new(side: FloatingSide, align: FloatingAlign) -> Self {
+        Self { side, align }
+    }
+
+    /// Class-friendly name such as `top`, `bottom-start` or `left-end`.
+    pub fn as_class_suffix(&self) -> String {
+        match self.align {
+            FloatingAlign::Center => self.side.as_str().to_string(),
+            FloatingAlign::Start => format!("{}-start", self.side.as_str()),
+            FloatingAlign::End => format!("{}-end", self.side.as_str()),
+        }
+    }
+}
+
+/// Tuning knobs for [`compute_floating_position`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PositionOptions {
+    /// Gap between trigger and overlay along the main axis.
+    pub offset: f64,
+    /// Minimum distance kept from the viewport edges.
+    pub padding: f64,
+    /// Allow moving to the opposite side when the preferred one overflows.
+    pub flip: bool,
+    /// Allow sliding along the cross axis to stay inside the viewport.
+    pub shift: bool,
+    /// Minimum distance between the arrow and the overlay corners.
+    pub arrow_padding: f64,
+}
+
+impl Default for PositionOptions {
+    fn default() -> Self {
+        Self {
+            offset: 8.0,
+            padding: 8.0,
+            flip: true,
+            shift: true,
+            arrow_padding: 12.0,
+        }
+    }
+}
+
+/// Result of a positioning pass.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatingPosition {
+    /// Left edge of the overlay in viewport coordinates.
+    pub x: f64,
+    /// Top edge of the overlay in viewport coordinates.
+    pub y: f64,
+    /// Placement actually used (may differ from the request after flipping).
+    pub placement: FloatingPlacement,
+    /// Arrow position along the cross axis, relative to the overlay's
+    /// left (top/bottom sides) or top (left/right sides) edge.
+    pub arrow_offset: f64,
+    /// Trigger rect used for this pass, e.g. to match the overlay width.
+    pub anchor: FloatingRect,
+}
+
+impl FloatingPosition {
+    /// Inline style placing the overlay with `position: fixed`. The arrow
+    /// offset is exposed as `--adui-arrow-offset`.
+    pub fn to_style(&self) -> String {
+        format!(
+            "position: fixed; left: {:.1}px; top: {:.1}px; --adui-arrow-offset: {:.1}px;",
+            self.x, self.y, self.arrow_offset
+        )
+    }
+}
+
+fn place(
+    anchor: &FloatingRect,
+    width: f64,
+    height: f64,
+    placement: FloatingPlacement,
+    offset: f64,
+) -> (f64, f64) {
+    let cross_x = match placement.align {
+        FloatingAlign::Start => anchor.x,
+        FloatingAlign::Center => anchor.center_x() - width / 2.0,
+        FloatingAlign::End => anchor.right() - width,
+    };
+    let cross_y = match placement.align {
+        FloatingAlign::Start => anchor.y,
+        FloatingAlign::Center => anchor.center_y() - height / 2.0,
+        FloatingAlign::End => anchor.bottom() - height,
+    };
+    match placement.side {
+        FloatingSide::Top => (cross_x, anchor.y - height - offset),
+        FloatingSide::Bottom => (cross_x, anchor.bottom() + offset),
+        FloatingSide::Left => (anchor.x - width - offset, cross_y),
+        FloatingSide::Right => (anchor.right() + offset, cross_y),
+    }
+}
+
+/// How far the overlay sticks out of the viewport on its main-axis side.
+fn main_axis_overflow(
+    side: FloatingSide,
+    rect: &FloatingRect,
+    viewport: &FloatingRect,
+    padding: f64,
+) -> f64 {
+    match side {
+        FloatingSide::Top => viewport.y + padding - rect.y,
+        FloatingSide::Bottom => rect.bottom() - (viewport.bottom() - padding),
+        FloatingSide::Left => viewport.x + padding - rect.x,
+        FloatingSide::Right => rect.right() - (viewport.right() - padding),
+    }
+}
+
+fn clamp_axis(pos: f64, size: f64, start: f64, len: f64, padding: f64) -> f64 {
+    let min = start + padding;
+    let max = start + len - padding - size;
+    if max < min { min } else { pos.clamp(min, max) }
+}
+
+/// Compute where an overlay of size `floating` (only width/height are used)
+/// should go next to `anchor`, keeping it inside `viewport` when possible.
+pub fn compute_floating_position(
+    anchor: FloatingRect,
+    floating: FloatingRect,
+    viewport: FloatingRect,
+    placement: FloatingPlacement,
+    options: &PositionOptions,
+) -> FloatingPosition {
+    let width = floating.width;
+    let height = floating.height;
+
+    let mut placement = placement;
+    let (mut x, mut y) = place(&anchor, width, height, placement, options.offset);
+
+    if options.flip {
+        let rect = FloatingRect::new(x, y, width, height);
+        let overflow = main_axis_overflow(placement.side, &rect, &viewport, options.padding);
+        if overflow > 0.0 {
+            let flipped = FloatingPlacement::new(placement.side.opposite(), placement.align);
+            let (fx, fy) = place(&anchor, width, height, flipped, options.offset);
+            let flipped_rect = FloatingRect::new(fx, fy, width, height);
+            let flipped_overflow =
+                main_axis_overflow(flipped.side, &flipped_rect, &viewport, options.padding);
+            if flipped_overflow < overflow {
+                placement = flipped;
+                x = fx;
+                y = fy;
+            }
+        }
+    }
+
+    if options.shift {
+        if placement.side.is_vertical() {
+            x = clamp_axis(x, width, viewport.x, viewport.width, options.padding);
+        } else {
+            y = clamp_axis(y, height, viewport.y, viewport.height, options.padding);
+        }
+    }
+
+    let (target, size) = if placement.side.is_vertical() {
+        (anchor.center_x() - x, width)
+    } else {
+        (anchor.center_y() - y, height)
+    };
+    let arrow_offset = if size <= options.arrow_padding * 2.0 {
+        size / 2.0
+    } else {
+        target.clamp(options.arrow_padding, size - options.arrow_padding)
+    };
+
+    FloatingPosition {
+        x,
+        y,
+        placement,
+        arrow_offset,
+        anchor,
+    }
+}
+
+/// Handle tying a trigger element and an overlay element to a computed
+/// [`FloatingPosition`].
+///
+/// Render the trigger with `id: positioner.anchor_id()` and the overlay with
+/// `id: positioner.overlay_id()`, then call [`FloatingPositioner::update`]
+/// once the overlay is mounted. Scroll and resize keep the position fresh
+/// while the overlay is open.
+#[derive(Clone, Copy)]
+pub struct FloatingPositioner {
+    anchor_id: Signal<String>,
+    overlay_id: Signal<String>,
+    config: Signal<Option<(FloatingPlacement, PositionOptions)>>,
+    position: Signal<Option<FloatingPosition>>,
+}
+
+impl FloatingPositioner {
+    pub fn anchor_id(&self) -> String {
+        self.anchor_id.read().clone()
+    }
+
+    pub fn overlay_id(&self) -> String {
+        self.overlay_id.read().clone()
+    }
+
+    /// Latest computed position, `None` until the first measurement.
+    pub fn position(&self) -> Option<FloatingPosition> {
+        *self.position.read()
+    }
+
+    /// Inline style for the overlay when a position is available.
+    pub fn overlay_style(&self) -> Option<String> {
+        self.position().map(|pos| pos.to_style())
+    }
+
+    /// Measure trigger and overlay with the given placement and store the
+    /// result. The placement is remembered for scroll/resize refreshes.
+    pub fn update(&self, placement: FloatingPlacement, options: PositionOptions) {
+        let mut config = self.config;
+        config.set(Some((placement, options)));
+        self.refresh();
+    }
+
+    /// Recompute using the last placement passed to [`update`](Self::update).
+    pub fn refresh(&self) {
+        let Ok(config) = self.config.try_peek().map(|c| *c) else {
+            return;
+        };
+        let Some((placement, options)) = config else {
+            return;
+        };
+        let Some((anchor, floating, viewport)) = self.measure() else {
+            return;
+        };
+        let next = compute_floating_position(anchor, floating, viewport, placement, &options);
+        let mut position = self.position;
+        if position
+            .try_peek()
+            .map(|p| *p != Some(next))
+            .unwrap_or(false)
+            && let Ok(mut slot) = position.try_write()
+        {
+            *slot = Some(next);
+        }
+    }
+
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
+    fn measure(&self) -> Option<(FloatingRect, FloatingRect, FloatingRect)> {
+        let window = web_sys::window()?;
+        let document = window.document()?;
+        let anchor_id = self.anchor_id.try_peek().ok()?.clone();
+        let overlay_id = self.overlay_id.try_peek().ok()?.clone();
+        let anchor = document.get_element_by_id(&anchor_id)?;
+        let overlay = document.get_element_by_id(&overlay_id)?;
+        let to_rect =
+            |r: web_sys::DomRect| FloatingRect::new(r.left(), r.top(), r.width(), r.height());
+        let viewport_width = window.inner_width().ok()?.as_f64()?;
+        let viewport_height = window.inner_height().ok()?.as_f64()?;
+        Some((
+            to_rect(anchor.get_bounding_client_rect()),
+            to_rect(overlay.get_bounding_client_rect()),
+            FloatingRect::new(0.0, 0.0, viewport_width, viewport_height),
+        ))
+    }
+
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+    fn measure(&self) -> Option<(FloatingRect, FloatingRect, FloatingRect)> {
+        None
+    }
+}
+
+/// Window `scroll` (capture) and `resize` listeners that refresh one
+/// positioner while its overlay is open.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+struct ViewportListener {
+    callback: wasm_bindgen::closure::Closure<dyn FnMut(web_sys::Event)>,
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+impl ViewportListener {
+    fn attach(positioner: FloatingPositioner) -> Option<Self> {
+        use wasm_bindgen::{JsCast, closure::Closure};
+
+        let window = web_sys::window()?;
+        let callback = Closure::<dyn FnMut(web_sys::Event)>::new(move |_evt: web_sys::Event| {
+            positioner.refresh();
+        });
+        let _ = window.add_event_listener_with_callback_and_bool(
+            "scroll",
+            callback.as_ref().unchecked_ref(),
+            true,
+        );
+        let _ =
+            window.add_event_listener_with_callback("resize", callback.as_ref().unchecked_ref());
+        Some(Self { callback })
+    }
+
+    fn detach(&self) {
+        use wasm_bindgen::JsCast;
+
+        if let Some(window) = web_sys::window() {
+            let _ = window.remove_event_listener_with_callback_and_bool(
+                "scroll",
+                self.callback.as_ref().unchecked_ref(),
+                true,
+            );
+            let _ = window.remove_event_listener_with_callback(
+                "resize",
+                self.callback.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
+
+/// Hook: allocate element ids and a position signal for one floating
+/// overlay. On wasm32 it also installs scroll/resize listeners that call
+/// [`FloatingPositioner::refresh`] while `open` is true; they are removed
+/// when the overlay closes or the component unmounts.
+pub fn use_floating_positioner(open: bool) -> FloatingPositioner {
+    let anchor_id = use_signal(next_floating_id);
+    let overlay_id = use_signal(|| anchor_id.peek().replace("-anchor-", "-overlay-"));
+    let config = use_signal(|| None);
+    let position = use_signal(|| None);
+
+    let positioner = FloatingPositioner {
+        anchor_id,
+        overlay_id,
+        config,
+        position,
+    };
+
+    // Drop the last measurement on close so a reopened overlay does not
+    // flash at its previous position before it is measured again.
+    use_effect(use_reactive!(|open| {
+        let mut position = position;
+        if !open && position.peek().is_some() {
+            position.set(None);
+        }
+    }));
+
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
+    {
+        let mut listener = use_hook(|| CopyValue::new(None::<ViewportListener>));
+        let attached = listener.peek().is_some();
+        if open && !attached {
+            *listener.write() = ViewportListener::attach(positioner);
+        } else if !open && let Some(current) = listener.write().take() {
+            current.detach();
+        }
+        dioxus::core::use_drop(move || {
+            if let Ok(mut slot) = listener.try_write()
+                && let Some(current) = slot.take()
+            {
+                current.detach();
+            }
+        });
+    }
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+    let _ = open;
+
+    positioner
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: FloatingRect = FloatingRect {
+        x: 0.0,
+        y: 0.0,
+        width: 1000.0,
+        height: 800.0,
+    };
+
+    fn top() -> FloatingPlacement {
+        FloatingPlacement::new(FloatingSide::Top, FloatingAlign::Center)
+    }
+
+    #[test]
+    fn places_centered_above_trigger() {
+        let anchor = FloatingRect::new(400.0, 400.0, 100.0, 30.0);
+        let floating = FloatingRect::new(0.0, 0.0, 60.0, 20.0);
+        let pos = compute_floating_position(
+            anchor,
+            floating,
+            VIEWPORT,
+            top(),
+            &PositionOptions::default(),
+        );
+        assert_eq!(pos.placement, top());
+        assert_eq!(pos.x, 420.0);
+        assert_eq!(pos.y, 400.0 - 20.0 - 8.0);
+        assert_eq!(pos.arrow_offset, 30.0);
+    }
+
+    #[test]
+    fn start_and_end_alignment() {
+        let anchor = FloatingRect::new(400.0, 400.0, 100.0, 30.0);
+        let floating = FloatingRect::new(0.0, 0.0, 200.0, 50.0);
+        let options = PositionOptions::default();
+        let start = FloatingPlacement::new(FloatingSide::Bottom, FloatingAlign::Start);
+        let end = FloatingPlacement::new(FloatingSide::Bottom, FloatingAlign::End);
+        let pos = compute_floating_position(anchor, floating, VIEWPORT, start, &options);
+        assert_eq!((pos.x, pos.y), (400.0, 438.0));
+        let pos = compute_floating_position(anchor, floating, VIEWPORT, end, &options);
+        assert_eq!(pos.x, 300.0);
+    }
+
+    #[test]
+    fn flips_to_opposite_side_when_overflowing() {
+        let anchor = FloatingRect::new(400.0, 10.0, 100.0, 30.0);
+        let floating = FloatingRect::new(0.0, 0.0, 60.0, 40.0);
+        let pos = compute_floating_position(
+            anchor,
+            floating,
+            VIEWPORT,
+            top(),
+            &PositionOptions::default(),
+        );
+        assert_eq!(pos.placement.side, FloatingSide::Bottom);
+        assert_eq!(pos.y, 48.0);
+    }
+
+    #[test]
+    fn keeps_side_when_flip_is_disabled_or_worse() {
+        let anchor = FloatingRect::new(400.0, 10.0, 100.0, 30.0);
+        let floating = FloatingRect::new(0.0, 0.0, 60.0, 40.0);
+        let options = PositionOptions {
+            flip: false,
+            ..PositionOptions::default()
+        };
+        let pos = compute_floating_position(anchor, floating, VIEWPORT, top(), &options);
+        assert_eq!(pos.placement.side, FloatingSide::Top);
+
+        // Overflows above, but would overflow far more below the trigger.
+        let low_anchor = FloatingRect::new(400.0, 700.0, 100.0, 30.0);
+        let tall = FloatingRect::new(0.0, 0.0, 60.0, 750.0);
+        let pos = compute_floating_position(
+            low_anchor,
+            tall,
+            VIEWPORT,
+            top(),
+            &PositionOptions::default(),
+        );
+        assert_eq!(pos.placement.side, FloatingSide::Top);
+    }
+
+    #[test]
+    fn shifts_into_viewport_and_moves_arrow() {
+        let anchor = FloatingRect::new(0.0, 400.0, 20.0, 20.0);
+        let floating = FloatingRect::new(0.0, 0.0, 200.0, 40.0);
+        let pos = compute_floating_position(
+            anchor,
+            floating,
+            VIEWPORT,
+            top(),
+            &PositionOptions::default(),
+        );
+        assert_eq!(pos.x, 8.0);
+        // Arrow still points at the trigger centre (x = 10), clamped by padding.
+        assert_eq!(pos.arrow_offset, 12.0);
+
+        let anchor = FloatingRect::new(960.0, 400.0, 20.0, 20.0);
+        let pos = compute_floating_position(
+            anchor,
+            floating,
+            VIEWPORT,
+            top(),
+            &PositionOptions::default(),
+        );
+        assert_eq!(pos.x, 1000.0 - 8.0 - 200.0);
+        assert_eq!(pos.arrow_offset, 970.0 - pos.x);
+    }
+
+    #[test]
+    fn side_placements_shift_vertically() {
+        let anchor = FloatingRect::new(400.0, 780.0, 40.0, 20.0);
+        let floating = FloatingRect::new(0.0, 0.0, 100.0, 100.0);
+        let right = FloatingPlacement::new(FloatingSide::Right, FloatingAlign::Center);
+        let pos = compute_floating_position(
+            anchor,
+            floating,
+            VIEWPORT,
+            right,
+            &PositionOptions::default(),
+        );
+        assert_eq!(pos.x, 448.0);
+        assert_eq!(pos.y, 800.0 - 8.0 - 100.0);
+        assert_eq!(pos.placement.as_class_suffix(), "right");
+    }
+
+    #[test]
+    fn position_style_uses_fixed_coordinates() {
+        let pos = FloatingPosition {
+            x: 10.0,
+            y: 20.5,
+            placement: top(),
+            arrow_offset: 6.0,
+            anchor: FloatingRect::default(),
+        };
+        assert_eq!(
+            pos.to_style(),
+            "position: fixed; left: 10.0px; top: 20.5px; --adui-arrow-offset: 6.0px;"
+        );
+    }
+
+    #[test]
+    fn ids_are_stable_across_virtual_doms() {
+        fn app() -> Element {
+            let first = use_floating_positioner(false);
+            let second = use_floating_positioner(false);
+            rsx! {
+                span { id: first.anchor_id() }
+                span { id: second.anchor_id() }
+                span { id: second.overlay_id() }
+            }
+        }
+        let render = || {
+            let mut dom = VirtualDom::new(app);
+            dom.rebuild_in_place();
+            dioxus_ssr::render(&dom)
+        };
+        let html = render();
+        assert_eq!(html, render(), "ids must not depend on a global counter");
+        let scope = scope_in_id(&html);
+        assert!(html.contains(&format!("adui-floating-anchor-{scope}-0")));
+        assert!(html.contains(&format!("adui-floating-anchor-{scope}-1")));
+        assert!(html.contains(&format!("adui-floating-overlay-{scope}-1")));
+    }
+
+    /// Scope id embedded in the first rendered positioner id.
+    fn scope_in_id(html: &str) -> &str {
+        let start = html.find("adui-floating-anchor-").unwrap() + "adui-floating-anchor-".len();
+        let rest = &html[start..];
+        &rest[..rest.find('-').unwrap()]
+    }
+}
@@ -1,4 +1,8 @@
+pub use crate::components::form_path::{NamePath, NameSegment};
+use crate::components::form_path::{get_in, merge_value, set_in};
+use crate::components::form_typed::typed_from_values;
 use crate::components::grid::ColProps;
+use crate::components::icon::{Icon, IconKind};
 use crate::foundation::{
     ClassListExt, FormClassNames, FormSemantic, FormStyles, StyleStringExt, Variant,
 };
@@ -7,17 +11,83 @@ use dioxus::{
     prelude::*,
 };
 use regex::Regex;
+use serde::{Serialize, de::DeserializeOwned};
 use serde_json::Value;
 use std::{
     cell::RefCell,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
+    fmt,
+    future::Future,
+    pin::Pin,
     rc::Rc,
     sync::atomic::{AtomicUsize, Ordering},
+    task::{Context, Poll, Waker},
 };
 
 pub type FormValues = HashMap<String, Value>;
 pub type FormErrors = HashMap<String, String>;
-pub type FormValidator = fn(Option<&Value>) -> Result<(), String>;
+
+/// Future returned by an async [`FormValidator`].
+pub type FormValidatorFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+/// Cross-field check with read access to the whole form.
+pub type FormFieldsValidatorFn = Rc<dyn Fn(&FormHandle, Option<&Value>) -> Result<(), String>>;
+
+/// Async check receiving a handle and a snapshot of the field value.
+pub type AsyncFormValidatorFn = Rc<dyn Fn(FormHandle, Option<Value>) -> FormValidatorFuture>;
+
+/// Custom check attached to a [`FormRule`].
+#[derive(Clone)]
+pub enum FormValidator {
+    /// Checks the field value on its own.
+    Value(fn(Option<&Value>) -> Result<(), String>),
+    /// Checks the field value against other fields, e.g. a password
+    /// confirmation.
+    Form(FormFieldsValidatorFn),
+    /// Awaits an external check such as "username taken". The field is marked
+    /// as validating meanwhile, and a result that arrives after the value has
+    /// changed again is discarded.
+    Async(AsyncFormValidatorFn),
+}
+
+impl FormValidator {
+    /// Value-only validator; accepts a plain `fn` item such as the functions
+    /// previously assigned to `FormRule::validator` directly.
+    pub fn value(f: fn(Option<&Value>) -> Result<(), String>) -> Self {
+        FormValidator::Value(f)
+    }
+
+    /// Cross-field validator with read access to the whole form.
+    pub fn form(f: impl Fn(&FormHandle, Option<&Value>) -> Result<(), String> + 'static) -> Self {
+        FormValidator::Form(Rc::new(f))
+    }
+
+    /// Async validator; the future receives a handle and a snapshot of the
+    /// field value.
+    pub fn future<F>(f: impl Fn(FormHandle, Option<Value>) -> F + 'static) -> Self
+    where
+        F: Future<Output = Result<(), String>> + 'static,
+    {
+        FormValidator::Async(Rc::new(move |handle, value| Box::pin(f(handle, value))))
+    }
+}
+
+impl From<fn(Option<&Value>) -> Result<(), String>> for FormValidator {
+    fn from(f: fn(Option<&Value>) -> Result<(), String>) -> Self {
+        FormValidator::Value(f)
+    }
+}
+
+impl fmt::Debug for FormValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            FormValidator::Value(_) => "Value",
+            FormValidator::Form(_) => "Form",
+            FormValidator::Async(_) => "Async",
+        };
+        write!(f, "FormValidator::{kind}")
+    }
+}
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum FormLayout {
@@ -133,6 +203,25 @@ pub struct ScrollToFirstErrorConfig {
     pub behavior: Option<String>,
 }
 
+/// When a [`FormRule`] runs. Every rule also runs on submit and in
+/// `FormHandle::validate_fields`. Once a trigger has fired for a field, its
+/// rules keep running on the field's later events, so a passing change check
+/// does not clear an error found on blur.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ValidateTrigger {
+    #[default]
+    Change,
+    Blur,
+    Submit,
+}
+
+impl ValidateTrigger {
+    /// Whether a rule with this trigger runs for `event`.
+    fn fires_on(self, event: ValidateTrigger) -> bool {
+        event == ValidateTrigger::Submit || self == event
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct FormRule {
     pub required: bool,
@@ -142,6 +231,9 @@ pub struct FormRule {
     pub pattern: Option<String>,
     pub message: Option<String>,
     pub validator: Option<FormValidator>,
+    /// Report failures as warnings: shown on the item but not blocking submit.
+    pub warning_only: bool,
+    pub validate_trigger: ValidateTrigger,
 }
 
 impl PartialEq for FormRule {
@@ -151,6 +243,8 @@ impl PartialEq for FormRule {
             && self.max == other.max
             && self.len == other.len
             && self.pattern == other.pattern
+            && self.warning_only == other.warning_only
+            && self.validate_trigger == other.validate_trigger
             && self.message == other.message
     }
 }
@@ -160,6 +254,13 @@ pub struct FormFinishEvent {
     pub values: FormValues,
 }
 
+impl FormFinishEvent {
+    /// Deserialize the submitted values into `T`.
+    pub fn typed<T: DeserializeOwned>(&self) -> Result<T, FormErrors> {
+        typed_from_values(self.values.clone())
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct FormFinishFailedEvent {
     pub errors: FormErrors,
@@ -174,17 +275,115 @@ pub struct ValuesChangeEvent {
     pub all_values: FormValues,
 }
 
+/// Per-field state, as returned by [`FormHandle::get_field_meta`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldMeta {
+    pub name: String,
+    /// The user changed the field.
+    pub touched: bool,
+    /// The value differs from `initial_values`.
+    pub dirty: bool,
+    /// An async validator is pending.
+    pub validating: bool,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+type RuleRegistry = Rc<RefCell<HashMap<String, Vec<FormRule>>>>;
+
 #[derive(Clone)]
 struct FormStore {
     values: FormValues,
+    initial_values: FormValues,
     errors: FormErrors,
+    warnings: HashMap<String, Vec<String>>,
+    touched: HashSet<String>,
+    /// Triggers that have fired per field; their rules join later runs.
+    fired: HashMap<String, HashSet<ValidateTrigger>>,
+    /// Fields with async validators in flight, keyed to the run token.
+    validating: HashMap<String, u64>,
+    validation_seq: u64,
 }
 
 impl FormStore {
     fn new() -> Self {
         Self {
             values: HashMap::new(),
+            initial_values: HashMap::new(),
             errors: HashMap::new(),
+            warnings: HashMap::new(),
+            touched: HashSet::new(),
+            fired: HashMap::new(),
+            validating: HashMap::new(),
+            validation_seq: 0,
+        }
+    }
+
+    fn is_touched(&self, path: &NamePath) -> bool {
+        self.touched
+            .iter()
+            .any(|name| path.is_prefix_of(&NamePath::parse(name)))
+    }
+
+    fn is_dirty(&self, path: &NamePath) -> bool {
+        get_in(&self.values, path) != get_in(&self.initial_values, path)
+    }
+
+    /// Record that `trigger` fired for `name` and return every trigger fired
+    /// so far. Submit runs all rules, so it counts as every trigger.
+    fn fire(&mut self, name: &str, trigger: ValidateTrigger) -> HashSet<ValidateTrigger> {
+        let fired = self.fired.entry(name.to_string()).or_default();
+        if trigger == ValidateTrigger::Submit {
+            fired.extend([
+                ValidateTrigger::Change,
+                ValidateTrigger::Blur,
+                ValidateTrigger::Submit,
+            ]);
+        } else {
+            fired.insert(trigger);
+        }
+        fired.clone()
+    }
+
+    /// Start an async run for `name`, superseding any run still in flight.
+    fn begin_validation(&mut self, name: &str) -> u64 {
+        self.validation_seq += 1;
+        self.validating
+            .insert(name.to_string(), self.validation_seq);
+        self.errors.remove(name);
+        self.validation_seq
+    }
+
+    /// Record the result of run `token`. Returns `false` when the run was
+    /// superseded or cancelled and the result was dropped.
+    fn finish_validation(
+        &mut self,
+        name: &str,
+        token: u64,
+        error: Option<String>,
+        warnings: Vec<String>,
+    ) -> bool {
+        if self.validating.get(name) != Some(&token) {
+            return false;
+        }
+        self.validating.remove(name);
+        match error {
+            Some(msg) => {
+                self.errors.insert(name.to_string(), msg);
+            }
+            None => {
+                self.errors.remove(name);
+            }
+        }
+        self.set_warnings(name, warnings);
+        true
+    }
+
+    fn set_warnings(&mut self, name: &str, warnings: Vec<String>) {
+        if warnings.is_empty() {
+            self.warnings.remove(name);
+        } else {
+            self.warnings.insert(name.to_string(), warnings);
         }
     }
 }
@@ -194,7 +393,12 @@ static FORM_HANDLE_ID: AtomicUsize = AtomicUsize::new(0);
 #[derive(Clone)]
 pub struct FormHandle {
     store: Rc<RefCell<FormStore>>,
-    listeners: Rc<RefCell<HashMap<String, Vec<ScopeId>>>>,
+    /// Scopes to re-render per field, keyed by the parsed path so
+    /// notifications do not re-parse every listener name.
+    listeners: Rc<RefCell<HashMap<NamePath, Vec<ScopeId>>>>,
+    registry: RuleRegistry,
+    /// Tasks waiting for an async run to settle, see [`ValidationSettled`].
+    settle_wakers: Rc<RefCell<Vec<Waker>>>,
     id: usize,
 }
 
@@ -210,42 +414,82 @@ impl FormHandle {
         Self {
             store: Rc::new(RefCell::new(FormStore::new())),
             listeners: Rc::new(RefCell::new(HashMap::new())),
+            registry: Rc::new(RefCell::new(HashMap::new())),
+            settle_wakers: Rc::new(RefCell::new(Vec::new())),
             id,
         }
     }
 
-    pub fn set_field_value(&self, name: &str, value: Value) {
+    /// Write the value at `name`, e.g. `"address.city"` or `"items[0].qty"`.
+    pub fn set_field_value(&self, name: impl Into<NamePath>, value: Value) {
+        let path = name.into();
         if let Ok(mut store) = self.store.try_borrow_mut() {
-            store.values.insert(name.to_string(), value);
+            set_in(&mut store.values, &path, value);
         }
-        self.notify(name);
+        self.notify_path(&path);
     }
 
-    pub fn get_field_value(&self, name: &str) -> Option<Value> {
+    pub fn get_field_value(&self, name: impl Into<NamePath>) -> Option<Value> {
+        let path = name.into();
         self.store
             .try_borrow()
             .ok()
-            .and_then(|store| store.values.get(name).cloned())
+            .and_then(|store| get_in(&store.values, &path).cloned())
     }
 
-    pub fn set_error(&self, name: &str, message: Option<String>) {
+    /// Values of the given fields as one nested object; an empty list returns
+    /// every value.
+    pub fn get_fields_value(&self, names: &[NamePath]) -> Value {
+        let Ok(store) = self.store.try_borrow() else {
+            return Value::Object(Default::default());
+        };
+        if names.is_empty() {
+            return Value::Object(store.values.clone().into_iter().collect());
+        }
+        let mut picked = FormValues::new();
+        for path in names {
+            if let Some(value) = get_in(&store.values, path) {
+                set_in(&mut picked, path, value.clone());
+            }
+        }
+        Value::Object(picked.into_iter().collect())
+    }
+
+    /// Deep-merge a nested object into the form values. Objects merge key by
+    /// key; arrays and scalars replace what was there.
+    pub fn set_fields_value(&self, values: Value) {
+        let Value::Object(patch) = values else {
+            return;
+        };
+        let changed: Vec<String> = patch.keys().cloned().collect();
+        if let Ok(mut store) = self.store.try_borrow_mut() {
+            for (key, value) in patch {
+                merge_value(store.values.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        self.notify_all(&changed);
+    }
+
+    pub fn set_error(&self, name: impl Into<NamePath>, message: Option<String>) {
+        let key = name.into().to_string();
         if let Ok(mut store) = self.store.try_borrow_mut() {
             match message {
                 Some(msg) => {
-                    store.errors.insert(name.to_string(), msg);
+                    store.errors.insert(key, msg);
                 }
                 None => {
-                    store.errors.remove(name);
+                    store.errors.remove(&key);
                 }
             }
         }
     }
 
-    pub fn get_error(&self, name: &str) -> Option<String> {
+    pub fn get_error(&self, name: impl Into<NamePath>) -> Option<String> {
+        let key = name.into().to_string();
         self.store
             .try_borrow()
             .ok()
-            .and_then(|store| store.errors.get(name).cloned())
+            .and_then(|store| store.errors.get(&key).cloned())
     }
 
     pub fn values(&self) -> FormValues {
@@ -262,22 +506,243 @@ impl FormHandle {
             .unwrap_or_default()
     }
 
+    /// Whether an async validator of `name` is still pending.
+    pub fn is_field_validating(&self, name: impl Into<NamePath>) -> bool {
+        let key = name.into().to_string();
+        self.store
+            .try_borrow()
+            .map(|store| store.validating.contains_key(&key))
+            .unwrap_or(false)
+    }
+
+    /// Whether any field has an async validator pending.
+    pub fn is_validating(&self) -> bool {
+        self.store
+            .try_borrow()
+            .map(|store| !store.validating.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Restore `initial_values` and clear errors, warnings and touched state.
     pub fn reset_fields(&self) {
         if let Ok(mut store) = self.store.try_borrow_mut() {
-            store.values.clear();
+            store.values = store.initial_values.clone();
             store.errors.clear();
+            store.warnings.clear();
+            store.touched.clear();
+            store.fired.clear();
+            store.validating.clear();
+        }
+        self.wake_settled();
+        let paths = self.listeners.borrow().keys().cloned().collect::<Vec<_>>();
+        for path in &paths {
+            self.notify_path(path);
+        }
+    }
+
+    /// Values that `reset_fields` restores and `dirty` compares against.
+    pub fn set_initial_values(&self, values: FormValues) {
+        if let Ok(mut store) = self.store.try_borrow_mut() {
+            store.initial_values = values;
+        }
+    }
+
+    /// Whether the user changed `name` (or any field below it).
+    pub fn is_field_touched(&self, name: impl Into<NamePath>) -> bool {
+        let path = name.into();
+        self.store
+            .try_borrow()
+            .map(|store| store.is_touched(&path))
+            .unwrap_or(false)
+    }
+
+    /// Whether any (or, with `all_touched`, every) listed field is touched.
+    /// `None` checks the fields that have rules or were touched.
+    pub fn is_fields_touched(&self, names: Option<&[NamePath]>, all_touched: bool) -> bool {
+        let names = match names {
+            Some(names) => names.to_vec(),
+            None => self
+                .field_names()
+                .iter()
+                .map(|name| NamePath::parse(name))
+                .collect(),
+        };
+        if all_touched {
+            !names.is_empty() && names.iter().all(|name| self.is_field_touched(name))
+        } else {
+            names.iter().any(|name| self.is_field_touched(name))
+        }
+    }
+
+    /// Whether the value at `name` differs from `initial_values`.
+    pub fn is_field_dirty(&self, name: impl Into<NamePath>) -> bool {
+        let path = name.into();
+        self.store
+            .try_borrow()
+            .map(|store| store.is_dirty(&path))
+            .unwrap_or(false)
+    }
+
+    /// Whether any value differs from `initial_values`, e.g. for an
+    /// "unsaved changes" guard.
+    pub fn is_dirty(&self) -> bool {
+        self.store
+            .try_borrow()
+            .map(|store| store.values != store.initial_values)
+            .unwrap_or(false)
+    }
+
+    pub fn get_field_meta(&self, name: impl Into<NamePath>) -> FieldMeta {
+        let path = name.into();
+        let key = path.to_string();
+        let Ok(store) = self.store.try_borrow() else {
+            return FieldMeta {
+                name: key,
+                ..FieldMeta::default()
+            };
+        };
+        FieldMeta {
+            touched: store.is_touched(&path),
+            dirty: store.is_dirty(&path),
+            validating: store.validating.contains_key(&key),
+            errors: store.errors.get(&key).cloned().into_iter().collect(),
+            warnings: store.warnings.get(&key).cloned().unwrap_or_default(),
+            name: key,
+        }
+    }
+
+    pub fn get_warnings(&self, name: impl Into<NamePath>) -> Vec<String> {
+        let key = name.into().to_string();
+        self.store
+            .try_borrow()
+            .ok()
+            .and_then(|store| store.warnings.get(&key).cloned())
+            .unwrap_or_default()
+    }
+
+    /// Run every rule of the listed fields (all fields with rules when
+    /// `None`), awaiting async validators. Resolves to the form values, or
+    /// to the errors of the listed fields.
+    pub async fn validate_fields(
+        &self,
+        names: Option<&[NamePath]>,
+    ) -> Result<FormValues, FormErrors> {
+        let names: Vec<String> = match names {
+            Some(names) => names.iter().map(NamePath::to_string).collect(),
+            None => self.registry.borrow().keys().cloned().collect(),
+        };
+        validate_names(self, &self.registry, &names, ValidateTrigger::Submit).await;
+        let errors: FormErrors = self
+            .errors()
+            .into_iter()
+            .filter(|(name, _)| names.contains(name))
+            .collect();
+        if errors.is_empty() {
+            Ok(self.values())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn touch(&self, name: &str) {
+        if let Ok(mut store) = self.store.try_borrow_mut() {
+            store.touched.insert(name.to_string());
+        }
+    }
+
+    fn field_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.registry.borrow().keys().cloned().collect();
+        if let Ok(store) = self.store.try_borrow() {
+            for name in &store.touched {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+        }
+        names
+    }
+
+    fn set_warnings(&self, name: &str, warnings: Vec<String>) {
+        if let Ok(mut store) = self.store.try_borrow_mut() {
+            store.set_warnings(name, warnings);
+        }
+    }
+
+    fn fire(&self, name: &str, trigger: ValidateTrigger) -> HashSet<ValidateTrigger> {
+        self.store
+            .try_borrow_mut()
+            .map(|mut store| store.fire(name, trigger))
+            .unwrap_or_default()
+    }
+
+    fn begin_validation(&self, name: &str) -> Option<u64> {
+        let token = self
+            .store
+            .try_borrow_mut()
+            .ok()
+            .map(|mut store| store.begin_validation(name));
+        self.notify(name);
+        token
+    }
+
+    fn finish_validation(
+        &self,
+        name: &str,
+        token: u64,
+        error: Option<String>,
+        warnings: Vec<String>,
+    ) -> bool {
+        let applied = self
+            .store
+            .try_borrow_mut()
+            .map(|mut store| store.finish_validation(name, token, error, warnings))
+            .unwrap_or(false);
+        if applied {
+            self.wake_settled();
+            self.notify(name);
+        }
+        applied
+    }
+
+    /// Drop any async run of `name`; its result will be ignored.
+    fn cancel_validation(&self, name: &str) {
+        let cancelled = self
+            .store
+            .try_borrow_mut()
+            .map(|mut store| store.validating.remove(name).is_some())
+            .unwrap_or(false);
+        if cancelled {
+            self.wake_settled();
+            self.notify(name);
+        }
+    }
+
+    fn wake_settled(&self) {
+        let wakers = std::mem::take(&mut *self.settle_wakers.borrow_mut());
+        for waker in wakers {
+            waker.wake();
         }
-        let names = self.listeners.borrow().keys().cloned().collect::<Vec<_>>();
-        self.notify_all(&names);
     }
 
     fn notify(&self, name: &str) {
-        let targets = self
-            .listeners
-            .borrow()
-            .get(name)
-            .cloned()
-            .unwrap_or_default();
+        self.notify_path(&NamePath::parse(name));
+    }
+
+    /// Re-render listeners of `path`, its ancestors and its descendants.
+    fn notify_path(&self, path: &NamePath) {
+        let mut targets: Vec<ScopeId> = Vec::new();
+        for (name, scopes) in self.listeners.borrow().iter() {
+            if name.overlaps(path) {
+                for scope in scopes {
+                    if !targets.contains(scope) {
+                        targets.push(*scope);
+                    }
+                }
+            }
+        }
+        if targets.is_empty() {
+            return;
+        }
         let scheduler = schedule_update_any();
         for scope in targets {
             scheduler(scope);
@@ -290,15 +755,55 @@ impl FormHandle {
         }
     }
 
-    pub fn register_listener(&self, name: &str, scope_id: ScopeId) {
+    pub fn register_listener(&self, name: impl Into<NamePath>, scope_id: ScopeId) {
         let mut map = self.listeners.borrow_mut();
-        let entry = map.entry(name.to_string()).or_default();
+        let entry = map.entry(name.into()).or_default();
         if !entry.contains(&scope_id) {
             entry.push(scope_id);
         }
     }
 }
 
+impl FormHandle {
+    /// Fill fields from a serializable value; its fields are merged into the
+    /// current values like [`set_fields_value`](Self::set_fields_value).
+    pub fn set_from<T: Serialize>(&self, value: &T) -> Result<(), serde_json::Error> {
+        match serde_json::to_value(value)? {
+            object @ Value::Object(_) => {
+                self.set_fields_value(object);
+                Ok(())
+            }
+            _ => Err(serde::ser::Error::custom(
+                "form values must serialize to an object",
+            )),
+        }
+    }
+
+    /// Deserialize the current values into `T`. On failure the error is keyed
+    /// by the path of the offending field, as in [`errors`](Self::errors).
+    pub fn try_into_typed<T: DeserializeOwned>(&self) -> Result<T, FormErrors> {
+        typed_from_values(self.values())
+    }
+}
+
+/// Adapt a handler taking `T` for `Form::on_finish`. When the submitted
+/// values do not deserialize, the field errors are shown on `form` (which must
+/// be the handle passed to the `Form`) and the handler is skipped.
+pub fn typed_finish<T: DeserializeOwned + 'static>(
+    form: FormHandle,
+    mut handler: impl FnMut(T) + 'static,
+) -> EventHandler<FormFinishEvent> {
+    EventHandler::new(move |evt: FormFinishEvent| match evt.typed::<T>() {
+        Ok(value) => handler(value),
+        Err(errors) => {
+            for (name, message) in errors {
+                form.set_error(&name, Some(message));
+                form.notify(&name);
+            }
+        }
+    })
+}
+
 impl Default for FormHandle {
     fn default() -> Self {
         FormHandle::new()
@@ -319,7 +824,6 @@ struct FormContext {
     disabled: bool,
     #[allow(dead_code)]
     variant: Option<Variant>,
-    #[allow(dead_code)]
     feedback_icons: Option<FeedbackIcons>,
     registry: Rc<RefCell<HashMap<String, Vec<FormRule>>>>,
     on_values_change: Option<EventHandler<ValuesChangeEvent>>,
@@ -334,6 +838,7 @@ pub struct FormItemControlContext {
     on_values_change: Option<EventHandler<ValuesChangeEvent>>,
     value_prop_name: Option<String>,
     get_value_from_event: Option<GetValueFromEventFn>,
+    normalize: Option<Callback<Value, Value>>,
 }
 
 impl FormItemControlContext {
@@ -366,13 +871,18 @@ impl FormItemControlContext {
         if self.disabled {
             return;
         }
+        let value = match self.normalize {
+            Some(normalize) => normalize.call(value),
+            None => value,
+        };
+        self.handle.touch(&self.name);
         self.handle.set_field_value(&self.name, value);
-        self.run_validation();
+        self.run_validation(ValidateTrigger::Change);
 
         if let Some(cb) = self.on_values_change.as_ref() {
             let mut changed = FormValues::new();
             if let Some(current) = self.handle.get_field_value(&self.name) {
-                changed.insert(self.name.clone(), current);
+                set_in(&mut changed, &NamePath::parse(&self.name), current);
             }
             let all = self.handle.values();
             cb.call(ValuesChangeEvent {
@@ -390,8 +900,15 @@ impl FormItemControlContext {
         self.disabled
     }
 
-    fn run_validation(&self) {
-        apply_validation_result(&self.handle, &self.registry, &self.name);
+    /// Run the field's blur-triggered rules. `FormItem` calls this when focus
+    /// leaves its control; like Ant Design, leaving a field does not mark it
+    /// as touched.
+    pub fn blur(&self) {
+        self.run_validation(ValidateTrigger::Blur);
+    }
+
+    fn run_validation(&self, trigger: ValidateTrigger) {
+        apply_validation_result(&self.handle, &self.registry, &self.name, trigger);
     }
 }
 
@@ -423,6 +940,29 @@ impl FormListContext {
     pub fn remove(&self, index: usize) {
         form_list_remove(&self.handle, &self.name, index);
     }
+
+    /// Path of the item at `index`, e.g. `users[0]`.
+    pub fn item_path(&self, index: usize) -> NamePath {
+        NamePath::parse(&self.name).index(index)
+    }
+
+    /// Path of `field` inside the item at `index`, e.g. `users[0].email`,
+    /// for use as the `name` of a nested `FormItem`.
+    pub fn field_path(&self, index: usize, field: impl Into<NamePath>) -> NamePath {
+        let mut path = self.item_path(index);
+        path.0.extend(field.into().0);
+        path
+    }
+
+    /// Metadata for every current item.
+    pub fn items(&self) -> Vec<FormListItemMeta> {
+        (0..self.len())
+            .map(|index| FormListItemMeta {
+                index,
+                name: self.item_path(index).to_string(),
+            })
+            .collect()
+    }
 }
 
 /// Access the nearest `FormListContext`, if any.
@@ -445,7 +985,7 @@ pub fn use_form_item_control() -> Option<FormItemControlContext> {
 
 /// Create a standalone form handle (类似 `Form.useForm`).
 pub fn use_form() -> FormHandle {
-    FormHandle::new()
+    use_hook(FormHandle::new)
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -538,21 +1078,17 @@ pub fn Form(props: FormProps) -> Element {
         children,
     } = props;
 
-    let handle = form.unwrap_or_else(FormHandle::new);
+    // 未传入 form 时使用组件内部的句柄，保证值、规则与字段状态跨渲染保留。
+    let internal_handle = use_hook(FormHandle::new);
+    let handle = form.unwrap_or(internal_handle);
     if let Some(initial) = initial_values
         && handle.values().is_empty()
     {
-        for (k, v) in initial.into_iter() {
-            handle.set_field_value(&k, v);
-        }
+        handle.set_initial_values(initial.clone());
+        handle.set_fields_value(Value::Object(initial.into_iter().collect()));
     }
 
-    // Registry 需要在整个 Form 生命周期内保持稳定的引用，不能每次渲染都重新创建。
-    // 使用 signal 保证只在首次渲染时构建一次 Rc<RefCell<..>>。
-    let registry_signal = use_signal::<Rc<RefCell<HashMap<String, Vec<FormRule>>>>>(|| {
-        Rc::new(RefCell::new(HashMap::new()))
-    });
-    let registry = registry_signal.read().clone();
+    let registry = handle.registry.clone();
     let context = FormContext {
         handle: handle.clone(),
         _layout: layout,
@@ -572,7 +1108,6 @@ pub fn Form(props: FormProps) -> Element {
     use_context_provider(|| context);
 
     let submit_handle = handle.clone();
-    let submit_registry = registry.clone();
     let finish_cb = on_finish;
     let failed_cb = on_finish_failed;
 
@@ -602,22 +1137,26 @@ pub fn Form(props: FormProps) -> Element {
             name: name_attr,
             onsubmit: move |evt| {
                 evt.prevent_default();
-                if validate_all(&submit_handle, &submit_registry) {
-                    if let Some(cb) = finish_cb.as_ref() {
-                        cb.call(FormFinishEvent { values: submit_handle.values() });
-                    }
-                } else {
-                    // Scroll to first error if enabled
-                    if scroll_to_first_error {
-                        #[cfg(target_arch = "wasm32")]
-                        {
-                            scroll_to_first_error_field(&submit_handle, &submit_registry);
+                let submit_handle = submit_handle.clone();
+                // Async validators are awaited before deciding the outcome.
+                spawn(async move {
+                    if validate_all(&submit_handle).await {
+                        if let Some(cb) = finish_cb.as_ref() {
+                            cb.call(FormFinishEvent { values: submit_handle.values() });
+                        }
+                    } else {
+                        // Scroll to first error if enabled
+                        if scroll_to_first_error {
+                            #[cfg(all(feature = "web", target_arch = "wasm32"))]
+                            {
+                                scroll_to_first_error_field(&submit_handle);
+                            }
+                        }
+                        if let Some(cb) = failed_cb.as_ref() {
+                            cb.call(FormFinishFailedEvent { errors: submit_handle.errors() });
                         }
                     }
-                    if let Some(cb) = failed_cb.as_ref() {
-                        cb.call(FormFinishFailedEvent { errors: submit_handle.errors() });
-                    }
-                }
+                });
             },
             {children}
         }
@@ -625,18 +1164,15 @@ pub fn Form(props: FormProps) -> Element {
 }
 
 /// Scroll to the first field with an error (WASM only).
-#[cfg(target_arch = "wasm32")]
-fn scroll_to_first_error_field(
-    handle: &FormHandle,
-    registry: &Rc<RefCell<HashMap<String, Vec<FormRule>>>>,
-) {
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn scroll_to_first_error_field(handle: &FormHandle) {
     let errors = handle.errors();
     if errors.is_empty() {
         return;
     }
 
     // Get field names in registration order
-    let field_names: Vec<String> = match registry.try_borrow() {
+    let field_names: Vec<String> = match handle.registry.try_borrow() {
         Ok(map) => map.keys().cloned().collect(),
         Err(_) => return,
     };
@@ -677,10 +1213,8 @@ fn form_class(layout: FormLayout, size: ControlSize) -> String {
     classes.join(" ")
 }
 
-fn validate_all(
-    handle: &FormHandle,
-    registry: &Rc<RefCell<HashMap<String, Vec<FormRule>>>>,
-) -> bool {
+async fn validate_all(handle: &FormHandle) -> bool {
+    let registry = &handle.registry;
     // Snapshot 所有字段名，若无法读取 registry（理论上不应该发生），则保守返回失败。
     let names: Vec<String> = match registry.try_borrow() {
         Ok(map) => map.keys().cloned().collect(),
@@ -711,20 +1245,13 @@ fn validate_all(
 
         if has_required {
             for name in &required_names {
-                apply_validation_result(handle, registry, name);
+                apply_validation_result(handle, registry, name, ValidateTrigger::Submit);
             }
             return false;
         }
     }
 
-    let mut ok = true;
-    for name in &names {
-        if !apply_validation_result(handle, registry, name) {
-            ok = false;
-        }
-    }
-
-    ok
+    validate_names(handle, registry, &names, ValidateTrigger::Submit).await
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -736,8 +1263,9 @@ pub struct FormListItemMeta {
 
 #[derive(Props, Clone, PartialEq)]
 pub struct FormListProps {
-    /// 列表字段名，如 "users"。
-    pub name: String,
+    /// 列表字段名，如 "users"，也可以是嵌套路径如 "team.members"。
+    #[props(into)]
+    pub name: NamePath,
     /// 可选的初始项数量，仅在 Form 中当前没有该字段值时生效。
     #[props(optional)]
     pub initial_count: Option<usize>,
@@ -745,8 +1273,9 @@ pub struct FormListProps {
     pub children: Element,
 }
 
-/// 占位实现：当前仅作为 API 声明，行为等同于普通 div 包裹 children。
-/// 后续 B3 步骤会补充动态增删项逻辑。
+/// 列表字段容器：列表值保存在表单值树中的数组里，子节点通过
+/// `use_form_list()` 获取上下文，并用 `field_path(index, "email")` 为嵌套的
+/// FormItem 生成路径。
 #[component]
 pub fn FormList(props: FormListProps) -> Element {
     let ctx = use_context::<FormContext>();
@@ -755,6 +1284,7 @@ pub fn FormList(props: FormListProps) -> Element {
         initial_count,
         children,
     } = props;
+    let name = name.to_string();
 
     // 若配置了 initial_count，且当前列表为空，则初始化指定数量的空元素。
     if let Some(count) = initial_count
@@ -784,8 +1314,10 @@ pub type GetValueFromEventFn = fn(Value) -> Value;
 #[allow(unpredictable_function_pointer_comparisons)]
 #[derive(Props, Clone, PartialEq)]
 pub struct FormItemProps {
+    /// Field path: a plain key, a string path such as `"items[0].qty"`, or a
+    /// [`NamePath`] built from segments.
     #[props(optional)]
-    pub name: Option<String>,
+    pub name: Option<NamePath>,
     #[props(optional)]
     pub label: Option<String>,
     #[props(optional)]
@@ -805,6 +1337,10 @@ pub struct FormItemProps {
     /// 自定义值映射：从原始 Value 映射到写入 FormStore 的 Value，供自定义控件配合 `apply_mapped_value` 使用。
     #[props(optional)]
     pub get_value_from_event: Option<GetValueFromEventFn>,
+    /// Map a value written by the control before it is stored, like Ant
+    /// Design's `normalize`.
+    #[props(optional)]
+    pub normalize: Option<Callback<Value, Value>>,
     #[props(optional)]
     pub class: Option<String>,
     #[props(optional)]
@@ -827,16 +1363,19 @@ pub fn FormItem(props: FormItemProps) -> Element {
         dependencies,
         value_prop_name,
         get_value_from_event,
+        normalize,
         class,
         style,
         has_feedback,
         children,
     } = props;
 
+    let name = name.map(|path| path.to_string());
     let item_rules = rules.unwrap_or_default();
     let is_required = item_rules.iter().any(|rule| rule.required);
 
     let item_scope = current_scope_id();
+    let mut blur_ctx: Option<FormItemControlContext> = None;
 
     if let Some(field_name) = name.clone() {
         {
@@ -853,13 +1392,23 @@ pub fn FormItem(props: FormItemProps) -> Element {
             on_values_change: ctx.on_values_change,
             value_prop_name: value_prop_name.clone(),
             get_value_from_event,
+            normalize,
         };
+        blur_ctx = Some(control_ctx.clone());
         use_context_provider(|| control_ctx);
         if !item_rules.is_empty() {
             let handle = ctx.handle.clone();
             let registry = ctx.registry.clone();
+            // Initial pass skips async validators so mounting a form does not
+            // fire remote checks.
             use_effect(move || {
-                apply_validation_result(&handle, &registry, &field_name);
+                start_field_validation(
+                    &handle,
+                    &registry,
+                    &field_name,
+                    ValidateTrigger::Change,
+                    false,
+                );
             });
         }
     }
@@ -872,6 +1421,13 @@ pub fn FormItem(props: FormItemProps) -> Element {
     }
 
     let error_message = name.as_ref().and_then(|field| ctx.handle.get_error(field));
+    let validating = name
+        .as_ref()
+        .is_some_and(|field| ctx.handle.is_field_validating(field));
+    let warnings = name
+        .as_ref()
+        .map(|field| ctx.handle.get_warnings(field))
+        .unwrap_or_default();
 
     let mut wrapper_class = vec!["adui-form-item".to_string()];
     if let Some(extra) = class {
@@ -879,9 +1435,21 @@ pub fn FormItem(props: FormItemProps) -> Element {
     }
     if error_message.is_some() {
         wrapper_class.push("adui-form-item-has-error".into());
+    } else if !warnings.is_empty() {
+        wrapper_class.push("adui-form-item-has-warning".into());
     } else if has_feedback {
         wrapper_class.push("adui-form-item-has-feedback".into());
     }
+    if validating {
+        wrapper_class.push("adui-form-item-is-validating".into());
+    }
+
+    let validating_icon = (has_feedback && validating).then(|| {
+        ctx.feedback_icons
+            .as_ref()
+            .and_then(|icons| icons.validating.clone())
+            .unwrap_or_else(|| rsx! { Icon { kind: IconKind::Loading, spin: true } })
+    });
 
     let tooltip_text = tooltip.clone().unwrap_or_default();
 
@@ -932,10 +1500,26 @@ pub fn FormItem(props: FormItemProps) -> Element {
                     {label_el}
                 }
             }
-            div { class: "adui-form-item-control", {children} }
-            if let Some(help_text) = error_message.or(help) {
+            div {
+                class: "adui-form-item-control",
+                onfocusout: move |_| {
+                    if let Some(control) = blur_ctx.as_ref() {
+                        control.blur();
+                    }
+                },
+                {children}
+                if let Some(icon) = validating_icon {
+                    span { class: "adui-form-item-feedback-icon", {icon} }
+                }
+            }
+            if let Some(help_text) = error_message.clone().or(help) {
                 div { class: "adui-form-item-help", "{help_text}" }
             }
+            if error_message.is_none() {
+                for warning in warnings {
+                    div { class: "adui-form-item-help adui-form-item-help-warning", "{warning}" }
+                }
+            }
             if let Some(extra_text) = extra {
                 div { class: "adui-form-item-extra", "{extra_text}" }
             }
@@ -943,47 +1527,179 @@ pub fn FormItem(props: FormItemProps) -> Element {
     }
 }
 
+/// Validate `name` after a change, running async validators in the background.
 fn apply_validation_result(
     handle: &FormHandle,
-    registry: &Rc<RefCell<HashMap<String, Vec<FormRule>>>>,
+    registry: &RuleRegistry,
     name: &str,
+    trigger: ValidateTrigger,
 ) -> bool {
-    if let Some(err) = validate_field(handle, registry, name) {
-        handle.set_error(name, Some(err));
-        false
-    } else {
-        handle.set_error(name, None);
-        true
+    let (ok, pending) = start_field_validation(handle, registry, name, trigger, true);
+    if let Some(task) = pending {
+        spawn(async move {
+            task.await;
+        });
+    }
+    ok
+}
+
+/// Future of an async validation run, resolving to whether the field passed,
+/// or to `None` when a newer run superseded it.
+type PendingValidation = Pin<Box<dyn Future<Output = Option<bool>>>>;
+
+/// Resolves once `name` has no async run in flight, to whether it is free of
+/// errors. Lets a superseded run wait for the result of the latest one.
+struct ValidationSettled<'a> {
+    handle: &'a FormHandle,
+    name: &'a str,
+}
+
+impl Future for ValidationSettled<'_> {
+    type Output = bool;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        if self.handle.is_field_validating(self.name) {
+            self.handle
+                .settle_wakers
+                .borrow_mut()
+                .push(cx.waker().clone());
+            Poll::Pending
+        } else {
+            Poll::Ready(self.handle.get_error(self.name).is_none())
+        }
     }
 }
 
-fn validate_field(
+/// Run the rules of `name` that fire on `trigger`, together with those of
+/// triggers that already fired for it. Synchronous failures are
+/// recorded immediately. When the field passes them and `run_async` is set,
+/// its async validators are started and returned as a pending run whose
+/// result is recorded unless a newer run superseded it.
+fn start_field_validation(
     handle: &FormHandle,
-    registry: &Rc<RefCell<HashMap<String, Vec<FormRule>>>>,
+    registry: &RuleRegistry,
     name: &str,
-) -> Option<String> {
-    let rules = registry
+    trigger: ValidateTrigger,
+    run_async: bool,
+) -> (bool, Option<PendingValidation>) {
+    let rules: Vec<FormRule> = registry
         .try_borrow()
         .ok()
         .and_then(|map| map.get(name).cloned())
         .unwrap_or_default();
-    run_rules(handle, name, &rules)
+    let fired = handle.fire(name, trigger);
+    let rules: Vec<FormRule> = rules
+        .into_iter()
+        .filter(|rule| {
+            rule.validate_trigger.fires_on(trigger) || fired.contains(&rule.validate_trigger)
+        })
+        .collect();
+    // Leave earlier results alone when nothing runs on this event.
+    if rules.is_empty() && trigger != ValidateTrigger::Submit {
+        return (handle.get_error(name).is_none(), None);
+    }
+
+    let (error, warnings) = run_rules(handle, name, &rules);
+    if let Some(err) = error {
+        handle.cancel_validation(name);
+        handle.set_error(name, Some(err));
+        handle.set_warnings(name, warnings);
+        handle.notify(name);
+        return (false, None);
+    }
+
+    let checks: Vec<_> = rules
+        .iter()
+        .filter_map(|rule| match &rule.validator {
+            Some(FormValidator::Async(check)) => Some((check.clone(), rule.warning_only)),
+            _ => None,
+        })
+        .collect();
+    if checks.is_empty() || !run_async {
+        handle.cancel_validation(name);
+        handle.set_error(name, None);
+        handle.set_warnings(name, warnings);
+        handle.notify(name);
+        return (true, None);
+    }
+
+    let Some(token) = handle.begin_validation(name) else {
+        return (true, None);
+    };
+    let value = handle.get_field_value(name);
+    let runs: Vec<(FormValidatorFuture, bool)> = checks
+        .iter()
+        .map(|(check, warning_only)| (check(handle.clone(), value.clone()), *warning_only))
+        .collect();
+    let handle = handle.clone();
+    let name = name.to_string();
+    let task = async move {
+        let mut error = None;
+        let mut warnings = warnings;
+        for (run, warning_only) in runs {
+            match run.await {
+                Err(msg) if warning_only => warnings.push(msg),
+                Err(msg) => {
+                    error = Some(msg);
+                    break;
+                }
+                Ok(()) => {}
+            }
+        }
+        let ok = error.is_none();
+        // A superseded or cancelled run must not report on a value that is
+        // no longer current.
+        handle
+            .finish_validation(&name, token, error, warnings)
+            .then_some(ok)
+    };
+    (true, Some(Box::pin(task)))
 }
 
-fn run_rules(handle: &FormHandle, name: &str, rules: &[FormRule]) -> Option<String> {
+/// Validate `names` with `trigger`, awaiting async validators.
+async fn validate_names(
+    handle: &FormHandle,
+    registry: &RuleRegistry,
+    names: &[String],
+    trigger: ValidateTrigger,
+) -> bool {
+    let mut ok = true;
+    let mut pending = Vec::new();
+    for name in names {
+        let (passed, task) = start_field_validation(handle, registry, name, trigger, true);
+        ok &= passed;
+        pending.extend(task.map(|task| (name, task)));
+    }
+    for (name, task) in pending {
+        ok &= match task.await {
+            Some(passed) => passed,
+            // The value changed meanwhile; the latest run decides.
+            None => ValidationSettled { handle, name }.await,
+        };
+    }
+    ok
+}
+
+/// First blocking error and every warning from `rules`.
+fn run_rules(handle: &FormHandle, name: &str, rules: &[FormRule]) -> (Option<String>, Vec<String>) {
+    let mut warnings = Vec::new();
     if rules.is_empty() {
-        return None;
+        return (None, warnings);
     }
     let value = handle.get_field_value(name);
     for rule in rules {
-        if let Some(msg) = evaluate_rule(rule, value.as_ref()) {
-            return Some(msg);
+        if let Some(msg) = evaluate_rule(rule, handle, value.as_ref()) {
+            if rule.warning_only {
+                warnings.push(msg);
+            } else {
+                return (Some(msg), warnings);
+            }
         }
     }
-    None
+    (None, warnings)
 }
 
-fn evaluate_rule(rule: &FormRule, value: Option<&Value>) -> Option<String> {
+fn evaluate_rule(rule: &FormRule, handle: &FormHandle, value: Option<&Value>) -> Option<String> {
     if rule.required && value_is_empty(value) {
         return Some(
             rule.message
@@ -1061,9 +1777,12 @@ fn evaluate_rule(rule: &FormRule, value: Option<&Value>) -> Option<String> {
         }
     }
 
-    if let Some(validator) = rule.validator
-        && let Err(err) = validator(value)
-    {
+    let result = match &rule.validator {
+        Some(FormValidator::Value(check)) => check(value),
+        Some(FormValidator::Form(check)) => check(handle, value),
+        Some(FormValidator::Async(_)) | None => Ok(()),
+    };
+    if let Err(err) = result {
         return Some(err);
     }
 
@@ -1216,6 +1935,28 @@ pub fn form_list_remove(handle: &FormHandle, name: &str, index: usize) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::Cell;
+
+    /// Drive a future whose validators resolve without real I/O.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(out) = future.as_mut().poll(&mut cx) {
+                return out;
+            }
+        }
+    }
+
+    fn registry_with(handle: &FormHandle, name: &str, rules: Vec<FormRule>) -> RuleRegistry {
+        handle.registry.borrow_mut().insert(name.to_string(), rules);
+        handle.registry.clone()
+    }
+
+    // No listeners are registered, so writes never reach the scheduler.
+    fn put(handle: &FormHandle, name: &str, value: &str) {
+        handle.set_field_value(name, Value::String(value.into()));
+    }
 
     #[test]
     fn form_value_to_string_covers_common_variants() {
@@ -1307,8 +2048,7 @@ mod tests {
     #[test]
     fn validate_all_fails_for_required_when_values_empty() {
         let handle = FormHandle::new();
-        let registry: Rc<RefCell<HashMap<String, Vec<FormRule>>>> =
-            Rc::new(RefCell::new(HashMap::new()));
+        let registry = handle.registry.clone();
 
         registry.borrow_mut().insert(
             "username".to_string(),
@@ -1320,21 +2060,19 @@ mod tests {
         );
 
         // 初始状态：没有任何表单值，但存在 required 规则，应当校验失败。
-        let ok = validate_all(&handle, &registry);
+        let ok = block_on(validate_all(&handle));
         assert!(!ok);
         let errors = handle.errors();
         assert_eq!(errors.get("username"), Some(&"请输入用户名".to_string()));
     }
 
-    // This scenario depends on the Dioxus runtime because `set_field_value` and
     // `reset_fields` will schedule view updates. In the library tests we don't
     // bootstrap a full runtime, so we keep this test ignored to avoid panics.
     #[test]
     #[ignore]
     fn validate_all_fails_again_after_reset() {
         let handle = FormHandle::new();
-        let registry: Rc<RefCell<HashMap<String, Vec<FormRule>>>> =
-            Rc::new(RefCell::new(HashMap::new()));
+        let registry = handle.registry.clone();
 
         registry.borrow_mut().insert(
             "username".to_string(),
@@ -1347,12 +2085,12 @@ mod tests {
 
         // 填写并第一次提交：应当通过。
         handle.set_field_value("username", Value::String("alice".into()));
-        let ok_first = validate_all(&handle, &registry);
+        let ok_first = block_on(validate_all(&handle));
         assert!(ok_first);
 
         // 重置后再次提交：应当失败。
         handle.reset_fields();
-        let ok_after_reset = validate_all(&handle, &registry);
+        let ok_after_reset = block_on(validate_all(&handle));
         assert!(!ok_after_reset);
         let errors_after_reset = handle.errors();
         assert_eq!(
@@ -1360,4 +2098,487 @@ mod tests {
             Some(&"请输入用户名".to_string())
         );
     }
+
+    #[test]
+    fn form_validator_reads_sibling_fields() {
+        let handle = FormHandle::new();
+        let registry = registry_with(
+            &handle,
+            "confirm",
+            vec![FormRule {
+                validator: Some(FormValidator::form(|form, value| {
+                    if form.get_field_value("password").as_ref() == value {
+                        Ok(())
+                    } else {
+                        Err("passwords differ".into())
+                    }
+                })),
+                ..FormRule::default()
+            }],
+        );
+        put(&handle, "password", "secret");
+        put(&handle, "confirm", "other");
+        assert!(
+            !start_field_validation(&handle, &registry, "confirm", ValidateTrigger::Change, true).0
+        );
+        assert_eq!(
+            handle.get_error("confirm").as_deref(),
+            Some("passwords differ")
+        );
+
+        put(&handle, "confirm", "secret");
+        assert!(
+            start_field_validation(&handle, &registry, "confirm", ValidateTrigger::Change, true).0
+        );
+        assert_eq!(handle.get_error("confirm"), None);
+    }
+
+    #[test]
+    fn async_validator_marks_field_validating_until_resolved() {
+        let handle = FormHandle::new();
+        let registry = registry_with(
+            &handle,
+            "username",
+            vec![FormRule {
+                validator: Some(FormValidator::future(|_, value| async move {
+                    match value.as_ref().and_then(Value::as_str) {
+                        Some("admin") => Err("username taken".to_string()),
+                        _ => Ok(()),
+                    }
+                })),
+                ..FormRule::default()
+            }],
+        );
+        put(&handle, "username", "admin");
+
+        let (ok, pending) = start_field_validation(
+            &handle,
+            &registry,
+            "username",
+            ValidateTrigger::Change,
+            true,
+        );
+        assert!(ok);
+        assert!(handle.is_field_validating("username"));
+        assert_eq!(block_on(pending.expect("async run")), Some(false));
+        assert!(!handle.is_validating());
+        assert_eq!(
+            handle.get_error("username").as_deref(),
+            Some("username taken")
+        );
+
+        // The mount pass never starts async checks.
+        let (_, pending) = start_field_validation(
+            &handle,
+            &registry,
+            "username",
+            ValidateTrigger::Change,
+            false,
+        );
+        assert!(pending.is_none());
+    }
+
+    #[test]
+    fn stale_async_result_is_discarded() {
+        let mut store = FormStore::new();
+        let first = store.begin_validation("username");
+        let second = store.begin_validation("username");
+        assert!(!store.finish_validation("username", first, Some("taken".into()), Vec::new()));
+        assert!(store.errors.is_empty());
+        assert!(store.validating.contains_key("username"));
+        assert!(store.finish_validation("username", second, None, Vec::new()));
+        assert!(store.validating.is_empty());
+    }
+
+    #[test]
+    fn validate_all_waits_for_async_validators() {
+        let handle = FormHandle::new();
+        registry_with(
+            &handle,
+            "username",
+            vec![FormRule {
+                validator: Some(FormValidator::future(|_, _| async {
+                    Err("username taken".to_string())
+                })),
+                ..FormRule::default()
+            }],
+        );
+        put(&handle, "username", "alice");
+        assert!(!block_on(validate_all(&handle)));
+        assert_eq!(
+            handle.errors().get("username").map(String::as_str),
+            Some("username taken")
+        );
+    }
+
+    #[test]
+    fn superseded_async_run_reports_no_outcome() {
+        let handle = FormHandle::new();
+        let registry = registry_with(
+            &handle,
+            "username",
+            vec![FormRule {
+                validator: Some(FormValidator::future(|_, _| async { Ok(()) })),
+                ..FormRule::default()
+            }],
+        );
+        put(&handle, "username", "alice");
+        let (_, first) = start_field_validation(
+            &handle,
+            &registry,
+            "username",
+            ValidateTrigger::Submit,
+            true,
+        );
+        let (_, second) = start_field_validation(
+            &handle,
+            &registry,
+            "username",
+            ValidateTrigger::Submit,
+            true,
+        );
+        assert_eq!(block_on(first.unwrap()), None);
+        assert_eq!(block_on(second.unwrap()), Some(true));
+    }
+
+    #[test]
+    fn submit_waits_for_the_run_that_superseded_it() {
+        let gate = Rc::new(Cell::new(false));
+        let check_gate = gate.clone();
+        let handle = FormHandle::new();
+        let registry = registry_with(
+            &handle,
+            "username",
+            vec![FormRule {
+                validator: Some(FormValidator::future(move |_, value| {
+                    let gate = check_gate.clone();
+                    std::future::poll_fn(move |_| {
+                        if !gate.get() {
+                            return Poll::Pending;
+                        }
+                        Poll::Ready(match value.as_ref().and_then(Value::as_str) {
+                            Some("taken") => Err("username taken".to_string()),
+                            _ => Ok(()),
+                        })
+                    })
+                })),
+                ..FormRule::default()
+            }],
+        );
+        put(&handle, "username", "alice");
+        let names = vec!["username".to_string()];
+        let mut submit = std::pin::pin!(validate_names(
+            &handle,
+            &registry,
+            &names,
+            ValidateTrigger::Submit
+        ));
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(submit.as_mut().poll(&mut cx).is_pending());
+
+        // The user edits the field while the submit run is pending.
+        put(&handle, "username", "taken");
+        let (_, latest) = start_field_validation(
+            &handle,
+            &registry,
+            "username",
+            ValidateTrigger::Change,
+            true,
+        );
+        gate.set(true);
+        assert!(submit.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(block_on(latest.unwrap()), Some(false));
+        assert_eq!(submit.as_mut().poll(&mut cx), Poll::Ready(false));
+        assert_eq!(
+            handle.errors().get("username").map(String::as_str),
+            Some("username taken")
+        );
+    }
+
+    #[test]
+    fn value_validator_accepts_fn_items() {
+        fn not_admin(value: Option<&Value>) -> Result<(), String> {
+            match value.and_then(Value::as_str) {
+                Some("admin") => Err("reserved".into()),
+                _ => Ok(()),
+            }
+        }
+        let rule = FormRule {
+            validator: Some(FormValidator::value(not_admin)),
+            ..FormRule::default()
+        };
+        let handle = FormHandle::new();
+        put(&handle, "username", "admin");
+        let (error, _) = run_rules(&handle, "username", &[rule]);
+        assert_eq!(error.as_deref(), Some("reserved"));
+    }
+
+    #[test]
+    fn name_paths_write_into_one_value_tree() {
+        let handle = FormHandle::new();
+        handle.set_field_value("address.city", Value::String("Paris".into()));
+        handle.set_field_value(
+            NamePath::default().key("items").index(0).key("qty"),
+            Value::from(3),
+        );
+        assert_eq!(
+            handle.get_field_value("address"),
+            Some(serde_json::json!({"city": "Paris"}))
+        );
+        assert_eq!(handle.get_field_value("items[0].qty"), Some(Value::from(3)));
+        assert_eq!(
+            handle.values().get("items"),
+            Some(&serde_json::json!([{"qty": 3}]))
+        );
+    }
+
+    #[test]
+    fn fields_value_reads_and_merges_subtrees() {
+        let handle = FormHandle::new();
+        handle.set_fields_value(serde_json::json!({
+            "address": {"city": "Paris", "zip": "75001"},
+            "name": "Ada"
+        }));
+        handle.set_fields_value(serde_json::json!({"address": {"city": "Lyon"}}));
+        assert_eq!(
+            handle.get_fields_value(&["address.city".into(), "name".into()]),
+            serde_json::json!({"address": {"city": "Lyon"}, "name": "Ada"})
+        );
+        assert_eq!(
+            handle.get_field_value("address.zip"),
+            Some(Value::String("75001".into()))
+        );
+    }
+
+    #[test]
+    fn list_items_hold_nested_fields() {
+        let handle = FormHandle::new();
+        let list = FormListContext {
+            name: "users".into(),
+            handle: handle.clone(),
+        };
+        list.insert(0, Value::Null);
+        handle.set_field_value(list.field_path(0, "email"), Value::String("a@b.c".into()));
+        assert_eq!(list.field_path(0, "email").to_string(), "users[0].email");
+        assert_eq!(
+            form_list_get(&handle, "users"),
+            vec![serde_json::json!({"email": "a@b.c"})]
+        );
+        assert_eq!(list.items()[0].name, "users[0]");
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Address {
+        city: String,
+        zip: u32,
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Profile {
+        name: String,
+        age: u8,
+        address: Address,
+    }
+
+    #[test]
+    fn typed_values_round_trip() {
+        let handle = FormHandle::new();
+        let profile = Profile {
+            name: "Ada".into(),
+            age: 36,
+            address: Address {
+                city: "London".into(),
+                zip: 1815,
+            },
+        };
+        handle.set_from(&profile).unwrap();
+        assert_eq!(
+            handle.get_field_value("address.city"),
+            Some(Value::String("London".into()))
+        );
+        assert_eq!(handle.try_into_typed::<Profile>().unwrap(), profile);
+        assert!(handle.set_from(&42).is_err());
+    }
+
+    #[test]
+    fn typed_errors_are_keyed_by_field_path() {
+        let handle = FormHandle::new();
+        handle.set_fields_value(serde_json::json!({
+            "name": "Ada",
+            "age": 36,
+            "address": {"city": "London", "zip": "not a number"}
+        }));
+        let errors = handle.try_into_typed::<Profile>().unwrap_err();
+        assert!(errors.contains_key("address.zip"), "{errors:?}");
+
+        let event = FormFinishEvent {
+            values: serde_json::from_value(serde_json::json!({"name": "Ada"})).unwrap(),
+        };
+        let errors = event.typed::<Profile>().unwrap_err();
+        assert_eq!(
+            errors.get("age").map(String::as_str),
+            Some("missing field `age`")
+        );
+    }
+
+    #[test]
+    fn typed_values_accept_numbers_written_by_input_number() {
+        let handle = FormHandle::new();
+        handle.set_fields_value(serde_json::json!({
+            "name": "Ada",
+            "address": {"city": "London"}
+        }));
+        // InputNumber writes every value through `Number::from_f64`.
+        let number = |n: f64| Value::Number(serde_json::Number::from_f64(n).unwrap());
+        handle.set_field_value("age", number(36.0));
+        handle.set_field_value("address.zip", number(1815.0));
+        let profile = handle.try_into_typed::<Profile>().unwrap();
+        assert_eq!((profile.age, profile.address.zip), (36, 1815));
+
+        handle.set_field_value("age", number(36.5));
+        handle.set_field_value("address.zip", Value::String("N1".into()));
+        let errors = handle.try_into_typed::<Profile>().unwrap_err();
+        let mut keys: Vec<_> = errors.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["address.zip", "age"]);
+    }
+
+    #[test]
+    fn warning_only_rules_do_not_block_submit() {
+        let handle = FormHandle::new();
+        registry_with(
+            &handle,
+            "password",
+            vec![FormRule {
+                min: Some(12),
+                message: Some("short passwords are weak".into()),
+                warning_only: true,
+                ..FormRule::default()
+            }],
+        );
+        put(&handle, "password", "secret");
+        assert!(block_on(validate_all(&handle)));
+        let meta = handle.get_field_meta("password");
+        assert!(meta.errors.is_empty());
+        assert_eq!(meta.warnings, vec!["short passwords are weak".to_string()]);
+    }
+
+    #[test]
+    fn rules_run_only_on_their_trigger() {
+        let handle = FormHandle::new();
+        let registry = registry_with(
+            &handle,
+            "email",
+            vec![FormRule {
+                pattern: Some("@".into()),
+                message: Some("invalid email".into()),
+                validate_trigger: ValidateTrigger::Blur,
+                ..FormRule::default()
+            }],
+        );
+        put(&handle, "email", "nobody");
+        assert!(
+            start_field_validation(&handle, &registry, "email", ValidateTrigger::Change, true).0
+        );
+        assert_eq!(handle.get_error("email"), None);
+        assert!(
+            !start_field_validation(&handle, &registry, "email", ValidateTrigger::Blur, true).0
+        );
+        assert_eq!(handle.get_error("email").as_deref(), Some("invalid email"));
+        // A change with no change-triggered rules keeps the blur result.
+        assert!(
+            !start_field_validation(&handle, &registry, "email", ValidateTrigger::Change, true).0
+        );
+    }
+
+    #[test]
+    fn passing_change_rules_keep_blur_errors() {
+        let handle = FormHandle::new();
+        let registry = registry_with(
+            &handle,
+            "email",
+            vec![
+                FormRule {
+                    required: true,
+                    ..FormRule::default()
+                },
+                FormRule {
+                    pattern: Some("@".into()),
+                    message: Some("invalid email".into()),
+                    validate_trigger: ValidateTrigger::Blur,
+                    ..FormRule::default()
+                },
+            ],
+        );
+        put(&handle, "email", "nobody");
+        start_field_validation(&handle, &registry, "email", ValidateTrigger::Blur, true);
+        assert_eq!(handle.get_error("email").as_deref(), Some("invalid email"));
+
+        put(&handle, "email", "nobody2");
+        start_field_validation(&handle, &registry, "email", ValidateTrigger::Change, true);
+        assert_eq!(handle.get_error("email").as_deref(), Some("invalid email"));
+
+        put(&handle, "email", "ada@example.com");
+        start_field_validation(&handle, &registry, "email", ValidateTrigger::Change, true);
+        assert_eq!(handle.get_error("email"), None);
+    }
+
+    #[test]
+    fn touched_and_dirty_track_user_changes() {
+        let handle = FormHandle::new();
+        let mut initial = FormValues::new();
+        initial.insert("name".into(), Value::String("Ada".into()));
+        handle.set_initial_values(initial.clone());
+        handle.set_fields_value(Value::Object(initial.into_iter().collect()));
+        assert!(!handle.is_dirty());
+        assert!(!handle.is_fields_touched(None, false));
+
+        handle.touch("address.city");
+        handle.set_field_value("address.city", Value::String("Paris".into()));
+        assert!(handle.is_field_touched("address"));
+        assert!(!handle.is_field_touched("name"));
+        assert!(handle.is_fields_touched(Some(&["name".into(), "address.city".into()]), false));
+        assert!(!handle.is_fields_touched(Some(&["name".into(), "address.city".into()]), true));
+        assert!(handle.is_field_dirty("address.city"));
+        assert!(!handle.is_field_dirty("name"));
+        assert!(handle.is_dirty());
+
+        handle.reset_fields();
+        assert!(!handle.is_dirty());
+        assert_eq!(
+            handle.get_field_meta("address.city"),
+            FieldMeta {
+                name: "address.city".into(),
+                ..FieldMeta::default()
+            }
+        );
+    }
+
+    #[test]
+    fn validate_fields_limits_to_listed_names() {
+        let handle = FormHandle::new();
+        let required = |message: &str| FormRule {
+            required: true,
+            message: Some(message.into()),
+            ..FormRule::default()
+        };
+        handle
+            .registry
+            .borrow_mut()
+            .insert("name".into(), vec![required("name required")]);
+        handle
+            .registry
+            .borrow_mut()
+            .insert("email".into(), vec![required("email required")]);
+        put(&handle, "name", "Ada");
+
+        let values = block_on(handle.validate_fields(Some(&["name".into()]))).unwrap();
+        assert_eq!(values.get("name"), Some(&Value::String("Ada".into())));
+        let errors = block_on(handle.validate_fields(None)).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors.get("email").map(String::as_str),
+            Some("email required")
+        );
+    }
 }
@@ -0,0 +1,336 @@
+//! Name paths addressing fields inside nested form values.
+//!
+//! A path such as `["address", "city"]` or `["items", 0, "qty"]` points into
+//! the nested `serde_json::Value` tree kept by `FormHandle`. Paths also have a
+//! string form (`address.city`, `items[0].qty`) which is what plain string
+//! field names are parsed as, and which keys errors and rule registries.
+
+use dioxus::core::SuperFrom;
+use serde_json::{Map, Value};
+use std::{collections::HashMap, fmt};
+
+/// One step of a [`NamePath`]: an object key or an array index.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NameSegment {
+    Key(String),
+    Index(usize),
+}
+
+impl From<&str> for NameSegment {
+    fn from(key: &str) -> Self {
+        NameSegment::Key(key.to_string())
+    }
+}
+
+impl From<String> for NameSegment {
+    fn from(key: String) -> Self {
+        NameSegment::Key(key)
+    }
+}
+
+impl From<usize> for NameSegment {
+    fn from(index: usize) -> Self {
+        NameSegment::Index(index)
+    }
+}
+
+/// Path of a form field, similar to Ant Design's `NamePath`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NamePath(pub Vec<NameSegment>);
+
+impl NamePath {
+    /// Parse the string form: keys separated by `.`, indices in brackets.
+    /// A name without either is a single top-level key.
+    pub fn parse(name: &str) -> Self {
+        let mut segments = Vec::new();
+        for part in name.split('.') {
+            let (key, mut rest) = match part.find('[') {
+                Some(pos) => (&part[..pos], &part[pos..]),
+                None => (part, ""),
+            };
+            if !key.is_empty() {
+                segments.push(NameSegment::Key(key.to_string()));
+            }
+            while let Some(stripped) = rest.strip_prefix('[') {
+                let Some(end) = stripped.find(']') else {
+                    // Unbalanced bracket: keep the remainder as a key.
+                    segments.push(NameSegment::Key(rest.to_string()));
+                    break;
+                };
+                let inner = &stripped[..end];
+                segments.push(match inner.parse::<usize>() {
+                    Ok(index) => NameSegment::Index(index),
+                    Err(_) => NameSegment::Key(inner.to_string()),
+                });
+                rest = &stripped[end + 1..];
+            }
+        }
+        NamePath(segments)
+    }
+
+    /// Append a key segment.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.0.push(NameSegment::Key(key.into()));
+        self
+    }
+
+    /// Append an index segment.
+    pub fn index(mut self, index: usize) -> Self {
+        self.0.push(NameSegment::Index(index));
+        self
+    }
+
+    pub fn segments(&self) -> &[NameSegment] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether `self` equals `other` or is one of its ancestors.
+    pub fn is_prefix_of(&self, other: &NamePath) -> bool {
+        other.0.len() >= self.0.len() && other.0[..self.0.len()] == self.0[..]
+    }
+
+    /// Whether a change at one path can affect a field at the other.
+    pub fn overlaps(&self, other: &NamePath) -> bool {
+        self.is_prefix_of(other) || other.is_prefix_of(self)
+    }
+}
+
+impl fmt::Display for NamePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                NameSegment::Key(key) if i == 0 => write!(f, "{key}")?,
+                NameSegment::Key(key) => write!(f, ".{key}")?,
+                NameSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for NamePath {
+    fn from(name: &str) -> Self {
+        NamePath::parse(name)
+    }
+}
+
+impl From<String> for NamePath {
+    fn from(name: String) -> Self {
+        NamePath::parse(&name)
+    }
+}
+
+impl From<&String> for NamePath {
+    fn from(name: &String) -> Self {
+        NamePath::parse(name)
+    }
+}
+
+impl From<&NamePath> for NamePath {
+    fn from(path: &NamePath) -> Self {
+        path.clone()
+    }
+}
+
+impl From<Vec<NameSegment>> for NamePath {
+    fn from(segments: Vec<NameSegment>) -> Self {
+        NamePath(segments)
+    }
+}
+
+impl<const N: usize> From<[NameSegment; N]> for NamePath {
+    fn from(segments: [NameSegment; N]) -> Self {
+        NamePath(segments.into())
+    }
+}
+
+// Let optional `name` props take a bare string, as `Option<String>` props do.
+#[doc(hidden)]
+pub struct OptionNamePathFromStr;
+
+impl<'a> SuperFrom<&'a str, OptionNamePathFromStr> for Option<NamePath> {
+    fn super_from(name: &'a str) -> Self {
+        Some(NamePath::parse(name))
+    }
+}
+
+#[doc(hidden)]
+pub struct OptionNamePathFromString;
+
+impl SuperFrom<String, OptionNamePathFromString> for Option<NamePath> {
+    fn super_from(name: String) -> Self {
+        Some(NamePath::parse(&name))
+    }
+}
+
+/// Read the value at `path` under `root`.
+pub(crate) fn get_in<'a>(root: &'a HashMap<String, Value>, path: &NamePath) -> Option<&'a Value> {
+    let (first, rest) = path.0.split_first()?;
+    let NameSegment::Key(key) = first else {
+        return None;
+    };
+    let mut current = root.get(key)?;
+    for segment in rest {
+        current = match (segment, current) {
+            (NameSegment::Key(key), Value::Object(map)) => map.get(key)?,
+            (NameSegment::Index(index), Value::Array(items)) => items.get(*index)?,
+            _ => return None,
+        };
+    }
+    Some(current)
+}
+
+/// Most `null` padding a single write may add to an array. Indices can come
+/// from user-supplied names, so `items[99999999]` must not allocate a huge
+/// array.
+const MAX_INDEX_GAP: usize = 1024;
+
+/// Write `value` at `path` under `root`, creating objects and arrays along
+/// the way. Containers of the wrong shape (including `null`) are replaced.
+/// Writes whose index lies more than [`MAX_INDEX_GAP`] past the end of an
+/// array are dropped.
+pub(crate) fn set_in(root: &mut HashMap<String, Value>, path: &NamePath, value: Value) {
+    let Some((NameSegment::Key(key), rest)) = path.0.split_first() else {
+        return;
+    };
+    if !gaps_within_limit(root.get(key), rest) {
+        return;
+    }
+    let slot = root.entry(key.clone()).or_insert(Value::Null);
+    set_in_value(slot, rest, value);
+}
+
+/// Whether writing at `path` below `slot` pads every array by at most
+/// [`MAX_INDEX_GAP`] entries.
+fn gaps_within_limit(mut slot: Option<&Value>, path: &[NameSegment]) -> bool {
+    for segment in path {
+        slot = match (segment, slot) {
+            (NameSegment::Key(key), Some(Value::Object(map))) => map.get(key),
+            (NameSegment::Index(index), Some(Value::Array(items))) => {
+                if *index > items.len() + MAX_INDEX_GAP {
+                    return false;
+                }
+                items.get(*index)
+            }
+            (NameSegment::Index(index), _) => {
+                if *index > MAX_INDEX_GAP {
+                    return false;
+                }
+                None
+            }
+            (NameSegment::Key(_), _) => None,
+        };
+    }
+    true
+}
+
+fn set_in_value(slot: &mut Value, path: &[NameSegment], value: Value) {
+    let Some((first, rest)) = path.split_first() else {
+        *slot = value;
+        return;
+    };
+    match first {
+        NameSegment::Key(key) => {
+            if !slot.is_object() {
+                *slot = Value::Object(Map::new());
+            }
+            if let Value::Object(map) = slot {
+                let child = map.entry(key.clone()).or_insert(Value::Null);
+                set_in_value(child, rest, value);
+            }
+        }
+        NameSegment::Index(index) => {
+            if !slot.is_array() {
+                *slot = Value::Array(Vec::new());
+            }
+            if let Value::Array(items) = slot {
+                if items.len() <= *index {
+                    items.resize(*index + 1, Value::Null);
+                }
+                set_in_value(&mut items[*index], rest, value);
+            }
+        }
+    }
+}
+
+/// Deep-merge `patch` into `target`: objects merge key by key, anything else
+/// (arrays included) replaces the target.
+pub(crate) fn merge_value(target: &mut Value, patch: Value) {
+    match (target, patch) {
+        (Value::Object(target), Value::Object(patch)) => {
+            for (key, value) in patch {
+                merge_value(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (target, patch) => *target = patch,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn string_form_round_trips() {
+        let path = NamePath::parse("items[0].qty");
+        assert_eq!(path, NamePath::default().key("items").index(0).key("qty"));
+        assert_eq!(path.to_string(), "items[0].qty");
+        assert_eq!(NamePath::parse("username").to_string(), "username");
+        assert_eq!(NamePath::parse("grid[1][2]").to_string(), "grid[1][2]");
+    }
+
+    #[test]
+    fn prefix_relations() {
+        let address = NamePath::parse("address");
+        let city = NamePath::parse("address.city");
+        assert!(address.is_prefix_of(&city));
+        assert!(!city.is_prefix_of(&address));
+        assert!(city.overlaps(&address));
+        assert!(!city.overlaps(&NamePath::parse("address.zip")));
+    }
+
+    #[test]
+    fn set_in_builds_nested_containers() {
+        let mut root = HashMap::new();
+        set_in(&mut root, &NamePath::parse("address.city"), json!("Paris"));
+        set_in(&mut root, &NamePath::parse("items[1].qty"), json!(2));
+        assert_eq!(
+            serde_json::to_value(&root).unwrap(),
+            json!({"address": {"city": "Paris"}, "items": [null, {"qty": 2}]})
+        );
+        assert_eq!(
+            get_in(&root, &NamePath::parse("items[1].qty")),
+            Some(&json!(2))
+        );
+        assert_eq!(get_in(&root, &NamePath::parse("items[5].qty")), None);
+    }
+
+    #[test]
+    fn set_in_refuses_huge_index_gaps() {
+        let mut root = HashMap::new();
+        set_in(&mut root, &NamePath::parse("items[99999999]"), json!(1));
+        set_in(&mut root, &NamePath::parse("a.b[99999999].c"), json!(1));
+        assert!(root.is_empty());
+
+        set_in(&mut root, &NamePath::parse("items[3]"), json!(1));
+        assert_eq!(root["items"], json!([null, null, null, 1]));
+    }
+
+    #[test]
+    fn merge_keeps_untouched_siblings() {
+        let mut target = json!({"address": {"city": "Paris", "zip": "75001"}});
+        merge_value(
+            &mut target,
+            json!({"address": {"city": "Lyon"}, "tags": ["a"]}),
+        );
+        assert_eq!(
+            target,
+            json!({"address": {"city": "Lyon", "zip": "75001"}, "tags": ["a"]})
+        );
+    }
+}
@@ -0,0 +1,484 @@
+//! Deserializing form values into typed structs.
+//!
+//! Values are read through a small deserializer over `serde_json::Value`
+//! instead of `serde_json::from_value`, for two reasons:
+//!
+//! - Numeric controls such as `InputNumber` store every number as an `f64`,
+//!   so whole floats are handed to integer fields as integers.
+//! - serde stops at the first invalid field. Each failing path is recorded
+//!   and replaced by a zero value on the next pass, so every invalid field
+//!   is reported at once.
+
+use crate::components::form::{FormErrors, FormValues};
+use crate::components::form_path::NamePath;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde_json::{Number, Value};
+use std::{collections::HashSet, fmt};
+
+/// Deserialize `values` into `T`, or return the errors keyed by field path.
+pub(crate) fn typed_from_values<T: DeserializeOwned>(values: FormValues) -> Result<T, FormErrors> {
+    use serde_path_to_error::Segment;
+
+    let root = Value::Object(values.into_iter().collect());
+    let mut zeroed = HashSet::new();
+    let mut errors = FormErrors::new();
+    loop {
+        let de = ValueDe {
+            value: Some(&root),
+            path: NamePath::default(),
+            zeroed: &zeroed,
+        };
+        let err = match serde_path_to_error::deserialize::<_, T>(de) {
+            Ok(value) if errors.is_empty() => return Ok(value),
+            Ok(_) => return Err(errors),
+            Err(err) => err,
+        };
+        let mut path = NamePath::default();
+        for segment in err.path().iter() {
+            path = match segment {
+                Segment::Seq { index } => path.index(*index),
+                Segment::Map { key } | Segment::Enum { variant: key } => path.key(key.clone()),
+                Segment::Unknown => path,
+            };
+        }
+        // serde reports a missing field against its parent.
+        if let FieldError::Missing(field) = err.inner() {
+            path = path.key(*field);
+        }
+        errors
+            .entry(path.to_string())
+            .or_insert_with(|| err.inner().to_string());
+        // A path that fails even when zeroed cannot be stepped over.
+        if !zeroed.insert(path) {
+            return Err(errors);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum FieldError {
+    Missing(&'static str),
+    Custom(String),
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldError::Missing(field) => write!(f, "missing field `{field}`"),
+            FieldError::Custom(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+impl de::Error for FieldError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        FieldError::Custom(msg.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        FieldError::Missing(field)
+    }
+}
+
+/// Deserializer for the value at `path`. Without a value, or when `path` is
+/// in `zeroed`, it produces the zero value of whatever type is requested.
+struct ValueDe<'a> {
+    value: Option<&'a Value>,
+    path: NamePath,
+    zeroed: &'a HashSet<NamePath>,
+}
+
+impl<'a> ValueDe<'a> {
+    fn child(&self, value: Option<&'a Value>, path: NamePath) -> Self {
+        ValueDe {
+            value,
+            path,
+            zeroed: self.zeroed,
+        }
+    }
+
+    /// The value to read, or `None` when a zero value must be produced.
+    fn current(&self) -> Option<&'a Value> {
+        self.value.filter(|_| !self.zeroed.contains(&self.path))
+    }
+}
+
+/// Visit `n`, passing whole floats on as integers.
+fn visit_number<'de, V: Visitor<'de>>(n: &Number, visitor: V) -> Result<V::Value, FieldError> {
+    if let Some(u) = n.as_u64() {
+        return visitor.visit_u64(u);
+    }
+    if let Some(i) = n.as_i64() {
+        return visitor.visit_i64(i);
+    }
+    let f = n.as_f64().unwrap_or_default();
+    if f.fract() == 0.0 && f >= 0.0 && f < u64::MAX as f64 {
+        visitor.visit_u64(f as u64)
+    } else if f.fract() == 0.0 && f >= i64::MIN as f64 && f < 0.0 {
+        visitor.visit_i64(f as i64)
+    } else {
+        visitor.visit_f64(f)
+    }
+}
+
+/// Deserializer methods that produce a zero value in zero mode and otherwise
+/// read the value as it is.
+macro_rules! zero_then_any {
+    ($($method:ident($($arg:ident: $ty:ty),*) => $visit:ident($($zero:expr)?);)*) => {$(
+        fn $method<V: Visitor<'de>>(
+            self,
+            $($arg: $ty,)*
+            visitor: V,
+        ) -> Result<V::Value, FieldError> {
+            match self.current() {
+                None => visitor.$visit($($zero)?),
+                Some(_) => self.deserialize_any(visitor),
+            }
+        }
+    )*};
+}
+
+impl<'de, 'a> de::Deserializer<'de> for ValueDe<'a> {
+    type Error = FieldError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+        match self.current() {
+            None | Some(Value::Null) => visitor.visit_unit(),
+            Some(Value::Bool(b)) => visitor.visit_bool(*b),
+            Some(Value::Number(n)) => visit_number(n, visitor),
+            Some(Value::String(s)) => visitor.visit_str(s),
+            Some(Value::Array(items)) => visitor.visit_seq(SeqDe {
+                items: items.iter().enumerate(),
+                parent: self,
+            }),
+            Some(Value::Object(map)) => visitor.visit_map(MapDe {
+                entries: map.iter().map(|(k, v)| (k.as_str(), Some(v))).collect(),
+                next: 0,
+                value: None,
+                parent: self,
+            }),
+        }
+    }
+
+    zero_then_any! {
+        deserialize_bool() => visit_bool(false);
+        deserialize_i8() => visit_i64(0);
+        deserialize_i16() => visit_i64(0);
+        deserialize_i32() => visit_i64(0);
+        deserialize_i64() => visit_i64(0);
+        deserialize_i128() => visit_i64(0);
+        deserialize_u8() => visit_u64(0);
+        deserialize_u16() => visit_u64(0);
+        deserialize_u32() => visit_u64(0);
+        deserialize_u64() => visit_u64(0);
+        deserialize_u128() => visit_u64(0);
+        deserialize_f32() => visit_f64(0.0);
+        deserialize_f64() => visit_f64(0.0);
+        deserialize_char() => visit_char('\0');
+        deserialize_str() => visit_str("");
+        deserialize_string() => visit_str("");
+        deserialize_identifier() => visit_str("");
+        deserialize_bytes() => visit_bytes(&[]);
+        deserialize_byte_buf() => visit_bytes(&[]);
+        deserialize_unit() => visit_unit();
+        deserialize_unit_struct(_name: &'static str) => visit_unit();
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+        self.deserialize_tuple(0, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+        self.deserialize_struct("", &[], visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+        match self.current() {
+            None | Some(Value::Null) => visitor.visit_none(),
+            Some(_) => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, FieldError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, FieldError> {
+        match self.current() {
+            None => visitor.visit_seq(ZeroSeq {
+                remaining: len,
+                parent: self,
+            }),
+            Some(_) => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, FieldError> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FieldError> {
+        let entries = match self.current() {
+            // Every field of a zero struct is zero as well.
+            None => fields.iter().map(|field| (*field, None)).collect(),
+            Some(Value::Object(map)) => {
+                let mut entries: Vec<(&str, Option<&Value>)> =
+                    map.iter().map(|(k, v)| (k.as_str(), Some(v))).collect();
+                // Missing fields that already failed are zeroed like the rest.
+                for field in fields {
+                    let path = self.path.clone().key(*field);
+                    if !map.contains_key(*field) && self.zeroed.contains(&path) {
+                        entries.push((field, None));
+                    }
+                }
+                entries
+            }
+            Some(_) => return self.deserialize_any(visitor),
+        };
+        visitor.visit_map(MapDe {
+            entries,
+            next: 0,
+            value: None,
+            parent: self,
+        })
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FieldError> {
+        let (variant, value) = match self.current() {
+            None => (variants.first().copied().unwrap_or_default(), None),
+            Some(Value::String(s)) => (s.as_str(), None),
+            Some(Value::Object(map)) if map.len() == 1 => {
+                let (variant, value) = map.iter().next().expect("one entry");
+                (variant.as_str(), Some(value))
+            }
+            Some(_) => return self.deserialize_any(visitor),
+        };
+        let path = self.path.clone().key(variant);
+        visitor.visit_enum(EnumDe {
+            variant,
+            content: self.child(value, path),
+        })
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FieldError> {
+        visitor.visit_unit()
+    }
+}
+
+struct SeqDe<'a, I> {
+    items: I,
+    parent: ValueDe<'a>,
+}
+
+impl<'de, 'a, I> SeqAccess<'de> for SeqDe<'a, I>
+where
+    I: Iterator<Item = (usize, &'a Value)>,
+{
+    type Error = FieldError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, FieldError> {
+        match self.items.next() {
+            Some((index, item)) => {
+                let path = self.parent.path.clone().index(index);
+                seed.deserialize(self.parent.child(Some(item), path))
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+struct ZeroSeq<'a> {
+    remaining: usize,
+    parent: ValueDe<'a>,
+}
+
+impl<'de> SeqAccess<'de> for ZeroSeq<'_> {
+    type Error = FieldError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, FieldError> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        let path = self.parent.path.clone();
+        seed.deserialize(self.parent.child(None, path)).map(Some)
+    }
+}
+
+struct MapDe<'a> {
+    entries: Vec<(&'a str, Option<&'a Value>)>,
+    next: usize,
+    value: Option<(&'a str, Option<&'a Value>)>,
+    parent: ValueDe<'a>,
+}
+
+impl<'de> MapAccess<'de> for MapDe<'_> {
+    type Error = FieldError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, FieldError> {
+        let Some(entry) = self.entries.get(self.next).copied() else {
+            return Ok(None);
+        };
+        self.next += 1;
+        self.value = Some(entry);
+        seed.deserialize(entry.0.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, FieldError> {
+        let (key, value) = self
+            .value
+            .take()
+            .ok_or_else(|| de::Error::custom("value requested before key"))?;
+        let path = self.parent.path.clone().key(key);
+        seed.deserialize(self.parent.child(value, path))
+    }
+}
+
+struct EnumDe<'a> {
+    variant: &'a str,
+    content: ValueDe<'a>,
+}
+
+impl<'de, 'a> EnumAccess<'de> for EnumDe<'a> {
+    type Error = FieldError;
+    type Variant = ValueDe<'a>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, ValueDe<'a>), FieldError> {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+        Ok((variant, self.content))
+    }
+}
+
+impl<'de> VariantAccess<'de> for ValueDe<'_> {
+    type Error = FieldError;
+
+    fn unit_variant(self) -> Result<(), FieldError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, FieldError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, FieldError> {
+        de::Deserializer::deserialize_tuple(self, len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FieldError> {
+        de::Deserializer::deserialize_struct(self, "", fields, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    fn values(value: Value) -> FormValues {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Order {
+        qty: u32,
+        price: f64,
+        note: Option<String>,
+        kind: Kind,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Kind {
+        Retail,
+        Bulk { min: u16 },
+    }
+
+    #[test]
+    fn whole_floats_fill_integer_fields() {
+        let order: Order =
+            typed_from_values(values(json!({"qty": 3.0, "price": 2.0, "kind": "retail"}))).unwrap();
+        assert_eq!(
+            order,
+            Order {
+                qty: 3,
+                price: 2.0,
+                note: None,
+                kind: Kind::Retail,
+            }
+        );
+        let errors = typed_from_values::<Order>(values(
+            json!({"qty": 2.5, "price": 1, "kind": {"bulk": {"min": 10.0}}}),
+        ))
+        .unwrap_err();
+        assert_eq!(errors.keys().collect::<Vec<_>>(), ["qty"]);
+    }
+
+    #[test]
+    fn every_invalid_field_is_reported() {
+        let errors = typed_from_values::<Order>(values(
+            json!({"qty": "many", "note": 7, "kind": {"bulk": {}}}),
+        ))
+        .unwrap_err();
+        let mut keys: Vec<_> = errors.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["kind.bulk.min", "note", "price", "qty"]);
+        assert_eq!(errors["price"], "missing field `price`");
+        assert_eq!(errors["kind.bulk.min"], "missing field `min`");
+    }
+}
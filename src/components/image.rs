@@ -3,6 +3,7 @@
 //! An enhanced image component that supports loading states, fallback images,
 //! and an interactive preview modal with zoom and navigation capabilities.
 
+use crate::components::locale::use_locale;
 use dioxus::prelude::*;
 
 /// Image loading status.
@@ -212,7 +213,9 @@ pub fn Image(props: ImageProps) -> Element {
 
     let current_src_val = current_src.read().clone();
     let alt_text = alt.clone().unwrap_or_default();
-    let preview_cfg = preview_config.unwrap_or_else(PreviewConfig::new);
+    let locale = use_locale();
+    let preview_cfg = preview_config
+        .unwrap_or_else(|| PreviewConfig::new().with_mask(locale.image.preview.clone()));
 
     rsx! {
         div { class: "{class_attr}", style: "{style_attr}",
@@ -231,7 +234,7 @@ pub fn Image(props: ImageProps) -> Element {
             if *status.read() == ImageStatus::Error {
                 div { class: "adui-image-error-content",
                     span { class: "adui-image-error-icon", "⚠" }
-                    span { class: "adui-image-error-text", "Failed to load" }
+                    span { class: "adui-image-error-text", "{locale.image.load_failed}" }
                 }
             }
 
@@ -288,6 +291,7 @@ fn ImagePreview(props: ImagePreviewProps) -> Element {
         config,
         on_close,
     } = props;
+    let locale = use_locale();
 
     // Zoom scale
     let mut scale: Signal<f32> = use_signal(|| config.scale);
@@ -379,35 +383,35 @@ fn ImagePreview(props: ImagePreviewProps) -> Element {
                         class: "adui-image-preview-action",
                         r#type: "button",
                         onclick: zoom_out,
-                        title: "Zoom Out",
+                        title: "{locale.image.zoom_out}",
                         "−"
                     }
                     button {
                         class: "adui-image-preview-action",
                         r#type: "button",
                         onclick: zoom_in,
-                        title: "Zoom In",
+                        title: "{locale.image.zoom_in}",
                         "+"
                     }
                     button {
                         class: "adui-image-preview-action",
                         r#type: "button",
                         onclick: rotate_left,
-                        title: "Rotate Left",
+                        title: "{locale.image.rotate_left}",
                         "↺"
                     }
                     button {
                         class: "adui-image-preview-action",
                         r#type: "button",
                         onclick: rotate_right,
-                        title: "Rotate Right",
+                        title: "{locale.image.rotate_right}",
                         "↻"
                     }
                     button {
                         class: "adui-image-preview-action",
                         r#type: "button",
                         onclick: reset,
-                        title: "Reset",
+                        title: "{locale.image.reset}",
                         "⟲"
                     }
                 }
@@ -151,7 +151,7 @@ pub fn Input(props: InputProps) -> Element {
     let input_id = use_signal(|| format!("adui-input-{}", rand_id()));
 
     // Set data-* attributes via JavaScript interop if provided
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         if let Some(data_attrs) = data_attributes.as_ref() {
             let id = input_id.read().clone();
@@ -173,7 +173,7 @@ pub fn Input(props: InputProps) -> Element {
             }
         }
     }
-    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
     {
         // Suppress unused variable warning on non-wasm32 targets
         let _ = data_attributes;
@@ -1257,13 +1257,13 @@ fn apply_input_value(
 /// Generate a simple random ID for element identification.
 fn rand_id() -> u32 {
     // Simple pseudo-random based on current time
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         use js_sys::Math;
         (Math::random() * 1_000_000.0) as u32
     }
 
-    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
     {
         use std::time::{SystemTime, UNIX_EPOCH};
         SystemTime::now()
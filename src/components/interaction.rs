@@ -2,9 +2,14 @@
 //!
 //! Centralizes pointer tracking so components like Slider/ColorPicker can reuse the same
 //! capture/release semantics and avoid duplicating DOM handling.
+//!
+//! The DOM helpers need the `web` feature on wasm32; `PointerState` and
+//! `reset_pointer` are available everywhere.
 
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use dioxus::events::PointerData;
 use dioxus::prelude::*;
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use wasm_bindgen::JsCast;
 
 /// Simple pointer tracking state.
@@ -15,11 +20,13 @@ pub struct PointerState {
 }
 
 /// Extract a `PointerEvent` from a Dioxus pointer event payload.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 pub fn as_pointer_event(evt: &Event<PointerData>) -> Option<web_sys::PointerEvent> {
     evt.data().downcast::<web_sys::PointerEvent>().cloned()
 }
 
 /// Begin tracking a pointer and set capture on the target element.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 pub fn start_pointer(state: &mut Signal<PointerState>, evt: &web_sys::PointerEvent) {
     state.set(PointerState {
         active_id: Some(evt.pointer_id()),
@@ -35,6 +42,7 @@ pub fn start_pointer(state: &mut Signal<PointerState>, evt: &web_sys::PointerEve
 }
 
 /// Stop tracking when the active pointer finishes, releasing capture if present.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 pub fn end_pointer(state: &mut Signal<PointerState>, evt: &web_sys::PointerEvent) {
     let should_end = {
         let reader = state.read();
@@ -58,6 +66,7 @@ pub fn end_pointer(state: &mut Signal<PointerState>, evt: &web_sys::PointerEvent
 }
 
 /// Whether the event belongs to the currently tracked pointer.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 pub fn is_active_pointer(state: &Signal<PointerState>, evt: &web_sys::PointerEvent) -> bool {
     state.read().active_id == Some(evt.pointer_id())
 }
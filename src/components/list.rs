@@ -1,7 +1,9 @@
 use crate::components::config_provider::ComponentSize;
 use crate::components::empty::Empty;
+use crate::components::locale::use_locale;
 use crate::components::pagination::Pagination;
 use crate::components::spin::Spin;
+use crate::components::virtual_list::{VirtualList, VirtualListHandle};
 use dioxus::prelude::*;
 
 /// Props for the List component (MVP subset).
@@ -49,8 +51,27 @@ pub struct ListProps {
     /// Callback when pagination changes.
     #[props(optional)]
     pub pagination_on_change: Option<EventHandler<(u32, u32)>>,
+    /// Number of data-driven items rendered through `render_item`.
+    #[props(optional)]
+    pub item_count: Option<usize>,
+    /// Renders the item at the given index. Takes precedence over `children`.
+    #[props(optional)]
+    pub render_item: Option<Callback<usize, Element>>,
+    /// Only mount items in view. Requires `render_item`, `item_count` and `height`.
+    #[props(default)]
+    pub r#virtual: bool,
+    /// Viewport height in pixels for virtual scrolling.
+    #[props(optional)]
+    pub height: Option<f32>,
+    /// Uniform item height in pixels for virtual scrolling.
+    #[props(default = 48.0)]
+    pub item_height: f32,
+    /// Handle for imperative scrolling (e.g. `scroll_to_index`) in virtual mode.
+    #[props(optional)]
+    pub virtual_handle: Option<VirtualListHandle>,
     /// List item content. Callers通常在内部渲染多个块元素，并使用
     /// `.adui-list-item` 类名标记每一行。
+    #[props(default)]
     pub children: Element,
 }
 
@@ -71,8 +92,15 @@ pub fn List(props: ListProps) -> Element {
         pagination_current,
         pagination_page_size,
         pagination_on_change,
+        item_count,
+        render_item,
+        r#virtual,
+        height,
+        item_height,
+        virtual_handle,
         children,
     } = props;
+    let locale = use_locale();
 
     let mut class_list = vec!["adui-list".to_string()];
     if bordered {
@@ -91,7 +119,28 @@ pub fn List(props: ListProps) -> Element {
     let class_attr = class_list.join(" ");
     let style_attr = style.unwrap_or_default();
 
-    let show_empty = !loading && is_empty.unwrap_or(false);
+    let show_empty = !loading && is_empty.unwrap_or(item_count == Some(0));
+
+    // Data-driven items, optionally windowed.
+    let items = match (render_item, item_count) {
+        (Some(render), Some(count)) => match height.filter(|_| r#virtual) {
+            Some(viewport) => rsx! {
+                VirtualList {
+                    item_count: count,
+                    item_height,
+                    height: viewport,
+                    render_item: render,
+                    handle: virtual_handle,
+                }
+            },
+            None => rsx! {
+                for index in 0..count {
+                    Fragment { key: "{index}", {render.call(index)} }
+                }
+            },
+        },
+        _ => children,
+    };
 
     rsx! {
         div { class: "{class_attr}", style: "{style_attr}",
@@ -104,8 +153,8 @@ pub fn List(props: ListProps) -> Element {
                 div { class: "adui-list-body",
                     Spin {
                         spinning: Some(true),
-                        tip: Some("加载中...".to_string()),
-                        div { class: "adui-list-items", {children} }
+                        tip: Some(locale.global.loading.clone()),
+                        div { class: "adui-list-items", {items.clone()} }
                     }
                 }
             } else if show_empty {
@@ -120,7 +169,7 @@ pub fn List(props: ListProps) -> Element {
                 }
             } else {
                 div { class: "adui-list-body",
-                    div { class: "adui-list-items", {children} }
+                    div { class: "adui-list-items", {items} }
                 }
             }
 
@@ -169,6 +218,12 @@ mod tests {
             pagination_current: None,
             pagination_page_size: None,
             pagination_on_change: None,
+            item_count: None,
+            render_item: None,
+            r#virtual: false,
+            height: None,
+            item_height: 48.0,
+            virtual_handle: None,
             children: rsx!(div {}),
         };
         assert_eq!(props.bordered, false);
@@ -193,6 +248,12 @@ mod tests {
             pagination_current: None,
             pagination_page_size: None,
             pagination_on_change: None,
+            item_count: None,
+            render_item: None,
+            r#virtual: false,
+            height: None,
+            item_height: 48.0,
+            virtual_handle: None,
             children: rsx!(div {}),
         };
         assert_eq!(props.bordered, true);
@@ -214,6 +275,12 @@ mod tests {
             pagination_current: None,
             pagination_page_size: None,
             pagination_on_change: None,
+            item_count: None,
+            render_item: None,
+            r#virtual: false,
+            height: None,
+            item_height: 48.0,
+            virtual_handle: None,
             children: rsx!(div {}),
         };
         assert_eq!(props.loading, true);
@@ -235,6 +302,12 @@ mod tests {
             pagination_current: None,
             pagination_page_size: None,
             pagination_on_change: None,
+            item_count: None,
+            render_item: None,
+            r#virtual: false,
+            height: None,
+            item_height: 48.0,
+            virtual_handle: None,
             children: rsx!(div {}),
         };
         assert_eq!(props.size, Some(ComponentSize::Small));
@@ -256,6 +329,12 @@ mod tests {
             pagination_current: Some(1),
             pagination_page_size: Some(10),
             pagination_on_change: None,
+            item_count: None,
+            render_item: None,
+            r#virtual: false,
+            height: None,
+            item_height: 48.0,
+            virtual_handle: None,
             children: rsx!(div {}),
         };
         assert_eq!(props.pagination_total, Some(100));
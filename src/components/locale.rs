@@ -0,0 +1,1402 @@
+//! Locale bundles: message catalogs consumed by every text-bearing component.
+//!
+//! A [`LocaleBundle`] groups the default texts of all components (Table,
+//! Pagination, Modal, Upload, Transfer, DatePicker, Empty, Tour, ...). Bundles
+//! are resolved through `ConfigProvider`: either an explicit `locale_bundle`
+//! prop or the bundle registered for its `locale` flag. Applications can ship
+//! their own catalogs via [`register_locale_bundle`]; a registered bundle takes
+//! precedence over the built-in one with the same code.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::config_provider::{Locale, use_config};
+use crate::components::table::TableLocale;
+use crate::components::upload::UploadLocale;
+
+/// Shared texts that are not owned by a single component.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GlobalLocale {
+    /// Tip shown next to loading spinners (Table, List, QRCode).
+    pub loading: String,
+}
+
+/// Texts for `Empty`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EmptyLocale {
+    pub description: String,
+}
+
+/// Texts for `Pagination`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaginationLocale {
+    /// Total template, `{total}` is replaced by the item count.
+    pub total: String,
+    pub prev_page: String,
+    pub next_page: String,
+    /// Size changer option template, `{size}` is replaced by the page size.
+    pub items_per_page: String,
+}
+
+/// Texts for `Modal` footer buttons.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModalLocale {
+    pub ok_text: String,
+    pub cancel_text: String,
+}
+
+/// Texts for `Popconfirm` buttons.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PopconfirmLocale {
+    pub ok_text: String,
+    pub cancel_text: String,
+}
+
+/// Texts for `Transfer`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TransferLocale {
+    pub source_title: String,
+    pub target_title: String,
+    pub search_placeholder: String,
+    pub not_found: String,
+    /// Unit shown after the `selected/total` counter in list headers.
+    pub items_unit: String,
+}
+
+/// Texts for `DatePicker`, `RangePicker` and `Calendar`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DatePickerLocale {
+    pub placeholder: String,
+    pub range_start_placeholder: String,
+    pub range_end_placeholder: String,
+    /// Panel header template. Supports `{year}`, `{month}` (1-12) and
+    /// `{month_name}` (from `month_names`).
+    pub year_month_format: String,
+    /// Short month names, January first.
+    pub month_names: [String; 12],
+    /// Short weekday names, Monday first.
+    pub short_weekdays: [String; 7],
+    /// Weekday names for the `ddd` format token, Monday first.
+    #[serde(default = "default_weekdays")]
+    pub weekdays: [String; 7],
+    /// Texts of the `A` format token: before and after noon.
+    #[serde(default = "default_meridiem")]
+    pub meridiem: [String; 2],
+    /// Template of the `Do` token. `{n}` is the day, `{th}` the English
+    /// ordinal suffix.
+    #[serde(default = "default_ordinal")]
+    pub day_ordinal: String,
+    /// Template of the `wo` token, as for `day_ordinal`.
+    #[serde(default = "default_ordinal")]
+    pub week_ordinal: String,
+    /// First day of the week, Sunday = 0 .. Saturday = 6 (dayjs `weekStart`).
+    #[serde(default = "default_week_start")]
+    pub week_start: u8,
+    /// Days of the new year the first week must contain, 1-7 (4 for ISO
+    /// weeks, 1 when the week holding January 1st is week 1).
+    #[serde(default = "default_week_min_days")]
+    pub week_min_days: u8,
+}
+
+fn default_weekdays() -> [String; 7] {
+    strings(["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"])
+}
+
+fn default_meridiem() -> [String; 2] {
+    strings(["AM", "PM"])
+}
+
+fn default_ordinal() -> String {
+    s("{n}{th}")
+}
+
+fn default_week_start() -> u8 {
+    1
+}
+
+fn default_week_min_days() -> u8 {
+    4
+}
+
+impl DatePickerLocale {
+    /// `short_weekdays` in grid order, starting at `week_start`.
+    pub fn weekday_labels(&self) -> Vec<String> {
+        // `short_weekdays` is Monday first, `week_start` Sunday based.
+        let offset = (self.week_start as usize + 6) % 7;
+        (0..7)
+            .map(|i| self.short_weekdays[(offset + i) % 7].clone())
+            .collect()
+    }
+
+    /// Render the panel header for the given year and month (1-12).
+    pub fn format_year_month(&self, year: i32, month: u32) -> String {
+        let month_name = month
+            .checked_sub(1)
+            .and_then(|idx| self.month_names.get(idx as usize))
+            .cloned()
+            .unwrap_or_default();
+        format_locale_template(
+            &self.year_month_format,
+            &[
+                ("year", year.to_string()),
+                ("month_name", month_name),
+                ("month", month.to_string()),
+            ],
+        )
+    }
+}
+
+/// Texts for `TimePicker`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TimePickerLocale {
+    pub placeholder: String,
+}
+
+/// Texts for `Tour` navigation buttons.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TourLocale {
+    pub next: String,
+    pub previous: String,
+    pub finish: String,
+}
+
+/// Texts for `Typography` ellipsis, copy and edit affordances.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TypographyLocale {
+    pub expand: String,
+    pub collapse: String,
+    pub copy: String,
+    pub copied: String,
+    pub edit_placeholder: String,
+}
+
+/// Texts for `Image` and its preview toolbar.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageLocale {
+    pub preview: String,
+    pub load_failed: String,
+    pub zoom_in: String,
+    pub zoom_out: String,
+    pub rotate_left: String,
+    pub rotate_right: String,
+    pub reset: String,
+}
+
+/// Texts for `QRCode` status overlays.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QRCodeLocale {
+    pub expired: String,
+    pub refresh: String,
+    pub scanned: String,
+}
+
+/// Texts for `ColorPicker`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ColorPickerLocale {
+    pub clear: String,
+}
+
+/// Texts for `SchemaForm`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SchemaFormLocale {
+    /// Message of `required` rules, `{label}` is replaced by the field label.
+    pub required: String,
+    /// Message for numbers below `minimum`, `{minimum}` is replaced by the
+    /// bound.
+    pub number_min: String,
+    /// Message for numbers above `maximum`, `{maximum}` is replaced by the
+    /// bound.
+    pub number_max: String,
+    /// Remove button of list items.
+    pub remove_item: String,
+    /// Add button of lists, `{label}` is replaced by the list label.
+    pub add_item: String,
+}
+
+impl Default for SchemaFormLocale {
+    fn default() -> Self {
+        en_us().schema_form
+    }
+}
+
+/// Complete message catalog for one language.
+///
+/// `locale` holds the BCP 47 code (e.g. `"ja-JP"`) under which the bundle is
+/// registered. Component-level props such as `ok_text` or `Table.locale`
+/// still take precedence over the bundle.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LocaleBundle {
+    pub locale: String,
+    pub global: GlobalLocale,
+    pub empty: EmptyLocale,
+    pub pagination: PaginationLocale,
+    pub modal: ModalLocale,
+    pub popconfirm: PopconfirmLocale,
+    pub transfer: TransferLocale,
+    pub date_picker: DatePickerLocale,
+    pub time_picker: TimePickerLocale,
+    pub tour: TourLocale,
+    pub typography: TypographyLocale,
+    pub image: ImageLocale,
+    pub qrcode: QRCodeLocale,
+    pub color_picker: ColorPickerLocale,
+    pub table: TableLocale,
+    pub upload: UploadLocale,
+    #[serde(default)]
+    pub schema_form: SchemaFormLocale,
+}
+
+impl Default for LocaleBundle {
+    fn default() -> Self {
+        zh_cn()
+    }
+}
+
+impl LocaleBundle {
+    /// Look up a built-in bundle by code, ignoring registered bundles.
+    pub fn builtin(code: &str) -> Option<Self> {
+        let locale = Locale::from_code(code)?;
+        Some(match locale {
+            Locale::ZhCN => zh_cn(),
+            Locale::EnUS => en_us(),
+            Locale::JaJP => ja_jp(),
+            Locale::DeDE => de_de(),
+            Locale::FrFR => fr_fr(),
+            Locale::EsES => es_es(),
+            Locale::KoKR => ko_kr(),
+            Locale::ZhTW => zh_tw(),
+        })
+    }
+}
+
+/// Replace `{name}` placeholders in a locale template.
+pub fn format_locale_template(template: &str, args: &[(&str, String)]) -> String {
+    let mut out = template.to_string();
+    for (name, value) in args {
+        out = out.replace(&format!("{{{name}}}"), value);
+    }
+    out
+}
+
+// ---- Registry ----------------------------------------------------------------
+
+thread_local! {
+    static LOCALE_REGISTRY: RefCell<HashMap<String, Rc<LocaleBundle>>> =
+        RefCell::new(HashMap::new());
+}
+
+fn normalize_code(code: &str) -> String {
+    code.trim().replace('_', "-").to_ascii_lowercase()
+}
+
+/// Register an application-provided bundle under `bundle.locale`.
+///
+/// Registering a code that matches a built-in locale overrides it for all
+/// subsequent lookups on the current thread.
+pub fn register_locale_bundle(bundle: LocaleBundle) {
+    let key = normalize_code(&bundle.locale);
+    LOCALE_REGISTRY.with(|registry| {
+        registry.borrow_mut().insert(key, Rc::new(bundle));
+    });
+}
+
+/// Resolve a bundle by code: registered bundles first, then built-ins.
+pub fn find_locale_bundle(code: &str) -> Option<Rc<LocaleBundle>> {
+    let key = normalize_code(code);
+    let registered = LOCALE_REGISTRY.with(|registry| registry.borrow().get(&key).cloned());
+    if registered.is_some() {
+        return registered;
+    }
+    let bundle = Rc::new(LocaleBundle::builtin(code)?);
+    // Cache the built-in so repeated lookups share one allocation.
+    LOCALE_REGISTRY.with(|registry| {
+        registry.borrow_mut().insert(key, bundle.clone());
+    });
+    Some(bundle)
+}
+
+// ---- Context -----------------------------------------------------------------
+
+/// Locale context installed by `ConfigProvider`.
+#[derive(Clone, Copy)]
+pub(crate) struct LocaleContext {
+    pub(crate) bundle: Signal<Rc<LocaleBundle>>,
+}
+
+/// Hook for components to read the active locale bundle.
+pub fn use_locale() -> Rc<LocaleBundle> {
+    let config = use_config();
+    match try_use_context::<LocaleContext>() {
+        Some(ctx) => ctx.bundle.read().clone(),
+        None => config.locale.bundle(),
+    }
+}
+
+// ---- Built-in catalogs -------------------------------------------------------
+
+fn s(value: &str) -> String {
+    value.to_string()
+}
+
+fn strings<const N: usize>(values: [&str; N]) -> [String; N] {
+    values.map(s)
+}
+
+fn numbered_months(suffix: &str) -> [String; 12] {
+    std::array::from_fn(|idx| format!("{}{suffix}", idx + 1))
+}
+
+#[allow(clippy::too_many_arguments)] // Mirrors the TableLocale field list one-to-one.
+fn table_locale(
+    filter_title: &str,
+    filter_confirm: &str,
+    filter_reset: &str,
+    filter_empty_text: &str,
+    select_all: &str,
+    select_none: &str,
+    select_invert: &str,
+    sort_title: &str,
+    expand: &str,
+    collapse: &str,
+    empty_text: &str,
+) -> TableLocale {
+    TableLocale {
+        filter_title: Some(s(filter_title)),
+        filter_confirm: Some(s(filter_confirm)),
+        filter_reset: Some(s(filter_reset)),
+        filter_empty_text: Some(s(filter_empty_text)),
+        select_all: Some(s(select_all)),
+        select_none: Some(s(select_none)),
+        select_invert: Some(s(select_invert)),
+        sort_title: Some(s(sort_title)),
+        expand: Some(s(expand)),
+        collapse: Some(s(collapse)),
+        empty_text: Some(s(empty_text)),
+    }
+}
+
+#[allow(clippy::too_many_arguments)] // Mirrors the UploadLocale field list one-to-one.
+fn upload_locale(
+    uploading: &str,
+    remove_file: &str,
+    download_file: &str,
+    upload_error: &str,
+    preview_file: &str,
+    pause_file: &str,
+    resume_file: &str,
+    edit_image: &str,
+    rotate_left: &str,
+    rotate_right: &str,
+    create_request_failed: &str,
+    open_connection_failed: &str,
+    unsupported_target: &str,
+) -> UploadLocale {
+    UploadLocale {
+        uploading: Some(s(uploading)),
+        remove_file: Some(s(remove_file)),
+        download_file: Some(s(download_file)),
+        upload_error: Some(s(upload_error)),
+        preview_file: Some(s(preview_file)),
+        pause_file: Some(s(pause_file)),
+        resume_file: Some(s(resume_file)),
+        edit_image: Some(s(edit_image)),
+        rotate_left: Some(s(rotate_left)),
+        rotate_right: Some(s(rotate_right)),
+        create_request_failed: Some(s(create_request_failed)),
+        open_connection_failed: Some(s(open_connection_failed)),
+        unsupported_target: Some(s(unsupported_target)),
+    }
+}
+
+fn zh_cn() -> LocaleBundle {
+    LocaleBundle {
+        locale: s("zh-CN"),
+        global: GlobalLocale {
+            loading: s("加载中..."),
+        },
+        empty: EmptyLocale {
+            description: s("暂无数据"),
+        },
+        pagination: PaginationLocale {
+            total: s("共 {total} 条"),
+            prev_page: s("上一页"),
+            next_page: s("下一页"),
+            items_per_page: s("{size} / 页"),
+        },
+        modal: ModalLocale {
+            ok_text: s("确定"),
+            cancel_text: s("取消"),
+        },
+        popconfirm: PopconfirmLocale {
+            ok_text: s("确定"),
+            cancel_text: s("取消"),
+        },
+        transfer: TransferLocale {
+            source_title: s("源列表"),
+            target_title: s("目标列表"),
+            search_placeholder: s("请输入搜索内容"),
+            not_found: s("暂无数据"),
+            items_unit: s("项"),
+        },
+        date_picker: DatePickerLocale {
+            placeholder: s("请选择日期"),
+            range_start_placeholder: s("开始日期"),
+            range_end_placeholder: s("结束日期"),
+            year_month_format: s("{year}年{month}月"),
+            month_names: numbered_months("月"),
+            short_weekdays: strings(["一", "二", "三", "四", "五", "六", "日"]),
+            weekdays: strings(["周一", "周二", "周三", "周四", "周五", "周六", "周日"]),
+            meridiem: strings(["上午", "下午"]),
+            day_ordinal: s("{n}日"),
+            week_ordinal: s("{n}周"),
+            week_start: 1,
+            week_min_days: 4,
+        },
+        time_picker: TimePickerLocale {
+            placeholder: s("请选择时间"),
+        },
+        tour: TourLocale {
+            next: s("下一步"),
+            previous: s("上一步"),
+            finish: s("结束导览"),
+        },
+        typography: TypographyLocale {
+            expand: s("展开"),
+            collapse: s("收起"),
+            copy: s("复制"),
+            copied: s("已复制"),
+            edit_placeholder: s("请输入"),
+        },
+        image: ImageLocale {
+            preview: s("预览"),
+            load_failed: s("加载失败"),
+            zoom_in: s("放大"),
+            zoom_out: s("缩小"),
+            rotate_left: s("向左旋转"),
+            rotate_right: s("向右旋转"),
+            reset: s("重置"),
+        },
+        qrcode: QRCodeLocale {
+            expired: s("二维码过期"),
+            refresh: s("点击刷新"),
+            scanned: s("已扫描"),
+        },
+        color_picker: ColorPickerLocale { clear: s("清除") },
+        table: table_locale(
+            "筛选",
+            "确定",
+            "重置",
+            "无筛选项",
+            "全选当页",
+            "清空所有",
+            "反选当页",
+            "排序",
+            "展开行",
+            "关闭行",
+            "暂无数据",
+        ),
+        upload: upload_locale(
+            "文件上传中",
+            "删除文件",
+            "下载文件",
+            "上传错误",
+            "预览文件",
+            "暂停上传",
+            "继续上传",
+            "编辑图片",
+            "向左旋转",
+            "向右旋转",
+            "无法创建请求",
+            "打开上传连接失败",
+            "仅 Web 平台支持上传",
+        ),
+        schema_form: SchemaFormLocale {
+            required: s("请输入{label}"),
+            number_min: s("数值不能小于 {minimum}"),
+            number_max: s("数值不能大于 {maximum}"),
+            remove_item: s("删除"),
+            add_item: s("+ 添加{label}"),
+        },
+    }
+}
+
+fn en_us() -> LocaleBundle {
+    LocaleBundle {
+        locale: s("en-US"),
+        global: GlobalLocale {
+            loading: s("Loading..."),
+        },
+        empty: EmptyLocale {
+            description: s("No data"),
+        },
+        pagination: PaginationLocale {
+            total: s("Total {total} items"),
+            prev_page: s("Previous Page"),
+            next_page: s("Next Page"),
+            items_per_page: s("{size} / page"),
+        },
+        modal: ModalLocale {
+            ok_text: s("OK"),
+            cancel_text: s("Cancel"),
+        },
+        popconfirm: PopconfirmLocale {
+            ok_text: s("OK"),
+            cancel_text: s("Cancel"),
+        },
+        transfer: TransferLocale {
+            source_title: s("Source"),
+            target_title: s("Target"),
+            search_placeholder: s("Search here"),
+            not_found: s("No data"),
+            items_unit: s("items"),
+        },
+        date_picker: DatePickerLocale {
+            placeholder: s("Select date"),
+            range_start_placeholder: s("Start date"),
+            range_end_placeholder: s("End date"),
+            year_month_format: s("{month_name} {year}"),
+            month_names: strings([
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ]),
+            short_weekdays: strings(["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"]),
+            weekdays: default_weekdays(),
+            meridiem: strings(["AM", "PM"]),
+            day_ordinal: s("{n}{th}"),
+            week_ordinal: s("{n}{th}"),
+            week_start: 0,
+            week_min_days: 1,
+        },
+        time_picker: TimePickerLocale {
+            placeholder: s("Select time"),
+        },
+        tour: TourLocale {
+            next: s("Next"),
+            previous: s("Previous"),
+            finish: s("Finish"),
+        },
+        typography: TypographyLocale {
+            expand: s("Expand"),
+            collapse: s("Collapse"),
+            copy: s("Copy"),
+            copied: s("Copied"),
+            edit_placeholder: s("Please enter"),
+        },
+        image: ImageLocale {
+            preview: s("Preview"),
+            load_failed: s("Failed to load"),
+            zoom_in: s("Zoom In"),
+            zoom_out: s("Zoom Out"),
+            rotate_left: s("Rotate Left"),
+            rotate_right: s("Rotate Right"),
+            reset: s("Reset"),
+        },
+        qrcode: QRCodeLocale {
+            expired: s("QR code expired"),
+            refresh: s("Refresh"),
+            scanned: s("Scanned"),
+        },
+        color_picker: ColorPickerLocale { clear: s("Clear") },
+        table: table_locale(
+            "Filter menu",
+            "OK",
+            "Reset",
+            "No filters",
+            "Select current page",
+            "Clear all data",
+            "Invert current page",
+            "Sort",
+            "Expand row",
+            "Collapse row",
+            "No data",
+        ),
+        upload: upload_locale(
+            "Uploading...",
+            "Remove file",
+            "Download file",
+            "Upload error",
+            "Preview file",
+            "Pause upload",
+            "Resume upload",
+            "Edit image",
+            "Rotate left",
+            "Rotate right",
+            "Could not create the upload request",
+            "Failed to open the upload connection",
+            "Upload is only supported on web targets",
+        ),
+        schema_form: SchemaFormLocale {
+            required: s("Please enter {label}"),
+            number_min: s("Must be at least {minimum}"),
+            number_max: s("Must be at most {maximum}"),
+            remove_item: s("Remove"),
+            add_item: s("+ Add {label}"),
+        },
+    }
+}
+
+fn ja_jp() -> LocaleBundle {
+    LocaleBundle {
+        locale: s("ja-JP"),
+        global: GlobalLocale {
+            loading: s("読み込み中..."),
+        },
+        empty: EmptyLocale {
+            description: s("データがありません"),
+        },
+        pagination: PaginationLocale {
+            total: s("合計 {total} 件"),
+            prev_page: s("前のページ"),
+            next_page: s("次のページ"),
+            items_per_page: s("{size} 件 / ページ"),
+        },
+        modal: ModalLocale {
+            ok_text: s("OK"),
+            cancel_text: s("キャンセル"),
+        },
+        popconfirm: PopconfirmLocale {
+            ok_text: s("OK"),
+            cancel_text: s("キャンセル"),
+        },
+        transfer: TransferLocale {
+            source_title: s("ソース"),
+            target_title: s("ターゲット"),
+            search_placeholder: s("ここを検索"),
+            not_found: s("データがありません"),
+            items_unit: s("項目"),
+        },
+        date_picker: DatePickerLocale {
+            placeholder: s("日付を選択"),
+            range_start_placeholder: s("開始日付"),
+            range_end_placeholder: s("終了日付"),
+            year_month_format: s("{year}年{month}月"),
+            month_names: numbered_months("月"),
+            short_weekdays: strings(["月", "火", "水", "木", "金", "土", "日"]),
+            weekdays: strings(["月曜", "火曜", "水曜", "木曜", "金曜", "土曜", "日曜"]),
+            meridiem: strings(["午前", "午後"]),
+            day_ordinal: s("{n}日"),
+            week_ordinal: s("{n}週"),
+            week_start: 0,
+            week_min_days: 1,
+        },
+        time_picker: TimePickerLocale {
+            placeholder: s("時間を選択"),
+        },
+        tour: TourLocale {
+            next: s("次へ"),
+            previous: s("前へ"),
+            finish: s("完了"),
+        },
+        typography: TypographyLocale {
+            expand: s("展開"),
+            collapse: s("折りたたむ"),
+            copy: s("コピー"),
+            copied: s("コピーしました"),
+            edit_placeholder: s("入力してください"),
+        },
+        image: ImageLocale {
+            preview: s("プレビュー"),
+            load_failed: s("読み込みに失敗しました"),
+            zoom_in: s("拡大"),
+            zoom_out: s("縮小"),
+            rotate_left: s("左に回転"),
+            rotate_right: s("右に回転"),
+            reset: s("リセット"),
+        },
+        qrcode: QRCodeLocale {
+            expired: s("QRコードの有効期限が切れました"),
+            refresh: s("更新"),
+            scanned: s("スキャン済み"),
+        },
+        color_picker: ColorPickerLocale {
+            clear: s("クリア")
+        },
+        table: table_locale(
+            "フィルター",
+            "OK",
+            "リセット",
+            "フィルターなし",
+            "ページ単位で選択",
+            "すべての選択を解除",
+            "ページ単位で反転",
+            "ソート",
+            "展開する",
+            "折り畳む",
+            "データがありません",
+        ),
+        upload: upload_locale(
+            "アップロード中...",
+            "ファイルを削除",
+            "ファイルをダウンロード",
+            "アップロードエラー",
+            "ファイルをプレビュー",
+            "アップロードを一時停止",
+            "アップロードを再開",
+            "画像を編集",
+            "左に回転",
+            "右に回転",
+            "アップロードリクエストを作成できません",
+            "アップロード接続を開けませんでした",
+            "アップロードは Web 環境でのみ利用できます",
+        ),
+        schema_form: SchemaFormLocale {
+            required: s("{label}を入力してください"),
+            number_min: s("{minimum} 以上の値を入力してください"),
+            number_max: s("{maximum} 以下の値を入力してください"),
+            remove_item: s("削除"),
+            add_item: s("+ {label}を追加"),
+        },
+    }
+}
+
+fn de_de() -> LocaleBundle {
+    LocaleBundle {
+        locale: s("de-DE"),
+        global: GlobalLocale {
+            loading: s("Wird geladen..."),
+        },
+        empty: EmptyLocale {
+            description: s("Keine Daten"),
+        },
+        pagination: PaginationLocale {
+            total: s("Insgesamt {total} Einträge"),
+            prev_page: s("Vorherige Seite"),
+            next_page: s("Nächste Seite"),
+            items_per_page: s("{size} / Seite"),
+        },
+        modal: ModalLocale {
+            ok_text: s("OK"),
+            cancel_text: s("Abbrechen"),
+        },
+        popconfirm: PopconfirmLocale {
+            ok_text: s("OK"),
+            cancel_text: s("Abbrechen"),
+        },
+        transfer: TransferLocale {
+            source_title: s("Quelle"),
+            target_title: s("Ziel"),
+            search_placeholder: s("Suchen"),
+            not_found: s("Keine Daten"),
+            items_unit: s("Elemente"),
+        },
+        date_picker: DatePickerLocale {
+            placeholder: s("Datum auswählen"),
+            range_start_placeholder: s("Startdatum"),
+            range_end_placeholder: s("Enddatum"),
+            year_month_format: s("{month_name} {year}"),
+            month_names: strings([
+                "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+            ]),
+            short_weekdays: strings(["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"]),
+            weekdays: strings(["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."]),
+            meridiem: strings(["AM", "PM"]),
+            day_ordinal: s("{n}."),
+            week_ordinal: s("{n}."),
+            week_start: 1,
+            week_min_days: 4,
+        },
+        time_picker: TimePickerLocale {
+            placeholder: s("Zeit auswählen"),
+        },
+        tour: TourLocale {
+            next: s("Weiter"),
+            previous: s("Zurück"),
+            finish: s("Fertig"),
+        },
+        typography: TypographyLocale {
+            expand: s("Erweitern"),
+            collapse: s("Reduzieren"),
+            copy: s("Kopieren"),
+            copied: s("Kopiert"),
+            edit_placeholder: s("Bitte eingeben"),
+        },
+        image: ImageLocale {
+            preview: s("Vorschau"),
+            load_failed: s("Laden fehlgeschlagen"),
+            zoom_in: s("Vergrößern"),
+            zoom_out: s("Verkleinern"),
+            rotate_left: s("Nach links drehen"),
+            rotate_right: s("Nach rechts drehen"),
+            reset: s("Zurücksetzen"),
+        },
+        qrcode: QRCodeLocale {
+            expired: s("QR-Code abgelaufen"),
+            refresh: s("Aktualisieren"),
+            scanned: s("Gescannt"),
+        },
+        color_picker: ColorPickerLocale {
+            clear: s("Löschen"),
+        },
+        table: table_locale(
+            "Filter-Menü",
+            "OK",
+            "Zurücksetzen",
+            "Keine Filter",
+            "Aktuelle Seite auswählen",
+            "Auswahl aufheben",
+            "Aktuelle Seite umkehren",
+            "Sortieren",
+            "Zeile erweitern",
+            "Zeile reduzieren",
+            "Keine Daten",
+        ),
+        upload: upload_locale(
+            "Hochladen...",
+            "Datei entfernen",
+            "Datei herunterladen",
+            "Fehler beim Hochladen",
+            "Dateivorschau",
+            "Hochladen pausieren",
+            "Hochladen fortsetzen",
+            "Bild bearbeiten",
+            "Nach links drehen",
+            "Nach rechts drehen",
+            "Upload-Anfrage konnte nicht erstellt werden",
+            "Upload-Verbindung konnte nicht geöffnet werden",
+            "Hochladen wird nur im Web unterstützt",
+        ),
+        schema_form: SchemaFormLocale {
+            required: s("Bitte {label} eingeben"),
+            number_min: s("Der Wert muss mindestens {minimum} sein"),
+            number_max: s("Der Wert darf höchstens {maximum} sein"),
+            remove_item: s("Entfernen"),
+            add_item: s("+ {label} hinzufügen"),
+        },
+    }
+}
+
+fn fr_fr() -> LocaleBundle {
+    LocaleBundle {
+        locale: s("fr-FR"),
+        global: GlobalLocale {
+            loading: s("Chargement..."),
+        },
+        empty: EmptyLocale {
+            description: s("Aucune donnée"),
+        },
+        pagination: PaginationLocale {
+            total: s("Total {total} éléments"),
+            prev_page: s("Page précédente"),
+            next_page: s("Page suivante"),
+            items_per_page: s("{size} / page"),
+        },
+        modal: ModalLocale {
+            ok_text: s("OK"),
+            cancel_text: s("Annuler"),
+        },
+        popconfirm: PopconfirmLocale {
+            ok_text: s("OK"),
+            cancel_text: s("Annuler"),
+        },
+        transfer: TransferLocale {
+            source_title: s("Source"),
+            target_title: s("Cible"),
+            search_placeholder: s("Rechercher"),
+            not_found: s("Aucune donnée"),
+            items_unit: s("éléments"),
+        },
+        date_picker: DatePickerLocale {
+            placeholder: s("Sélectionner une date"),
+            range_start_placeholder: s("Date de début"),
+            range_end_placeholder: s("Date de fin"),
+            year_month_format: s("{month_name} {year}"),
+            month_names: strings([
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ]),
+            short_weekdays: strings(["lu", "ma", "me", "je", "ve", "sa", "di"]),
+            weekdays: strings(["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."]),
+            meridiem: strings(["AM", "PM"]),
+            day_ordinal: s("{n}"),
+            week_ordinal: s("{n}"),
+            week_start: 1,
+            week_min_days: 4,
+        },
+        time_picker: TimePickerLocale {
+            placeholder: s("Sélectionner l'heure"),
+        },
+        tour: TourLocale {
+            next: s("Suivant"),
+            previous: s("Précédent"),
+            finish: s("Terminer"),
+        },
+        typography: TypographyLocale {
+            expand: s("Développer"),
+            collapse: s("Réduire"),
+            copy: s("Copier"),
+            copied: s("Copié"),
+            edit_placeholder: s("Veuillez saisir"),
+        },
+        image: ImageLocale {
+            preview: s("Aperçu"),
+            load_failed: s("Échec du chargement"),
+            zoom_in: s("Zoom avant"),
+            zoom_out: s("Zoom arrière"),
+            rotate_left: s("Pivoter à gauche"),
+            rotate_right: s("Pivoter à droite"),
+            reset: s("Réinitialiser"),
+        },
+        qrcode: QRCodeLocale {
+            expired: s("QR code expiré"),
+            refresh: s("Actualiser"),
+            scanned: s("Scanné"),
+        },
+        color_picker: ColorPickerLocale {
+            clear: s("Effacer"),
+        },
+        table: table_locale(
+            "Filtrer",
+            "OK",
+            "Réinitialiser",
+            "Aucun filtre",
+            "Sélectionner la page actuelle",
+            "Tout désélectionner",
+            "Inverser la sélection de la page",
+            "Trier",
+            "Développer la ligne",
+            "Réduire la ligne",
+            "Aucune donnée",
+        ),
+        upload: upload_locale(
+            "Téléversement en cours...",
+            "Supprimer le fichier",
+            "Télécharger le fichier",
+            "Erreur de téléversement",
+            "Aperçu du fichier",
+            "Suspendre le téléversement",
+            "Reprendre le téléversement",
+            "Modifier l'image",
+            "Pivoter à gauche",
+            "Pivoter à droite",
+            "Impossible de créer la requête de téléversement",
+            "Impossible d'ouvrir la connexion de téléversement",
+            "Le téléversement n'est disponible que sur le Web",
+        ),
+        schema_form: SchemaFormLocale {
+            required: s("Veuillez saisir {label}"),
+            number_min: s("La valeur doit être au moins {minimum}"),
+            number_max: s("La valeur doit être au plus {maximum}"),
+            remove_item: s("Supprimer"),
+            add_item: s("+ Ajouter {label}"),
+        },
+    }
+}
+
+fn es_es() -> LocaleBundle {
+    LocaleBundle {
+        locale: s("es-ES"),
+        global: GlobalLocale {
+            loading: s("Cargando..."),
+        },
+        empty: EmptyLocale {
+            description: s("No hay datos"),
+        },
+        pagination: PaginationLocale {
+            total: s("Total {total} elementos"),
+            prev_page: s("Página anterior"),
+            next_page: s("Página siguiente"),
+            items_per_page: s("{size} / página"),
+        },
+        modal: ModalLocale {
+            ok_text: s("Aceptar"),
+            cancel_text: s("Cancelar"),
+        },
+        popconfirm: PopconfirmLocale {
+            ok_text: s("Aceptar"),
+            cancel_text: s("Cancelar"),
+        },
+        transfer: TransferLocale {
+            source_title: s("Origen"),
+            target_title: s("Destino"),
+            search_placeholder: s("Buscar aquí"),
+            not_found: s("No hay datos"),
+            items_unit: s("elementos"),
+        },
+        date_picker: DatePickerLocale {
+            placeholder: s("Seleccionar fecha"),
+            range_start_placeholder: s("Fecha inicial"),
+            range_end_placeholder: s("Fecha final"),
+            year_month_format: s("{month_name} {year}"),
+            month_names: strings([
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic",
+            ]),
+            short_weekdays: strings(["lu", "ma", "mi", "ju", "vi", "sá", "do"]),
+            weekdays: strings(["lun.", "mar.", "mié.", "jue.", "vie.", "sáb.", "dom."]),
+            meridiem: strings(["a. m.", "p. m."]),
+            day_ordinal: s("{n}º"),
+            week_ordinal: s("{n}ª"),
+            week_start: 1,
+            week_min_days: 4,
+        },
+        time_picker: TimePickerLocale {
+            placeholder: s("Seleccionar hora"),
+        },
+        tour: TourLocale {
+            next: s("Siguiente"),
+            previous: s("Anterior"),
+            finish: s("Finalizar"),
+        },
+        typography: TypographyLocale {
+            expand: s("Expandir"),
+            collapse: s("Contraer"),
+            copy: s("Copiar"),
+            copied: s("Copiado"),
+            edit_placeholder: s("Por favor ingrese"),
+        },
+        image: ImageLocale {
+            preview: s("Vista previa"),
+            load_failed: s("Error al cargar"),
+            zoom_in: s("Acercar"),
+            zoom_out: s("Alejar"),
+            rotate_left: s("Girar a la izquierda"),
+            rotate_right: s("Girar a la derecha"),
+            reset: s("Restablecer"),
+        },
+        qrcode: QRCodeLocale {
+            expired: s("Código QR expirado"),
+            refresh: s("Actualizar"),
+            scanned: s("Escaneado"),
+        },
+        color_picker: ColorPickerLocale { clear: s("Borrar") },
+        table: table_locale(
+            "Filtrar menú",
+            "Aceptar",
+            "Reiniciar",
+            "Sin filtros",
+            "Seleccionar página actual",
+            "Deseleccionar todo",
+            "Invertir página actual",
+            "Ordenar",
+            "Expandir fila",
+            "Contraer fila",
+            "No hay datos",
+        ),
+        upload: upload_locale(
+            "Subiendo...",
+            "Eliminar archivo",
+            "Descargar archivo",
+            "Error al subir el archivo",
+            "Vista previa del archivo",
+            "Pausar subida",
+            "Reanudar subida",
+            "Editar imagen",
+            "Girar a la izquierda",
+            "Girar a la derecha",
+            "No se pudo crear la solicitud de subida",
+            "No se pudo abrir la conexión de subida",
+            "La subida solo está disponible en la web",
+        ),
+        schema_form: SchemaFormLocale {
+            required: s("Introduzca {label}"),
+            number_min: s("El valor debe ser al menos {minimum}"),
+            number_max: s("El valor debe ser como máximo {maximum}"),
+            remove_item: s("Eliminar"),
+            add_item: s("+ Añadir {label}"),
+        },
+    }
+}
+
+fn ko_kr() -> LocaleBundle {
+    LocaleBundle {
+        locale: s("ko-KR"),
+        global: GlobalLocale {
+            loading: s("로딩 중..."),
+        },
+        empty: EmptyLocale {
+            description: s("데이터 없음"),
+        },
+        pagination: PaginationLocale {
+            total: s("총 {total}개"),
+            prev_page: s("이전 페이지"),
+            next_page: s("다음 페이지"),
+            items_per_page: s("{size} / 쪽"),
+        },
+        modal: ModalLocale {
+            ok_text: s("확인"),
+            cancel_text: s("취소"),
+        },
+        popconfirm: PopconfirmLocale {
+            ok_text: s("확인"),
+            cancel_text: s("취소"),
+        },
+        transfer: TransferLocale {
+            source_title: s("원본"),
+            target_title: s("대상"),
+            search_placeholder: s("여기에 검색하세요"),
+            not_found: s("데이터 없음"),
+            items_unit: s("개"),
+        },
+        date_picker: DatePickerLocale {
+            placeholder: s("날짜 선택"),
+            range_start_placeholder: s("시작일"),
+            range_end_placeholder: s("종료일"),
+            year_month_format: s("{year}년 {month}월"),
+            month_names: numbered_months("월"),
+            short_weekdays: strings(["월", "화", "수", "목", "금", "토", "일"]),
+            weekdays: strings(["월", "화", "수", "목", "금", "토", "일"]),
+            meridiem: strings(["오전", "오후"]),
+            day_ordinal: s("{n}일"),
+            week_ordinal: s("{n}주차"),
+            week_start: 0,
+            week_min_days: 1,
+        },
+        time_picker: TimePickerLocale {
+            placeholder: s("시간 선택"),
+        },
+        tour: TourLocale {
+            next: s("다음"),
+            previous: s("이전"),
+            finish: s("완료"),
+        },
+        typography: TypographyLocale {
+            expand: s("펼치기"),
+            collapse: s("접기"),
+            copy: s("복사"),
+            copied: s("복사됨"),
+            edit_placeholder: s("입력해 주세요"),
+        },
+        image: ImageLocale {
+            preview: s("미리보기"),
+            load_failed: s("불러오기 실패"),
+            zoom_in: s("확대"),
+            zoom_out: s("축소"),
+            rotate_left: s("왼쪽으로 회전"),
+            rotate_right: s("오른쪽으로 회전"),
+            reset: s("초기화"),
+        },
+        qrcode: QRCodeLocale {
+            expired: s("QR 코드가 만료되었습니다"),
+            refresh: s("새로고침"),
+            scanned: s("스캔 완료"),
+        },
+        color_picker: ColorPickerLocale {
+            clear: s("지우기")
+        },
+        table: table_locale(
+            "필터 메뉴",
+            "확인",
+            "초기화",
+            "필터 없음",
+            "현재 페이지 선택",
+            "모두 선택 해제",
+            "현재 페이지 선택 반전",
+            "정렬",
+            "행 펼치기",
+            "행 접기",
+            "데이터 없음",
+        ),
+        upload: upload_locale(
+            "업로드 중...",
+            "파일 삭제",
+            "파일 다운로드",
+            "업로드 오류",
+            "파일 미리보기",
+            "업로드 일시 중지",
+            "업로드 재개",
+            "이미지 편집",
+            "왼쪽으로 회전",
+            "오른쪽으로 회전",
+            "업로드 요청을 만들 수 없습니다",
+            "업로드 연결을 열지 못했습니다",
+            "업로드는 웹 환경에서만 지원됩니다",
+        ),
+        schema_form: SchemaFormLocale {
+            required: s("{label}을(를) 입력하세요"),
+            number_min: s("{minimum} 이상이어야 합니다"),
+            number_max: s("{maximum} 이하여야 합니다"),
+            remove_item: s("삭제"),
+            add_item: s("+ {label} 추가"),
+        },
+    }
+}
+
+fn zh_tw() -> LocaleBundle {
+    LocaleBundle {
+        locale: s("zh-TW"),
+        global: GlobalLocale {
+            loading: s("載入中..."),
+        },
+        empty: EmptyLocale {
+            description: s("無此資料"),
+        },
+        pagination: PaginationLocale {
+            total: s("共 {total} 條"),
+            prev_page: s("上一頁"),
+            next_page: s("下一頁"),
+            items_per_page: s("{size} / 頁"),
+        },
+        modal: ModalLocale {
+            ok_text: s("確定"),
+            cancel_text: s("取消"),
+        },
+        popconfirm: PopconfirmLocale {
+            ok_text: s("確定"),
+            cancel_text: s("取消"),
+        },
+        transfer: TransferLocale {
+            source_title: s("來源清單"),
+            target_title: s("目的清單"),
+            search_placeholder: s("搜尋資料"),
+            not_found: s("無此資料"),
+            items_unit: s("項"),
+        },
+        date_picker: DatePickerLocale {
+            placeholder: s("請選擇日期"),
+            range_start_placeholder: s("開始日期"),
+            range_end_placeholder: s("結束日期"),
+            year_month_format: s("{year}年{month}月"),
+            month_names: numbered_months("月"),
+            short_weekdays: strings(["一", "二", "三", "四", "五", "六", "日"]),
+            weekdays: strings(["週一", "週二", "週三", "週四", "週五", "週六", "週日"]),
+            meridiem: strings(["上午", "下午"]),
+            day_ordinal: s("{n}日"),
+            week_ordinal: s("{n}週"),
+            week_start: 0,
+            week_min_days: 1,
+        },
+        time_picker: TimePickerLocale {
+            placeholder: s("請選擇時間"),
+        },
+        tour: TourLocale {
+            next: s("下一步"),
+            previous: s("上一步"),
+            finish: s("結束導覽"),
+        },
+        typography: TypographyLocale {
+            expand: s("展開"),
+            collapse: s("收起"),
+            copy: s("複製"),
+            copied: s("已複製"),
+            edit_placeholder: s("請輸入"),
+        },
+        image: ImageLocale {
+            preview: s("預覽"),
+            load_failed: s("載入失敗"),
+            zoom_in: s("放大"),
+            zoom_out: s("縮小"),
+            rotate_left: s("向左旋轉"),
+            rotate_right: s("向右旋轉"),
+            reset: s("重設"),
+        },
+        qrcode: QRCodeLocale {
+            expired: s("二維碼已過期"),
+            refresh: s("點擊刷新"),
+            scanned: s("已掃描"),
+        },
+        color_picker: ColorPickerLocale { clear: s("清除") },
+        table: table_locale(
+            "篩選器",
+            "確定",
+            "重設",
+            "無篩選項",
+            "全部選取",
+            "清空所有",
+            "反向選取",
+            "排序",
+            "展開行",
+            "關閉行",
+            "無此資料",
+        ),
+        upload: upload_locale(
+            "正在上傳",
+            "刪除檔案",
+            "下載檔案",
+            "上傳失敗",
+            "預覽檔案",
+            "暫停上傳",
+            "繼續上傳",
+            "編輯圖片",
+            "向左旋轉",
+            "向右旋轉",
+            "無法建立請求",
+            "開啟上傳連線失敗",
+            "僅 Web 平台支援上傳",
+        ),
+        schema_form: SchemaFormLocale {
+            required: s("請輸入{label}"),
+            number_min: s("數值不能小於 {minimum}"),
+            number_max: s("數值不能大於 {maximum}"),
+            remove_item: s("刪除"),
+            add_item: s("+ 新增{label}"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODES: [&str; 8] = [
+        "zh-CN", "en-US", "ja-JP", "de-DE", "fr-FR", "es-ES", "ko-KR", "zh-TW",
+    ];
+
+    #[test]
+    fn builtin_bundles_cover_all_codes() {
+        for code in CODES {
+            let bundle = LocaleBundle::builtin(code).expect("built-in bundle");
+            assert_eq!(bundle.locale, code);
+            assert!(!bundle.empty.description.is_empty());
+            assert!(bundle.pagination.total.contains("{total}"));
+            assert!(bundle.pagination.items_per_page.contains("{size}"));
+            assert!(bundle.schema_form.required.contains("{label}"));
+            assert!(bundle.schema_form.number_min.contains("{minimum}"));
+            assert!(bundle.schema_form.number_max.contains("{maximum}"));
+            assert!(bundle.schema_form.add_item.contains("{label}"));
+            assert!(bundle.table.expand.is_some());
+            assert!(bundle.upload.remove_file.is_some());
+        }
+        assert!(LocaleBundle::builtin("xx-XX").is_none());
+    }
+
+    #[test]
+    fn locale_codes_round_trip() {
+        for code in CODES {
+            let locale = Locale::from_code(code).unwrap();
+            assert_eq!(locale.code(), code);
+        }
+        assert_eq!(Locale::from_code("ja_jp"), Some(Locale::JaJP));
+        assert_eq!(Locale::from_code("pt-BR"), None);
+    }
+
+    #[test]
+    fn format_year_month_uses_template() {
+        let zh = LocaleBundle::builtin("zh-CN").unwrap();
+        assert_eq!(zh.date_picker.format_year_month(2024, 3), "2024年3月");
+        let en = LocaleBundle::builtin("en-US").unwrap();
+        assert_eq!(en.date_picker.format_year_month(2024, 3), "Mar 2024");
+        let ko = LocaleBundle::builtin("ko-KR").unwrap();
+        assert_eq!(ko.date_picker.format_year_month(2024, 12), "2024년 12월");
+    }
+
+    #[test]
+    fn format_locale_template_replaces_all_placeholders() {
+        let out = format_locale_template("共 {total} 条 / {total}", &[("total", "5".into())]);
+        assert_eq!(out, "共 5 条 / 5");
+    }
+
+    #[test]
+    fn registered_bundle_overrides_builtin() {
+        let mut custom = LocaleBundle::builtin("de-DE").unwrap();
+        custom.locale = "de-AT".into();
+        custom.modal.cancel_text = "Abbruch".into();
+        register_locale_bundle(custom);
+        let found = find_locale_bundle("de_at").expect("registered bundle");
+        assert_eq!(found.modal.cancel_text, "Abbruch");
+
+        let mut override_en = LocaleBundle::builtin("en-US").unwrap();
+        override_en.modal.ok_text = "Got it".into();
+        register_locale_bundle(override_en);
+        assert_eq!(Locale::EnUS.bundle().modal.ok_text, "Got it");
+    }
+
+    #[test]
+    fn bundle_round_trips_through_json() {
+        let bundle = LocaleBundle::builtin("fr-FR").unwrap();
+        let json = serde_json::to_string(&bundle).unwrap();
+        let parsed: LocaleBundle = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, bundle);
+    }
+}
@@ -5,6 +5,10 @@
 
 use crate::components::config_provider::{ComponentSize, use_config};
 use crate::components::control::ControlStatus;
+use crate::components::locale::use_locale;
+use crate::components::select_base::{
+    RemoteQuery, RemoteSearchFn, same_remote_search, use_remote_search,
+};
 use dioxus::prelude::*;
 
 /// A single mention option.
@@ -106,9 +110,24 @@ pub struct MentionsProps {
     /// Callback when an option is selected.
     #[props(optional)]
     pub on_select: Option<EventHandler<MentionOption>>,
-    /// Callback when search text changes.
+    /// Callback when search text changes, after `search_debounce`.
     #[props(optional)]
     pub on_search: Option<EventHandler<(String, char)>>,
+    /// Delay in milliseconds between the last keystroke and `on_search` /
+    /// `remote_search`. Defaults to 300 with `remote_search` and 0 otherwise.
+    #[props(optional)]
+    pub search_debounce: Option<u32>,
+    /// Load options from a server instead of filtering `options` locally.
+    /// The query carries the trigger character in `prefix`.
+    #[props(optional)]
+    pub remote_search: Option<RemoteSearchFn<MentionOption>>,
+    /// Content shown when a search matches nothing; only rendered when set or
+    /// in remote mode.
+    #[props(optional)]
+    pub not_found_content: Option<Element>,
+    /// Callback when the dropdown list scrolls.
+    #[props(optional)]
+    pub on_popup_scroll: Option<EventHandler<ScrollEvent>>,
     /// Callback when focus changes.
     #[props(optional)]
     pub on_focus: Option<EventHandler<()>>,
@@ -142,8 +161,11 @@ impl PartialEq for MentionsProps {
             && self.placement == other.placement
             && self.auto_focus == other.auto_focus
             && self.rows == other.rows
+            && self.search_debounce == other.search_debounce
+            && self.not_found_content == other.not_found_content
             && self.class == other.class
             && self.style == other.style
+            && same_remote_search(&self.remote_search, &other.remote_search)
         // filter_option and event handlers cannot be compared for equality
     }
 }
@@ -169,6 +191,10 @@ pub fn Mentions(props: MentionsProps) -> Element {
         on_change,
         on_select,
         on_search,
+        search_debounce,
+        remote_search,
+        not_found_content,
+        on_popup_scroll,
         on_focus,
         on_blur,
         filter_option,
@@ -177,7 +203,9 @@ pub fn Mentions(props: MentionsProps) -> Element {
     } = props;
 
     let config = use_config();
+    let locale = use_locale();
     let final_size = size.unwrap_or(config.size);
+    let remote = use_remote_search(remote_search, search_debounce);
 
     // Internal value state
     let mut internal_value: Signal<String> =
@@ -198,15 +226,21 @@ pub fn Mentions(props: MentionsProps) -> Element {
     // Filter options based on search (clone to avoid lifetime issues)
     let filter_fn = filter_option.unwrap_or(default_filter);
     let search_val = search_text.read().clone();
-    let filtered_options: Vec<MentionOption> = if *dropdown_open.read() {
+    let filtered_options: Vec<MentionOption> = if !*dropdown_open.read() {
+        Vec::new()
+    } else if remote.enabled() {
+        remote.options()
+    } else {
         options
             .iter()
             .filter(|opt| filter_fn(&search_val, opt))
             .cloned()
             .collect()
-    } else {
-        Vec::new()
     };
+    let remote_loading = remote.loading();
+    let remote_error = remote.error();
+    let show_not_found =
+        filtered_options.is_empty() && (remote.not_found() || not_found_content.is_some());
 
     // Build class list
     let mut class_list = vec!["adui-mentions".to_string()];
@@ -284,9 +318,12 @@ pub fn Mentions(props: MentionsProps) -> Element {
                     search_text.set(search_str.clone());
                     active_index.set(0);
 
-                    if let Some(handler) = &on_search {
-                        handler.call((search_str, ch));
-                    }
+                    let on_search = on_search;
+                    remote.search(search_str, Some(ch), move |request: &RemoteQuery| {
+                        if let Some(handler) = on_search {
+                            handler.call((request.query.clone(), request.prefix.unwrap_or(ch)));
+                        }
+                    });
                     return;
                 }
             }
@@ -425,10 +462,23 @@ pub fn Mentions(props: MentionsProps) -> Element {
             }
 
             // Dropdown
-            if *dropdown_open.read() && (!filtered_options.is_empty() || loading) {
-                div { class: "{dropdown_class}",
+            if *dropdown_open.read()
+                && (!filtered_options.is_empty()
+                    || loading
+                    || remote_loading
+                    || remote_error.is_some()
+                    || show_not_found)
+            {
+                div {
+                    class: "{dropdown_class}",
+                    onscroll: move |evt| {
+                        remote.handle_scroll(&evt);
+                        if let Some(handler) = on_popup_scroll {
+                            handler.call(evt);
+                        }
+                    },
                     if loading {
-                        div { class: "adui-mentions-dropdown-loading", "Loading..." }
+                        div { class: "adui-mentions-dropdown-loading", "{locale.global.loading}" }
                     } else {
                         ul { class: "adui-mentions-dropdown-list",
                             for (i, option) in filtered_options.iter().enumerate() {
@@ -458,6 +508,15 @@ pub fn Mentions(props: MentionsProps) -> Element {
                                 }
                             }
                         }
+                        if remote_loading {
+                            div { class: "adui-mentions-dropdown-loading", "{locale.global.loading}" }
+                        } else if let Some(message) = remote_error {
+                            div { class: "adui-mentions-dropdown-empty adui-mentions-dropdown-error", "{message}" }
+                        } else if show_not_found {
+                            div { class: "adui-mentions-dropdown-empty",
+                                {not_found_content.unwrap_or_else(|| rsx! { "{locale.empty.description}" })}
+                            }
+                        }
                     }
                 }
             }
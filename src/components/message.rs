@@ -1,3 +1,6 @@
+use crate::components::dismiss_timer::{
+    DismissTimer, TimedEntry, find_keyed, now_ms, pause_dismiss, resume_dismiss, schedule_dismiss,
+};
 use crate::components::overlay::{OverlayHandle, OverlayKey, OverlayKind, OverlayMeta};
 use dioxus::prelude::*;
 
@@ -24,10 +27,13 @@ pub struct MessageConfig {
     pub class: Option<String>,
     /// Inline styles.
     pub style: Option<String>,
-    /// Unique key for this message (for programmatic updates).
+    /// Unique key for this message. Opening another message with the same
+    /// key updates it in place and restarts its timer.
     pub key: Option<String>,
     /// Callback when message is clicked.
     pub on_click: Option<EventHandler<()>>,
+    /// Pause the auto-dismiss timer while hovered.
+    pub pause_on_hover: bool,
 }
 
 impl Default for MessageConfig {
@@ -41,6 +47,7 @@ impl Default for MessageConfig {
             style: None,
             key: None,
             on_click: None,
+            pause_on_hover: true,
         }
     }
 }
@@ -51,6 +58,25 @@ pub struct MessageEntry {
     pub key: OverlayKey,
     pub meta: OverlayMeta,
     pub config: MessageConfig,
+    pub(crate) timer: DismissTimer,
+}
+
+impl TimedEntry for MessageEntry {
+    fn overlay_key(&self) -> OverlayKey {
+        self.key
+    }
+
+    fn user_key(&self) -> Option<&str> {
+        self.config.key.as_deref()
+    }
+
+    fn timer(&self) -> &DismissTimer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut DismissTimer {
+        &mut self.timer
+    }
 }
 
 /// Signal type used to hold the current message queue.
@@ -82,16 +108,35 @@ impl MessageApi {
     }
 
     /// Low-level open method that accepts a full config object.
+    ///
+    /// When `config.key` matches an open message, that message is updated in
+    /// place, its timer restarts and its existing `OverlayKey` is returned.
     pub fn open(&self, config: MessageConfig) -> OverlayKey {
-        let (key, meta) = self.overlay.open(OverlayKind::Message, false);
+        let now = now_ms();
         let mut entries = self.entries;
-        entries.write().push(MessageEntry {
-            key,
-            meta,
-            config: config.clone(),
-        });
+        let existing = find_keyed(&entries.peek(), config.key.as_deref());
+        let key = match existing {
+            Some(index) => {
+                let mut list = entries.write();
+                let entry = &mut list[index];
+                entry.timer.restart(config.duration, now);
+                entry.config = config;
+                entry.key
+            }
+            None => {
+                let (key, meta) = self.overlay.open(OverlayKind::Message, false);
+                let timer = DismissTimer::new(config.duration, now);
+                entries.write().push(MessageEntry {
+                    key,
+                    meta,
+                    config,
+                    timer,
+                });
+                key
+            }
+        };
 
-        schedule_message_dismiss(key, self.entries, self.overlay.clone(), config.duration);
+        schedule_dismiss(self.entries, self.overlay.clone(), key);
         key
     }
 
@@ -105,6 +150,7 @@ impl MessageApi {
             style: None,
             key: None,
             on_click: None,
+            pause_on_hover: true,
         };
         self.open(cfg)
     }
@@ -136,6 +182,7 @@ impl MessageApi {
             style: None,
             key: None,
             on_click: None,
+            pause_on_hover: true,
         };
         self.open(cfg)
     }
@@ -163,6 +210,7 @@ impl MessageApi {
 #[component]
 pub fn MessageHost() -> Element {
     let entries_signal = use_message_entries();
+    let overlay = use_context::<OverlayHandle>();
     let entries = entries_signal.read().clone();
 
     if entries.is_empty() {
@@ -177,6 +225,9 @@ pub fn MessageHost() -> Element {
                 let key = entry.key;
                 let z = entry.meta.z_index;
                 let text = entry.config.content.clone();
+                let pause_on_hover = entry.config.pause_on_hover;
+                let resume_overlay = overlay.clone();
+                let close_overlay = overlay.clone();
                 let kind_class = match entry.config.r#type {
                     MessageType::Info => "adui-message-info",
                     MessageType::Success => "adui-message-success",
@@ -189,12 +240,23 @@ pub fn MessageHost() -> Element {
                         key: "message-{key:?}",
                         class: "adui-message {kind_class}",
                         style: "pointer-events: auto; z-index: {z}; min-width: 200px; max-width: 480px; padding: 8px 16px; border-radius: 4px; background: var(--adui-color-bg-container); box-shadow: var(--adui-shadow); color: var(--adui-color-text); border: 1px solid var(--adui-color-border);",
+                        onmouseenter: move |_| {
+                            if pause_on_hover {
+                                pause_dismiss(entries_signal, key);
+                            }
+                        },
+                        onmouseleave: move |_| {
+                            if pause_on_hover {
+                                resume_dismiss(entries_signal, resume_overlay.clone(), key);
+                            }
+                        },
                         span { "{text}" }
                         button {
                             style: "margin-left: 8px; background: none; border: none; cursor: pointer; color: var(--adui-color-text-secondary);",
                             onclick: move |_| {
                                 let mut entries = entries_signal;
                                 entries.write().retain(|e| e.key != key);
+                                close_overlay.close(key);
                             },
                             "×"
                         }
@@ -205,44 +267,6 @@ pub fn MessageHost() -> Element {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
-fn schedule_message_dismiss(
-    key: OverlayKey,
-    entries: MessageEntriesSignal,
-    overlay: OverlayHandle,
-    duration_secs: f32,
-) {
-    use wasm_bindgen::{JsCast, closure::Closure};
-
-    if duration_secs <= 0.0 {
-        return;
-    }
-
-    if let Some(window) = web_sys::window() {
-        let delay_ms = (duration_secs * 1000.0) as i32;
-        let mut entries_signal = entries;
-        let overlay_clone = overlay.clone();
-        let callback = Closure::once(move || {
-            entries_signal.write().retain(|e| e.key != key);
-            overlay_clone.close(key);
-        });
-        let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
-            callback.as_ref().unchecked_ref(),
-            delay_ms,
-        );
-        callback.forget();
-    }
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn schedule_message_dismiss(
-    _key: OverlayKey,
-    _entries: MessageEntriesSignal,
-    _overlay: OverlayHandle,
-    _duration_secs: f32,
-) {
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,6 +302,7 @@ mod tests {
         assert_eq!(config.style, None);
         assert_eq!(config.key, None);
         assert_eq!(config.on_click, None);
+        assert!(config.pause_on_hover);
     }
 
     #[test]
@@ -291,6 +316,7 @@ mod tests {
             style: Some("color: red;".to_string()),
             key: Some("msg-1".to_string()),
             on_click: None,
+            pause_on_hover: false,
         };
         let config2 = config1.clone();
         assert_eq!(config1, config2);
@@ -307,6 +333,7 @@ mod tests {
             style: None,
             key: None,
             on_click: None,
+            pause_on_hover: true,
         };
         let config2 = MessageConfig {
             content: "Test".to_string(),
@@ -317,6 +344,7 @@ mod tests {
             style: None,
             key: None,
             on_click: None,
+            pause_on_hover: true,
         };
         let config3 = MessageConfig {
             content: "Different".to_string(),
@@ -327,8 +355,33 @@ mod tests {
             style: None,
             key: None,
             on_click: None,
+            pause_on_hover: true,
         };
         assert_eq!(config1, config2);
         assert_ne!(config1, config3);
     }
+
+    #[test]
+    fn keyed_messages_are_found_by_user_key() {
+        let mut manager = crate::components::overlay::OverlayManager::default();
+        let entries: Vec<MessageEntry> = ["saving", "other"]
+            .into_iter()
+            .map(|k| {
+                let (key, meta) = manager.open(OverlayKind::Message, false);
+                MessageEntry {
+                    key,
+                    meta,
+                    config: MessageConfig {
+                        key: Some(k.to_string()),
+                        ..MessageConfig::default()
+                    },
+                    timer: DismissTimer::new(3.0, 0.0),
+                }
+            })
+            .collect();
+        assert_eq!(find_keyed(&entries, Some("saving")), Some(0));
+        assert_eq!(find_keyed(&entries, Some("other")), Some(1));
+        assert_eq!(find_keyed(&entries, Some("missing")), None);
+        assert_eq!(find_keyed(&entries, None), None);
+    }
 }
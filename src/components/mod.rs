@@ -15,14 +15,20 @@ pub mod checkbox;
 pub mod collapse;
 pub mod config_provider;
 pub mod control;
+pub mod date_format;
+pub mod date_panel;
 pub mod date_picker;
 pub mod descriptions;
+mod dismiss_timer;
 pub mod divider;
 pub mod dropdown;
 pub mod flex;
 pub mod float_button;
 pub mod floating;
+pub mod floating_position;
 pub mod form;
+pub mod form_path;
+mod form_typed;
 pub mod result;
 pub mod select_base;
 pub use select_base::TreeNode;
@@ -30,9 +36,11 @@ pub mod input;
 pub mod input_number;
 mod layout_utils;
 pub mod list;
+pub mod locale;
 pub mod progress;
 pub mod radio;
 pub mod rate;
+pub mod schema_form;
 pub mod segmented;
 pub mod select;
 pub mod slider;
@@ -62,6 +70,7 @@ pub mod popconfirm;
 pub mod popover;
 pub mod qrcode;
 pub mod skeleton;
+mod sleep;
 pub mod slider_base;
 pub mod space;
 pub mod spin;
@@ -78,4 +87,7 @@ pub mod tree;
 pub mod tree_select;
 pub mod typography;
 pub mod upload;
+pub mod upload_chunk;
+pub mod upload_image;
+pub mod virtual_list;
 pub mod watermark;
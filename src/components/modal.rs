@@ -5,15 +5,24 @@
 //! - Centered positioning
 //! - Customizable buttons
 //! - Semantic classNames/styles
+//! - Imperative `confirm`/`info`/`success`/`error`/`warning` dialogs rendered
+//!   by `ModalHost`
 
 use crate::components::button::{Button, ButtonType};
-use crate::components::overlay::{OverlayKey, OverlayKind, use_overlay};
+use crate::components::icon::{Icon, IconKind};
+use crate::components::locale::use_locale;
+use crate::components::overlay::{
+    OverlayHandle, OverlayKey, OverlayKind, OverlayMeta, use_overlay,
+};
 use crate::foundation::{
     ClassListExt, ModalClassNames, ModalSemantic, ModalStyles, StyleStringExt,
 };
 use dioxus::events::KeyboardEvent;
 use dioxus::prelude::*;
 use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
 use std::rc::Rc;
 
 /// Modal type for static method variants.
@@ -27,7 +36,6 @@ pub enum ModalType {
 }
 
 impl ModalType {
-    #[allow(dead_code)]
     fn as_class(&self) -> &'static str {
         match self {
             ModalType::Info => "adui-modal-info",
@@ -37,6 +45,21 @@ impl ModalType {
             ModalType::Confirm => "adui-modal-confirm",
         }
     }
+
+    fn icon(&self) -> (IconKind, &'static str) {
+        match self {
+            ModalType::Info => (IconKind::Info, "var(--adui-color-primary)"),
+            ModalType::Success => (IconKind::Check, "var(--adui-color-success)"),
+            ModalType::Error => (IconKind::Close, "var(--adui-color-error)"),
+            ModalType::Warning => (IconKind::Info, "var(--adui-color-warning)"),
+            ModalType::Confirm => (IconKind::Question, "var(--adui-color-warning)"),
+        }
+    }
+
+    /// Only confirm dialogs offer a cancel button, as in Ant Design.
+    fn has_cancel(&self) -> bool {
+        matches!(self, ModalType::Confirm)
+    }
 }
 
 /// Basic modal props, targeting the most common controlled use cases.
@@ -323,6 +346,8 @@ pub fn Modal(props: ModalProps) -> Element {
         });
     }
 
+    let locale = use_locale();
+
     if !open && destroy_on_close {
         return rsx! {};
     }
@@ -372,8 +397,8 @@ pub fn Modal(props: ModalProps) -> Element {
     };
 
     // Default button texts
-    let ok_button_text = ok_text.unwrap_or_else(|| "确定".to_string());
-    let cancel_button_text = cancel_text.unwrap_or_else(|| "取消".to_string());
+    let ok_button_text = ok_text.unwrap_or_else(|| locale.modal.ok_text.clone());
+    let cancel_button_text = cancel_text.unwrap_or_else(|| locale.modal.cancel_text.clone());
     let ok_button_type = ok_type.unwrap_or(ButtonType::Primary);
 
     // Close icon
@@ -414,6 +439,7 @@ pub fn Modal(props: ModalProps) -> Element {
                 style: "{content_style}",
                 onkeydown: on_keydown,
                 tabindex: 0,
+                onmounted: focus_on_mount,
                 div {
                     class: "adui-modal-content",
                     style: "min-width: {width_px}px; max-width: 80vw; background: var(--adui-color-bg-container); border-radius: var(--adui-radius-lg, 8px); box-shadow: var(--adui-shadow-secondary); border: 1px solid var(--adui-color-border); overflow: hidden;",
@@ -474,6 +500,465 @@ pub fn Modal(props: ModalProps) -> Element {
     }
 }
 
+/// Move focus into a dialog when it opens so Escape reaches its handler.
+async fn focus_on_mount(evt: MountedEvent) {
+    let _ = evt.set_focus(true).await;
+}
+
+/// Future returned by an async [`ModalOk`] handler. `Err` keeps the dialog
+/// open.
+pub type ModalOkFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+/// OK handler of an imperative dialog opened through [`ModalApi`].
+#[derive(Clone)]
+pub enum ModalOk {
+    /// Runs on click; the dialog closes right after.
+    Sync(Rc<dyn Fn()>),
+    /// Keeps the OK button loading until the future resolves. The dialog
+    /// closes on `Ok`; on `Err` it stays open so the user can retry or
+    /// cancel.
+    Async(Rc<dyn Fn() -> ModalOkFuture>),
+}
+
+impl ModalOk {
+    /// Synchronous OK handler.
+    pub fn new(f: impl Fn() + 'static) -> Self {
+        ModalOk::Sync(Rc::new(f))
+    }
+
+    /// Async OK handler, e.g. a request that must succeed before closing.
+    pub fn future<F>(f: impl Fn() -> F + 'static) -> Self
+    where
+        F: Future<Output = Result<(), String>> + 'static,
+    {
+        ModalOk::Async(Rc::new(move || Box::pin(f())))
+    }
+}
+
+impl fmt::Debug for ModalOk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            ModalOk::Sync(_) => "Sync",
+            ModalOk::Async(_) => "Async",
+        };
+        write!(f, "ModalOk::{kind}")
+    }
+}
+
+/// Configuration of a dialog opened through [`ModalApi`].
+#[derive(Clone, Debug)]
+pub struct ModalConfig {
+    /// Dialog variant. Overwritten by the typed helpers such as
+    /// [`ModalApi::info`].
+    pub r#type: ModalType,
+    pub title: Option<String>,
+    pub content: Option<String>,
+    /// OK button text. Falls back to the locale.
+    pub ok_text: Option<String>,
+    /// Cancel button text. Falls back to the locale.
+    pub cancel_text: Option<String>,
+    /// OK button type. Defaults to primary.
+    pub ok_type: Option<ButtonType>,
+    pub on_ok: Option<ModalOk>,
+    /// Called when the dialog is cancelled via button, mask or Escape.
+    pub on_cancel: Option<EventHandler<()>>,
+    /// Show a close button in the top-right corner.
+    pub closable: bool,
+    /// Whether clicking the mask cancels the dialog.
+    pub mask_closable: bool,
+    /// Whether Escape cancels the dialog.
+    pub keyboard: bool,
+    pub centered: bool,
+    /// Width in pixels. Defaults to 416, as in Ant Design.
+    pub width: Option<f32>,
+    /// Additional CSS class.
+    pub class: Option<String>,
+}
+
+impl Default for ModalConfig {
+    fn default() -> Self {
+        Self {
+            r#type: ModalType::Confirm,
+            title: None,
+            content: None,
+            ok_text: None,
+            cancel_text: None,
+            ok_type: None,
+            on_ok: None,
+            on_cancel: None,
+            closable: false,
+            mask_closable: false,
+            keyboard: true,
+            centered: false,
+            width: None,
+            class: None,
+        }
+    }
+}
+
+impl PartialEq for ModalConfig {
+    fn eq(&self, other: &Self) -> bool {
+        self.r#type == other.r#type
+            && self.title == other.title
+            && self.content == other.content
+            && self.ok_text == other.ok_text
+            && self.cancel_text == other.cancel_text
+            && self.ok_type == other.ok_type
+            && self.on_cancel == other.on_cancel
+            && self.closable == other.closable
+            && self.mask_closable == other.mask_closable
+            && self.keyboard == other.keyboard
+            && self.centered == other.centered
+            && self.width == other.width
+            && self.class == other.class
+        // `on_ok` holds a closure and cannot be compared
+    }
+}
+
+/// Internal representation of an open imperative dialog.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModalEntry {
+    pub key: OverlayKey,
+    pub meta: OverlayMeta,
+    pub config: ModalConfig,
+    /// Whether an async OK handler is in flight.
+    pub loading: bool,
+}
+
+/// Signal type used to hold the open imperative dialogs.
+pub type ModalEntriesSignal = Signal<Vec<ModalEntry>>;
+
+/// Create the modal entries signal and install it into context.
+///
+/// This should be called once near the top of the App tree.
+pub fn use_modal_entries_provider() -> ModalEntriesSignal {
+    let signal: ModalEntriesSignal = use_context_provider(|| Signal::new(Vec::new()));
+    signal
+}
+
+/// Retrieve the modal entries signal from context.
+pub fn use_modal_entries() -> ModalEntriesSignal {
+    use_context::<ModalEntriesSignal>()
+}
+
+/// Public API used by `use_modal()` callers.
+#[derive(Clone)]
+pub struct ModalApi {
+    overlay: OverlayHandle,
+    entries: ModalEntriesSignal,
+}
+
+impl ModalApi {
+    pub fn new(overlay: OverlayHandle, entries: ModalEntriesSignal) -> Self {
+        Self { overlay, entries }
+    }
+
+    /// Low-level open method that keeps `config.r#type` as given.
+    pub fn open(&self, config: ModalConfig) -> ModalRef {
+        let (key, meta) = self.overlay.open(OverlayKind::Modal, true);
+        let mut entries = self.entries;
+        entries.write().push(ModalEntry {
+            key,
+            meta,
+            config,
+            loading: false,
+        });
+        ModalRef {
+            key,
+            overlay: self.overlay.clone(),
+            entries: self.entries,
+        }
+    }
+
+    fn open_with_type(&self, mut config: ModalConfig, kind: ModalType) -> ModalRef {
+        config.r#type = kind;
+        self.open(config)
+    }
+
+    pub fn confirm(&self, config: ModalConfig) -> ModalRef {
+        self.open_with_type(config, ModalType::Confirm)
+    }
+
+    pub fn info(&self, config: ModalConfig) -> ModalRef {
+        self.open_with_type(config, ModalType::Info)
+    }
+
+    pub fn success(&self, config: ModalConfig) -> ModalRef {
+        self.open_with_type(config, ModalType::Success)
+    }
+
+    pub fn error(&self, config: ModalConfig) -> ModalRef {
+        self.open_with_type(config, ModalType::Error)
+    }
+
+    pub fn warning(&self, config: ModalConfig) -> ModalRef {
+        self.open_with_type(config, ModalType::Warning)
+    }
+
+    /// Close every dialog opened through this API without calling
+    /// `on_cancel`.
+    pub fn destroy_all(&self) {
+        let mut entries = self.entries;
+        let current: Vec<_> = entries.read().iter().map(|e| e.key).collect();
+        entries.write().clear();
+        for k in current {
+            self.overlay.close(k);
+        }
+    }
+}
+
+/// Handle to a dialog returned by [`ModalApi`].
+#[derive(Clone)]
+pub struct ModalRef {
+    key: OverlayKey,
+    overlay: OverlayHandle,
+    entries: ModalEntriesSignal,
+}
+
+impl ModalRef {
+    pub fn key(&self) -> OverlayKey {
+        self.key
+    }
+
+    /// Whether the dialog is still open.
+    pub fn is_open(&self) -> bool {
+        self.entries.read().iter().any(|e| e.key == self.key)
+    }
+
+    /// Change the config of the open dialog, e.g. its content while an
+    /// async OK handler runs. Does nothing once it has closed.
+    pub fn update(&self, f: impl FnOnce(&mut ModalConfig)) {
+        let mut entries = self.entries;
+        update_entry(&mut entries.write(), self.key, |entry| f(&mut entry.config));
+    }
+
+    /// Close the dialog without calling `on_cancel`.
+    pub fn destroy(&self) {
+        let mut entries = self.entries;
+        entries.write().retain(|e| e.key != self.key);
+        self.overlay.close(self.key);
+    }
+
+    fn set_loading(&self, loading: bool) {
+        let mut entries = self.entries;
+        update_entry(&mut entries.write(), self.key, |entry| {
+            entry.loading = loading
+        });
+    }
+}
+
+/// Apply `f` to the entry with `key`, returning whether it was found.
+fn update_entry(
+    entries: &mut [ModalEntry],
+    key: OverlayKey,
+    f: impl FnOnce(&mut ModalEntry),
+) -> bool {
+    match entries.iter_mut().find(|e| e.key == key) {
+        Some(entry) => {
+            f(entry);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Host component rendering the dialogs opened through [`ModalApi`].
+#[component]
+pub fn ModalHost() -> Element {
+    let entries_signal = use_modal_entries();
+    let overlay = use_context::<OverlayHandle>();
+    let locale = use_locale();
+    let entries = entries_signal.read().clone();
+
+    if entries.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        {entries.into_iter().map(|entry| {
+            let ModalEntry { key, meta, config, loading } = entry;
+            let ModalConfig {
+                r#type,
+                title,
+                content,
+                ok_text,
+                cancel_text,
+                ok_type,
+                on_ok,
+                on_cancel,
+                closable,
+                mask_closable,
+                keyboard,
+                centered,
+                width,
+                class,
+            } = config;
+            let modal_ref = ModalRef {
+                key,
+                overlay: overlay.clone(),
+                entries: entries_signal,
+            };
+
+            let z = meta.z_index;
+            let width_px = width.unwrap_or(416.0);
+            let (icon_kind, icon_color) = r#type.icon();
+            let ok_button_text = ok_text.unwrap_or_else(|| locale.modal.ok_text.clone());
+            let cancel_button_text =
+                cancel_text.unwrap_or_else(|| locale.modal.cancel_text.clone());
+            let ok_button_type = ok_type.unwrap_or(ButtonType::Primary);
+
+            let mut class_list = vec![
+                "adui-modal".to_string(),
+                "adui-modal-confirm".to_string(),
+                r#type.as_class().to_string(),
+            ];
+            if centered {
+                class_list.push("adui-modal-centered".into());
+            }
+            if let Some(extra) = class {
+                class_list.push(extra);
+            }
+            class_list.dedup();
+            let class_attr = class_list.join(" ");
+            let position_style = if centered {
+                "inset: 0; display: flex; align-items: center; justify-content: center;"
+            } else {
+                "top: 100px; left: 50%; transform: translateX(-50%);"
+            };
+
+            let cancel = {
+                let modal_ref = modal_ref.clone();
+                move || {
+                    if let Some(cb) = on_cancel {
+                        cb.call(());
+                    }
+                    modal_ref.destroy();
+                }
+            };
+            // Cancelling is blocked while an async OK handler runs.
+            let on_mask = cancel.clone();
+            let on_escape = cancel.clone();
+            let on_close_button = cancel.clone();
+            let on_cancel_button = cancel;
+            let ok_ref = modal_ref;
+
+            rsx! {
+                div {
+                    key: "modal-mask-{key:?}",
+                    class: "adui-modal-mask",
+                    style: "position: fixed; inset: 0; background: rgba(0,0,0,0.45); z-index: {z};",
+                    onclick: move |_| {
+                        if mask_closable && !loading {
+                            on_mask();
+                        }
+                    }
+                }
+                div {
+                    key: "modal-{key:?}",
+                    class: "{class_attr}",
+                    style: "position: fixed; {position_style} z-index: {z + 1};",
+                    tabindex: 0,
+                    onmounted: focus_on_mount,
+                    onkeydown: move |evt: KeyboardEvent| {
+                        if keyboard && !loading && matches!(evt.key(), Key::Escape) {
+                            evt.prevent_default();
+                            on_escape();
+                        }
+                    },
+                    div {
+                        class: "adui-modal-content",
+                        style: "position: relative; width: {width_px}px; max-width: 80vw; padding: 20px 24px; background: var(--adui-color-bg-container); border-radius: var(--adui-radius-lg, 8px); box-shadow: var(--adui-shadow-secondary); border: 1px solid var(--adui-color-border);",
+                        onclick: move |evt| evt.stop_propagation(),
+                        if closable {
+                            button {
+                                class: "adui-modal-close",
+                                r#type: "button",
+                                style: "position: absolute; top: 12px; right: 12px; border: none; background: none; cursor: pointer; font-size: 16px;",
+                                disabled: loading,
+                                onclick: move |_| {
+                                    if !loading {
+                                        on_close_button();
+                                    }
+                                },
+                                "×"
+                            }
+                        }
+                        div {
+                            class: "adui-modal-confirm-body",
+                            style: "display: flex; gap: 12px; align-items: flex-start;",
+                            span {
+                                class: "adui-modal-confirm-icon",
+                                Icon { kind: icon_kind, size: 22.0, color: icon_color.to_string() }
+                            }
+                            div {
+                                class: "adui-modal-confirm-paragraph",
+                                style: "flex: 1; min-width: 0;",
+                                if let Some(text) = title {
+                                    div {
+                                        class: "adui-modal-confirm-title",
+                                        style: "font-weight: 600; font-size: 16px; color: var(--adui-color-text);",
+                                        "{text}"
+                                    }
+                                }
+                                if let Some(text) = content {
+                                    div {
+                                        class: "adui-modal-confirm-content",
+                                        style: "margin-top: 8px; color: var(--adui-color-text);",
+                                        "{text}"
+                                    }
+                                }
+                            }
+                        }
+                        div {
+                            class: "adui-modal-confirm-btns",
+                            style: "margin-top: 24px; display: flex; gap: 8px; justify-content: flex-end;",
+                            if r#type.has_cancel() {
+                                Button {
+                                    disabled: loading,
+                                    onclick: move |_| {
+                                        if !loading {
+                                            on_cancel_button();
+                                        }
+                                    },
+                                    "{cancel_button_text}"
+                                }
+                            }
+                            Button {
+                                r#type: ok_button_type,
+                                loading,
+                                onclick: move |_| {
+                                    if loading {
+                                        return;
+                                    }
+                                    match on_ok.clone() {
+                                        None => ok_ref.destroy(),
+                                        Some(ModalOk::Sync(f)) => {
+                                            f();
+                                            ok_ref.destroy();
+                                        }
+                                        Some(ModalOk::Async(f)) => {
+                                            let pending = f();
+                                            ok_ref.set_loading(true);
+                                            let done = ok_ref.clone();
+                                            spawn(async move {
+                                                match pending.await {
+                                                    Ok(()) => done.destroy(),
+                                                    Err(_) => done.set_loading(false),
+                                                }
+                                            });
+                                        }
+                                    }
+                                },
+                                "{ok_button_text}"
+                            }
+                        }
+                    }
+                }
+            }
+        })}
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -565,4 +1050,90 @@ mod tests {
         assert_eq!(config.closable, true);
         assert!(config.style.is_none());
     }
+
+    #[test]
+    fn modal_type_icons_and_cancel() {
+        assert_eq!(ModalType::Success.icon().0, IconKind::Check);
+        assert_eq!(ModalType::Error.icon().0, IconKind::Close);
+        assert_eq!(ModalType::Confirm.icon().0, IconKind::Question);
+        assert!(ModalType::Confirm.has_cancel());
+        assert!(!ModalType::Info.has_cancel());
+        assert!(!ModalType::Warning.has_cancel());
+    }
+
+    #[test]
+    fn modal_config_default_and_eq() {
+        let config = ModalConfig::default();
+        assert_eq!(config.r#type, ModalType::Confirm);
+        assert!(config.keyboard);
+        assert!(!config.mask_closable);
+        assert!(!config.closable);
+
+        // `on_ok` is ignored when comparing.
+        let with_ok = ModalConfig {
+            on_ok: Some(ModalOk::new(|| {})),
+            ..ModalConfig::default()
+        };
+        assert_eq!(config, with_ok);
+        let titled = ModalConfig {
+            title: Some("Delete?".into()),
+            ..ModalConfig::default()
+        };
+        assert_ne!(config, titled);
+    }
+
+    #[test]
+    fn modal_ok_future_runs_handler() {
+        use std::cell::Cell;
+        use std::task::{Context, Poll, Waker};
+
+        let hits = Rc::new(Cell::new(0));
+        let counter = hits.clone();
+        let ok = ModalOk::future(move || {
+            let counter = counter.clone();
+            async move {
+                counter.set(counter.get() + 1);
+                if counter.get() > 1 {
+                    Err("rejected".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+        });
+        assert_eq!(format!("{ok:?}"), "ModalOk::Async");
+        let ModalOk::Async(f) = ok else {
+            panic!("expected an async handler");
+        };
+        let mut pending = f();
+        assert_eq!(hits.get(), 0, "the handler only runs when polled");
+        let mut cx = Context::from_waker(Waker::noop());
+        assert_eq!(pending.as_mut().poll(&mut cx), Poll::Ready(Ok(())));
+        assert_eq!(hits.get(), 1);
+        assert_eq!(
+            f().as_mut().poll(&mut cx),
+            Poll::Ready(Err("rejected".to_string()))
+        );
+    }
+
+    #[test]
+    fn update_entry_targets_matching_key() {
+        let mut manager = crate::components::overlay::OverlayManager::default();
+        let (first, meta) = manager.open(OverlayKind::Modal, true);
+        let (second, _) = manager.open(OverlayKind::Modal, true);
+        let mut entries = vec![ModalEntry {
+            key: first,
+            meta,
+            config: ModalConfig::default(),
+            loading: false,
+        }];
+
+        assert!(update_entry(&mut entries, first, |entry| {
+            entry.config.content = Some("Saving".into());
+            entry.loading = true;
+        }));
+        assert_eq!(entries[0].config.content.as_deref(), Some("Saving"));
+        assert!(entries[0].loading);
+        assert!(!update_entry(&mut entries, second, |entry| entry.loading = false));
+        assert!(entries[0].loading);
+    }
 }
@@ -1,3 +1,6 @@
+use crate::components::dismiss_timer::{
+    DismissTimer, TimedEntry, find_keyed, now_ms, pause_dismiss, resume_dismiss, schedule_dismiss,
+};
 use crate::components::overlay::{OverlayHandle, OverlayKey, OverlayKind, OverlayMeta};
 use dioxus::prelude::*;
 
@@ -21,7 +24,29 @@ pub enum NotificationPlacement {
 }
 
 impl NotificationPlacement {
-    #[allow(dead_code)]
+    const ALL: [NotificationPlacement; 4] = [
+        NotificationPlacement::TopRight,
+        NotificationPlacement::TopLeft,
+        NotificationPlacement::BottomRight,
+        NotificationPlacement::BottomLeft,
+    ];
+
+    pub(crate) fn as_class(&self) -> &'static str {
+        match self {
+            NotificationPlacement::TopRight => "adui-notification-top-right",
+            NotificationPlacement::TopLeft => "adui-notification-top-left",
+            NotificationPlacement::BottomRight => "adui-notification-bottom-right",
+            NotificationPlacement::BottomLeft => "adui-notification-bottom-left",
+        }
+    }
+
+    fn is_bottom(&self) -> bool {
+        matches!(
+            self,
+            NotificationPlacement::BottomRight | NotificationPlacement::BottomLeft
+        )
+    }
+
     pub(crate) fn as_style(&self) -> &'static str {
         match self {
             NotificationPlacement::TopRight => "top: 24px; right: 24px;",
@@ -49,8 +74,13 @@ pub struct NotificationConfig {
     pub style: Option<String>,
     /// Callback when notification is clicked.
     pub on_click: Option<EventHandler<()>>,
-    /// Unique key for this notification.
+    /// Unique key for this notification. Opening another notification with
+    /// the same key updates it in place and restarts its timer.
     pub key: Option<String>,
+    /// Pause the auto-dismiss timer while hovered.
+    pub pause_on_hover: bool,
+    /// Show a bar counting down the auto-dismiss delay.
+    pub show_progress: bool,
 }
 
 impl Default for NotificationConfig {
@@ -66,15 +96,58 @@ impl Default for NotificationConfig {
             style: None,
             on_click: None,
             key: None,
+            pause_on_hover: true,
+            show_progress: false,
         }
     }
 }
 
+/// Collapsed stack settings, see [`NotificationGlobalConfig::stack`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotificationStack {
+    /// Collapse once more than this many notifications share a placement.
+    pub threshold: usize,
+}
+
+impl Default for NotificationStack {
+    fn default() -> Self {
+        Self { threshold: 3 }
+    }
+}
+
+/// App-wide notification options, passed to `App { notification: .. }`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NotificationGlobalConfig {
+    /// Keep at most this many notifications open; the oldest close first.
+    pub max_count: Option<usize>,
+    /// Collapse each placement into a stack that expands on hover.
+    pub stack: Option<NotificationStack>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct NotificationEntry {
     pub key: OverlayKey,
     pub meta: OverlayMeta,
     pub config: NotificationConfig,
+    pub(crate) timer: DismissTimer,
+}
+
+impl TimedEntry for NotificationEntry {
+    fn overlay_key(&self) -> OverlayKey {
+        self.key
+    }
+
+    fn user_key(&self) -> Option<&str> {
+        self.config.key.as_deref()
+    }
+
+    fn timer(&self) -> &DismissTimer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut DismissTimer {
+        &mut self.timer
+    }
 }
 
 pub type NotificationEntriesSignal = Signal<Vec<NotificationEntry>>;
@@ -92,22 +165,59 @@ pub fn use_notification_entries() -> NotificationEntriesSignal {
 pub struct NotificationApi {
     overlay: OverlayHandle,
     entries: NotificationEntriesSignal,
+    config: NotificationGlobalConfig,
 }
 
 impl NotificationApi {
     pub fn new(overlay: OverlayHandle, entries: NotificationEntriesSignal) -> Self {
-        Self { overlay, entries }
+        Self {
+            overlay,
+            entries,
+            config: NotificationGlobalConfig::default(),
+        }
+    }
+
+    /// Apply app-wide options such as `max_count`.
+    pub fn with_config(mut self, config: NotificationGlobalConfig) -> Self {
+        self.config = config;
+        self
     }
 
+    /// Open a notification. When `config.key` matches an open notification,
+    /// that one is updated in place, its timer restarts and its existing
+    /// `OverlayKey` is returned.
     pub fn open(&self, config: NotificationConfig) -> OverlayKey {
-        let (key, meta) = self.overlay.open(OverlayKind::Notification, false);
+        let now = now_ms();
         let mut entries = self.entries;
-        entries.write().push(NotificationEntry {
-            key,
-            meta,
-            config: config.clone(),
-        });
-        schedule_notification_dismiss(key, self.entries, self.overlay.clone(), config.duration);
+        let existing = find_keyed(&entries.peek(), config.key.as_deref());
+        let key = match existing {
+            Some(index) => {
+                let mut list = entries.write();
+                let entry = &mut list[index];
+                entry.timer.restart(config.duration, now);
+                entry.config = config;
+                entry.key
+            }
+            None => {
+                let (key, meta) = self.overlay.open(OverlayKind::Notification, false);
+                let timer = DismissTimer::new(config.duration, now);
+                let evicted = {
+                    let mut list = entries.write();
+                    list.push(NotificationEntry {
+                        key,
+                        meta,
+                        config,
+                        timer,
+                    });
+                    evict_oldest(&mut list, self.config.max_count)
+                };
+                for k in evicted {
+                    self.overlay.close(k);
+                }
+                key
+            }
+        };
+        schedule_dismiss(self.entries, self.overlay.clone(), key);
         key
     }
 
@@ -129,6 +239,8 @@ impl NotificationApi {
             style: None,
             on_click: None,
             key: None,
+            pause_on_hover: true,
+            show_progress: false,
         };
         self.open(cfg)
     }
@@ -185,149 +297,201 @@ impl NotificationApi {
     }
 }
 
+/// Drop the oldest entries beyond `max_count`, returning their keys.
+fn evict_oldest(entries: &mut Vec<NotificationEntry>, max_count: Option<usize>) -> Vec<OverlayKey> {
+    let Some(max) = max_count else {
+        return Vec::new();
+    };
+    let excess = entries.len().saturating_sub(max.max(1));
+    entries.drain(..excess).map(|e| e.key).collect()
+}
+
+/// Offset and scale of a notice `depth` steps behind the newest one in a
+/// collapsed stack, or `None` when it is hidden behind the others.
+fn stack_offset(depth: usize, bottom: bool) -> Option<(i32, f32)> {
+    if depth > 2 {
+        return None;
+    }
+    let offset = depth as i32 * 8;
+    let scale = 1.0 - depth as f32 * 0.05;
+    Some((if bottom { -offset } else { offset }, scale))
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NotificationHostProps {
+    /// Collapse each placement into a stack, see
+    /// [`NotificationGlobalConfig::stack`].
+    #[props(default)]
+    pub stack: Option<NotificationStack>,
+}
+
 #[component]
-pub fn NotificationHost() -> Element {
+pub fn NotificationHost(props: NotificationHostProps) -> Element {
+    let NotificationHostProps { stack } = props;
     let entries_signal = use_notification_entries();
+    let overlay = use_context::<OverlayHandle>();
+    // Placements whose collapsed stack is expanded by hover.
+    let expanded: Signal<Vec<NotificationPlacement>> = use_signal(Vec::new);
     let entries = entries_signal.read().clone();
 
     if entries.is_empty() {
         return rsx! {};
     }
 
-    let top_right: Vec<_> = entries
-        .iter()
-        .filter(|e| e.config.placement == NotificationPlacement::TopRight)
-        .cloned()
-        .collect();
-    let bottom_right: Vec<_> = entries
-        .iter()
-        .filter(|e| e.config.placement == NotificationPlacement::BottomRight)
-        .cloned()
+    let groups: Vec<(NotificationPlacement, Vec<NotificationEntry>)> = NotificationPlacement::ALL
+        .into_iter()
+        .map(|placement| {
+            let list: Vec<_> = entries
+                .iter()
+                .filter(|e| e.config.placement == placement)
+                .cloned()
+                .collect();
+            (placement, list)
+        })
+        .filter(|(_, list)| !list.is_empty())
         .collect();
 
     rsx! {
-        // topRight container
-        if !top_right.is_empty() {
-            div {
-                class: "adui-notification-root adui-notification-top-right",
-                style: "position: fixed; top: 24px; inset-inline-end: 0; z-index: 1000; display: flex; flex-direction: column; gap: 8px; padding-inline-end: 24px;",
-                {top_right.iter().map(|entry| {
-                    let key = entry.key;
-                    let z = entry.meta.z_index;
-                    let title = entry.config.title.clone();
-                    let desc = entry.config.description.clone();
-                    let kind_class = match entry.config.r#type {
-                        NotificationType::Info => "adui-notification-info",
-                        NotificationType::Success => "adui-notification-success",
-                        NotificationType::Warning => "adui-notification-warning",
-                        NotificationType::Error => "adui-notification-error",
-                    };
-                    rsx! {
-                        div {
-                            key: "notice-{key:?}",
-                            class: "adui-notification {kind_class}",
-                            style: "pointer-events: auto; z-index: {z}; min-width: 280px; max-width: 480px; padding: 12px 16px; border-radius: 4px; background: var(--adui-color-bg-container); box-shadow: var(--adui-shadow); color: var(--adui-color-text); border: 1px solid var(--adui-color-border);",
-                            div {
-                                style: "font-weight: 500; margin-bottom: 4px;",
-                                "{title}"
-                            }
-                            if let Some(text) = desc {
-                                div { style: "font-size: 13px; color: var(--adui-color-text-secondary);", "{text}" }
-                            }
-                            button {
-                                style: "margin-left: 8px; background: none; border: none; cursor: pointer; color: var(--adui-color-text-secondary); float: right;",
-                                onclick: move |_| {
-                                    let mut entries = entries_signal;
-                                    entries.write().retain(|e| e.key != key);
-                                },
-                                "×"
-                            }
-                        }
-                    }
-                })}
+        {groups.into_iter().map(|(placement, list)| {
+            let count = list.len();
+            let collapsed = stack.is_some_and(|s| count > s.threshold)
+                && !expanded.read().contains(&placement);
+            let mut class_list = vec!["adui-notification-root", placement.as_class()];
+            if stack.is_some() {
+                class_list.push("adui-notification-stack");
             }
-        }
-
-        // bottomRight container
-        if !bottom_right.is_empty() {
-            div {
-                class: "adui-notification-root adui-notification-bottom-right",
-                style: "position: fixed; bottom: 24px; inset-inline-end: 0; z-index: 1000; display: flex; flex-direction: column; gap: 8px; padding-inline-end: 24px;",
-                {bottom_right.iter().map(|entry| {
-                    let key = entry.key;
-                    let z = entry.meta.z_index;
-                    let title = entry.config.title.clone();
-                    let desc = entry.config.description.clone();
-                    let kind_class = match entry.config.r#type {
-                        NotificationType::Info => "adui-notification-info",
-                        NotificationType::Success => "adui-notification-success",
-                        NotificationType::Warning => "adui-notification-warning",
-                        NotificationType::Error => "adui-notification-error",
-                    };
-                    rsx! {
-                        div {
-                            key: "notice-bottom-{key:?}",
-                            class: "adui-notification {kind_class}",
-                            style: "pointer-events: auto; z-index: {z}; min-width: 280px; max-width: 480px; padding: 12px 16px; border-radius: 4px; background: var(--adui-color-bg-container); box-shadow: var(--adui-shadow); color: var(--adui-color-text); border: 1px solid var(--adui-color-border);",
-                            div {
-                                style: "font-weight: 500; margin-bottom: 4px;",
-                                "{title}"
-                            }
-                            if let Some(text) = desc {
-                                div { style: "font-size: 13px; color: var(--adui-color-text-secondary);", "{text}" }
-                            }
-                            button {
-                                style: "margin-left: 8px; background: none; border: none; cursor: pointer; color: var(--adui-color-text-secondary); float: right;",
-                                onclick: move |_| {
-                                    let mut entries = entries_signal;
-                                    entries.write().retain(|e| e.key != key);
-                                },
-                                "×"
-                            }
+            if collapsed {
+                class_list.push("adui-notification-stack-collapsed");
+            }
+            let class_attr = class_list.join(" ");
+            let position = placement.as_style();
+            // Collapsed notices overlap in a single grid cell.
+            let display = if collapsed { "grid" } else { "flex" };
+            let overlay = overlay.clone();
+
+            rsx! {
+                div {
+                    key: "{placement.as_class()}",
+                    class: "{class_attr}",
+                    style: "position: fixed; {position} z-index: 1000; display: {display}; flex-direction: column; gap: 8px;",
+                    onmouseenter: move |_| {
+                        if stack.is_some() {
+                            let mut expanded = expanded;
+                            expanded.write().push(placement);
                         }
-                    }
-                })}
+                    },
+                    onmouseleave: move |_| {
+                        if stack.is_some() {
+                            let mut expanded = expanded;
+                            expanded.write().retain(|p| *p != placement);
+                        }
+                    },
+                    {list.into_iter().enumerate().map(|(index, entry)| {
+                        let stack_style = if collapsed {
+                            match stack_offset(count - 1 - index, placement.is_bottom()) {
+                                Some((offset, scale)) => format!(
+                                    "transform: translateY({offset}px) scale({scale});"
+                                ),
+                                None => "visibility: hidden;".to_string(),
+                            }
+                        } else {
+                            String::new()
+                        };
+                        render_notice(entry, entries_signal, overlay.clone(), stack_style)
+                    })}
+                }
             }
-        }
+        })}
     }
 }
 
-#[cfg(target_arch = "wasm32")]
-fn schedule_notification_dismiss(
-    key: OverlayKey,
-    entries: NotificationEntriesSignal,
+fn render_notice(
+    entry: NotificationEntry,
+    entries_signal: NotificationEntriesSignal,
     overlay: OverlayHandle,
-    duration_secs: f32,
-) {
-    use wasm_bindgen::{JsCast, closure::Closure};
-
-    if duration_secs <= 0.0 {
-        return;
-    }
-
-    if let Some(window) = web_sys::window() {
-        let delay_ms = (duration_secs * 1000.0) as i32;
-        let mut entries_signal = entries;
-        let overlay_clone = overlay.clone();
-        let callback = Closure::once(move || {
-            entries_signal.write().retain(|e| e.key != key);
-            overlay_clone.close(key);
-        });
-        let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
-            callback.as_ref().unchecked_ref(),
-            delay_ms,
-        );
-        callback.forget();
+    stack_style: String,
+) -> Element {
+    let key = entry.key;
+    let z = entry.meta.z_index;
+    let epoch = entry.timer.epoch;
+    let duration_ms = entry.timer.duration_ms;
+    let NotificationConfig {
+        title,
+        description,
+        r#type,
+        class,
+        style,
+        on_click,
+        pause_on_hover,
+        show_progress,
+        ..
+    } = entry.config;
+    let kind_class = match r#type {
+        NotificationType::Info => "adui-notification-info",
+        NotificationType::Success => "adui-notification-success",
+        NotificationType::Warning => "adui-notification-warning",
+        NotificationType::Error => "adui-notification-error",
+    };
+    let mut class_list = vec!["adui-notification".to_string(), kind_class.to_string()];
+    if pause_on_hover {
+        class_list.push("adui-notification-pause-on-hover".into());
     }
-}
+    if let Some(extra) = class {
+        class_list.push(extra);
+    }
+    let class_attr = class_list.join(" ");
+    let extra_style = style.unwrap_or_default();
+    let resume_overlay = overlay.clone();
 
-#[cfg(not(target_arch = "wasm32"))]
-fn schedule_notification_dismiss(
-    _key: OverlayKey,
-    _entries: NotificationEntriesSignal,
-    _overlay: OverlayHandle,
-    _duration_secs: f32,
-) {
+    rsx! {
+        div {
+            // A keyed update restarts the progress animation by remounting.
+            key: "notice-{key:?}-{epoch}",
+            class: "{class_attr}",
+            style: "position: relative; overflow: hidden; pointer-events: auto; z-index: {z}; min-width: 280px; max-width: 480px; padding: 12px 16px; border-radius: 4px; background: var(--adui-color-bg-container); box-shadow: var(--adui-shadow); color: var(--adui-color-text); border: 1px solid var(--adui-color-border); {stack_style} {extra_style}",
+            onmouseenter: move |_| {
+                if pause_on_hover {
+                    pause_dismiss(entries_signal, key);
+                }
+            },
+            onmouseleave: move |_| {
+                if pause_on_hover {
+                    resume_dismiss(entries_signal, resume_overlay.clone(), key);
+                }
+            },
+            onclick: move |_| {
+                if let Some(cb) = on_click {
+                    cb.call(());
+                }
+            },
+            div {
+                style: "font-weight: 500; margin-bottom: 4px;",
+                "{title}"
+            }
+            if let Some(text) = description {
+                div { style: "font-size: 13px; color: var(--adui-color-text-secondary);", "{text}" }
+            }
+            button {
+                style: "margin-left: 8px; background: none; border: none; cursor: pointer; color: var(--adui-color-text-secondary); float: right;",
+                onclick: move |evt| {
+                    evt.stop_propagation();
+                    let mut entries = entries_signal;
+                    entries.write().retain(|e| e.key != key);
+                    overlay.close(key);
+                },
+                "×"
+            }
+            if show_progress && duration_ms > 0.0 {
+                div {
+                    class: "adui-notification-progress",
+                    div {
+                        class: "adui-notification-progress-bar",
+                        style: "animation-duration: {duration_ms}ms;",
+                    }
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -428,6 +592,8 @@ mod tests {
         assert_eq!(config.style, None);
         assert_eq!(config.on_click, None);
         assert_eq!(config.key, None);
+        assert!(config.pause_on_hover);
+        assert!(!config.show_progress);
     }
 
     #[test]
@@ -443,6 +609,8 @@ mod tests {
             style: Some("color: blue;".to_string()),
             on_click: None,
             key: Some("notif-1".to_string()),
+            pause_on_hover: false,
+            show_progress: true,
         };
         let config2 = config1.clone();
         assert_eq!(config1, config2);
@@ -461,6 +629,8 @@ mod tests {
             style: None,
             on_click: None,
             key: None,
+            pause_on_hover: true,
+            show_progress: false,
         };
         let config2 = NotificationConfig {
             title: "Test".to_string(),
@@ -473,6 +643,8 @@ mod tests {
             style: None,
             on_click: None,
             key: None,
+            pause_on_hover: true,
+            show_progress: false,
         };
         let config3 = NotificationConfig {
             title: "Different".to_string(),
@@ -485,8 +657,62 @@ mod tests {
             style: None,
             on_click: None,
             key: None,
+            pause_on_hover: true,
+            show_progress: false,
         };
         assert_eq!(config1, config2);
         assert_ne!(config1, config3);
     }
+
+    fn entries(count: usize) -> Vec<NotificationEntry> {
+        let mut manager = crate::components::overlay::OverlayManager::default();
+        (0..count)
+            .map(|i| {
+                let (key, meta) = manager.open(OverlayKind::Notification, false);
+                NotificationEntry {
+                    key,
+                    meta,
+                    config: NotificationConfig {
+                        key: Some(format!("n{i}")),
+                        ..NotificationConfig::default()
+                    },
+                    timer: DismissTimer::new(4.5, 0.0),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn evict_oldest_respects_max_count() {
+        let mut list = entries(5);
+        let oldest: Vec<_> = list.iter().take(2).map(|e| e.key).collect();
+        assert_eq!(evict_oldest(&mut list, Some(3)), oldest);
+        assert_eq!(list.len(), 3);
+        assert_eq!(find_keyed(&list, Some("n2")), Some(0));
+
+        assert!(evict_oldest(&mut list, None).is_empty());
+        assert!(evict_oldest(&mut list, Some(10)).is_empty());
+        // The notification just opened is always kept.
+        assert_eq!(evict_oldest(&mut list, Some(0)).len(), 2);
+        assert_eq!(find_keyed(&list, Some("n4")), Some(0));
+    }
+
+    #[test]
+    fn stack_offsets_peek_away_from_the_edge() {
+        assert_eq!(stack_offset(0, false), Some((0, 1.0)));
+        assert_eq!(stack_offset(1, false), Some((8, 0.95)));
+        assert_eq!(stack_offset(2, true), Some((-16, 0.9)));
+        assert_eq!(stack_offset(3, false), None);
+        assert_eq!(NotificationStack::default().threshold, 3);
+    }
+
+    #[test]
+    fn notification_placement_classes() {
+        assert_eq!(
+            NotificationPlacement::BottomLeft.as_class(),
+            "adui-notification-bottom-left"
+        );
+        assert!(NotificationPlacement::BottomRight.is_bottom());
+        assert!(!NotificationPlacement::TopLeft.is_bottom());
+    }
 }
@@ -1,3 +1,4 @@
+use crate::components::locale::{format_locale_template, use_locale};
 use dioxus::prelude::*;
 
 /// Props for the Pagination component (MVP subset).
@@ -134,10 +135,17 @@ pub fn Pagination(props: PaginationProps) -> Element {
     let size_changer_options = page_size_options.unwrap_or_else(|| vec![10, 20, 50]);
     let update_for_size = update_state;
 
+    let locale = use_locale();
+    let total_text =
+        format_locale_template(&locale.pagination.total, &[("total", total.to_string())]);
+    let prev_text = locale.pagination.prev_page.clone();
+    let next_text = locale.pagination.next_page.clone();
+    let items_per_page = locale.pagination.items_per_page.clone();
+
     rsx! {
         div { class: "{class_attr}", style: "{style_attr}",
             if show_total {
-                span { class: "adui-pagination-total", "共 {total} 条" }
+                span { class: "adui-pagination-total", "{total_text}" }
             }
 
             // Page items
@@ -158,7 +166,7 @@ pub fn Pagination(props: PaginationProps) -> Element {
                                     update(current_value.saturating_sub(1), page_size_value, false);
                                 }
                             },
-                            "上一页"
+                            "{prev_text}"
                         }
                     )
                 }
@@ -203,7 +211,7 @@ pub fn Pagination(props: PaginationProps) -> Element {
                                     update(current_value + 1, page_size_value, false);
                                 }
                             },
-                            "下一页"
+                            "{next_text}"
                         }
                     )
                 }
@@ -222,7 +230,7 @@ pub fn Pagination(props: PaginationProps) -> Element {
                         rsx! {
                             option {
                                 value: format!("{opt}"),
-                                "{opt} / 页"
+                                {format_locale_template(&items_per_page, &[("size", opt.to_string())])}
                             }
                         }
                     })}
@@ -1,4 +1,5 @@
 use crate::components::button::{Button, ButtonType};
+use crate::components::locale::use_locale;
 use crate::components::popover::Popover;
 use crate::components::tooltip::{TooltipPlacement, TooltipTrigger, update_open_state};
 use dioxus::prelude::*;
@@ -94,8 +95,9 @@ pub fn Popconfirm(props: PopconfirmProps) -> Element {
     let open_for_handlers = open_state;
     let on_open_change_cb = on_open_change;
 
-    let ok_label = ok_text.unwrap_or_else(|| "确定".to_string());
-    let cancel_label = cancel_text.unwrap_or_else(|| "取消".to_string());
+    let locale = use_locale();
+    let ok_label = ok_text.unwrap_or_else(|| locale.popconfirm.ok_text.clone());
+    let cancel_label = cancel_text.unwrap_or_else(|| locale.popconfirm.cancel_text.clone());
     let ok_button_type = ok_type.unwrap_or(ButtonType::Primary);
     let ok_danger_flag = ok_danger;
 
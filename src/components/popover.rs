@@ -1,4 +1,5 @@
 use crate::components::floating::use_floating_close_handle;
+use crate::components::floating_position::{FloatingPlacement, PositionOptions};
 use crate::components::overlay::OverlayKind;
 use crate::components::select_base::use_floating_layer;
 use crate::components::tooltip::{TooltipPlacement, TooltipTrigger};
@@ -74,6 +75,10 @@ pub fn Popover(props: PopoverProps) -> Element {
     // Register with the overlay manager to obtain a z-index slot.
     let floating = use_floating_layer(OverlayKind::Popup, current_open);
     let current_z = *floating.z_index.read();
+    let positioner = floating.position;
+    let measured = positioner.position();
+    let anchor_id = positioner.anchor_id();
+    let overlay_id = positioner.overlay_id();
 
     // Click-outside + Esc close for uncontrolled click-triggered popovers.
     let close_handle = if !is_controlled && matches!(trigger, TooltipTrigger::Click) {
@@ -98,15 +103,28 @@ pub fn Popover(props: PopoverProps) -> Element {
         list.join(" ")
     };
 
+    let effective_placement: FloatingPlacement = measured
+        .map(|pos| pos.placement)
+        .unwrap_or_else(|| placement.into());
+
     let overlay_class_attr = {
-        let mut list = vec!["adui-popover".to_string()];
+        let mut list = vec![
+            "adui-popover".to_string(),
+            format!(
+                "adui-popover-placement-{}",
+                effective_placement.side.as_str()
+            ),
+        ];
         if let Some(extra) = overlay_class {
             list.push(extra);
         }
         list.join(" ")
     };
 
-    let overlay_style_attr = {
+    let overlay_style_attr = if let Some(pos) = measured {
+        let extra = overlay_style.unwrap_or_default();
+        format!("{} z-index: {}; {}", pos.to_style(), current_z, extra)
+    } else {
         let placement_css = match placement {
             TooltipPlacement::Top => {
                 "bottom: 100%; left: 50%; transform: translateX(-50%); margin-bottom: 8px;"
@@ -130,6 +148,7 @@ pub fn Popover(props: PopoverProps) -> Element {
 
     rsx! {
         span {
+            id: "{anchor_id}",
             class: "{class_attr}",
             style: "position: relative; display: inline-block;",
             onmouseenter: move |_evt: MouseEvent| {
@@ -172,9 +191,13 @@ pub fn Popover(props: PopoverProps) -> Element {
             {children}
             if current_open {
                 div {
+                    id: "{overlay_id}",
                     class: "{overlay_class_attr}",
                     style: "{overlay_style_attr}",
                     tabindex: 0,
+                    onmounted: move |_| {
+                        positioner.update(placement.into(), PositionOptions::default());
+                    },
                     onkeydown: move |evt: KeyboardEvent| {
                         if matches!(evt.key(), Key::Escape) {
                             evt.prevent_default();
@@ -2,6 +2,7 @@
 //!
 //! Ported from Ant Design 6.x QRCode component.
 
+use crate::components::locale::use_locale;
 use dioxus::prelude::*;
 
 /// QR code rendering type.
@@ -143,12 +144,19 @@ pub fn QRCode(props: QRCodeProps) -> Element {
         root_class,
         style,
     } = props;
+    let locale = use_locale();
 
     // Return empty if no value provided
     if value.is_empty() {
         return rsx! {};
     }
 
+    // Generate QR code data; content beyond version 40 capacity renders nothing.
+    let Some(qr_modules) = generate_qr_modules(&value, error_level) else {
+        return rsx! {};
+    };
+    let module_count = qr_modules.len();
+
     let fg_color = color.unwrap_or_else(|| "currentColor".to_string());
     let bg_color = bg_color.unwrap_or_else(|| "transparent".to_string());
     let icon_size = icon_size.unwrap_or(40);
@@ -178,10 +186,6 @@ pub fn QRCode(props: QRCodeProps) -> Element {
         style.unwrap_or_default()
     );
 
-    // Generate QR code data
-    let qr_modules = generate_qr_modules(&value, error_level);
-    let module_count = qr_modules.len();
-
     // Render based on type
     let qr_content = match r#type {
         QRCodeType::Svg => {
@@ -201,7 +205,7 @@ pub fn QRCode(props: QRCodeProps) -> Element {
             Some(rsx! {
                 div { class: "adui-qrcode-cover",
                     div { class: "adui-qrcode-status",
-                        span { class: "adui-qrcode-status-text", "QR code expired" }
+                        span { class: "adui-qrcode-status-text", "{locale.qrcode.expired}" }
                         button {
                             class: "adui-qrcode-refresh-btn",
                             onclick: move |_| {
@@ -209,7 +213,7 @@ pub fn QRCode(props: QRCodeProps) -> Element {
                                     cb.call(());
                                 }
                             },
-                            "Refresh"
+                            "{locale.qrcode.refresh}"
                         }
                     }
                 }
@@ -219,7 +223,7 @@ pub fn QRCode(props: QRCodeProps) -> Element {
             div { class: "adui-qrcode-cover",
                 div { class: "adui-qrcode-status",
                     div { class: "adui-qrcode-spinner" }
-                    span { class: "adui-qrcode-status-text", "Loading..." }
+                    span { class: "adui-qrcode-status-text", "{locale.global.loading}" }
                 }
             }
         }),
@@ -227,7 +231,7 @@ pub fn QRCode(props: QRCodeProps) -> Element {
             div { class: "adui-qrcode-cover",
                 div { class: "adui-qrcode-status",
                     span { class: "adui-qrcode-status-icon", "✓" }
-                    span { class: "adui-qrcode-status-text", "Scanned" }
+                    span { class: "adui-qrcode-status-text", "{locale.qrcode.scanned}" }
                 }
             }
         }),
@@ -246,151 +250,662 @@ pub fn QRCode(props: QRCodeProps) -> Element {
     }
 }
 
-/// Generate QR code modules (simplified version).
-/// For a production implementation, use a proper QR code library.
-fn generate_qr_modules(value: &str, error_level: QRCodeErrorLevel) -> Vec<Vec<bool>> {
-    // This is a simplified QR code generation that creates a deterministic pattern
-    // based on the input. For production use, integrate a proper QR library like `qrcode`.
+/// Encode `value` into a QR code module matrix (ISO/IEC 18004).
+///
+/// The smallest version (1-40) that fits the content at the requested error
+/// correction level is selected, the most compact mode (numeric,
+/// alphanumeric or byte) is used for the whole string, and the mask with the
+/// lowest penalty score is applied. Returns `None` when the content does not
+/// fit into a version 40 symbol.
+fn generate_qr_modules(value: &str, error_level: QRCodeErrorLevel) -> Option<Vec<Vec<bool>>> {
+    let segment = QrSegment::from_text(value);
+    let version = calculate_version(&segment, error_level)?;
+    let data = build_data_codewords(&segment, version, error_level);
+    let codewords = add_ecc_and_interleave(&data, version, error_level);
+
+    let mut matrix = QrMatrix::new(version);
+    matrix.draw_function_patterns();
+    matrix.draw_codewords(&codewords);
+
+    let mut best_mask = 0;
+    let mut min_penalty = i32::MAX;
+    for mask in 0..8 {
+        matrix.apply_mask(mask);
+        matrix.draw_format_bits(error_level, mask);
+        let penalty = matrix.penalty_score();
+        if penalty < min_penalty {
+            best_mask = mask;
+            min_penalty = penalty;
+        }
+        // XOR masking is its own inverse.
+        matrix.apply_mask(mask);
+    }
+    matrix.apply_mask(best_mask);
+    matrix.draw_format_bits(error_level, best_mask);
+
+    Some(matrix.modules)
+}
 
-    // Calculate version based on content length and error level
-    let version = calculate_version(value.len(), error_level);
-    let module_count = 21 + (version - 1) * 4;
+const MIN_VERSION: usize = 1;
+const MAX_VERSION: usize = 40;
 
-    let mut modules = vec![vec![false; module_count]; module_count];
+const ALPHANUMERIC_CHARSET: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
 
-    // Add finder patterns (the three large squares in corners)
-    add_finder_pattern(&mut modules, 0, 0);
-    add_finder_pattern(&mut modules, module_count - 7, 0);
-    add_finder_pattern(&mut modules, 0, module_count - 7);
+/// Error correction codewords per block, indexed by `[level][version]`.
+const ECC_CODEWORDS_PER_BLOCK: [[i8; 41]; 4] = [
+    [
+        -1, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28,
+        30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        -1, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ],
+    [
+        -1, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30,
+        30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        -1, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+];
 
-    // Add timing patterns
-    add_timing_patterns(&mut modules, module_count);
+/// Number of error correction blocks, indexed by `[level][version]`.
+const NUM_ERROR_CORRECTION_BLOCKS: [[i8; 41]; 4] = [
+    [
+        -1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12,
+        13, 14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ],
+    [
+        -1, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21,
+        23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ],
+    [
+        -1, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27,
+        29, 34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ],
+    [
+        -1, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32,
+        35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ],
+];
 
-    // Add alignment pattern for version > 1
-    if version > 1 {
-        add_alignment_pattern(&mut modules, module_count);
+impl QRCodeErrorLevel {
+    /// Row index into the ECC lookup tables.
+    fn table_index(self) -> usize {
+        match self {
+            QRCodeErrorLevel::L => 0,
+            QRCodeErrorLevel::M => 1,
+            QRCodeErrorLevel::Q => 2,
+            QRCodeErrorLevel::H => 3,
+        }
     }
 
-    // Fill data area with pattern derived from value
-    fill_data_pattern(&mut modules, value, module_count);
+    /// Two-bit indicator stored in the format information.
+    fn format_bits(self) -> u32 {
+        match self {
+            QRCodeErrorLevel::L => 1,
+            QRCodeErrorLevel::M => 0,
+            QRCodeErrorLevel::Q => 3,
+            QRCodeErrorLevel::H => 2,
+        }
+    }
+}
 
-    modules
+/// Data encoding mode of a segment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum QrMode {
+    Numeric,
+    Alphanumeric,
+    Byte,
 }
 
-/// Calculate QR code version based on content length.
-fn calculate_version(content_len: usize, _error_level: QRCodeErrorLevel) -> usize {
-    // Simplified version calculation
-    if content_len <= 17 {
-        1
-    } else if content_len <= 32 {
-        2
-    } else if content_len <= 53 {
-        3
-    } else if content_len <= 78 {
-        4
-    } else if content_len <= 106 {
-        5
-    } else {
-        6.min((content_len / 20).max(1))
+impl QrMode {
+    fn indicator(self) -> u32 {
+        match self {
+            QrMode::Numeric => 0x1,
+            QrMode::Alphanumeric => 0x2,
+            QrMode::Byte => 0x4,
+        }
+    }
+
+    /// Width of the character count field for the given version.
+    fn char_count_bits(self, version: usize) -> usize {
+        let idx = (version + 7) / 17;
+        match self {
+            QrMode::Numeric => [10, 12, 14][idx],
+            QrMode::Alphanumeric => [9, 11, 13][idx],
+            QrMode::Byte => [8, 16, 16][idx],
+        }
     }
 }
 
-/// Add finder pattern at specified position.
-fn add_finder_pattern(modules: &mut [Vec<bool>], row: usize, col: usize) {
-    for r in 0..7 {
-        for c in 0..7 {
-            let is_border = r == 0 || r == 6 || c == 0 || c == 6;
-            let is_inner = (2..=4).contains(&r) && (2..=4).contains(&c);
-            if row + r < modules.len() && col + c < modules[0].len() {
-                modules[row + r][col + c] = is_border || is_inner;
+/// A single encoded data segment (mode + character count + payload bits).
+#[derive(Clone, Debug)]
+struct QrSegment {
+    mode: QrMode,
+    char_count: usize,
+    bits: Vec<bool>,
+}
+
+impl QrSegment {
+    /// Pick the most compact mode able to represent the whole string.
+    fn from_text(text: &str) -> Self {
+        if !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit()) {
+            Self::numeric(text)
+        } else if !text.is_empty() && text.chars().all(|c| ALPHANUMERIC_CHARSET.contains(c)) {
+            Self::alphanumeric(text)
+        } else {
+            Self::bytes(text.as_bytes())
+        }
+    }
+
+    fn numeric(text: &str) -> Self {
+        let mut bits = Vec::new();
+        for chunk in text.as_bytes().chunks(3) {
+            let value = chunk
+                .iter()
+                .fold(0u32, |acc, b| acc * 10 + u32::from(b - b'0'));
+            append_bits(&mut bits, value, chunk.len() * 3 + 1);
+        }
+        Self {
+            mode: QrMode::Numeric,
+            char_count: text.len(),
+            bits,
+        }
+    }
+
+    fn alphanumeric(text: &str) -> Self {
+        let indices: Vec<u32> = text
+            .chars()
+            .filter_map(|c| ALPHANUMERIC_CHARSET.find(c).map(|i| i as u32))
+            .collect();
+        let mut bits = Vec::new();
+        for pair in indices.chunks(2) {
+            match pair {
+                [a, b] => append_bits(&mut bits, a * 45 + b, 11),
+                [a] => append_bits(&mut bits, *a, 6),
+                _ => unreachable!(),
             }
         }
+        Self {
+            mode: QrMode::Alphanumeric,
+            char_count: indices.len(),
+            bits,
+        }
+    }
+
+    fn bytes(data: &[u8]) -> Self {
+        let mut bits = Vec::with_capacity(data.len() * 8);
+        for &b in data {
+            append_bits(&mut bits, u32::from(b), 8);
+        }
+        Self {
+            mode: QrMode::Byte,
+            char_count: data.len(),
+            bits,
+        }
     }
 
-    // Add separator (white border around finder)
-    for i in 0..8 {
-        if row + 7 < modules.len() && col + i < modules[0].len() {
-            modules[row + 7][col + i] = false;
+    /// Total bit length of this segment at the given version, or `None` when
+    /// the character count does not fit into the count field.
+    fn total_bits(&self, version: usize) -> Option<usize> {
+        let count_bits = self.mode.char_count_bits(version);
+        if self.char_count >= 1 << count_bits {
+            return None;
         }
-        if row + i < modules.len() && col + 7 < modules[0].len() {
-            modules[row + i][col + 7] = false;
+        Some(4 + count_bits + self.bits.len())
+    }
+}
+
+/// Append the lowest `len` bits of `value`, most significant first.
+fn append_bits(bits: &mut Vec<bool>, value: u32, len: usize) {
+    for i in (0..len).rev() {
+        bits.push((value >> i) & 1 != 0);
+    }
+}
+
+/// Number of modules available for data and ECC codewords (excluding
+/// function patterns and format/version information). May include
+/// remainder bits, so it is not always a multiple of 8.
+fn num_raw_data_modules(version: usize) -> usize {
+    let mut result = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let num_align = version / 7 + 2;
+        result -= (25 * num_align - 10) * num_align - 55;
+        if version >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+/// Number of 8-bit data codewords for the given version and level.
+fn num_data_codewords(version: usize, error_level: QRCodeErrorLevel) -> usize {
+    let idx = error_level.table_index();
+    num_raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[idx][version] as usize
+            * NUM_ERROR_CORRECTION_BLOCKS[idx][version] as usize
+}
+
+/// Smallest version whose data capacity fits the segment.
+fn calculate_version(segment: &QrSegment, error_level: QRCodeErrorLevel) -> Option<usize> {
+    (MIN_VERSION..=MAX_VERSION).find(|&version| {
+        segment
+            .total_bits(version)
+            .is_some_and(|used| used <= num_data_codewords(version, error_level) * 8)
+    })
+}
+
+/// Build the data codeword sequence: header, payload, terminator and padding.
+fn build_data_codewords(
+    segment: &QrSegment,
+    version: usize,
+    error_level: QRCodeErrorLevel,
+) -> Vec<u8> {
+    let capacity = num_data_codewords(version, error_level) * 8;
+    let mut bits = Vec::with_capacity(capacity);
+    append_bits(&mut bits, segment.mode.indicator(), 4);
+    append_bits(
+        &mut bits,
+        segment.char_count as u32,
+        segment.mode.char_count_bits(version),
+    );
+    bits.extend_from_slice(&segment.bits);
+
+    let terminator = (capacity - bits.len()).min(4);
+    append_bits(&mut bits, 0, terminator);
+    let byte_padding = (8 - bits.len() % 8) % 8;
+    append_bits(&mut bits, 0, byte_padding);
+
+    let mut codewords: Vec<u8> = bits
+        .chunks(8)
+        .map(|byte| byte.iter().fold(0u8, |acc, &bit| (acc << 1) | bit as u8))
+        .collect();
+    for pad in [0xEC, 0x11].into_iter().cycle() {
+        if codewords.len() * 8 >= capacity {
+            break;
         }
+        codewords.push(pad);
     }
+    codewords
 }
 
-/// Add timing patterns.
-fn add_timing_patterns(modules: &mut [Vec<bool>], size: usize) {
-    for i in 8..size - 8 {
-        let pattern = i % 2 == 0;
-        modules[6][i] = pattern;
-        modules[i][6] = pattern;
+/// Split data into blocks, append Reed-Solomon ECC to each block and
+/// interleave the result into the final codeword sequence.
+fn add_ecc_and_interleave(data: &[u8], version: usize, error_level: QRCodeErrorLevel) -> Vec<u8> {
+    let idx = error_level.table_index();
+    let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[idx][version] as usize;
+    let block_ecc_len = ECC_CODEWORDS_PER_BLOCK[idx][version] as usize;
+    let raw_codewords = num_raw_data_modules(version) / 8;
+    let num_short_blocks = num_blocks - raw_codewords % num_blocks;
+    let short_block_len = raw_codewords / num_blocks;
+
+    let divisor = reed_solomon_divisor(block_ecc_len);
+    let mut blocks: Vec<Vec<u8>> = Vec::with_capacity(num_blocks);
+    let mut offset = 0;
+    for i in 0..num_blocks {
+        let data_len = short_block_len - block_ecc_len + usize::from(i >= num_short_blocks);
+        let chunk = &data[offset..offset + data_len];
+        offset += data_len;
+        let ecc = reed_solomon_remainder(chunk, &divisor);
+        let mut block = chunk.to_vec();
+        if i < num_short_blocks {
+            // Placeholder so all blocks share the same length while interleaving.
+            block.push(0);
+        }
+        block.extend(ecc);
+        blocks.push(block);
     }
+
+    let mut result = Vec::with_capacity(raw_codewords);
+    for i in 0..blocks[0].len() {
+        for (j, block) in blocks.iter().enumerate() {
+            if i != short_block_len - block_ecc_len || j >= num_short_blocks {
+                result.push(block[i]);
+            }
+        }
+    }
+    result
 }
 
-/// Add alignment pattern.
-fn add_alignment_pattern(modules: &mut [Vec<bool>], size: usize) {
-    let center = size - 7;
-    for r in 0..5 {
-        for c in 0..5 {
-            let is_border = r == 0 || r == 4 || c == 0 || c == 4;
-            let is_center = r == 2 && c == 2;
-            let row = center - 2 + r;
-            let col = center - 2 + c;
-            if row < size && col < size {
-                modules[row][col] = is_border || is_center;
+/// Generator polynomial coefficients (highest degree first, leading 1 omitted).
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0u8; degree];
+    result[degree - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_multiply(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
             }
         }
+        root = gf_multiply(root, 0x02);
+    }
+    result
+}
+
+/// Remainder of `data` divided by the generator polynomial.
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0u8; divisor.len()];
+    for &b in data {
+        let factor = b ^ result.remove(0);
+        result.push(0);
+        for (r, &d) in result.iter_mut().zip(divisor) {
+            *r ^= gf_multiply(d, factor);
+        }
     }
+    result
+}
+
+/// Multiplication in GF(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1.
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= ((u32::from(y) >> i) & 1) * u32::from(x);
+    }
+    z as u8
+}
+
+/// Module grid under construction, tracking which cells belong to function
+/// patterns so that data placement and masking skip them.
+struct QrMatrix {
+    version: usize,
+    size: usize,
+    modules: Vec<Vec<bool>>,
+    is_function: Vec<Vec<bool>>,
 }
 
-/// Fill data area with pattern derived from value.
-fn fill_data_pattern(modules: &mut [Vec<bool>], value: &str, size: usize) {
-    // Simple hash-based pattern generation
-    let hash = simple_hash(value);
-    let mut seed = hash;
+impl QrMatrix {
+    fn new(version: usize) -> Self {
+        let size = version * 4 + 17;
+        Self {
+            version,
+            size,
+            modules: vec![vec![false; size]; size],
+            is_function: vec![vec![false; size]; size],
+        }
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y][x] = dark;
+        self.is_function[y][x] = true;
+    }
+
+    fn draw_function_patterns(&mut self) {
+        // Timing patterns
+        for i in 0..self.size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        // Finder patterns with separators
+        let far = self.size - 4;
+        self.draw_finder_pattern(3, 3);
+        self.draw_finder_pattern(far, 3);
+        self.draw_finder_pattern(3, far);
+
+        // Alignment patterns, skipping the three finder corners
+        let positions = alignment_pattern_positions(self.version);
+        let n = positions.len();
+        for i in 0..n {
+            for j in 0..n {
+                let overlaps_finder = (i == 0 && (j == 0 || j == n - 1)) || (i == n - 1 && j == 0);
+                if !overlaps_finder {
+                    self.draw_alignment_pattern(positions[i], positions[j]);
+                }
+            }
+        }
+
+        // Reserve format areas; real bits are drawn after masking.
+        self.draw_format_bits(QRCodeErrorLevel::M, 0);
+        self.draw_version();
+    }
+
+    /// Draw a 9x9 finder pattern (including separator) centered at (x, y).
+    fn draw_finder_pattern(&mut self, x: usize, y: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let xx = x as i32 + dx;
+                let yy = y as i32 + dy;
+                if (0..self.size as i32).contains(&xx) && (0..self.size as i32).contains(&yy) {
+                    let dist = dx.abs().max(dy.abs());
+                    self.set_function(xx as usize, yy as usize, dist != 2 && dist != 4);
+                }
+            }
+        }
+    }
+
+    /// Draw a 5x5 alignment pattern centered at (x, y).
+    fn draw_alignment_pattern(&mut self, x: usize, y: usize) {
+        for dy in -2i32..=2 {
+            for dx in -2i32..=2 {
+                let dark = dx.abs().max(dy.abs()) != 1;
+                self.set_function((x as i32 + dx) as usize, (y as i32 + dy) as usize, dark);
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, error_level: QRCodeErrorLevel, mask: u8) {
+        let bits = format_bits(error_level, mask);
+        let bit = |i: usize| (bits >> i) & 1 != 0;
+        let size = self.size;
+
+        // First copy, around the top-left finder
+        for i in 0..=5 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        // Second copy, split between the other two finders
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        // Always-dark module
+        self.set_function(8, size - 8, true);
+    }
+
+    fn draw_version(&mut self) {
+        if self.version < 7 {
+            return;
+        }
+        let bits = version_bits(self.version);
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 != 0;
+            let a = self.size - 11 + i % 3;
+            let b = i / 3;
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    /// Place codewords in the zig-zag order defined by the standard.
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let total_bits = codewords.len() * 8;
+        let mut index = 0;
+        let mut right = self.size as i32 - 1;
+        while right >= 1 {
+            if right == 6 {
+                // Skip the vertical timing column.
+                right = 5;
+            }
+            for vert in 0..self.size {
+                for j in 0..2 {
+                    let x = (right - j) as usize;
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward { self.size - 1 - vert } else { vert };
+                    if !self.is_function[y][x] && index < total_bits {
+                        self.modules[y][x] = (codewords[index >> 3] >> (7 - (index & 7))) & 1 != 0;
+                        index += 1;
+                    }
+                    // Remaining remainder bits stay light.
+                }
+            }
+            right -= 2;
+        }
+    }
+
+    fn apply_mask(&mut self, mask: u8) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if !self.is_function[y][x] && mask_applies(mask, x, y) {
+                    self.modules[y][x] = !self.modules[y][x];
+                }
+            }
+        }
+    }
+
+    /// Penalty score used to pick the mask (rules N1-N4).
+    fn penalty_score(&self) -> i32 {
+        const N1: i32 = 3;
+        const N2: i32 = 3;
+        const N3: i32 = 40;
+        const N4: i32 = 10;
+
+        let size = self.size as i32;
+        let mut result = 0;
 
-    for row in 0..size {
-        for col in 0..size {
-            // Skip finder patterns and timing patterns
-            if is_function_module(row, col, size) {
-                continue;
+        for horizontal in [true, false] {
+            for a in 0..self.size {
+                let mut run_color = false;
+                let mut run_len = 0;
+                let mut history = [0i32; 7];
+                for b in 0..self.size {
+                    let dark = if horizontal {
+                        self.modules[a][b]
+                    } else {
+                        self.modules[b][a]
+                    };
+                    if dark == run_color {
+                        run_len += 1;
+                        if run_len == 5 {
+                            result += N1;
+                        } else if run_len > 5 {
+                            result += 1;
+                        }
+                    } else {
+                        finder_penalty_add_history(run_len, &mut history, size);
+                        if !run_color {
+                            result += finder_penalty_count_patterns(&history) * N3;
+                        }
+                        run_color = dark;
+                        run_len = 1;
+                    }
+                }
+                result +=
+                    finder_penalty_terminate_and_count(run_color, run_len, &mut history, size) * N3;
             }
+        }
 
-            // Generate pseudo-random pattern based on position and hash
-            seed = (seed.wrapping_mul(1103515245).wrapping_add(12345)) & 0x7fffffff;
-            modules[row][col] = (seed % 3) != 0;
+        for y in 0..self.size - 1 {
+            for x in 0..self.size - 1 {
+                let color = self.modules[y][x];
+                if color == self.modules[y][x + 1]
+                    && color == self.modules[y + 1][x]
+                    && color == self.modules[y + 1][x + 1]
+                {
+                    result += N2;
+                }
+            }
         }
+
+        let dark = self.modules.iter().flatten().filter(|&&dark| dark).count() as i32;
+        let total = size * size;
+        let k = ((dark * 20 - total * 10).abs() + total - 1) / total - 1;
+        result + k * N4
     }
 }
 
-/// Check if position is a function module (finder, timing, etc.).
-fn is_function_module(row: usize, col: usize, size: usize) -> bool {
-    // Top-left finder
-    if row < 9 && col < 9 {
-        return true;
+/// Whether mask pattern `mask` inverts the module at (x, y).
+fn mask_applies(mask: u8, x: usize, y: usize) -> bool {
+    match mask {
+        0 => (x + y).is_multiple_of(2),
+        1 => y.is_multiple_of(2),
+        2 => x.is_multiple_of(3),
+        3 => (x + y).is_multiple_of(3),
+        4 => (x / 3 + y / 2).is_multiple_of(2),
+        5 => x * y % 2 + x * y % 3 == 0,
+        6 => (x * y % 2 + x * y % 3).is_multiple_of(2),
+        7 => ((x + y) % 2 + x * y % 3).is_multiple_of(2),
+        _ => unreachable!("mask pattern must be in 0..8"),
     }
-    // Top-right finder
-    if row < 9 && col >= size - 8 {
-        return true;
+}
+
+/// 15-bit BCH-protected format information (level + mask), already XOR-masked.
+fn format_bits(error_level: QRCodeErrorLevel, mask: u8) -> u32 {
+    let data = (error_level.format_bits() << 3) | u32::from(mask);
+    let mut rem = data;
+    for _ in 0..10 {
+        rem = (rem << 1) ^ ((rem >> 9) * 0x537);
     }
-    // Bottom-left finder
-    if row >= size - 8 && col < 9 {
-        return true;
+    ((data << 10) | rem) ^ 0x5412
+}
+
+/// 18-bit BCH-protected version information (versions 7 and up).
+fn version_bits(version: usize) -> u32 {
+    let mut rem = version as u32;
+    for _ in 0..12 {
+        rem = (rem << 1) ^ ((rem >> 11) * 0x1F25);
     }
-    // Timing patterns
-    if row == 6 || col == 6 {
-        return true;
+    ((version as u32) << 12) | rem
+}
+
+/// Center coordinates of alignment patterns, ascending.
+fn alignment_pattern_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
     }
-    false
+    let num_align = version / 7 + 2;
+    let step = (version * 8 + num_align * 3 + 5) / (num_align * 4 - 4) * 2;
+    let size = version * 4 + 17;
+    let mut result: Vec<usize> = (0..num_align - 1).map(|i| size - 7 - i * step).collect();
+    result.push(6);
+    result.reverse();
+    result
+}
+
+fn finder_penalty_count_patterns(history: &[i32; 7]) -> i32 {
+    let n = history[1];
+    let core =
+        n > 0 && history[2] == n && history[3] == n * 3 && history[4] == n && history[5] == n;
+    i32::from(core && history[0] >= n * 4 && history[6] >= n)
+        + i32::from(core && history[6] >= n * 4 && history[0] >= n)
 }
 
-/// Simple hash function for generating deterministic patterns.
-fn simple_hash(s: &str) -> u32 {
-    let mut hash: u32 = 5381;
-    for byte in s.bytes() {
-        hash = hash.wrapping_mul(33).wrapping_add(byte as u32);
+fn finder_penalty_terminate_and_count(
+    run_color: bool,
+    mut run_len: i32,
+    history: &mut [i32; 7],
+    size: i32,
+) -> i32 {
+    if run_color {
+        finder_penalty_add_history(run_len, history, size);
+        run_len = 0;
     }
-    hash
+    // Treat the quiet zone past the edge as a light run.
+    run_len += size;
+    finder_penalty_add_history(run_len, history, size);
+    finder_penalty_count_patterns(history)
+}
+
+fn finder_penalty_add_history(mut run_len: i32, history: &mut [i32; 7], size: i32) {
+    if history[0] == 0 {
+        // Account for the light quiet zone before the first run.
+        run_len += size;
+    }
+    history.copy_within(0..6, 1);
+    history[0] = run_len;
 }
 
 /// Render QR code as SVG.
@@ -463,6 +978,174 @@ fn render_svg_qrcode(
 mod tests {
     use super::*;
 
+    const ALL_LEVELS: [QRCodeErrorLevel; 4] = [
+        QRCodeErrorLevel::L,
+        QRCodeErrorLevel::M,
+        QRCodeErrorLevel::Q,
+        QRCodeErrorLevel::H,
+    ];
+
+    /// Minimal reader used to round-trip encoded symbols: it recovers the
+    /// format information, unmasks the data area, verifies every
+    /// Reed-Solomon block through its syndromes and decodes the payload.
+    fn decode(modules: &[Vec<bool>]) -> (QRCodeErrorLevel, String) {
+        let size = modules.len();
+        assert_eq!((size - 17) % 4, 0, "invalid symbol size {size}");
+        let version = (size - 17) / 4;
+        let get = |x: usize, y: usize| modules[y][x];
+
+        let mut first = 0u32;
+        let mut second = 0u32;
+        for i in 0..15 {
+            let (x1, y1) = match i {
+                0..=5 => (8, i),
+                6 => (8, 7),
+                7 => (8, 8),
+                8 => (7, 8),
+                _ => (14 - i, 8),
+            };
+            let (x2, y2) = if i < 8 {
+                (size - 1 - i, 8)
+            } else {
+                (8, size - 15 + i)
+            };
+            first |= u32::from(get(x1, y1)) << i;
+            second |= u32::from(get(x2, y2)) << i;
+        }
+        assert_eq!(first, second, "format information copies differ");
+        assert!(get(8, size - 8), "dark module missing");
+
+        let (level, mask) = ALL_LEVELS
+            .iter()
+            .flat_map(|&level| (0..8).map(move |mask| (level, mask)))
+            .find(|&(level, mask)| format_bits(level, mask) == first)
+            .expect("unknown format information");
+
+        let mut template = QrMatrix::new(version);
+        template.draw_function_patterns();
+
+        // Read data bits back in placement order.
+        let mut bits = Vec::new();
+        let mut right = size as i32 - 1;
+        while right >= 1 {
+            if right == 6 {
+                right = 5;
+            }
+            for vert in 0..size {
+                for j in 0..2 {
+                    let x = (right - j) as usize;
+                    let y = if (right + 1) & 2 == 0 {
+                        size - 1 - vert
+                    } else {
+                        vert
+                    };
+                    if !template.is_function[y][x] {
+                        bits.push(get(x, y) ^ mask_applies(mask, x, y));
+                    }
+                }
+            }
+            right -= 2;
+        }
+        let raw_codewords = num_raw_data_modules(version) / 8;
+        let codewords: Vec<u8> = bits[..raw_codewords * 8]
+            .chunks(8)
+            .map(|byte| byte.iter().fold(0u8, |acc, &bit| (acc << 1) | bit as u8))
+            .collect();
+
+        // De-interleave into blocks.
+        let idx = level.table_index();
+        let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[idx][version] as usize;
+        let ecc_len = ECC_CODEWORDS_PER_BLOCK[idx][version] as usize;
+        let short_data_len = raw_codewords / num_blocks - ecc_len;
+        let num_short_blocks = num_blocks - raw_codewords % num_blocks;
+        let data_lens: Vec<usize> = (0..num_blocks)
+            .map(|i| short_data_len + usize::from(i >= num_short_blocks))
+            .collect();
+        let mut blocks = vec![Vec::new(); num_blocks];
+        let mut iter = codewords.iter().copied();
+        for i in 0..=short_data_len {
+            for (block, &len) in blocks.iter_mut().zip(&data_lens) {
+                if i < len {
+                    block.push(iter.next().unwrap());
+                }
+            }
+        }
+        for _ in 0..ecc_len {
+            for block in blocks.iter_mut() {
+                block.push(iter.next().unwrap());
+            }
+        }
+        assert!(iter.next().is_none());
+
+        // Every syndrome must vanish for an error-free codeword.
+        let mut data = Vec::new();
+        for (block, &len) in blocks.iter().zip(&data_lens) {
+            let mut root = 1u8;
+            for _ in 0..ecc_len {
+                let syndrome = block.iter().fold(0u8, |acc, &c| gf_multiply(acc, root) ^ c);
+                assert_eq!(syndrome, 0, "non-zero syndrome");
+                root = gf_multiply(root, 0x02);
+            }
+            data.extend_from_slice(&block[..len]);
+        }
+
+        // Decode the single segment.
+        let bits: Vec<bool> = data
+            .iter()
+            .flat_map(|&b| (0..8).rev().map(move |i| (b >> i) & 1 != 0))
+            .collect();
+        let mut pos = 0;
+        let mut read = |len: usize| {
+            let value = bits[pos..pos + len]
+                .iter()
+                .fold(0u32, |acc, &bit| (acc << 1) | u32::from(bit));
+            pos += len;
+            value
+        };
+        let mode = match read(4) {
+            0x1 => QrMode::Numeric,
+            0x2 => QrMode::Alphanumeric,
+            0x4 => QrMode::Byte,
+            other => panic!("unexpected mode indicator {other}"),
+        };
+        let count = read(mode.char_count_bits(version)) as usize;
+        let alphabet: Vec<char> = ALPHANUMERIC_CHARSET.chars().collect();
+        let text = match mode {
+            QrMode::Numeric => {
+                let mut out = String::new();
+                let mut remaining = count;
+                while remaining > 0 {
+                    let digits = remaining.min(3);
+                    let value = read(digits * 3 + 1);
+                    out.push_str(&format!("{value:0digits$}"));
+                    remaining -= digits;
+                }
+                out
+            }
+            QrMode::Alphanumeric => {
+                let mut out = String::new();
+                let mut remaining = count;
+                while remaining > 0 {
+                    if remaining >= 2 {
+                        let value = read(11) as usize;
+                        out.push(alphabet[value / 45]);
+                        out.push(alphabet[value % 45]);
+                        remaining -= 2;
+                    } else {
+                        out.push(alphabet[read(6) as usize]);
+                        remaining -= 1;
+                    }
+                }
+                out
+            }
+            QrMode::Byte => {
+                let bytes: Vec<u8> = (0..count).map(|_| read(8) as u8).collect();
+                String::from_utf8(bytes).expect("payload is not UTF-8")
+            }
+        };
+        (level, text)
+    }
+
     #[test]
     fn status_class_mapping_is_stable() {
         assert_eq!(QRCodeStatus::Active.as_class(), "adui-qrcode-active");
@@ -471,43 +1154,275 @@ mod tests {
         assert_eq!(QRCodeStatus::Scanned.as_class(), "adui-qrcode-scanned");
     }
 
+    #[test]
+    fn segment_mode_is_most_compact() {
+        assert_eq!(QrSegment::from_text("0123456789").mode, QrMode::Numeric);
+        assert_eq!(
+            QrSegment::from_text("HELLO WORLD").mode,
+            QrMode::Alphanumeric
+        );
+        assert_eq!(QrSegment::from_text("hello").mode, QrMode::Byte);
+        assert_eq!(QrSegment::from_text("你好").char_count, 6);
+    }
+
+    #[test]
+    fn data_capacity_matches_standard_tables() {
+        assert_eq!(num_raw_data_modules(1), 208);
+        assert_eq!(num_raw_data_modules(40), 29648);
+        let v1: Vec<usize> = ALL_LEVELS
+            .iter()
+            .map(|&l| num_data_codewords(1, l))
+            .collect();
+        assert_eq!(v1, vec![19, 16, 13, 9]);
+        let v40: Vec<usize> = ALL_LEVELS
+            .iter()
+            .map(|&l| num_data_codewords(40, l))
+            .collect();
+        assert_eq!(v40, vec![2956, 2334, 1666, 1276]);
+    }
+
     #[test]
     fn calculate_version_increases_with_content_length() {
-        assert_eq!(calculate_version(10, QRCodeErrorLevel::M), 1);
-        assert_eq!(calculate_version(30, QRCodeErrorLevel::M), 2);
-        assert_eq!(calculate_version(50, QRCodeErrorLevel::M), 3);
+        let version = |text: &str, level| calculate_version(&QrSegment::from_text(text), level);
+        assert_eq!(version("HELLO WORLD", QRCodeErrorLevel::Q), Some(1));
+        // Version 1-H holds at most 17 digits.
+        assert_eq!(version(&"1".repeat(17), QRCodeErrorLevel::H), Some(1));
+        assert_eq!(version(&"1".repeat(18), QRCodeErrorLevel::H), Some(2));
+        // Version 40-L holds at most 2953 bytes.
+        assert_eq!(version(&"a".repeat(2953), QRCodeErrorLevel::L), Some(40));
+        assert_eq!(version(&"a".repeat(2954), QRCodeErrorLevel::L), None);
     }
 
     #[test]
-    fn simple_hash_is_deterministic() {
-        let hash1 = simple_hash("test");
-        let hash2 = simple_hash("test");
-        let hash3 = simple_hash("different");
+    fn hello_world_codewords_match_reference() {
+        let segment = QrSegment::from_text("HELLO WORLD");
+        let data = build_data_codewords(&segment, 1, QRCodeErrorLevel::M);
+        assert_eq!(
+            data,
+            vec![
+                32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17
+            ]
+        );
+        let ecc = reed_solomon_remainder(&data, &reed_solomon_divisor(10));
+        assert_eq!(ecc, vec![196, 35, 39, 119, 235, 215, 231, 226, 93, 23]);
+    }
+
+    #[test]
+    fn format_and_version_bits_match_reference() {
+        assert_eq!(format_bits(QRCodeErrorLevel::L, 0), 0b111011111000100);
+        assert_eq!(format_bits(QRCodeErrorLevel::M, 0), 0b101010000010010);
+        assert_eq!(format_bits(QRCodeErrorLevel::Q, 0), 0b011010101011111);
+        assert_eq!(format_bits(QRCodeErrorLevel::H, 0), 0b001011010001001);
+        assert_eq!(version_bits(7), 0b000111110010010100);
+        assert_eq!(version_bits(40), 0b101000110001101001);
+    }
 
-        assert_eq!(hash1, hash2);
-        assert_ne!(hash1, hash3);
+    #[test]
+    fn alignment_positions_match_reference() {
+        assert!(alignment_pattern_positions(1).is_empty());
+        assert_eq!(alignment_pattern_positions(2), vec![6, 18]);
+        assert_eq!(alignment_pattern_positions(7), vec![6, 22, 38]);
+        assert_eq!(
+            alignment_pattern_positions(32),
+            vec![6, 34, 60, 86, 112, 138]
+        );
+        assert_eq!(
+            alignment_pattern_positions(40),
+            vec![6, 30, 58, 86, 114, 142, 170]
+        );
     }
 
     #[test]
     fn generate_qr_modules_creates_correct_size() {
-        let modules = generate_qr_modules("test", QRCodeErrorLevel::M);
+        let modules = generate_qr_modules("test", QRCodeErrorLevel::M).unwrap();
         // Version 1 should be 21x21
         assert_eq!(modules.len(), 21);
         assert_eq!(modules[0].len(), 21);
+        assert!(generate_qr_modules(&"a".repeat(3000), QRCodeErrorLevel::L).is_none());
+    }
+
+    #[test]
+    fn finder_patterns_are_drawn_in_three_corners() {
+        let modules = generate_qr_modules("https://example.com", QRCodeErrorLevel::M).unwrap();
+        let size = modules.len();
+        for (x0, y0) in [(0, 0), (size - 7, 0), (0, size - 7)] {
+            for d in 0..7 {
+                assert!(modules[y0][x0 + d]);
+                assert!(modules[y0 + 6][x0 + d]);
+                assert!(modules[y0 + d][x0]);
+                assert!(modules[y0 + d][x0 + 6]);
+            }
+            assert!(!modules[y0 + 1][x0 + 1]);
+            assert!(modules[y0 + 3][x0 + 3]);
+        }
+    }
+
+    /// Compares a generated symbol with a reference matrix written as rows of
+    /// `#` (dark) and `.` (light) modules.
+    fn assert_matrix(modules: &[Vec<bool>], expected: &[&str]) {
+        assert_eq!(modules.len(), expected.len(), "symbol size");
+        for (y, (row, reference)) in modules.iter().zip(expected).enumerate() {
+            let actual: String = row
+                .iter()
+                .map(|&dark| if dark { '#' } else { '.' })
+                .collect();
+            assert_eq!(&actual, reference, "row {y} differs");
+        }
+    }
+
+    // The reference symbols below come from Project Nayuki's qrcodegen 1.8.0
+    // (fixed error correction level, automatic mask selection), so they are
+    // independent of the helpers in this module.
+
+    /// ISO/IEC 18004 Annex I payload, version 1-M, mask 0.
+    const REFERENCE_01234567_1M: [&str; 21] = [
+        "#######...###.#######",
+        "#.....#.###...#.....#",
+        "#.###.#..##...#.###.#",
+        "#.###.#..#.##.#.###.#",
+        "#.###.#.##.##.#.###.#",
+        "#.....#....#..#.....#",
+        "#######.#.#.#.#######",
+        ".....................",
+        "#.#.#.#...#.#...#..#.",
+        "##.#....#.##.#.#...#.",
+        "...##.###.##.###.###.",
+        "##..##.#.#.###.##..#.",
+        "..#..###.###.###....#",
+        "........#.#...#....#.",
+        "#######.....#...#...#",
+        "#.....#...#...#..#.##",
+        "#.###.#.###.#.#.###.#",
+        "#.###.#..#.#.#.#.###.",
+        "#.###.#.##.#.###..#.#",
+        "#.....#....###.###...",
+        "#######.#..#.###..#.#",
+    ];
+
+    /// "HELLO WORLD", version 1-Q, mask 0.
+    const REFERENCE_HELLO_WORLD_1Q: [&str; 21] = [
+        "#######.##....#######",
+        "#.....#.#..#..#.....#",
+        "#.###.#.#..##.#.###.#",
+        "#.###.#.#.....#.###.#",
+        "#.###.#.#.#...#.###.#",
+        "#.....#...#...#.....#",
+        "#######.#.#.#.#######",
+        "........#............",
+        ".##.#.##....#.#.#####",
+        ".#......####....#...#",
+        "..##.###.##...#.##...",
+        ".##.##.#..##.#.#.###.",
+        "#...#.#.#.###.###.#.#",
+        "........##.#..#...#.#",
+        "#######.#.#....#.##..",
+        "#.....#..#.##.##.#...",
+        "#.###.#.#.#...#######",
+        "#.###.#..#.#.#.#...#.",
+        "#.###.#.#..#.###.#..#",
+        "#.....#.#.####...#.##",
+        "#######....#.###....#",
+    ];
+
+    /// 150 bytes of `abc…z` repeated, version 8-M, mask 2. Version 8 carries
+    /// the two 18-bit version information blocks.
+    const REFERENCE_ALPHABET_8M: [&str; 49] = [
+        "#######..#.#####.........#.#.##.#....#..#.#######",
+        "#.....#..##.....###.#####.#.#..#.##...###.#.....#",
+        "#.###.#.####..#.#..###.#....######.....##.#.###.#",
+        "#.###.#.#.#.####..##.####.#....#.#.###.#..#.###.#",
+        "#.###.#.#.######.##..######..####..###....#.###.#",
+        "#.....#.#....#.....#..#...####....##..#...#.....#",
+        "#######.#.#.#.#.#.#.#.#.#.#.#.#.#.#.#.#.#.#######",
+        "........##.##..##.....#...###...##...#.##........",
+        "#.#####..##.#.##..#.#.#####...##.####.#.#.#####..",
+        "........#...#.##..##....##...####..###...##..##..",
+        ".#....###.#.#.#..###.#..#.#.#..#.##.####.#.....##",
+        "#.#.#....#####....#.#....########.##..#..##.#..##",
+        "..#.####.#...###.....####.#...##.####..##..#..##.",
+        "...##...#.###.##...###.#.#...###...#.#...####.#..",
+        "......#..###.####.#.#...#.##.#.#.###..#.#....#.##",
+        "#.#.#..###..######.##..#..#####.##.#.#....###..##",
+        "###..##....#.#...#..#.###.#..#.#...###..#..#.##.#",
+        "..###...#.#.#.######...###..####...###...###.#...",
+        ".##..##..#.##.#.#.....#.#.###..#.##..##..#...####",
+        "###.##.#..###.#...#.#....##.######....#..##.#..#.",
+        ".###..#..#..####..########...#.#...######.##.##..",
+        "..#.........#.###.##.#.###.#.##.#...##...###..##.",
+        ".#.######.####.##...#.########....##..#.#########",
+        "#..##...#.##..#.####..#...###...##...#.##...#...#",
+        "#..##.#.#.#.##.###..###.#.#..###..#######.#.###..",
+        ".##.#...#..####....#..#...##.##.#....#..#...###..",
+        "##..######.##.#..#..#.#####.#..#.##..##.#########",
+        "#......#....#.##....#####.#######.#...#.#..#....#",
+        "#..##.#.#..##..##....###.....###..####.##.######.",
+        "##..#..######.#.#.#..##########......#...###..#..",
+        "#.#.#.#.#..####.###...##.#.###.#..##..##.####..##",
+        "#..###.##....#.#...#.#.#..###...##.#.#..##.##....",
+        "..#.#.#...###..#....#....##...##.####.##.##.#####",
+        "##.##...##.##....#.#.#.########.....##..##.#.#...",
+        "###..##..#.....##..##.#..#.##..#.##..######.#.###",
+        "###....#.##.#.....##..#.###.#..###...##.#........",
+        "###...#..##.#...##.###.#......##.####..#..#####..",
+        "..##....##.####..#.#.#.###..####...#.#.###.#..##.",
+        ".#...###.#..#..#.###..#.##...#....#...##.##...###",
+        ".###...#.#.#.###.#.#.#..#.###...#..#..#.##.....#.",
+        "###...#.#...#..#.##.#.#####....#.#.##..######.###",
+        "........#.#...#.####.##...#.####...###.##...##...",
+        "#######..###...#..##.##.#.###..#.##..####.#.#..##",
+        "#.....#.#..#...##...###...#.######....###...#..#.",
+        "#.###.#.##..#..###.##.#####....#.#.##.#.#######..",
+        "#.###.#.######.#..##..#..##..####..###..###.#.###",
+        "#.###.#.#..###...#.##..##.####....##..#..###.##..",
+        "#.....#..#..##.......##.##.##...##.#.#.####.....#",
+        "#######.##.#.#...##.#..##.#..###..######...#.####",
+    ];
+
+    #[test]
+    fn annex_i_symbol_matches_reference_matrix() {
+        let modules = generate_qr_modules("01234567", QRCodeErrorLevel::M).unwrap();
+        assert_matrix(&modules, &REFERENCE_01234567_1M);
+    }
+
+    #[test]
+    fn hello_world_symbol_matches_reference_matrix() {
+        let modules = generate_qr_modules("HELLO WORLD", QRCodeErrorLevel::Q).unwrap();
+        assert_matrix(&modules, &REFERENCE_HELLO_WORLD_1Q);
+    }
+
+    #[test]
+    fn version_8_symbol_matches_reference_matrix() {
+        let text: String = (0..150u8).map(|i| char::from(b'a' + i % 26)).collect();
+        let modules = generate_qr_modules(&text, QRCodeErrorLevel::M).unwrap();
+        assert_matrix(&modules, &REFERENCE_ALPHABET_8M);
+    }
+
+    #[test]
+    fn encoded_symbols_round_trip() {
+        let samples = [
+            "01234567".to_string(),
+            "HELLO WORLD".to_string(),
+            "https://ant.design/components/qr-code".to_string(),
+            "你好，世界".to_string(),
+            "ADUI-".repeat(40),
+            "9".repeat(700),
+            "The quick brown fox jumps over the lazy dog. ".repeat(12),
+        ];
+        for text in &samples {
+            for level in ALL_LEVELS {
+                let modules = generate_qr_modules(text, level).unwrap();
+                let (decoded_level, decoded) = decode(&modules);
+                assert_eq!(decoded_level, level);
+                assert_eq!(&decoded, text);
+            }
+        }
     }
 
     #[test]
-    fn finder_pattern_is_added_correctly() {
-        let mut modules = vec![vec![false; 21]; 21];
-        add_finder_pattern(&mut modules, 0, 0);
-
-        // Check corners of finder pattern
-        assert!(modules[0][0]); // top-left
-        assert!(modules[0][6]); // top-right
-        assert!(modules[6][0]); // bottom-left
-        assert!(modules[6][6]); // bottom-right
-
-        // Check center
-        assert!(modules[3][3]);
+    fn largest_symbol_round_trips() {
+        let text = "a".repeat(2953);
+        let modules = generate_qr_modules(&text, QRCodeErrorLevel::L).unwrap();
+        assert_eq!(modules.len(), 177);
+        assert_eq!(decode(&modules).1, text);
     }
 }
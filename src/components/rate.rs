@@ -1,13 +1,13 @@
 use crate::components::config_provider::use_config;
 use crate::components::form::use_form_item_control;
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use crate::components::interaction::as_pointer_event;
 use crate::components::number_utils::{NumberRules, apply_step};
 use dioxus::events::{KeyboardEvent, PointerData};
 use dioxus::prelude::Key;
 use dioxus::prelude::*;
 use serde_json::{Number, Value};
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use wasm_bindgen::JsCast;
 
 /// Rate component for collecting evaluations with stars (supports half).
@@ -296,7 +296,7 @@ fn apply_rate(
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 fn pointer_value(evt: &Event<PointerData>, star_index: usize, allow_half: bool) -> Option<f64> {
     if let Some(p_evt) = as_pointer_event(evt) {
         if let Some(target) = p_evt
@@ -316,7 +316,7 @@ fn pointer_value(evt: &Event<PointerData>, star_index: usize, allow_half: bool)
     Some(star_index as f64)
 }
 
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
 fn pointer_value(_evt: &Event<PointerData>, star_index: usize, _allow_half: bool) -> Option<f64> {
     Some(star_index as f64)
 }
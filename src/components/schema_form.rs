@@ -0,0 +1,943 @@
+//! Forms generated from a JSON Schema.
+//!
+//! `SchemaForm` walks an object schema and renders one `FormItem` per
+//! property with a control picked from the property type: strings become
+//! `Input`, numbers `InputNumber`, booleans `Switch`, enums `Select`,
+//! `format: date` strings `DatePicker`, arrays a `FormList` and nested
+//! objects a titled section.
+//! Keywords such as `required`, `minLength` or `pattern` are translated into
+//! `FormRule`s, and `ui:*` keywords on a property override the defaults.
+//!
+//! The walk itself is exposed as [`schema_fields`] so the descriptors can be
+//! inspected or rendered by hand.
+
+use crate::components::button::{Button, ButtonHtmlType, ButtonType};
+use crate::components::date_picker::{DatePicker, DateValue};
+use crate::components::form::{
+    Form, FormFinishEvent, FormFinishFailedEvent, FormHandle, FormItem, FormLayout, FormList,
+    FormRule, FormValidator, FormValues, ValuesChangeEvent, use_form_item_control, use_form_list,
+};
+use crate::components::form_path::{NamePath, merge_value};
+use crate::components::input::{Input, Password, TextArea};
+use crate::components::input_number::InputNumber;
+use crate::components::locale::{
+    LocaleBundle, SchemaFormLocale, format_locale_template, use_locale,
+};
+use crate::components::select::{Select, SelectMode};
+use crate::components::select_base::SelectOption;
+use crate::components::switch::Switch;
+use dioxus::{core::current_scope_id, prelude::*};
+use serde_json::{Map, Value};
+
+const EMAIL_PATTERN: &str = r"^[^@\s]+@[^@\s]+\.[^@\s]+$";
+
+/// Control used to edit a schema property.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaWidget {
+    Input,
+    TextArea,
+    Password,
+    Number,
+    Select,
+    /// `Select` in multiple mode, for arrays of enum values.
+    MultiSelect,
+    Switch,
+    Date,
+    /// Array of items rendered through `FormList`.
+    List,
+    /// Nested object rendered as a titled group of fields.
+    Section,
+    /// Kept in the form values but not rendered.
+    Hidden,
+}
+
+impl SchemaWidget {
+    /// Parse a `ui:widget` value.
+    pub fn from_ui(name: &str) -> Option<Self> {
+        Some(match name {
+            "input" | "text" => SchemaWidget::Input,
+            "textarea" => SchemaWidget::TextArea,
+            "password" => SchemaWidget::Password,
+            "number" | "updown" => SchemaWidget::Number,
+            "select" => SchemaWidget::Select,
+            "switch" | "checkbox" => SchemaWidget::Switch,
+            "date" => SchemaWidget::Date,
+            "hidden" => SchemaWidget::Hidden,
+            _ => return None,
+        })
+    }
+}
+
+/// One rendered property, derived from its schema.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaField {
+    /// Path of the value; empty for the item template of a list.
+    pub name: NamePath,
+    /// `title`, falling back to the property key.
+    pub label: String,
+    /// `ui:help`, falling back to `description`.
+    pub help: Option<String>,
+    pub placeholder: Option<String>,
+    pub widget: SchemaWidget,
+    pub required: bool,
+    pub disabled: bool,
+    pub rules: Vec<FormRule>,
+    /// Choices of `Select` widgets, from `enum` or `oneOf` / `const`.
+    pub options: Vec<SelectOption>,
+    /// Schema values of `options`, in the same order. The form stores these
+    /// rather than the string keys, so integer and boolean enums keep their
+    /// type.
+    pub option_values: Vec<Value>,
+    /// `minimum` / `maximum` of numeric fields.
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+    /// Whether a numeric field only accepts integers.
+    pub integer: bool,
+    /// `default` of the property.
+    pub default: Option<Value>,
+    /// Fields of a `Section`.
+    pub children: Vec<SchemaField>,
+    /// Template of each `List` item, with paths relative to the item.
+    pub item: Option<Box<SchemaField>>,
+}
+
+impl SchemaField {
+    /// Copy of the field with `prefix` prepended to its path and the paths
+    /// of its children.
+    pub fn with_prefix(&self, prefix: &NamePath) -> SchemaField {
+        let mut field = self.clone();
+        let mut name = prefix.clone();
+        name.0.extend(self.name.0.iter().cloned());
+        field.name = name;
+        field.children = self
+            .children
+            .iter()
+            .map(|child| child.with_prefix(prefix))
+            .collect();
+        field
+    }
+}
+
+/// Walk the properties of an object schema into field descriptors.
+///
+/// Local `$ref`s (`#/definitions/...`, `#/$defs/...`) are resolved against
+/// `schema`. Anything other than an object schema yields no fields. Rule
+/// messages come from the default (`zh-CN`) bundle; see
+/// [`schema_fields_with_locale`].
+pub fn schema_fields(schema: &Value) -> Vec<SchemaField> {
+    schema_fields_with_locale(schema, &LocaleBundle::default().schema_form)
+}
+
+/// [`schema_fields`] with rule messages taken from `locale`.
+pub fn schema_fields_with_locale(schema: &Value, locale: &SchemaFormLocale) -> Vec<SchemaField> {
+    object_fields(schema, schema, &NamePath::default(), locale)
+}
+
+/// Collect the `default`s of an object schema into form values.
+pub fn schema_defaults(schema: &Value) -> FormValues {
+    let mut values = FormValues::new();
+    let Some(Value::Object(defaults)) = object_default(schema, schema) else {
+        return values;
+    };
+    values.extend(defaults);
+    values
+}
+
+fn object_default(root: &Value, schema: &Value) -> Option<Value> {
+    let schema = resolve(root, schema);
+    let mut value = schema.get("default").cloned();
+    if let Some(Value::Object(properties)) = schema.get("properties") {
+        let mut nested = Map::new();
+        for (key, property) in properties {
+            if let Some(default) = object_default(root, property) {
+                nested.insert(key.clone(), default);
+            }
+        }
+        if !nested.is_empty() {
+            let target = value.get_or_insert_with(|| Value::Object(Map::new()));
+            merge_value(target, Value::Object(nested));
+        }
+    }
+    value
+}
+
+fn resolve<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+    let mut current = schema;
+    // Bounded so that a self-referencing `$ref` cannot loop forever.
+    for _ in 0..16 {
+        let Some(pointer) = current
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|r| r.strip_prefix('#'))
+        else {
+            break;
+        };
+        match root.pointer(pointer) {
+            Some(target) => current = target,
+            None => break,
+        }
+    }
+    current
+}
+
+fn object_fields(
+    root: &Value,
+    schema: &Value,
+    prefix: &NamePath,
+    locale: &SchemaFormLocale,
+) -> Vec<SchemaField> {
+    let schema = resolve(root, schema);
+    let Some(Value::Object(properties)) = schema.get("properties") else {
+        return Vec::new();
+    };
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|keys| keys.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    ordered_keys(properties, schema.get("ui:order"))
+        .into_iter()
+        .map(|key| {
+            field_from_schema(
+                root,
+                prefix.clone().key(key.as_str()),
+                &key,
+                &properties[&key],
+                required.contains(&key.as_str()),
+                locale,
+            )
+        })
+        .collect()
+}
+
+/// Property keys in `ui:order` order. A `"*"` entry stands for every key not
+/// listed; without one, unlisted keys follow the listed ones.
+fn ordered_keys(properties: &Map<String, Value>, order: Option<&Value>) -> Vec<String> {
+    let Some(order) = order.and_then(Value::as_array) else {
+        return properties.keys().cloned().collect();
+    };
+    let listed: Vec<&str> = order.iter().filter_map(Value::as_str).collect();
+    let rest: Vec<String> = properties
+        .keys()
+        .filter(|key| !listed.contains(&key.as_str()))
+        .cloned()
+        .collect();
+
+    let mut keys = Vec::with_capacity(properties.len());
+    let mut rest_placed = false;
+    for entry in listed {
+        if entry == "*" {
+            keys.extend(rest.iter().cloned());
+            rest_placed = true;
+        } else if properties.contains_key(entry) {
+            keys.push(entry.to_string());
+        }
+    }
+    if !rest_placed {
+        keys.extend(rest);
+    }
+    keys
+}
+
+fn schema_type(schema: &Value) -> Option<&str> {
+    match schema.get("type") {
+        Some(Value::String(ty)) => Some(ty.as_str()),
+        // `["string", "null"]`: the first non-null type wins.
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|ty| *ty != "null"),
+        _ => None,
+    }
+}
+
+/// Choices of an enum schema, each with the value it stands for.
+fn enum_options(schema: &Value) -> Vec<(SelectOption, Value)> {
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        let names = schema
+            .get("enumNames")
+            .or_else(|| schema.get("ui:enumNames"))
+            .and_then(Value::as_array);
+        return values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let key = option_key(value);
+                let label = names
+                    .and_then(|names| names.get(i))
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| key.clone());
+                let option = SelectOption {
+                    key,
+                    label,
+                    disabled: false,
+                };
+                (option, value.clone())
+            })
+            .collect();
+    }
+    if let Some(variants) = schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(Value::as_array)
+        && variants.iter().all(|v| v.get("const").is_some())
+    {
+        return variants
+            .iter()
+            .map(|variant| {
+                let key = option_key(&variant["const"]);
+                let label = variant
+                    .get("title")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| key.clone());
+                let option = SelectOption {
+                    key,
+                    label,
+                    disabled: false,
+                };
+                (option, variant["const"].clone())
+            })
+            .collect();
+    }
+    Vec::new()
+}
+
+fn option_key(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Map the string keys `Select` writes back to the schema values of `field`.
+fn option_normalizer(field: &SchemaField) -> Option<Callback<Value, Value>> {
+    if field.option_values.iter().all(Value::is_string) {
+        return None;
+    }
+    let choices: Vec<(String, Value)> = field
+        .options
+        .iter()
+        .map(|option| option.key.clone())
+        .zip(field.option_values.iter().cloned())
+        .collect();
+    Some(Callback::new(move |value| option_value(&choices, value)))
+}
+
+fn option_value(choices: &[(String, Value)], value: Value) -> Value {
+    match value {
+        Value::String(key) => choices
+            .iter()
+            .find(|(choice, _)| *choice == key)
+            .map(|(_, value)| value.clone())
+            .unwrap_or(Value::String(key)),
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| option_value(choices, item))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+fn field_from_schema(
+    root: &Value,
+    name: NamePath,
+    key: &str,
+    schema: &Value,
+    required: bool,
+    locale: &SchemaFormLocale,
+) -> SchemaField {
+    let schema = resolve(root, schema);
+    let ty = schema_type(schema);
+    let options = enum_options(schema);
+    let format = schema.get("format").and_then(Value::as_str);
+
+    let item_schema = schema.get("items").map(|items| resolve(root, items));
+    let item_options = item_schema.map(enum_options).unwrap_or_default();
+
+    let mut widget = match ty {
+        _ if !options.is_empty() => SchemaWidget::Select,
+        Some("boolean") => SchemaWidget::Switch,
+        Some("integer") | Some("number") => SchemaWidget::Number,
+        Some("array") if !item_options.is_empty() => SchemaWidget::MultiSelect,
+        Some("array") => SchemaWidget::List,
+        Some("object") => SchemaWidget::Section,
+        _ if schema.get("properties").is_some() => SchemaWidget::Section,
+        // `date-time` stays a text input: the picker only yields a date.
+        _ if format == Some("date") => SchemaWidget::Date,
+        _ if format == Some("password") => SchemaWidget::Password,
+        _ => SchemaWidget::Input,
+    };
+    if let Some(ui) = schema
+        .get("ui:widget")
+        .and_then(Value::as_str)
+        .and_then(SchemaWidget::from_ui)
+    {
+        widget = ui;
+    }
+
+    let label = schema
+        .get("title")
+        .and_then(Value::as_str)
+        .unwrap_or(key)
+        .to_string();
+    let minimum = schema.get("minimum").and_then(Value::as_f64);
+    let maximum = schema.get("maximum").and_then(Value::as_f64);
+
+    let (options, option_values) = if widget == SchemaWidget::MultiSelect {
+        item_options
+    } else {
+        options
+    }
+    .into_iter()
+    .unzip();
+    let mut field = SchemaField {
+        name,
+        label,
+        help: schema
+            .get("ui:help")
+            .or_else(|| schema.get("description"))
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        placeholder: schema
+            .get("ui:placeholder")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        widget,
+        // A required boolean is satisfied by `false`, so it needs no rule.
+        required: required && widget != SchemaWidget::Switch,
+        disabled: schema
+            .get("ui:disabled")
+            .or_else(|| schema.get("readOnly"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false),
+        rules: Vec::new(),
+        options,
+        option_values,
+        minimum,
+        maximum,
+        integer: ty == Some("integer"),
+        default: schema.get("default").cloned(),
+        children: Vec::new(),
+        item: None,
+    };
+
+    match widget {
+        SchemaWidget::Section => {
+            field.children = object_fields(root, schema, &field.name, locale);
+        }
+        SchemaWidget::List => {
+            let item = item_schema.unwrap_or(&Value::Null);
+            let template = field_from_schema(root, NamePath::default(), key, item, false, locale);
+            field.item = Some(Box::new(template));
+        }
+        _ => {}
+    }
+
+    field.rules = field_rules(&field, schema, format, locale);
+    field
+}
+
+fn field_rules(
+    field: &SchemaField,
+    schema: &Value,
+    format: Option<&str>,
+    locale: &SchemaFormLocale,
+) -> Vec<FormRule> {
+    let mut rules = Vec::new();
+    if field.required {
+        rules.push(FormRule {
+            required: true,
+            message: Some(format_locale_template(
+                &locale.required,
+                &[("label", field.label.clone())],
+            )),
+            ..FormRule::default()
+        });
+    }
+
+    let usize_keyword = |name: &str| schema.get(name).and_then(Value::as_u64).map(|n| n as usize);
+    let (min, max) = match field.widget {
+        SchemaWidget::List | SchemaWidget::MultiSelect => {
+            (usize_keyword("minItems"), usize_keyword("maxItems"))
+        }
+        SchemaWidget::Number | SchemaWidget::Switch | SchemaWidget::Section => (None, None),
+        _ => (usize_keyword("minLength"), usize_keyword("maxLength")),
+    };
+    if min.is_some() || max.is_some() {
+        rules.push(FormRule {
+            min,
+            max,
+            ..FormRule::default()
+        });
+    }
+
+    let pattern = schema
+        .get("pattern")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .or_else(|| (format == Some("email")).then(|| EMAIL_PATTERN.to_string()));
+    if let Some(pattern) = pattern {
+        rules.push(FormRule {
+            pattern: Some(pattern),
+            message: schema
+                .get("ui:patternMessage")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            ..FormRule::default()
+        });
+    }
+
+    if field.widget == SchemaWidget::Number && (field.minimum.is_some() || field.maximum.is_some())
+    {
+        let (minimum, maximum) = (field.minimum, field.maximum);
+        let (min_message, max_message) = (locale.number_min.clone(), locale.number_max.clone());
+        rules.push(FormRule {
+            validator: Some(FormValidator::form(move |_, value| {
+                let Some(number) = value.and_then(Value::as_f64) else {
+                    return Ok(());
+                };
+                if let Some(minimum) = minimum
+                    && number < minimum
+                {
+                    return Err(format_locale_template(
+                        &min_message,
+                        &[("minimum", minimum.to_string())],
+                    ));
+                }
+                if let Some(maximum) = maximum
+                    && number > maximum
+                {
+                    return Err(format_locale_template(
+                        &max_message,
+                        &[("maximum", maximum.to_string())],
+                    ));
+                }
+                Ok(())
+            })),
+            ..FormRule::default()
+        });
+    }
+    rules
+}
+
+/// Props for [`SchemaForm`].
+#[derive(Props, Clone, PartialEq)]
+pub struct SchemaFormProps {
+    /// Object schema describing the form values.
+    pub schema: Value,
+    #[props(optional)]
+    pub form: Option<FormHandle>,
+    /// Initial values, merged over the schema `default`s.
+    #[props(optional)]
+    pub initial_values: Option<FormValues>,
+    #[props(default)]
+    pub layout: FormLayout,
+    #[props(default)]
+    pub disabled: bool,
+    #[props(optional)]
+    pub class: Option<String>,
+    #[props(optional)]
+    pub style: Option<String>,
+    #[props(optional)]
+    pub on_finish: Option<EventHandler<FormFinishEvent>>,
+    #[props(optional)]
+    pub on_finish_failed: Option<EventHandler<FormFinishFailedEvent>>,
+    #[props(optional)]
+    pub on_values_change: Option<EventHandler<ValuesChangeEvent>>,
+    /// Rendered after the generated fields, e.g. a submit button.
+    pub children: Element,
+}
+
+/// Form whose fields are generated from a JSON Schema.
+#[component]
+pub fn SchemaForm(props: SchemaFormProps) -> Element {
+    let SchemaFormProps {
+        schema,
+        form,
+        initial_values,
+        layout,
+        disabled,
+        class,
+        style,
+        on_finish,
+        on_finish_failed,
+        on_values_change,
+        children,
+    } = props;
+
+    let locale = use_locale();
+    let fields = schema_fields_with_locale(&schema, &locale.schema_form);
+    let mut values = schema_defaults(&schema);
+    for (key, value) in initial_values.unwrap_or_default() {
+        merge_value(values.entry(key).or_insert(Value::Null), value);
+    }
+    let class_attr = match class {
+        Some(extra) => format!("adui-schema-form {extra}"),
+        None => "adui-schema-form".to_string(),
+    };
+
+    rsx! {
+        Form {
+            class: class_attr,
+            style,
+            layout,
+            disabled,
+            form,
+            initial_values: values,
+            on_finish,
+            on_finish_failed,
+            on_values_change,
+            for field in fields {
+                {render_field(field)}
+            }
+            {children}
+        }
+    }
+}
+
+fn render_field(field: SchemaField) -> Element {
+    let key = field.name.to_string();
+    match field.widget {
+        SchemaWidget::Hidden => rsx! {},
+        SchemaWidget::Section => rsx! {
+            div { key: "{key}", class: "adui-schema-form-section",
+                div { class: "adui-schema-form-section-title", "{field.label}" }
+                if let Some(help) = field.help.clone() {
+                    div { class: "adui-schema-form-section-description", "{help}" }
+                }
+                for child in field.children {
+                    {render_field(child)}
+                }
+            }
+        },
+        SchemaWidget::List => rsx! {
+            SchemaListField { key: "{key}", field }
+        },
+        _ => rsx! {
+            FormItem {
+                key: "{key}",
+                name: field.name.clone(),
+                label: field.label.clone(),
+                extra: field.help.clone(),
+                rules: field.rules.clone(),
+                normalize: option_normalizer(&field),
+                {render_control(&field)}
+            }
+        },
+    }
+}
+
+fn render_control(field: &SchemaField) -> Element {
+    let placeholder = field.placeholder.clone();
+    let disabled = field.disabled;
+    match field.widget {
+        SchemaWidget::TextArea => rsx! {
+            TextArea { placeholder, disabled }
+        },
+        SchemaWidget::Password => rsx! {
+            Password { placeholder, disabled }
+        },
+        SchemaWidget::Number => rsx! {
+            InputNumber {
+                min: field.minimum,
+                max: field.maximum,
+                precision: field.integer.then_some(0),
+                disabled,
+            }
+        },
+        SchemaWidget::Select => rsx! {
+            Select {
+                options: field.options.clone(),
+                placeholder,
+                disabled,
+                allow_clear: !field.required,
+            }
+        },
+        SchemaWidget::MultiSelect => rsx! {
+            Select {
+                options: field.options.clone(),
+                mode: SelectMode::Multiple,
+                placeholder,
+                disabled,
+            }
+        },
+        SchemaWidget::Switch => rsx! {
+            Switch { disabled }
+        },
+        SchemaWidget::Date => rsx! {
+            SchemaDateControl { placeholder, disabled }
+        },
+        _ => rsx! {
+            Input { placeholder, disabled }
+        },
+    }
+}
+
+/// Bridges `DatePicker` to the surrounding `FormItem`, storing the date as a
+/// `YYYY-MM-DD` string.
+#[component]
+fn SchemaDateControl(placeholder: Option<String>, disabled: bool) -> Element {
+    let control = use_form_item_control();
+    let value = control
+        .as_ref()
+        .and_then(|ctx| ctx.value())
+        .and_then(|value| value.as_str().and_then(DateValue::parse_ymd));
+
+    rsx! {
+        DatePicker {
+            value,
+            placeholder,
+            disabled,
+            on_change: move |date: Option<DateValue>| {
+                if let Some(ctx) = control.as_ref() {
+                    ctx.set_value(
+                        date.map(|d| Value::String(d.to_ymd_string()))
+                            .unwrap_or(Value::Null),
+                    );
+                }
+            },
+        }
+    }
+}
+
+#[component]
+fn SchemaListField(field: SchemaField) -> Element {
+    rsx! {
+        div { class: "adui-schema-form-list",
+            div { class: "adui-schema-form-list-title", "{field.label}" }
+            if let Some(help) = field.help.clone() {
+                div { class: "adui-schema-form-section-description", "{help}" }
+            }
+            FormList { name: field.name.clone(), SchemaListItems { field } }
+        }
+    }
+}
+
+#[component]
+fn SchemaListItems(field: SchemaField) -> Element {
+    let locale = use_locale();
+    let Some(list) = use_form_list() else {
+        return rsx! {};
+    };
+    // Re-render when items are added or removed.
+    list.handle
+        .register_listener(list.name.as_str(), current_scope_id());
+
+    let Some(template) = field.item.clone() else {
+        return rsx! {};
+    };
+    let new_item = template.default.clone().unwrap_or(Value::Null);
+    let add_text = format_locale_template(
+        &locale.schema_form.add_item,
+        &[("label", field.label.clone())],
+    );
+    let len = list.len();
+    let add_list = list.clone();
+
+    rsx! {
+        for index in 0..len {
+            div { key: "{index}", class: "adui-schema-form-list-item",
+                div { class: "adui-schema-form-list-item-body",
+                    {render_field(list_item_field(&template, &list.item_path(index), index))}
+                }
+                Button {
+                    r#type: ButtonType::Text,
+                    html_type: ButtonHtmlType::Button,
+                    danger: true,
+                    disabled: field.disabled,
+                    onclick: {
+                        let list = list.clone();
+                        move |_| list.remove(index)
+                    },
+                    "{locale.schema_form.remove_item}"
+                }
+            }
+        }
+        Button {
+            r#type: ButtonType::Dashed,
+            html_type: ButtonHtmlType::Button,
+            block: true,
+            disabled: field.disabled,
+            onclick: move |_| add_list.insert(add_list.len(), new_item.clone()),
+            "{add_text}"
+        }
+    }
+}
+
+fn list_item_field(template: &SchemaField, path: &NamePath, index: usize) -> SchemaField {
+    let mut field = template.with_prefix(path);
+    field.label = format!("{} {}", template.label, index + 1);
+    field
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn user_schema() -> Value {
+        json!({
+            "type": "object",
+            "required": ["name", "age"],
+            "properties": {
+                "name": {"type": "string", "title": "Name", "minLength": 2, "pattern": "^[a-z]+$"},
+                "age": {"type": "integer", "minimum": 18, "maximum": 99},
+                "role": {"type": "string", "enum": ["admin", "user"], "default": "user"},
+                "active": {"type": "boolean"},
+                "birthday": {"type": "string", "format": "date"},
+                "stamp": {"type": "string", "format": "date-time"},
+                "bio": {"type": "string", "ui:widget": "textarea"},
+                "address": {"$ref": "#/definitions/address"},
+                "tags": {"type": "array", "items": {"type": "string"}, "maxItems": 3}
+            },
+            "definitions": {
+                "address": {
+                    "type": "object",
+                    "properties": {"city": {"type": "string", "default": "Paris"}}
+                }
+            }
+        })
+    }
+
+    fn field<'a>(fields: &'a [SchemaField], name: &str) -> &'a SchemaField {
+        fields
+            .iter()
+            .find(|f| f.name.to_string() == name)
+            .expect("field exists")
+    }
+
+    #[test]
+    fn widgets_follow_types_and_hints() {
+        let fields = schema_fields(&user_schema());
+        assert_eq!(field(&fields, "name").widget, SchemaWidget::Input);
+        assert_eq!(field(&fields, "stamp").widget, SchemaWidget::Input);
+        assert_eq!(field(&fields, "age").widget, SchemaWidget::Number);
+        assert!(field(&fields, "age").integer);
+        assert_eq!(field(&fields, "role").widget, SchemaWidget::Select);
+        assert_eq!(field(&fields, "role").options.len(), 2);
+        assert_eq!(field(&fields, "active").widget, SchemaWidget::Switch);
+        assert_eq!(field(&fields, "birthday").widget, SchemaWidget::Date);
+        assert_eq!(field(&fields, "bio").widget, SchemaWidget::TextArea);
+        assert_eq!(field(&fields, "tags").widget, SchemaWidget::List);
+
+        let address = field(&fields, "address");
+        assert_eq!(address.widget, SchemaWidget::Section);
+        assert_eq!(address.children[0].name.to_string(), "address.city");
+    }
+
+    #[test]
+    fn keywords_become_rules() {
+        let fields = schema_fields(&user_schema());
+        let name = field(&fields, "name");
+        assert_eq!(name.label, "Name");
+        assert!(name.rules[0].required);
+        assert_eq!(name.rules[1].min, Some(2));
+        assert_eq!(name.rules[2].pattern.as_deref(), Some("^[a-z]+$"));
+
+        let age = field(&fields, "age");
+        let FormValidator::Form(check) = age.rules[1].validator.clone().unwrap() else {
+            panic!("expected a form validator");
+        };
+        let handle = FormHandle::new();
+        assert!(check(&handle, Some(&json!(20))).is_ok());
+        assert_eq!(
+            check(&handle, Some(&json!(12))).unwrap_err(),
+            "数值不能小于 18"
+        );
+
+        assert_eq!(field(&fields, "tags").rules[0].max, Some(3));
+        assert!(field(&fields, "active").rules.is_empty());
+    }
+
+    #[test]
+    fn messages_follow_the_locale() {
+        let en = LocaleBundle::builtin("en-US").unwrap();
+        let fields = schema_fields_with_locale(&user_schema(), &en.schema_form);
+        assert_eq!(
+            field(&fields, "name").rules[0].message.as_deref(),
+            Some("Please enter Name")
+        );
+        let FormValidator::Form(check) = field(&fields, "age").rules[1].validator.clone().unwrap()
+        else {
+            panic!("expected a form validator");
+        };
+        assert_eq!(
+            check(&FormHandle::new(), Some(&json!(120))).unwrap_err(),
+            "Must be at most 99"
+        );
+    }
+
+    #[test]
+    fn non_string_enums_store_their_values() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "level": {"type": "integer", "enum": [1, 2, 3]},
+                "flags": {"type": "array", "items": {"enum": [true, false]}},
+                "role": {"type": "string", "enum": ["admin"]}
+            }
+        });
+        let fields = schema_fields(&schema);
+        let level = field(&fields, "level");
+        assert_eq!(level.options[1].key, "2");
+        assert_eq!(level.option_values[1], json!(2));
+        assert!(option_normalizer(field(&fields, "role")).is_none());
+
+        let choices: Vec<(String, Value)> = level
+            .options
+            .iter()
+            .map(|option| option.key.clone())
+            .zip(level.option_values.iter().cloned())
+            .collect();
+        assert_eq!(option_value(&choices, json!("2")), json!(2));
+        let flags = field(&fields, "flags");
+        let choices: Vec<(String, Value)> = flags
+            .options
+            .iter()
+            .map(|option| option.key.clone())
+            .zip(flags.option_values.iter().cloned())
+            .collect();
+        assert_eq!(
+            option_value(&choices, json!(["false", "true"])),
+            json!([false, true])
+        );
+    }
+
+    #[test]
+    fn ui_order_and_list_items() {
+        let schema = json!({
+            "type": "object",
+            "ui:order": ["b", "*", "a"],
+            "properties": {
+                "a": {"type": "string"},
+                "b": {"type": "string"},
+                "c": {"type": "string"},
+                "users": {
+                    "type": "array",
+                    "items": {"type": "object", "properties": {"email": {"type": "string", "format": "email"}}}
+                }
+            }
+        });
+        let fields = schema_fields(&schema);
+        let names: Vec<String> = fields.iter().map(|f| f.name.to_string()).collect();
+        assert_eq!(names, ["b", "c", "users", "a"]);
+
+        let template = field(&fields, "users").item.as_deref().unwrap();
+        let item = template.with_prefix(&NamePath::parse("users[1]"));
+        assert_eq!(item.children[0].name.to_string(), "users[1].email");
+        assert!(item.children[0].rules[0].pattern.is_some());
+    }
+
+    #[test]
+    fn defaults_are_collected() {
+        let defaults = schema_defaults(&user_schema());
+        assert_eq!(defaults.get("role"), Some(&json!("user")));
+        assert_eq!(defaults.get("address"), Some(&json!({"city": "Paris"})));
+        assert!(!defaults.contains_key("name"));
+    }
+}
@@ -4,12 +4,20 @@
 
 use crate::components::config_provider::{ComponentSize, use_config};
 use crate::components::control::{ControlStatus, push_status_class};
+use crate::components::floating_position::{
+    FloatingAlign, FloatingPlacement, FloatingSide, PositionOptions,
+};
 use crate::components::form::{FormItemControlContext, use_form_item_control};
 use crate::components::icon::{Icon, IconKind};
+use crate::components::locale::use_locale;
 use crate::components::select_base::{
-    DropdownLayer, OptionKey, SelectOption, handle_option_list_key_event, option_key_to_value,
-    option_keys_to_value, toggle_option_key, use_dropdown_layer, value_to_option_key,
-    value_to_option_keys,
+    DropdownLayer, OptionKey, RemoteQuery, RemoteSearchFn, SelectOption, filter_options_by_query,
+    handle_option_list_key_event, option_key_to_value, option_keys_to_value, same_remote_search,
+    split_by_separators, toggle_option_key, use_dropdown_layer, use_remote_search,
+    value_to_option_key, value_to_option_keys,
+};
+use crate::components::virtual_list::{
+    DEFAULT_OVERSCAN, ScrollAlign, VirtualRange, use_virtual_list,
 };
 use crate::foundation::{
     ClassListExt, SelectClassNames, SelectSemantic, SelectStyles, StyleStringExt, Variant,
@@ -18,6 +26,7 @@ use crate::foundation::{
 use dioxus::events::KeyboardEvent;
 use dioxus::prelude::*;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 /// Re-export of the shared option type so that callers can build option lists
@@ -71,6 +80,18 @@ impl SelectPlacement {
     }
 }
 
+impl From<SelectPlacement> for FloatingPlacement {
+    fn from(placement: SelectPlacement) -> Self {
+        let (side, align) = match placement {
+            SelectPlacement::BottomLeft => (FloatingSide::Bottom, FloatingAlign::Start),
+            SelectPlacement::BottomRight => (FloatingSide::Bottom, FloatingAlign::End),
+            SelectPlacement::TopLeft => (FloatingSide::Top, FloatingAlign::Start),
+            SelectPlacement::TopRight => (FloatingSide::Top, FloatingAlign::End),
+        };
+        FloatingPlacement::new(side, align)
+    }
+}
+
 /// Props for the Select component.
 #[derive(Props, Clone)]
 pub struct SelectProps {
@@ -104,10 +125,34 @@ pub struct SelectProps {
     /// When provided, overrides the default label-based filtering.
     #[props(optional)]
     pub filter_option: Option<Rc<dyn Fn(&str, &SelectOption) -> bool>>,
-    /// Token separators for tags mode (e.g., [",", " "]).
-    /// When user types these characters, a new tag is created.
+    /// Token separators for tags and multiple mode (e.g., [",", " "]).
+    /// Typed or pasted text containing one of them is split into selections:
+    /// every piece becomes a tag in tags mode, while multiple mode only picks
+    /// pieces that name an existing option.
     #[props(optional)]
     pub token_separators: Option<Vec<String>>,
+    /// Called with the search text, after `search_debounce`.
+    #[props(optional)]
+    pub on_search: Option<EventHandler<String>>,
+    /// Delay in milliseconds between the last keystroke and `on_search` /
+    /// `remote_search`. Defaults to 300 with `remote_search` and 0 otherwise.
+    #[props(optional)]
+    pub search_debounce: Option<u32>,
+    /// Load options from a server instead of filtering `options` locally.
+    /// Stale requests are cancelled, and scrolling to the bottom of the popup
+    /// loads the next page. `options` is still used to label preset values.
+    #[props(optional)]
+    pub remote_search: Option<RemoteSearchFn<SelectOption>>,
+    /// Show a loading row in the popup, for callers driving `options` from
+    /// `on_search` themselves.
+    #[props(default)]
+    pub loading: bool,
+    /// Content shown when no option matches; defaults to the locale's empty text.
+    #[props(optional)]
+    pub not_found_content: Option<Element>,
+    /// Called when the option list scrolls.
+    #[props(optional)]
+    pub on_popup_scroll: Option<EventHandler<ScrollEvent>>,
     /// Optional visual status applied to the wrapper.
     #[props(optional)]
     pub status: Option<ControlStatus>,
@@ -171,6 +216,15 @@ pub struct SelectProps {
     /// @deprecated Please use `on_dropdown_visible_change` instead.
     #[props(optional)]
     pub on_open_change: Option<EventHandler<bool>>,
+    /// Only mount the options in view when the list overflows `list_height`.
+    #[props(default = true)]
+    pub r#virtual: bool,
+    /// Height of the option list viewport in pixels.
+    #[props(default = 256.0)]
+    pub list_height: f32,
+    /// Height of a single option in pixels, used by virtual scrolling.
+    #[props(default = 32.0)]
+    pub list_item_height: f32,
 }
 
 impl PartialEq for SelectProps {
@@ -207,6 +261,15 @@ impl PartialEq for SelectProps {
             && self.on_dropdown_visible_change == other.on_dropdown_visible_change
             && self.on_open_change == other.on_open_change
             && self.token_separators == other.token_separators
+            && self.on_search == other.on_search
+            && self.search_debounce == other.search_debounce
+            && self.loading == other.loading
+            && self.not_found_content == other.not_found_content
+            && self.on_popup_scroll == other.on_popup_scroll
+            && self.r#virtual == other.r#virtual
+            && self.list_height == other.list_height
+            && self.list_item_height == other.list_item_height
+            && same_remote_search(&self.remote_search, &other.remote_search)
         // Function pointers cannot be compared for equality
     }
 }
@@ -242,17 +305,29 @@ pub fn Select(props: SelectProps) -> Element {
         dropdown_style,
         on_change,
         on_dropdown_visible_change,
-        filter_option: _,
-        token_separators: _,
+        filter_option,
+        token_separators,
+        on_search,
+        search_debounce,
+        remote_search,
+        loading,
+        not_found_content,
+        on_popup_scroll,
         dropdown_class_name: _,
         dropdown_style_deprecated: _,
         dropdown_match_select_width: _,
         popup_render: _,
-        on_open_change: _,
+        on_open_change,
+        r#virtual,
+        list_height,
+        list_item_height,
     } = props;
 
     let config = use_config();
+    let locale = use_locale();
     let form_control = use_form_item_control();
+    let remote = use_remote_search(remote_search, search_debounce);
+    let searchable = show_search || remote.enabled();
 
     // Resolve if multiple selection is enabled (mode takes precedence over deprecated `multiple`)
     let is_multiple = mode.is_multiple() || multiple;
@@ -307,13 +382,22 @@ pub fn Select(props: SelectProps) -> Element {
     // interfering with normal selection.
     let internal_click_flag: Signal<bool> = use_signal(|| false);
 
+    // Visibility changes go to both the current and the deprecated callback.
+    let on_visible_cb = move |open: bool| {
+        if let Some(cb) = on_dropdown_visible_change {
+            cb.call(open);
+        }
+        if let Some(cb) = on_open_change {
+            cb.call(open);
+        }
+    };
+
     // Document-level click handler for closing the dropdown when clicking
     // outside of the select. This is only compiled for wasm32 targets.
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         let mut open_for_global = open_state;
         let mut internal_flag = internal_click_flag;
-        let on_visible_cb = on_dropdown_visible_change;
         use_effect(move || {
             use wasm_bindgen::{JsCast, closure::Closure};
 
@@ -332,9 +416,7 @@ pub fn Select(props: SelectProps) -> Element {
                             let mut open_signal = open_for_global;
                             if *open_signal.read() {
                                 open_signal.set(false);
-                                if let Some(cb) = on_visible_cb {
-                                    cb.call(false);
-                                }
+                                on_visible_cb(false);
                             }
                         },
                     ));
@@ -355,18 +437,79 @@ pub fn Select(props: SelectProps) -> Element {
     let search_query: Signal<String> = use_signal(String::new);
 
     let open_flag = *open_state.read();
-    let DropdownLayer { z_index, .. } = use_dropdown_layer(open_flag);
+    let DropdownLayer {
+        z_index, position, ..
+    } = use_dropdown_layer(open_flag);
     let current_z = *z_index.read();
+    let measured = position.position();
+    let anchor_id = position.anchor_id();
+    let overlay_id = position.overlay_id();
 
     let placeholder_str = placeholder.unwrap_or_default();
 
-    // Apply search filtering when enabled.
-    let filtered_options: Vec<SelectOption> = if show_search {
+    // Remote results replace the local list; otherwise apply search filtering
+    // when enabled.
+    let filtered_options: Vec<SelectOption> = if remote.enabled() {
+        remote.options()
+    } else if show_search {
         let query = search_query.read().clone();
-        crate::components::select_base::filter_options_by_query(&options, &query)
+        filter_select_options(&options, &query, filter_option.as_deref())
     } else {
         options.clone()
     };
+    let list_loading = loading || remote.loading();
+    let no_options = filtered_options.is_empty();
+    let remote_error = remote.error();
+
+    // Debounced search shared by the search box and the first open in remote
+    // mode.
+    let run_search = move |query: String| {
+        remote.search(query, None, move |request: &RemoteQuery| {
+            if let Some(cb) = on_search {
+                cb.call(request.query.clone());
+            }
+        });
+    };
+    let fetch_on_open = move |open: bool| {
+        if open && remote.enabled() && !remote.searched() {
+            run_search(String::new());
+        }
+    };
+    let handle_popup_scroll = move |evt: ScrollEvent| {
+        remote.handle_scroll(&evt);
+        if let Some(cb) = on_popup_scroll {
+            cb.call(evt);
+        }
+    };
+
+    // Window the option list when it overflows the viewport.
+    let option_list = use_virtual_list();
+    let virtual_options =
+        r#virtual && filtered_options.len() as f32 * list_item_height > list_height;
+    let option_range = if virtual_options {
+        option_list.window(
+            filtered_options.len(),
+            list_item_height as f64,
+            list_height as f64,
+            DEFAULT_OVERSCAN,
+        )
+    } else {
+        VirtualRange {
+            start: 0,
+            end: filtered_options.len(),
+            ..Default::default()
+        }
+    };
+    let option_list_style = if virtual_options {
+        format!("max-height: {list_height}px; overflow-y: auto;")
+    } else {
+        String::new()
+    };
+    let option_item_style = if virtual_options {
+        format!("height: {list_item_height}px; box-sizing: border-box;")
+    } else {
+        String::new()
+    };
 
     // Build wrapper classes.
     let mut class_list = vec!["adui-select".to_string()];
@@ -405,14 +548,62 @@ pub fn Select(props: SelectProps) -> Element {
     let mut style_attr = style.unwrap_or_default();
     style_attr.append_semantic(&styles, SelectSemantic::Root);
 
+    // Labels of options picked from remote pages, which a later search may
+    // no longer return.
+    let picked_labels: Signal<HashMap<OptionKey, String>> = use_signal(HashMap::new);
+
     // Helper to find the label for a given key.
     let find_label = |key: &str| -> String {
         options
             .iter()
+            .chain(filtered_options.iter())
             .find(|opt| opt.key == key)
             .map(|opt| opt.label.clone())
+            .or_else(|| picked_labels.read().get(key).cloned())
             .unwrap_or_else(|| key.to_string())
     };
+    let remember_label = move |opt: &SelectOption| {
+        if remote.enabled() {
+            let mut labels = picked_labels;
+            labels.write().insert(opt.key.clone(), opt.label.clone());
+        }
+    };
+
+    // Selections typed or pasted with `token_separators`.
+    let separators = token_separators.unwrap_or_default();
+    let split_tokens = move |text: &str| -> Option<Vec<String>> {
+        if multiple_flag {
+            split_by_separators(text, &separators)
+        } else {
+            None
+        }
+    };
+    let add_tokens = {
+        let form_control = form_control.clone();
+        let options = options.clone();
+        let selected_keys = selected_keys.clone();
+        move |tokens: Vec<String>| {
+            let tags = matches!(mode, SelectMode::Tags);
+            let mut known = options.clone();
+            known.extend(remote.options());
+            let mut next_keys = selected_keys.clone();
+            for key in keys_for_tokens(&tokens, &known, tags) {
+                if !next_keys.contains(&key) {
+                    next_keys.push(key);
+                }
+            }
+            if next_keys != selected_keys {
+                apply_selected_keys(
+                    &form_control,
+                    multiple_flag,
+                    controlled_by_prop,
+                    &internal_selected,
+                    on_change,
+                    next_keys,
+                );
+            }
+        }
+    };
 
     // Clone form_control and selected_keys early for use in display_node closures
     let form_for_tags = form_control.clone();
@@ -461,9 +652,20 @@ pub fn Select(props: SelectProps) -> Element {
                         input {
                             class: "adui-select-selection-search-input",
                             value: "{tags_input.read()}",
-                            oninput: move |evt| {
-                                let mut sig = tags_input;
-                                sig.set(evt.value());
+                            oninput: {
+                                let split_tokens = split_tokens.clone();
+                                let add_tokens = add_tokens.clone();
+                                move |evt: Event<FormData>| {
+                                    let mut sig = tags_input;
+                                    let text = evt.value();
+                                    match split_tokens(&text) {
+                                        Some(tokens) => {
+                                            add_tokens(tokens);
+                                            sig.set(String::new());
+                                        }
+                                        None => sig.set(text),
+                                    }
+                                }
                             },
                             onkeydown: move |evt: KeyboardEvent| {
                                 use dioxus::prelude::Key;
@@ -524,20 +726,32 @@ pub fn Select(props: SelectProps) -> Element {
         list.join(" ")
     };
 
-    let min_width_style = if popup_match_select_width {
-        "min-width: 100%;"
-    } else {
-        ""
+    let min_width_style = match (popup_match_select_width, measured) {
+        (false, _) => String::new(),
+        // Fixed positioning resolves percentages against the viewport, so use
+        // the measured trigger width instead.
+        (true, Some(pos)) => format!("min-width: {:.1}px;", pos.anchor.width),
+        (true, None) => "min-width: 100%;".to_string(),
+    };
+
+    let position_style = match measured {
+        Some(pos) => pos.to_style(),
+        None => format!("position: absolute; {}", placement.as_style()),
     };
 
     let dropdown_style_attr = format!(
-        "position: absolute; {} {} z-index: {}; {}",
-        placement.as_style(),
+        "{} {} z-index: {}; {}",
+        position_style,
         min_width_style,
         current_z,
         dropdown_style.unwrap_or_default()
     );
 
+    let position_options = PositionOptions {
+        offset: 4.0,
+        ..PositionOptions::default()
+    };
+
     // Default suffix icon
     let suffix_element = suffix_icon.unwrap_or_else(|| {
         rsx! {
@@ -547,10 +761,9 @@ pub fn Select(props: SelectProps) -> Element {
         }
     });
 
-    let on_visible_cb = on_dropdown_visible_change;
-
     rsx! {
         div {
+            id: "{anchor_id}",
             class: "adui-select-root",
             style: "position: relative; display: inline-block;",
             div {
@@ -573,9 +786,8 @@ pub fn Select(props: SelectProps) -> Element {
                     let current = *open_signal.read();
                     let next = !current;
                     open_signal.set(next);
-                    if let Some(cb) = on_visible_cb {
-                        cb.call(next);
-                    }
+                    on_visible_cb(next);
+                    fetch_on_open(next);
                 },
                 onkeydown: move |evt: KeyboardEvent| {
                     if is_disabled_flag {
@@ -590,9 +802,8 @@ pub fn Select(props: SelectProps) -> Element {
                                 evt.prevent_default();
                                 let mut open_signal = open_for_keydown;
                                 open_signal.set(true);
-                                if let Some(cb) = on_visible_cb {
-                                    cb.call(true);
-                                }
+                                on_visible_cb(true);
+                                fetch_on_open(true);
                             }
                             Key::Escape => {
                                 // 没有打开时按 Escape 不做任何事。
@@ -605,9 +816,7 @@ pub fn Select(props: SelectProps) -> Element {
                     if matches!(evt.key(), Key::Escape) {
                         let mut open_signal = open_for_keydown;
                         open_signal.set(false);
-                        if let Some(cb) = on_visible_cb {
-                            cb.call(false);
-                        }
+                        on_visible_cb(false);
                         return;
                     }
 
@@ -621,12 +830,20 @@ pub fn Select(props: SelectProps) -> Element {
                     let mut flag = internal_click_for_keydown;
                     flag.set(true);
 
-                    if let Some(idx) = handle_option_list_key_event(&evt, opts_len, &active_for_keydown) {
+                    let picked = handle_option_list_key_event(&evt, opts_len, &active_for_keydown);
+
+                    // Keep the active option in view while navigating.
+                    if virtual_options && let Some(active) = *active_for_keydown.read() {
+                        option_list.scroll_to_index(active, ScrollAlign::Auto);
+                    }
+
+                    if let Some(idx) = picked {
                         if idx < opts_len {
                             let opt = &filtered_options[idx];
                             if opt.disabled {
                                 return;
                             }
+                            remember_label(opt);
 
                             let key = opt.key.clone();
                             let current_keys = selected_keys.clone();
@@ -648,9 +865,7 @@ pub fn Select(props: SelectProps) -> Element {
                             if !multiple_flag {
                                 let mut open_signal = open_for_keydown;
                                 open_signal.set(false);
-                                if let Some(cb) = on_visible_cb {
-                                    cb.call(false);
-                                }
+                                on_visible_cb(false);
                             }
                         }
                     }
@@ -680,29 +895,61 @@ pub fn Select(props: SelectProps) -> Element {
             }
             if open_flag {
                 div {
+                    id: "{overlay_id}",
                     class: "{dropdown_class_attr}",
                     style: "{dropdown_style_attr}",
                     role: "listbox",
+                    onmounted: move |_| {
+                        position.update(placement.into(), position_options);
+                    },
                     "aria-multiselectable": multiple_flag,
                     onclick: move |_| {
                         // Prevent clicks inside dropdown from closing it
                         let mut flag = internal_click_flag;
                         flag.set(true);
                     },
-                    if show_search {
+                    onscroll: handle_popup_scroll,
+                    if searchable {
                         div { class: "adui-select-search",
                             input {
                                 class: "adui-select-search-input",
                                 value: "{search_for_input.read()}",
                                 oninput: move |evt| {
                                     let mut signal = search_for_input;
-                                    signal.set(evt.value());
+                                    let text = evt.value();
+                                    if let Some(tokens) = split_tokens(&text) {
+                                        // The text became tags, so the search
+                                        // for it is dropped rather than rerun.
+                                        add_tokens(tokens);
+                                        signal.set(String::new());
+                                        remote.cancel();
+                                        return;
+                                    }
+                                    signal.set(text.clone());
+                                    run_search(text);
                                 }
                             }
                         }
                     }
-                    ul { class: "adui-select-item-list",
-                        {filtered_options.iter().enumerate().map(|(index, opt)| {
+                    ul {
+                        class: "adui-select-item-list",
+                        style: "{option_list_style}",
+                        onmounted: move |evt| option_list.handle_mounted(&evt),
+                        onscroll: move |evt| {
+                            if virtual_options {
+                                option_list.handle_scroll(&evt);
+                            }
+                            handle_popup_scroll(evt);
+                        },
+                        if option_range.offset_top > 0.0 {
+                            li {
+                                class: "adui-select-item-list-spacer",
+                                "aria-hidden": true,
+                                style: "height: {option_range.offset_top}px;",
+                            }
+                        }
+                        {filtered_options[option_range.start..option_range.end].iter().enumerate().map(|(local_index, opt)| {
+                            let index = option_range.start + local_index;
                             let key = opt.key.clone();
                             let label = opt.label.clone();
                             let disabled_opt = opt.disabled || is_disabled_flag;
@@ -717,6 +964,7 @@ pub fn Select(props: SelectProps) -> Element {
                             let internal_selected_for_click = internal_selected;
                             let open_for_click = open_state;
                             let internal_click_for_item = internal_click_flag;
+                            let option_for_click = opt.clone();
 
                             rsx! {
                                 li {
@@ -734,6 +982,7 @@ pub fn Select(props: SelectProps) -> Element {
                                         classes.join(" ")
                                     },
                                     role: "option",
+                                    style: "{option_item_style}",
                                     "aria-selected": is_selected,
                                     onclick: move |_| {
                                         if disabled_opt {
@@ -743,6 +992,7 @@ pub fn Select(props: SelectProps) -> Element {
                                         // handler does not treat this as outside.
                                         let mut flag = internal_click_for_item;
                                         flag.set(true);
+                                        remember_label(&option_for_click);
 
                                         let current_keys = selected_snapshot.clone();
                                         let next_keys = if multiple_flag {
@@ -763,9 +1013,7 @@ pub fn Select(props: SelectProps) -> Element {
                                         if !multiple_flag {
                                             let mut open_signal = open_for_click;
                                             open_signal.set(false);
-                                            if let Some(cb) = on_visible_cb {
-                                                cb.call(false);
-                                            }
+                                            on_visible_cb(false);
                                         }
                                     },
                                     "{label}"
@@ -777,6 +1025,22 @@ pub fn Select(props: SelectProps) -> Element {
                                 }
                             }
                         })}
+                        if option_range.offset_bottom > 0.0 {
+                            li {
+                                class: "adui-select-item-list-spacer",
+                                "aria-hidden": true,
+                                style: "height: {option_range.offset_bottom}px;",
+                            }
+                        }
+                    }
+                    if list_loading {
+                        div { class: "adui-select-item-loading", "{locale.global.loading}" }
+                    } else if let Some(message) = remote_error {
+                        div { class: "adui-select-item-empty adui-select-item-error", "{message}" }
+                    } else if no_options {
+                        div { class: "adui-select-item-empty",
+                            {not_found_content.unwrap_or_else(|| rsx! { "{locale.empty.description}" })}
+                        }
                     }
                 }
             }
@@ -784,6 +1048,45 @@ pub fn Select(props: SelectProps) -> Element {
     }
 }
 
+type OptionFilter = dyn Fn(&str, &SelectOption) -> bool;
+
+/// Local search: `filter_option` when given, otherwise a case-insensitive
+/// label match.
+fn filter_select_options(
+    options: &[SelectOption],
+    query: &str,
+    filter_option: Option<&OptionFilter>,
+) -> Vec<SelectOption> {
+    match filter_option {
+        Some(filter) if !query.trim().is_empty() => options
+            .iter()
+            .filter(|opt| filter(query, opt))
+            .cloned()
+            .collect(),
+        Some(_) => options.to_vec(),
+        None => filter_options_by_query(options, query),
+    }
+}
+
+/// Keys for pieces split off by `token_separators`. A piece naming an option
+/// by label (case-insensitively) or key selects that option; other pieces
+/// become new tags in tags mode and are dropped otherwise.
+fn keys_for_tokens(tokens: &[String], options: &[SelectOption], tags: bool) -> Vec<OptionKey> {
+    tokens
+        .iter()
+        .filter_map(|token| {
+            let lower = token.to_lowercase();
+            options
+                .iter()
+                .find(|opt| {
+                    !opt.disabled && (opt.key == *token || opt.label.to_lowercase() == lower)
+                })
+                .map(|opt| opt.key.clone())
+                .or_else(|| tags.then(|| token.clone()))
+        })
+        .collect()
+}
+
 fn apply_selected_keys(
     form_control: &Option<FormItemControlContext>,
     multiple: bool,
@@ -899,4 +1202,39 @@ mod tests {
         assert!(!SelectMode::Single.allows_input());
         assert!(!SelectMode::Multiple.allows_input());
     }
+
+    fn fruit(key: &str, label: &str) -> SelectOption {
+        SelectOption {
+            key: key.into(),
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    #[test]
+    fn filter_select_options_prefers_custom_filter() {
+        let options = vec![fruit("apple", "Apple"), fruit("pear", "Pear")];
+        let by_key = |query: &str, opt: &SelectOption| opt.key.starts_with(query);
+        let keys: Vec<String> = filter_select_options(&options, "pe", Some(&by_key))
+            .into_iter()
+            .map(|o| o.key)
+            .collect();
+        assert_eq!(keys, vec!["pear".to_string()]);
+        assert_eq!(filter_select_options(&options, "", Some(&by_key)).len(), 2);
+        assert_eq!(filter_select_options(&options, "APP", None).len(), 1);
+    }
+
+    #[test]
+    fn keys_for_tokens_maps_labels_and_creates_tags() {
+        let options = vec![fruit("1", "Apple"), fruit("2", "Pear")];
+        let tokens = vec!["apple".to_string(), "2".to_string(), "kiwi".to_string()];
+        assert_eq!(
+            keys_for_tokens(&tokens, &options, false),
+            vec!["1".to_string(), "2".to_string()]
+        );
+        assert_eq!(
+            keys_for_tokens(&tokens, &options, true),
+            vec!["1".to_string(), "2".to_string(), "kiwi".to_string()]
+        );
+    }
 }
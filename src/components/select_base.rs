@@ -1,10 +1,17 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
 
+use dioxus::core::Task;
 use dioxus::{events::KeyboardEvent, prelude::*};
 
-use crate::components::form::{form_value_to_radio_key, form_value_to_string_vec};
+use crate::components::floating_position::{FloatingPositioner, use_floating_positioner};
+use crate::components::form::form_value_to_radio_key;
 use crate::components::overlay::{OverlayKey, OverlayKind, use_overlay};
+use crate::components::sleep::sleep_ms;
 
 /// Shared key type used by selector-like components.
 ///
@@ -55,10 +62,17 @@ pub fn option_key_to_value(key: &OptionKey) -> Value {
 
 /// Convert a JSON value into a vector of option keys.
 ///
-/// This is primarily used for multi-select scenarios and internally reuses
-/// `form_value_to_string_vec` (which expects an array of strings).
+/// This is primarily used for multi-select scenarios. Items are converted
+/// like [`value_to_option_key`], so numeric and boolean values still match
+/// their options.
 pub fn value_to_option_keys(val: Option<Value>) -> Vec<OptionKey> {
-    form_value_to_string_vec(val)
+    match val {
+        Some(Value::Array(items)) => items
+            .into_iter()
+            .filter_map(|item| form_value_to_radio_key(Some(item)))
+            .collect(),
+        _ => Vec::new(),
+    }
 }
 
 /// Convert a slice of keys into a JSON array value.
@@ -126,6 +140,7 @@ pub struct DropdownLayer {
     #[allow(dead_code)] // Kept for future overlay-key based control of dropdown layers.
     pub key: Signal<Option<OverlayKey>>,
     pub z_index: Signal<i32>,
+    pub position: FloatingPositioner,
 }
 
 /// Generic overlay handle for floating layers (tooltip, popover, dropdown, ...).
@@ -133,6 +148,9 @@ pub struct DropdownLayer {
 pub struct FloatingLayer {
     pub key: Signal<Option<OverlayKey>>,
     pub z_index: Signal<i32>,
+    /// Collision-aware positioning for the overlay, see
+    /// [`FloatingPositioner`]. Stays `None` until the overlay is measured.
+    pub position: FloatingPositioner,
 }
 
 /// Internal helper: register/unregister a floating entry with the global
@@ -141,11 +159,14 @@ pub struct FloatingLayer {
 /// - 当 `open` 为 true 时，确保存在对应 kind 的 overlay entry，并通过返回的
 ///   [`FloatingLayer`] 暴露 z-index；
 /// - 当 `open` 变为 false 时，释放对应 entry，便于 z-index 复用；
-/// - 若当前树中不存在 OverlayProvider，则回退到固定 z-index（1000）。
+/// - 若当前树中不存在 OverlayProvider，则回退到固定 z-index（1000）；
+/// - 同时分配一个 [`FloatingPositioner`]，组件在浮层挂载后调用
+///   `position.update(..)` 即可获得基于实测尺寸的翻转/平移定位。
 pub fn use_floating_layer(kind: OverlayKind, open: bool) -> FloatingLayer {
     let overlay = use_overlay();
     let entry_key: Signal<Option<OverlayKey>> = use_signal(|| None);
     let z_index: Signal<i32> = use_signal(|| 1000);
+    let position = use_floating_positioner(open);
 
     {
         let overlay = overlay.clone();
@@ -171,6 +192,7 @@ pub fn use_floating_layer(kind: OverlayKind, open: bool) -> FloatingLayer {
     FloatingLayer {
         key: entry_key,
         z_index,
+        position,
     }
 }
 
@@ -180,9 +202,17 @@ pub fn use_floating_layer(kind: OverlayKind, open: bool) -> FloatingLayer {
 /// 保持现有 Select/TreeSelect/Cascader 等调用点稳定，也方便后续在下拉类 Overlay
 /// 上做额外元信息扩展。
 pub fn use_dropdown_layer(open: bool) -> DropdownLayer {
-    let FloatingLayer { key, z_index } = use_floating_layer(OverlayKind::Dropdown, open);
+    let FloatingLayer {
+        key,
+        z_index,
+        position,
+    } = use_floating_layer(OverlayKind::Dropdown, open);
 
-    DropdownLayer { key, z_index }
+    DropdownLayer {
+        key,
+        z_index,
+        position,
+    }
 }
 
 /// Internal helper: compute the next active index in a linear option list.
@@ -256,6 +286,578 @@ pub fn handle_option_list_key_event(
     }
 }
 
+// ---- Async lazy loading ---------------------------------------------------
+
+/// Future returned by an async `load_data` callback: the children of the
+/// requested node, or an error message shown on the node.
+pub type LoadDataFuture = Pin<Box<dyn Future<Output = Result<Vec<OptionNode>, String>>>>;
+
+/// Async loader for the children of a tree-shaped option node, shared by
+/// `Tree`, `TreeSelect` and `Cascader`.
+pub type LoadDataFn = Rc<dyn Fn(OptionNode) -> LoadDataFuture>;
+
+/// Load status of a lazily loaded node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NodeLoadStatus {
+    Loading,
+    Loaded,
+    Failed(String),
+}
+
+/// Bookkeeping for lazily loaded children.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LazyLoadState {
+    status: HashMap<OptionKey, NodeLoadStatus>,
+    children: HashMap<OptionKey, Vec<OptionNode>>,
+    /// Loaded keys in completion order.
+    loaded: Vec<OptionKey>,
+}
+
+impl LazyLoadState {
+    /// Status of `key`. When `loaded_keys` is controlled it decides which
+    /// nodes count as loaded; in-flight and failed states are always tracked
+    /// internally.
+    pub fn status(&self, key: &str, loaded_keys: Option<&[OptionKey]>) -> Option<NodeLoadStatus> {
+        match self.status.get(key) {
+            Some(NodeLoadStatus::Loaded) | None => match loaded_keys {
+                Some(keys) => keys
+                    .iter()
+                    .any(|k| k == key)
+                    .then_some(NodeLoadStatus::Loaded),
+                None => self.status.get(key).cloned(),
+            },
+            other => other.cloned(),
+        }
+    }
+
+    /// Mark `key` as loading. Returns `false` when it is already loading or
+    /// loaded, in which case no request should be issued.
+    pub fn begin(&mut self, key: &str, loaded_keys: Option<&[OptionKey]>) -> bool {
+        match self.status(key, loaded_keys) {
+            Some(NodeLoadStatus::Loading) | Some(NodeLoadStatus::Loaded) => false,
+            _ => {
+                self.status.insert(key.to_string(), NodeLoadStatus::Loading);
+                true
+            }
+        }
+    }
+
+    /// Record the outcome of a load started with [`LazyLoadState::begin`].
+    pub fn finish(&mut self, key: &str, result: Result<Vec<OptionNode>, String>) {
+        match result {
+            Ok(children) => {
+                self.children.insert(key.to_string(), children);
+                self.status.insert(key.to_string(), NodeLoadStatus::Loaded);
+                self.loaded.retain(|k| k != key);
+                self.loaded.push(key.to_string());
+            }
+            Err(message) => {
+                self.status
+                    .insert(key.to_string(), NodeLoadStatus::Failed(message));
+            }
+        }
+    }
+
+    /// Keys loaded so far, in completion order.
+    pub fn loaded_keys(&self) -> Vec<OptionKey> {
+        self.loaded.clone()
+    }
+
+    /// Graft loaded children onto `nodes`. Nodes that already carry children
+    /// (for example because the caller merged them into its own data) are
+    /// left untouched.
+    pub fn merge(&self, nodes: &[OptionNode]) -> Vec<OptionNode> {
+        nodes
+            .iter()
+            .map(|node| {
+                let children = match self.children.get(&node.key) {
+                    Some(loaded) if node.children.is_empty() => loaded,
+                    _ => &node.children,
+                };
+                OptionNode {
+                    children: self.merge(children),
+                    ..node.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+/// Handle driving async `load_data` requests for a tree-shaped component.
+#[derive(Clone, Copy)]
+pub struct LazyNodeLoader {
+    state: Signal<LazyLoadState>,
+    load_data: CopyValue<Option<LoadDataFn>>,
+    loaded_keys: CopyValue<Option<Vec<OptionKey>>>,
+    on_load: CopyValue<Option<EventHandler<Vec<OptionKey>>>>,
+}
+
+impl LazyNodeLoader {
+    /// Whether an async loader is configured.
+    pub fn enabled(&self) -> bool {
+        self.load_data.read().is_some()
+    }
+
+    /// Current load status of `key`.
+    pub fn status(&self, key: &str) -> Option<NodeLoadStatus> {
+        let loaded_keys = self.loaded_keys.read();
+        self.state.read().status(key, loaded_keys.as_deref())
+    }
+
+    /// Whether a childless node may still gain children from `load_data`.
+    pub fn can_load(&self, key: &str) -> bool {
+        self.enabled() && self.status(key) != Some(NodeLoadStatus::Loaded)
+    }
+
+    /// Graft loaded children onto `nodes`.
+    pub fn merge(&self, nodes: &[OptionNode]) -> Vec<OptionNode> {
+        self.state.read().merge(nodes)
+    }
+
+    /// Request the children of `node`. Does nothing while a request for the
+    /// same node is in flight or once it is loaded; a failed node is retried.
+    pub fn load(&self, node: &OptionNode) {
+        let Some(load_data) = self.load_data.read().clone() else {
+            return;
+        };
+        let mut state = self.state;
+        let started = {
+            let loaded_keys = self.loaded_keys.read();
+            state.write().begin(&node.key, loaded_keys.as_deref())
+        };
+        if !started {
+            return;
+        }
+        let key = node.key.clone();
+        let request = load_data(node.clone());
+        let on_load = self.on_load;
+        spawn(async move {
+            let result = request.await;
+            let loaded = result.is_ok();
+            state.write().finish(&key, result);
+            if loaded && let Some(cb) = *on_load.read() {
+                cb.call(state.peek().loaded_keys());
+            }
+        });
+    }
+}
+
+/// Create a lazy loader for the current render's `load_data` configuration.
+pub fn use_lazy_node_loader(
+    load_data: Option<LoadDataFn>,
+    loaded_keys: Option<Vec<OptionKey>>,
+    on_load: Option<EventHandler<Vec<OptionKey>>>,
+) -> LazyNodeLoader {
+    let state = use_signal(LazyLoadState::default);
+    let mut load_data_slot = use_hook(|| CopyValue::new(None));
+    let mut loaded_keys_slot = use_hook(|| CopyValue::new(None));
+    let mut on_load_slot = use_hook(|| CopyValue::new(None));
+    load_data_slot.set(load_data);
+    loaded_keys_slot.set(loaded_keys);
+    on_load_slot.set(on_load);
+    LazyNodeLoader {
+        state,
+        load_data: load_data_slot,
+        loaded_keys: loaded_keys_slot,
+        on_load: on_load_slot,
+    }
+}
+
+// ---- Remote search ---------------------------------------------------------
+
+/// Debounce applied to remote searches when the component does not set one.
+pub const DEFAULT_SEARCH_DEBOUNCE_MS: u32 = 300;
+
+/// Distance from the bottom of the popup, in pixels, at which the next remote
+/// page is requested.
+pub const LOAD_MORE_THRESHOLD: f64 = 24.0;
+
+/// Request passed to a remote search callback.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RemoteQuery {
+    /// Text typed by the user.
+    pub query: String,
+    /// 1-based page number. Scrolling to the bottom of the popup requests the
+    /// next page of the same query.
+    pub page: usize,
+    /// Trigger character for `Mentions`, `None` elsewhere.
+    pub prefix: Option<char>,
+}
+
+/// One page of remote results.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemotePage<T> {
+    pub options: Vec<T>,
+    /// Whether another page can be requested.
+    pub has_more: bool,
+}
+
+/// Future returned by a remote search callback: one page of options, or an
+/// error message shown in the popup.
+pub type RemoteSearchFuture<T> = Pin<Box<dyn Future<Output = Result<RemotePage<T>, String>>>>;
+
+/// Remote option source shared by `Select`, `AutoComplete` and `Mentions`.
+pub type RemoteSearchFn<T> = Rc<dyn Fn(RemoteQuery) -> RemoteSearchFuture<T>>;
+
+/// Props equality for [`RemoteSearchFn`]: the same callback instance.
+pub(crate) fn same_remote_search<T>(
+    a: &Option<RemoteSearchFn<T>>,
+    b: &Option<RemoteSearchFn<T>>,
+) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Results of a remote search, accumulated page by page.
+///
+/// Every request gets a token; a result carrying an older token belongs to a
+/// superseded query and is dropped.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoteSearchState<T> {
+    request: Option<RemoteQuery>,
+    options: Vec<T>,
+    has_more: bool,
+    loading: bool,
+    error: Option<String>,
+    token: u64,
+}
+
+impl<T> Default for RemoteSearchState<T> {
+    fn default() -> Self {
+        Self {
+            request: None,
+            options: Vec::new(),
+            has_more: false,
+            loading: false,
+            error: None,
+            token: 0,
+        }
+    }
+}
+
+impl<T> RemoteSearchState<T> {
+    /// Start a search for `query`, discarding earlier results and any
+    /// request still in flight. Returns the token and the first page request.
+    pub fn begin_search(&mut self, query: String, prefix: Option<char>) -> (u64, RemoteQuery) {
+        let request = RemoteQuery {
+            query,
+            page: 1,
+            prefix,
+        };
+        self.token += 1;
+        self.request = Some(request.clone());
+        self.options.clear();
+        self.has_more = false;
+        self.loading = true;
+        self.error = None;
+        (self.token, request)
+    }
+
+    /// Request the page after the last loaded one. Returns `None` before the
+    /// first search, while a request is in flight or when the server reported
+    /// no more pages.
+    pub fn begin_next_page(&mut self) -> Option<(u64, RemoteQuery)> {
+        if self.loading || !self.has_more {
+            return None;
+        }
+        let request = self.request.as_mut()?;
+        request.page += 1;
+        self.token += 1;
+        self.loading = true;
+        self.error = None;
+        Some((self.token, request.clone()))
+    }
+
+    /// Drop the request in flight, if any. A cancelled first page forgets the
+    /// search; a cancelled follow-up page is requested again on the next
+    /// scroll to the bottom.
+    pub fn cancel(&mut self) {
+        if !self.loading {
+            return;
+        }
+        self.token += 1;
+        self.loading = false;
+        match self.request.as_mut() {
+            Some(request) if request.page > 1 => {
+                request.page -= 1;
+                self.has_more = true;
+            }
+            _ => self.request = None,
+        }
+    }
+
+    /// Record the outcome of a request. Returns `false` when `token` is stale
+    /// and the result was ignored.
+    pub fn finish(&mut self, token: u64, result: Result<RemotePage<T>, String>) -> bool {
+        if token != self.token {
+            return false;
+        }
+        self.loading = false;
+        match result {
+            Ok(page) => {
+                self.options.extend(page.options);
+                self.has_more = page.has_more;
+            }
+            Err(message) => {
+                // A failed follow-up page is requested again on the next
+                // scroll to the bottom.
+                if let Some(request) = self.request.as_mut()
+                    && request.page > 1
+                {
+                    request.page -= 1;
+                    self.has_more = true;
+                }
+                self.error = Some(message);
+            }
+        }
+        true
+    }
+
+    /// Options loaded so far, in page order.
+    pub fn options(&self) -> &[T] {
+        &self.options
+    }
+
+    pub fn loading(&self) -> bool {
+        self.loading
+    }
+
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    /// Error of the last request, if it failed.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// The last search, with the page most recently requested.
+    pub fn request(&self) -> Option<&RemoteQuery> {
+        self.request.as_ref()
+    }
+
+    /// Whether a finished search came back empty.
+    pub fn not_found(&self) -> bool {
+        self.request.is_some() && !self.loading && self.error.is_none() && self.options.is_empty()
+    }
+}
+
+/// Whether a scroll container is within [`LOAD_MORE_THRESHOLD`] of its end.
+pub fn is_scrolled_to_bottom(scroll_top: f64, client_height: f64, scroll_height: f64) -> bool {
+    scroll_height > client_height
+        && scroll_top + client_height >= scroll_height - LOAD_MORE_THRESHOLD
+}
+
+/// Handle driving debounced remote searches for a selector-like component.
+///
+/// Without a `fetch` callback the handle only debounces `on_search`, which
+/// keeps the option list in the caller's hands.
+pub struct RemoteSearch<T: 'static> {
+    state: Signal<RemoteSearchState<T>>,
+    fetch: CopyValue<Option<RemoteSearchFn<T>>>,
+    debounce_ms: CopyValue<u32>,
+    task: CopyValue<Option<Task>>,
+}
+
+impl<T: 'static> Clone for RemoteSearch<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for RemoteSearch<T> {}
+
+impl<T: Clone + 'static> RemoteSearch<T> {
+    /// Whether a remote `fetch` callback is configured.
+    pub fn enabled(&self) -> bool {
+        self.fetch.read().is_some()
+    }
+
+    /// Options loaded so far.
+    pub fn options(&self) -> Vec<T> {
+        self.state.read().options().to_vec()
+    }
+
+    pub fn loading(&self) -> bool {
+        self.state.read().loading()
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.state.read().error().map(str::to_string)
+    }
+
+    pub fn not_found(&self) -> bool {
+        self.state.read().not_found()
+    }
+
+    /// Whether a search has been issued since the component mounted.
+    pub fn searched(&self) -> bool {
+        self.state.read().request().is_some()
+    }
+
+    /// Search for `query` once typing pauses for the debounce delay.
+    ///
+    /// Each call cancels the pending search and any request still in flight.
+    /// `notify` runs when the debounce settles, before the fetch starts, and
+    /// is where components emit their `on_search` event.
+    pub fn search(
+        &self,
+        query: String,
+        prefix: Option<char>,
+        notify: impl FnOnce(&RemoteQuery) + 'static,
+    ) {
+        let mut slot = self.task;
+        if let Some(task) = slot.write().take() {
+            task.cancel();
+        }
+        let fetch = self.fetch.read().clone();
+        let delay = *self.debounce_ms.read();
+        let mut state = self.state;
+        let (token, request) = match fetch {
+            Some(_) => state.write().begin_search(query, prefix),
+            None => (
+                0,
+                RemoteQuery {
+                    query,
+                    page: 1,
+                    prefix,
+                },
+            ),
+        };
+        if delay == 0 {
+            notify(&request);
+            if let Some(fetch) = fetch {
+                self.spawn_fetch(token, fetch(request));
+            }
+            return;
+        }
+        let task = spawn(async move {
+            sleep_ms(delay).await;
+            notify(&request);
+            if let Some(fetch) = fetch {
+                let result = fetch(request).await;
+                state.write().finish(token, result);
+            }
+        });
+        slot.set(Some(task));
+    }
+
+    /// Drop the pending search and any request still in flight without
+    /// notifying, e.g. when the typed text was turned into tags.
+    pub fn cancel(&self) {
+        let mut slot = self.task;
+        if let Some(task) = slot.write().take() {
+            task.cancel();
+        }
+        let mut state = self.state;
+        state.write().cancel();
+    }
+
+    /// Request the next page of the current search, if there is one.
+    pub fn load_more(&self) {
+        let Some(fetch) = self.fetch.read().clone() else {
+            return;
+        };
+        let mut state = self.state;
+        let Some((token, request)) = state.write().begin_next_page() else {
+            return;
+        };
+        self.spawn_fetch(token, fetch(request));
+    }
+
+    /// Load the next page when a popup scroll reaches the bottom.
+    pub fn handle_scroll(&self, evt: &ScrollEvent) {
+        if is_scrolled_to_bottom(
+            evt.scroll_top(),
+            f64::from(evt.client_height()),
+            f64::from(evt.scroll_height()),
+        ) {
+            self.load_more();
+        }
+    }
+
+    fn spawn_fetch(&self, token: u64, request: RemoteSearchFuture<T>) {
+        let mut state = self.state;
+        let task = spawn(async move {
+            let result = request.await;
+            state.write().finish(token, result);
+        });
+        let mut slot = self.task;
+        slot.set(Some(task));
+    }
+}
+
+/// Create a remote search handle for the current render's configuration.
+///
+/// `debounce_ms` falls back to [`DEFAULT_SEARCH_DEBOUNCE_MS`] when `fetch` is
+/// set and to no delay otherwise, so plain `on_search` handlers keep firing on
+/// every keystroke.
+pub fn use_remote_search<T: Clone + 'static>(
+    fetch: Option<RemoteSearchFn<T>>,
+    debounce_ms: Option<u32>,
+) -> RemoteSearch<T> {
+    let state = use_signal(RemoteSearchState::default);
+    let mut fetch_slot = use_hook(|| CopyValue::new(None));
+    let mut debounce_slot = use_hook(|| CopyValue::new(0));
+    let task = use_hook(|| CopyValue::new(None));
+    let delay = debounce_ms.unwrap_or(if fetch.is_some() {
+        DEFAULT_SEARCH_DEBOUNCE_MS
+    } else {
+        0
+    });
+    fetch_slot.set(fetch);
+    debounce_slot.set(delay);
+    RemoteSearch {
+        state,
+        fetch: fetch_slot,
+        debounce_ms: debounce_slot,
+        task,
+    }
+}
+
+/// Split typed or pasted text on any of `separators`.
+///
+/// Returns `None` when the text contains no separator, so the caller keeps it
+/// as the current input; otherwise the trimmed, non-empty pieces.
+pub fn split_by_separators(text: &str, separators: &[String]) -> Option<Vec<String>> {
+    let separators: Vec<&str> = separators
+        .iter()
+        .map(String::as_str)
+        .filter(|sep| !sep.is_empty())
+        .collect();
+    if !separators.iter().any(|sep| text.contains(sep)) {
+        return None;
+    }
+    let mut pieces = vec![text.to_string()];
+    for sep in separators {
+        pieces = pieces
+            .iter()
+            .flat_map(|piece| piece.split(sep).map(str::to_string))
+            .collect();
+    }
+    Some(
+        pieces
+            .into_iter()
+            .map(|piece| piece.trim().to_string())
+            .filter(|piece| !piece.is_empty())
+            .collect(),
+    )
+}
+
+/// Find a node anywhere in a tree of options.
+pub fn find_option_node<'a>(nodes: &'a [OptionNode], key: &str) -> Option<&'a OptionNode> {
+    nodes.iter().find_map(|node| {
+        if node.key == key {
+            Some(node)
+        } else {
+            find_option_node(&node.children, key)
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -274,6 +876,10 @@ mod tests {
         let json = option_keys_to_value(&keys);
         let parsed = value_to_option_keys(Some(json));
         assert_eq!(parsed, keys);
+        assert_eq!(
+            value_to_option_keys(Some(serde_json::json!([1, true]))),
+            ["1", "true"]
+        );
     }
 
     #[test]
@@ -334,4 +940,174 @@ mod tests {
         assert_eq!(next_active_index(Some(0), 3, -1), Some(2));
         assert_eq!(next_active_index(Some(1), 3, -1), Some(0));
     }
+
+    fn leaf(key: &str) -> OptionNode {
+        OptionNode {
+            key: key.into(),
+            label: key.into(),
+            disabled: false,
+            children: vec![],
+        }
+    }
+
+    #[test]
+    fn lazy_load_state_tracks_loading_and_merges_children() {
+        let mut state = LazyLoadState::default();
+        assert_eq!(state.status("a", None), None);
+        assert!(state.begin("a", None));
+        assert_eq!(state.status("a", None), Some(NodeLoadStatus::Loading));
+        // A second request while in flight is ignored.
+        assert!(!state.begin("a", None));
+
+        state.finish("a", Ok(vec![leaf("a1"), leaf("a2")]));
+        assert_eq!(state.status("a", None), Some(NodeLoadStatus::Loaded));
+        assert!(!state.begin("a", None));
+        assert_eq!(state.loaded_keys(), vec!["a".to_string()]);
+
+        let tree = vec![leaf("a"), leaf("b")];
+        let merged = state.merge(&tree);
+        let keys: Vec<&str> = merged[0].children.iter().map(|n| n.key.as_str()).collect();
+        assert_eq!(keys, vec!["a1", "a2"]);
+        assert!(merged[1].children.is_empty());
+    }
+
+    #[test]
+    fn lazy_load_state_allows_retry_after_failure() {
+        let mut state = LazyLoadState::default();
+        assert!(state.begin("a", None));
+        state.finish("a", Err("timeout".into()));
+        assert_eq!(
+            state.status("a", None),
+            Some(NodeLoadStatus::Failed("timeout".into()))
+        );
+        assert!(state.begin("a", None));
+        state.finish("a", Ok(vec![]));
+        assert_eq!(state.status("a", None), Some(NodeLoadStatus::Loaded));
+    }
+
+    #[test]
+    fn lazy_load_state_respects_controlled_loaded_keys() {
+        let mut state = LazyLoadState::default();
+        let controlled = vec!["b".to_string()];
+        assert_eq!(
+            state.status("b", Some(&controlled)),
+            Some(NodeLoadStatus::Loaded)
+        );
+        assert!(!state.begin("b", Some(&controlled)));
+
+        // Removing a key from the controlled set allows reloading it.
+        assert!(state.begin("a", None));
+        state.finish("a", Ok(vec![]));
+        assert_eq!(state.status("a", Some(&controlled)), None);
+        assert!(state.begin("a", Some(&controlled)));
+    }
+
+    #[test]
+    fn find_option_node_searches_nested_children() {
+        let tree = vec![OptionNode {
+            children: vec![leaf("child")],
+            ..leaf("root")
+        }];
+        assert_eq!(
+            find_option_node(&tree, "child").map(|n| n.label.as_str()),
+            Some("child")
+        );
+        assert!(find_option_node(&tree, "missing").is_none());
+    }
+
+    fn page(labels: &[&str], has_more: bool) -> RemotePage<String> {
+        RemotePage {
+            options: labels.iter().map(|l| l.to_string()).collect(),
+            has_more,
+        }
+    }
+
+    #[test]
+    fn remote_search_state_drops_stale_results() {
+        let mut state = RemoteSearchState::<String>::default();
+        let (first, request) = state.begin_search("ap".into(), None);
+        assert_eq!(request.page, 1);
+        let (second, _) = state.begin_search("app".into(), None);
+        assert!(state.loading());
+
+        // The slower first request resolves after the newer one started.
+        assert!(!state.finish(first, Ok(page(&["apricot"], false))));
+        assert!(state.loading());
+        assert!(state.finish(second, Ok(page(&["apple"], true))));
+        assert_eq!(state.options(), ["apple".to_string()]);
+        assert!(state.has_more());
+    }
+
+    #[test]
+    fn remote_search_state_appends_pages_and_retries_failures() {
+        let mut state = RemoteSearchState::<String>::default();
+        assert!(state.begin_next_page().is_none());
+
+        let (token, _) = state.begin_search("a".into(), Some('@'));
+        state.finish(token, Ok(page(&["a1", "a2"], true)));
+
+        let (token, request) = state.begin_next_page().unwrap();
+        assert_eq!(request.page, 2);
+        assert_eq!(request.prefix, Some('@'));
+        // No second request while the page is loading.
+        assert!(state.begin_next_page().is_none());
+        state.finish(token, Err("timeout".into()));
+        assert_eq!(state.error(), Some("timeout"));
+
+        let (token, request) = state.begin_next_page().unwrap();
+        assert_eq!(request.page, 2);
+        state.finish(token, Ok(page(&["a3"], false)));
+        assert_eq!(state.options().len(), 3);
+        assert!(state.begin_next_page().is_none());
+    }
+
+    #[test]
+    fn remote_search_state_cancel_drops_the_request_in_flight() {
+        let mut state = RemoteSearchState::<String>::default();
+        let (token, _) = state.begin_search("a".into(), None);
+        state.cancel();
+        assert!(!state.loading());
+        assert!(
+            state.request().is_none(),
+            "a cancelled first page is forgotten"
+        );
+        assert!(!state.finish(token, Ok(page(&["a1"], false))));
+
+        let (token, _) = state.begin_search("b".into(), None);
+        state.finish(token, Ok(page(&["b1"], true)));
+        let (token, _) = state.begin_next_page().unwrap();
+        state.cancel();
+        assert!(!state.finish(token, Ok(page(&["b2"], false))));
+        assert_eq!(state.options(), ["b1".to_string()]);
+        assert_eq!(state.begin_next_page().unwrap().1.page, 2);
+    }
+
+    #[test]
+    fn remote_search_state_reports_not_found() {
+        let mut state = RemoteSearchState::<String>::default();
+        assert!(!state.not_found());
+        let (token, _) = state.begin_search("zzz".into(), None);
+        assert!(!state.not_found());
+        state.finish(token, Ok(page(&[], false)));
+        assert!(state.not_found());
+    }
+
+    #[test]
+    fn is_scrolled_to_bottom_uses_threshold() {
+        assert!(is_scrolled_to_bottom(280.0, 200.0, 500.0));
+        assert!(!is_scrolled_to_bottom(200.0, 200.0, 500.0));
+        // A list that does not overflow never asks for more.
+        assert!(!is_scrolled_to_bottom(0.0, 200.0, 200.0));
+    }
+
+    #[test]
+    fn split_by_separators_returns_trimmed_pieces() {
+        let seps = vec![",".to_string(), "\n".to_string()];
+        assert_eq!(split_by_separators("apple", &seps), None);
+        assert_eq!(
+            split_by_separators("apple, pear,\nplum,", &seps),
+            Some(vec!["apple".into(), "pear".into(), "plum".into()])
+        );
+        assert_eq!(split_by_separators(",", &seps), Some(vec![]));
+    }
 }
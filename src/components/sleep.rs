@@ -0,0 +1,98 @@
+//! Async delay shared by debounced search and chunk upload retries.
+//!
+//! Web targets use browser timers. Native targets park a helper thread for
+//! the delay and wake the awaiting task, so the delay works under any
+//! executor (desktop, SSR). Bare wasm32 without the `web` feature has no
+//! clock and resolves immediately.
+
+/// Resolve after `delay_ms` milliseconds on web targets.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+pub(crate) async fn sleep_ms(delay_ms: u32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        if let Some(window) = web_sys::window() {
+            let _ = window
+                .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, delay_ms as i32);
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+/// Resolve after `delay_ms` milliseconds on native targets.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep_ms(delay_ms: u32) {
+    if delay_ms > 0 {
+        native::Sleep::new(delay_ms).await;
+    }
+}
+
+/// No timer is available on wasm32 without the `web` feature.
+#[cfg(all(target_arch = "wasm32", not(feature = "web")))]
+pub(crate) async fn sleep_ms(_delay_ms: u32) {}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct State {
+        done: bool,
+        waker: Option<Waker>,
+    }
+
+    pub(super) struct Sleep {
+        state: Arc<Mutex<State>>,
+    }
+
+    impl Sleep {
+        pub(super) fn new(delay_ms: u32) -> Self {
+            let state = Arc::new(Mutex::new(State::default()));
+            let timer = Arc::clone(&state);
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(u64::from(delay_ms)));
+                let mut state = timer.lock().unwrap_or_else(|e| e.into_inner());
+                state.done = true;
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            });
+            Self { state }
+        }
+    }
+
+    impl Future for Sleep {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            if state.done {
+                Poll::Ready(())
+            } else {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use std::future::Future;
+    use std::task::{Context, Waker};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn native_sleep_waits_for_the_delay() {
+        let start = Instant::now();
+        let mut sleep = std::pin::pin!(sleep_ms(30));
+        let mut cx = Context::from_waker(Waker::noop());
+        while sleep.as_mut().poll(&mut cx).is_pending() {
+            std::thread::yield_now();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(30));
+    }
+}
@@ -1,13 +1,15 @@
 use crate::components::config_provider::use_config;
 use crate::components::form::use_form_item_control;
-#[cfg(target_arch = "wasm32")]
-use crate::components::interaction::start_pointer;
+use crate::components::interaction::PointerState;
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+use crate::components::interaction::reset_pointer;
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use crate::components::interaction::{
-    PointerState, as_pointer_event, end_pointer, is_active_pointer,
+    as_pointer_event, end_pointer, is_active_pointer, start_pointer,
 };
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use crate::components::slider_base::ratio_from_pointer_event;
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use crate::components::slider_base::ratio_to_value;
 use crate::components::slider_base::{
     SliderMath, SliderOrientation, apply_keyboard_action, keyboard_action_for_key, snap_value,
@@ -17,7 +19,7 @@ use dioxus::events::{KeyboardEvent, PointerData};
 use dioxus::prelude::*;
 use serde_json::{Number, Value};
 use std::rc::Rc;
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use wasm_bindgen::JsCast;
 
 /// A labeled mark rendered along the slider track.
@@ -245,7 +247,7 @@ pub fn Slider(props: SliderProps) -> Element {
         #[allow(unused_variables)]
         let apply_for_move = apply_value.clone();
         move |evt: Event<PointerData>| {
-            #[cfg(target_arch = "wasm32")]
+            #[cfg(all(feature = "web", target_arch = "wasm32"))]
             {
                 if is_disabled {
                     return;
@@ -278,7 +280,7 @@ pub fn Slider(props: SliderProps) -> Element {
                 );
                 apply_for_move(next, true);
             }
-            #[cfg(not(target_arch = "wasm32"))]
+            #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
             {
                 let _ = evt;
             }
@@ -291,13 +293,15 @@ pub fn Slider(props: SliderProps) -> Element {
     let on_change_complete_for_up = on_change_complete_cb.clone();
 
     let handle_pointer_up = move |evt: Event<PointerData>| {
-        let Some(pevt) = as_pointer_event(&evt) else {
-            return;
-        };
-        if !is_active_pointer(&pointer_state_for_up, &pevt) {
+        if pointer_state_for_up.read().active_id != Some(evt.data().pointer_id()) {
             return;
         }
-        end_pointer(&mut pointer_state_for_up, &pevt);
+        #[cfg(all(feature = "web", target_arch = "wasm32"))]
+        if let Some(pevt) = as_pointer_event(&evt) {
+            end_pointer(&mut pointer_state_for_up, &pevt);
+        }
+        #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+        reset_pointer(&mut pointer_state_for_up);
         active_handle_for_up.set(None);
         if let Some(cb) = on_change_complete_for_up.as_ref() {
             cb.call(current_for_up.read().clone());
@@ -310,7 +314,7 @@ pub fn Slider(props: SliderProps) -> Element {
         #[allow(unused_variables)]
         let apply_for_track = apply_value.clone();
         move |evt: Event<PointerData>| {
-            #[cfg(target_arch = "wasm32")]
+            #[cfg(all(feature = "web", target_arch = "wasm32"))]
             {
                 if is_disabled {
                     return;
@@ -343,7 +347,7 @@ pub fn Slider(props: SliderProps) -> Element {
                     cb.call(normalized);
                 }
             }
-            #[cfg(not(target_arch = "wasm32"))]
+            #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
             {
                 let _ = evt;
             }
@@ -595,7 +599,7 @@ fn slider_value_to_form(value: &SliderValue) -> Value {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 #[allow(dead_code)]
 fn pointer_ratio(
     evt: &web_sys::PointerEvent,
@@ -605,16 +609,6 @@ fn pointer_ratio(
     ratio_from_pointer_event(evt, rect, math)
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-#[allow(dead_code)]
-fn pointer_ratio(
-    _evt: &web_sys::PointerEvent,
-    _rect: &web_sys::DomRect,
-    _math: &SliderMath,
-) -> Option<f64> {
-    None
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,7 +167,7 @@ pub fn apply_keyboard_action(current: f64, action: KeyboardAction, math: &Slider
 }
 
 /// Convert pointer position to ratio using track bounding box.
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 pub fn ratio_from_pointer_event(
     event: &web_sys::PointerEvent,
     rect: &web_sys::DomRect,
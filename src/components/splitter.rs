@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use web_sys::wasm_bindgen::JsCast;
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -110,29 +111,13 @@ pub fn Splitter(props: SplitterProps) -> Element {
             if !*dragging_signal.read() {
                 return;
             }
-            let binding = evt.data();
-            let Some(web_evt) = binding.downcast::<web_sys::PointerEvent>() else {
+            let Some((offset, size)) = pointer_offset_in_target(&evt, orientation) else {
                 return;
             };
-            let Some(el) = web_evt
-                .current_target()
-                .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
-            else {
-                return;
-            };
-            let rect = el.get_bounding_client_rect();
-            let (size, origin) = match orientation {
-                SplitterOrientation::Horizontal => (rect.width(), rect.x()),
-                SplitterOrientation::Vertical => (rect.height(), rect.y()),
-            };
             if size <= 0.0 {
                 return;
             }
-            let pointer_pos = match orientation {
-                SplitterOrientation::Horizontal => web_evt.client_x() as f64,
-                SplitterOrientation::Vertical => web_evt.client_y() as f64,
-            };
-            let mut next = ((pointer_pos - origin) / size).clamp(0.05, 0.95) as f32;
+            let mut next = (offset / size).clamp(0.05, 0.95) as f32;
             next = set_ratio_with_constraints(next, size);
             ratio_signal.set(next);
             if let Some(cb) = on_moving_cb.as_ref() {
@@ -148,16 +133,9 @@ pub fn Splitter(props: SplitterProps) -> Element {
         let mut dragging_signal = dragging;
         let mut active_pointer = active_pointer;
         move |evt: Event<PointerData>| {
-            if let Some(web_evt) = evt.data().downcast::<web_sys::PointerEvent>() {
-                active_pointer.set(Some(web_evt.pointer_id()));
-                dragging_signal.set(true);
-                if let Some(target) = web_evt
-                    .target()
-                    .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
-                {
-                    let _ = target.set_pointer_capture(web_evt.pointer_id());
-                }
-            }
+            active_pointer.set(Some(evt.data().pointer_id()));
+            dragging_signal.set(true);
+            set_pointer_capture(&evt, true);
         }
     };
 
@@ -166,20 +144,13 @@ pub fn Splitter(props: SplitterProps) -> Element {
         let mut active_pointer = active_pointer;
         let on_release_cb = on_release;
         move |evt: Event<PointerData>| {
-            if let Some(web_evt) = evt.data().downcast::<web_sys::PointerEvent>()
-                && Some(web_evt.pointer_id()) == *active_pointer.read()
-            {
+            if Some(evt.data().pointer_id()) == *active_pointer.read() {
                 active_pointer.set(None);
                 dragging_signal.set(false);
                 if let Some(cb) = on_release_cb.as_ref() {
                     cb.call(*ratio.read());
                 }
-                if let Some(target) = web_evt
-                    .target()
-                    .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
-                {
-                    let _ = target.release_pointer_capture(web_evt.pointer_id());
-                }
+                set_pointer_capture(&evt, false);
             }
         }
     };
@@ -242,6 +213,56 @@ pub fn SplitterPane(props: SplitterPaneProps) -> Element {
     rsx! { {props.children} }
 }
 
+/// Pointer position inside the event's current target along the split axis,
+/// as `(offset_from_start, target_size)`. Needs the DOM to measure the target.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn pointer_offset_in_target(
+    evt: &Event<PointerData>,
+    orientation: SplitterOrientation,
+) -> Option<(f64, f64)> {
+    let binding = evt.data();
+    let web_evt = binding.downcast::<web_sys::PointerEvent>()?;
+    let el = web_evt
+        .current_target()
+        .and_then(|t| t.dyn_into::<web_sys::Element>().ok())?;
+    let rect = el.get_bounding_client_rect();
+    Some(match orientation {
+        SplitterOrientation::Horizontal => (web_evt.client_x() as f64 - rect.x(), rect.width()),
+        SplitterOrientation::Vertical => (web_evt.client_y() as f64 - rect.y(), rect.height()),
+    })
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+fn pointer_offset_in_target(
+    _evt: &Event<PointerData>,
+    _orientation: SplitterOrientation,
+) -> Option<(f64, f64)> {
+    None
+}
+
+/// Capture (or release) the pointer on the event target so dragging keeps
+/// working outside the gutter.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn set_pointer_capture(evt: &Event<PointerData>, capture: bool) {
+    let binding = evt.data();
+    let Some(web_evt) = binding.downcast::<web_sys::PointerEvent>() else {
+        return;
+    };
+    if let Some(target) = web_evt
+        .target()
+        .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+    {
+        let _ = if capture {
+            target.set_pointer_capture(web_evt.pointer_id())
+        } else {
+            target.release_pointer_capture(web_evt.pointer_id())
+        };
+    }
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+fn set_pointer_capture(_evt: &Event<PointerData>, _capture: bool) {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -11,14 +11,18 @@ use crate::components::checkbox::Checkbox;
 use crate::components::config_provider::ComponentSize;
 use crate::components::empty::Empty;
 use crate::components::icon::{Icon, IconKind};
+use crate::components::locale::use_locale;
 use crate::components::pagination::Pagination;
 use crate::components::spin::Spin;
+use crate::components::virtual_list::{DEFAULT_OVERSCAN, VirtualListHandle, use_virtual_list};
 use crate::foundation::{
     ClassListExt, StyleStringExt, TableClassNames, TableSemantic, TableStyles,
 };
 use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
 /// Horizontal alignment for table cells.
@@ -213,29 +217,50 @@ pub enum SelectionType {
     Radio,
 }
 
+/// Type alias for expanded row render function: (record, index) -> Element
+pub type ExpandedRowRenderFn = Rc<dyn Fn(&Value, usize) -> Element>;
+
+/// Type alias for row expandable predicate: (record) -> bool
+pub type RowExpandableFn = Rc<dyn Fn(&Value) -> bool>;
+
 /// Expandable row configuration.
 #[derive(Clone)]
 pub struct ExpandableConfig {
-    /// Whether rows are expandable by default.
-    pub expanded_row_keys: Vec<String>,
-    /// Callback when expanded rows change.
+    /// Controlled expanded row keys. When `None`, expansion is tracked internally.
+    pub expanded_row_keys: Option<Vec<String>>,
+    /// Initially expanded row keys in uncontrolled mode.
+    pub default_expanded_row_keys: Vec<String>,
+    /// Callback when a row is expanded or collapsed: (expanded, row_key).
     pub on_expand: Option<EventHandler<(bool, String)>>,
+    /// Callback with the full list of expanded keys after a toggle.
+    pub on_expanded_rows_change: Option<EventHandler<Vec<String>>>,
     /// Custom expand icon.
     pub expand_icon: Option<Element>,
-    /// Custom expanded row render function: (record, index, indent, expanded) -> Element
-    pub expanded_row_render: Option<Rc<dyn Fn(&Value, usize, usize, bool) -> Element>>,
+    /// Custom expanded row render function: (record, index) -> Element
+    pub expanded_row_render: Option<ExpandedRowRenderFn>,
+    /// Whether a given row can be expanded. All rows are expandable when `None`.
+    pub row_expandable: Option<RowExpandableFn>,
     /// Whether to show expand icon for all rows (even if expanded_row_render returns None).
     pub show_expand_icon: bool,
+    /// Toggle expansion when clicking anywhere on the row.
+    pub expand_row_by_click: bool,
+    /// Extra class applied to expanded row containers.
+    pub expanded_row_class_name: Option<String>,
 }
 
 impl Default for ExpandableConfig {
     fn default() -> Self {
         Self {
-            expanded_row_keys: Vec::new(),
+            expanded_row_keys: None,
+            default_expanded_row_keys: Vec::new(),
             on_expand: None,
+            on_expanded_rows_change: None,
             expand_icon: None,
             expanded_row_render: None,
+            row_expandable: None,
             show_expand_icon: true,
+            expand_row_by_click: false,
+            expanded_row_class_name: None,
         }
     }
 }
@@ -243,11 +268,27 @@ impl Default for ExpandableConfig {
 impl PartialEq for ExpandableConfig {
     fn eq(&self, other: &Self) -> bool {
         self.expanded_row_keys == other.expanded_row_keys
+            && self.default_expanded_row_keys == other.default_expanded_row_keys
+            && self.on_expand == other.on_expand
+            && self.on_expanded_rows_change == other.on_expanded_rows_change
+            && self.expand_icon == other.expand_icon
             && self.show_expand_icon == other.show_expand_icon
+            && self.expand_row_by_click == other.expand_row_by_click
+            && self.expanded_row_class_name == other.expanded_row_class_name
         // Functions cannot be compared
     }
 }
 
+impl ExpandableConfig {
+    /// Whether `record` can be expanded under this configuration.
+    fn is_row_expandable(&self, record: &Value) -> bool {
+        match &self.row_expandable {
+            Some(predicate) => predicate(record),
+            None => true,
+        }
+    }
+}
+
 /// Scroll configuration for fixed header/columns.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct TableScroll {
@@ -270,13 +311,25 @@ pub struct StickyConfig {
     pub get_container: Option<String>,
 }
 
+/// Fixed position for the summary row.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SummaryFixed {
+    /// Stick to the top of the scroll area, right below the header.
+    Top,
+    /// Stick to the bottom of the scroll area.
+    Bottom,
+}
+
+/// Type alias for summary render function: (columns, data) -> Element
+pub type SummaryRenderFn = Rc<dyn Fn(&[TableColumn], &[Value]) -> Element>;
+
 /// Summary row configuration.
 #[derive(Clone)]
 pub struct SummaryConfig {
     /// Custom summary row render function: (columns, data) -> Element
-    pub render: Option<Rc<dyn Fn(&[TableColumn], &[Value]) -> Element>>,
+    pub render: Option<SummaryRenderFn>,
     /// Fixed position for summary row.
-    pub fixed: Option<ColumnFixed>,
+    pub fixed: Option<SummaryFixed>,
 }
 
 impl PartialEq for SummaryConfig {
@@ -293,7 +346,7 @@ pub type RowClassNameFn = Rc<dyn Fn(&Value, usize) -> Option<String>>;
 pub type RowPropsFn = Rc<dyn Fn(&Value, usize) -> HashMap<String, String>>;
 
 /// Locale configuration for table text.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct TableLocale {
     /// Text for filter title.
     pub filter_title: Option<String>,
@@ -399,9 +452,17 @@ pub struct TableProps {
     /// In Rust, this is simplified to a container selector string.
     #[props(optional)]
     pub get_popup_container: Option<String>,
-    /// Enable virtual scrolling for large datasets.
+    /// Enable virtual scrolling for large datasets. Requires `scroll.y`, which
+    /// sets the viewport height; only rows in view (plus overscan) are mounted.
     #[props(default)]
     pub r#virtual: bool,
+    /// Row height in pixels used by virtual scrolling. Rows are assumed to be
+    /// uniform, so expanded row content is best avoided in virtual mode.
+    #[props(default = 40.0)]
+    pub virtual_row_height: f32,
+    /// Handle for imperative scrolling (e.g. `scroll_to_index`) in virtual mode.
+    #[props(optional)]
+    pub virtual_handle: Option<VirtualListHandle>,
     /// Locale configuration for table text.
     #[props(optional)]
     pub locale: Option<TableLocale>,
@@ -454,6 +515,8 @@ impl PartialEq for TableProps {
             && self.styles == other.styles
             && self.get_popup_container == other.get_popup_container
             && self.r#virtual == other.r#virtual
+            && self.virtual_row_height == other.virtual_row_height
+            && self.virtual_handle == other.virtual_handle
             && self.locale == other.locale
             && self.pagination_total == other.pagination_total
             && self.pagination_current == other.pagination_current
@@ -470,8 +533,8 @@ pub fn Table(props: TableProps) -> Element {
         data,
         row_key_field,
         row_class_name,
-        row_class_name_fn: _,
-        row_props_fn: _,
+        row_class_name_fn,
+        row_props_fn,
         bordered,
         size,
         loading,
@@ -479,9 +542,9 @@ pub fn Table(props: TableProps) -> Element {
         empty,
         row_selection,
         scroll,
-        sticky: _,
-        expandable: _,
-        summary: _,
+        sticky,
+        expandable,
+        summary,
         on_change,
         show_header,
         class,
@@ -489,8 +552,10 @@ pub fn Table(props: TableProps) -> Element {
         class_names,
         styles,
         get_popup_container: _,
-        r#virtual: _virtual_scrolling,
-        locale: _,
+        r#virtual,
+        virtual_row_height,
+        virtual_handle,
+        locale,
         pagination_total,
         pagination_current,
         pagination_page_size,
@@ -500,6 +565,18 @@ pub fn Table(props: TableProps) -> Element {
     // Internal sort state
     let sort_state: Signal<Option<(String, SortOrder)>> = use_signal(|| None);
 
+    // Virtual scrolling state; an external handle takes precedence.
+    let internal_virtual = use_virtual_list();
+    let virtual_list = virtual_handle.unwrap_or(internal_virtual);
+
+    // Internal expanded keys, used when `expanded_row_keys` is not controlled.
+    let expanded_internal: Signal<Vec<String>> = use_signal(|| {
+        expandable
+            .as_ref()
+            .map(|cfg| cfg.default_expanded_row_keys.clone())
+            .unwrap_or_default()
+    });
+
     // Filter visible columns
     let visible_columns: Vec<&TableColumn> = columns.iter().filter(|c| !c.hidden).collect();
 
@@ -518,6 +595,12 @@ pub fn Table(props: TableProps) -> Element {
     if row_selection.is_some() {
         class_list.push("adui-table-selection".into());
     }
+    if sticky.is_some() {
+        class_list.push("adui-table-sticky".into());
+    }
+    if r#virtual && scroll.as_ref().is_some_and(|sc| sc.y.is_some()) {
+        class_list.push("adui-table-virtual".into());
+    }
     class_list.push_semantic(&class_names, TableSemantic::Root);
     if let Some(extra) = class {
         class_list.push(extra);
@@ -540,11 +623,28 @@ pub fn Table(props: TableProps) -> Element {
         if let Some(x) = sc.x {
             s.push_str(&format!("overflow-x: auto; min-width: {}px;", x));
         }
+        if r#virtual && sc.y.is_some() {
+            s.push_str(&format!(
+                "--adui-table-row-height: {}px;",
+                virtual_row_height
+            ));
+        }
         s
     } else {
         String::new()
     };
 
+    // Sticky header offset
+    let header_style = sticky
+        .as_ref()
+        .map(|cfg| format!("top: {}px;", cfg.offset_top.unwrap_or(0.0)))
+        .unwrap_or_default();
+    let header_class = if sticky.is_some() {
+        "adui-table-header adui-table-header-sticky"
+    } else {
+        "adui-table-header"
+    };
+
     let show_empty = !loading && is_empty.unwrap_or(data.is_empty());
 
     // Row key helper
@@ -579,6 +679,93 @@ pub fn Table(props: TableProps) -> Element {
     // Selection change handler
     let on_select_change = row_selection.as_ref().and_then(|r| r.on_change);
 
+    // Expand helpers
+    let has_expand = expandable.is_some();
+    let expanded_keys = expandable
+        .as_ref()
+        .and_then(|cfg| cfg.expanded_row_keys.clone())
+        .unwrap_or_else(|| expanded_internal.read().clone());
+    let bundle = use_locale();
+    let table_locale = locale.unwrap_or_default();
+    let expand_state = expandable.as_ref().map(|config| RowExpandState {
+        config,
+        expanded_keys: &expanded_keys,
+        internal: expanded_internal,
+        expand_label: table_locale
+            .expand
+            .clone()
+            .or_else(|| bundle.table.expand.clone())
+            .unwrap_or_default(),
+        collapse_label: table_locale
+            .collapse
+            .clone()
+            .or_else(|| bundle.table.collapse.clone())
+            .unwrap_or_default(),
+    });
+
+    // Virtual window over the body rows.
+    let virtual_viewport = scroll.as_ref().and_then(|sc| sc.y);
+    let virtual_enabled = r#virtual && virtual_viewport.is_some();
+    let virtual_range = virtual_viewport.filter(|_| r#virtual).map(|height| {
+        let row_height = virtual_row_height as f64;
+        let mut range =
+            virtual_list.window(data.len(), row_height, height as f64, DEFAULT_OVERSCAN);
+        // Keep the window start even so zebra striping stays stable while scrolling.
+        if range.start % 2 == 1 {
+            range.start -= 1;
+            range.offset_top -= row_height;
+        }
+        range
+    });
+
+    let row_ctx = RowRenderContext {
+        columns: &visible_columns,
+        row_key_field: &row_key_field,
+        row_class_name: &row_class_name,
+        row_class_name_fn: &row_class_name_fn,
+        row_props_fn: &row_props_fn,
+        has_selection,
+        selection_type,
+        selected_keys: &selected_keys,
+        on_select_change,
+        expand: expand_state,
+        index_offset: virtual_range.map(|range| range.start).unwrap_or(0),
+    };
+
+    let body_rows = match virtual_range {
+        Some(range) => rsx! {
+            div { class: "adui-table-virtual-spacer", style: "height: {range.offset_top}px;" }
+            {render_rows(&row_ctx, &data[range.start..range.end])}
+            div { class: "adui-table-virtual-spacer", style: "height: {range.offset_bottom}px;" }
+        },
+        None => render_rows(&row_ctx, &data),
+    };
+
+    // Summary row
+    let summary_node = summary.as_ref().and_then(|cfg| {
+        let render = cfg.render.as_ref()?;
+        let content = render(&columns, &data);
+        let mut summary_class = "adui-table-summary".to_string();
+        let mut summary_style = String::new();
+        match cfg.fixed {
+            Some(SummaryFixed::Top) => {
+                summary_class.push_str(" adui-table-summary-fixed adui-table-summary-fixed-top");
+            }
+            Some(SummaryFixed::Bottom) => {
+                summary_class.push_str(" adui-table-summary-fixed adui-table-summary-fixed-bottom");
+                let offset = sticky.as_ref().and_then(|s| s.offset_bottom).unwrap_or(0.0);
+                summary_style = format!("bottom: {}px;", offset);
+            }
+            None => {}
+        }
+        Some(rsx! {
+            div { class: "{summary_class}", style: "{summary_style}", {content} }
+        })
+    });
+    let summary_on_top = summary
+        .as_ref()
+        .is_some_and(|cfg| cfg.fixed == Some(SummaryFixed::Top));
+
     // Sort handler
     let on_change_cb = on_change;
     let pagination_total_for_change = pagination_total;
@@ -588,7 +775,7 @@ pub fn Table(props: TableProps) -> Element {
     rsx! {
         div { class: "{class_attr}", style: "{style_attr}",
             if show_header {
-                div { class: "adui-table-header",
+                div { class: "{header_class}", style: "{header_style}",
                     div { class: "adui-table-row adui-table-row-header",
                         // Selection column header
                         if has_selection {
@@ -610,6 +797,10 @@ pub fn Table(props: TableProps) -> Element {
                                 }
                             }
                         }
+                        // Expand column header
+                        if has_expand {
+                            div { class: "adui-table-cell adui-table-cell-expand" }
+                        }
                         // Data columns
                         {visible_columns.iter().map(|col| {
                             let mut cell_classes = vec!["adui-table-cell".to_string(), "adui-table-cell-header".to_string()];
@@ -689,25 +880,33 @@ pub fn Table(props: TableProps) -> Element {
             div {
                 class: "adui-table-body",
                 style: "{scroll_style}",
+                onmounted: move |evt| virtual_list.handle_mounted(&evt),
+                onscroll: move |evt| {
+                    if virtual_enabled {
+                        virtual_list.handle_scroll(&evt);
+                    }
+                },
                 if loading {
                     Spin {
                         spinning: Some(true),
-                        tip: Some("加载中...".to_string()),
-                        div { class: "adui-table-body-inner",
-                            {render_rows(&visible_columns, &data, &row_key_field, &row_class_name, has_selection, selection_type, &selected_keys, on_select_change)}
-                        }
+                        tip: Some(bundle.global.loading.clone()),
+                        div { class: "adui-table-body-inner", {body_rows.clone()} }
                     }
                 } else if show_empty {
                     div { class: "adui-table-empty",
                         if let Some(node) = empty {
                             {node}
                         } else {
-                            Empty {}
+                            Empty { description: table_locale.empty_text.clone() }
                         }
                     }
                 } else {
-                    div { class: "adui-table-body-inner",
-                        {render_rows(&visible_columns, &data, &row_key_field, &row_class_name, has_selection, selection_type, &selected_keys, on_select_change)}
+                    if summary_on_top {
+                        {summary_node.clone()}
+                    }
+                    div { class: "adui-table-body-inner", {body_rows} }
+                    if !summary_on_top {
+                        {summary_node}
                     }
                 }
             }
@@ -744,36 +943,239 @@ pub fn Table(props: TableProps) -> Element {
     }
 }
 
-fn render_rows(
-    columns: &[&TableColumn],
-    data: &[Value],
-    row_key_field: &Option<String>,
-    row_class_name: &Option<String>,
+/// Props for a row rendered inside [`SummaryConfig::render`].
+#[derive(Props, Clone, PartialEq)]
+pub struct TableSummaryRowProps {
+    #[props(optional)]
+    pub class: Option<String>,
+    #[props(optional)]
+    pub style: Option<String>,
+    pub children: Element,
+}
+
+/// Summary (totals) row aligned with the table's cell layout.
+#[component]
+pub fn TableSummaryRow(props: TableSummaryRowProps) -> Element {
+    let TableSummaryRowProps {
+        class,
+        style,
+        children,
+    } = props;
+    let class_attr = format!(
+        "adui-table-row adui-table-summary-row {}",
+        class.unwrap_or_default()
+    );
+    let style_attr = style.unwrap_or_default();
+    rsx! {
+        div { class: "{class_attr}", style: "{style_attr}", {children} }
+    }
+}
+
+/// Props for a cell inside [`TableSummaryRow`].
+#[derive(Props, Clone, PartialEq)]
+pub struct TableSummaryCellProps {
+    /// Number of columns this cell spans, including selection/expand columns.
+    #[props(default = 1)]
+    pub col_span: usize,
+    #[props(optional)]
+    pub align: Option<ColumnAlign>,
+    #[props(optional)]
+    pub class: Option<String>,
+    #[props(optional)]
+    pub style: Option<String>,
+    pub children: Element,
+}
+
+/// Summary cell spanning one or more columns.
+#[component]
+pub fn TableSummaryCell(props: TableSummaryCellProps) -> Element {
+    let TableSummaryCellProps {
+        col_span,
+        align,
+        class,
+        style,
+        children,
+    } = props;
+    let mut class_list = vec![
+        "adui-table-cell".to_string(),
+        "adui-table-summary-cell".to_string(),
+    ];
+    if let Some(align) = align {
+        class_list.push(align.as_class().to_string());
+    }
+    if let Some(extra) = class {
+        class_list.push(extra);
+    }
+    let class_attr = class_list.join(" ");
+    let style_attr = format!("flex: {};{}", col_span.max(1), style.unwrap_or_default());
+    rsx! {
+        div { class: "{class_attr}", style: "{style_attr}", {children} }
+    }
+}
+
+/// Shared inputs for rendering body rows.
+struct RowRenderContext<'a> {
+    columns: &'a [&'a TableColumn],
+    row_key_field: &'a Option<String>,
+    row_class_name: &'a Option<String>,
+    row_class_name_fn: &'a Option<RowClassNameFn>,
+    row_props_fn: &'a Option<RowPropsFn>,
     has_selection: bool,
     selection_type: SelectionType,
-    selected_keys: &[String],
+    selected_keys: &'a [String],
     on_select_change: Option<EventHandler<Vec<String>>>,
-) -> Element {
+    expand: Option<RowExpandState<'a>>,
+    /// Absolute index of the first row in the slice being rendered.
+    index_offset: usize,
+}
+
+/// Expansion state for the current render.
+struct RowExpandState<'a> {
+    config: &'a ExpandableConfig,
+    expanded_keys: &'a [String],
+    internal: Signal<Vec<String>>,
+    expand_label: String,
+    collapse_label: String,
+}
+
+impl RowExpandState<'_> {
+    /// Build a handler toggling `key`, updating internal state when uncontrolled.
+    fn toggle_handler(&self, key: String) -> impl FnMut() + 'static {
+        let controlled = self.config.expanded_row_keys.is_some();
+        let current = self.expanded_keys.to_vec();
+        let on_expand = self.config.on_expand;
+        let on_rows_change = self.config.on_expanded_rows_change;
+        let mut internal = self.internal;
+        move || {
+            let (expanded, next) = toggle_expanded_key(&current, &key);
+            if !controlled {
+                internal.set(next.clone());
+            }
+            if let Some(cb) = on_expand {
+                cb.call((expanded, key.clone()));
+            }
+            if let Some(cb) = on_rows_change {
+                cb.call(next);
+            }
+        }
+    }
+}
+
+fn render_rows(ctx: &RowRenderContext<'_>, data: &[Value]) -> Element {
+    let has_selection = ctx.has_selection;
+    let selection_type = ctx.selection_type;
+    let on_select_change = ctx.on_select_change;
+
     rsx! {
-        {data.iter().enumerate().map(|(idx, row)| {
-            let key = if let Some(field) = row_key_field {
+        {data.iter().enumerate().map(|(local_idx, row)| {
+            let idx = ctx.index_offset + local_idx;
+            let key = if let Some(field) = ctx.row_key_field {
                 get_cell_text(row, field)
             } else {
                 idx.to_string()
             };
-            let row_class = row_class_name.clone().unwrap_or_default();
-            let is_selected = selected_keys.contains(&key);
+
+            let mut row_classes = vec!["adui-table-row".to_string()];
+            if let Some(extra) = ctx.row_class_name {
+                row_classes.push(extra.clone());
+            }
+            if let Some(class_fn) = ctx.row_class_name_fn
+                && let Some(extra) = class_fn(row, idx)
+            {
+                row_classes.push(extra);
+            }
+
+            let (row_attrs, extra_class, row_style) = match ctx.row_props_fn {
+                Some(props_fn) => split_row_props(props_fn(row, idx)),
+                None => (Vec::new(), None, String::new()),
+            };
+            row_classes.extend(extra_class);
+
+            let is_selected = ctx.selected_keys.contains(&key);
+            if is_selected {
+                row_classes.push("adui-table-row-selected".into());
+            }
             let key_for_select = key.clone();
-            let selected_keys_clone = selected_keys.to_vec();
+            let selected_keys_clone = ctx.selected_keys.to_vec();
+
+            // Expansion
+            let expand = ctx.expand.as_ref();
+            let row_expandable = expand.is_some_and(|e| e.config.is_row_expandable(row));
+            let is_expanded = row_expandable && expand.is_some_and(|e| e.expanded_keys.contains(&key));
+            if is_expanded {
+                row_classes.push("adui-table-row-expanded".into());
+            }
+            let by_click = row_expandable && expand.is_some_and(|e| e.config.expand_row_by_click);
+            let mut row_toggle = expand.filter(|_| by_click).map(|e| e.toggle_handler(key.clone()));
+            let expand_cell = expand.map(|e| {
+                let mut icon_toggle = e.toggle_handler(key.clone());
+                let show_icon = row_expandable && e.config.show_expand_icon;
+                let label = if is_expanded { e.collapse_label.clone() } else { e.expand_label.clone() };
+                let icon_class = if is_expanded {
+                    "adui-table-row-expand-icon adui-table-row-expand-icon-expanded"
+                } else {
+                    "adui-table-row-expand-icon adui-table-row-expand-icon-collapsed"
+                };
+                let icon = match &e.config.expand_icon {
+                    Some(custom) => custom.clone(),
+                    None => rsx! {
+                        Icon {
+                            kind: if is_expanded { IconKind::Minus } else { IconKind::Plus },
+                            size: 12.0,
+                        }
+                    },
+                };
+                rsx! {
+                    div { class: "adui-table-cell adui-table-cell-expand",
+                        if show_icon {
+                            button {
+                                r#type: "button",
+                                class: "{icon_class}",
+                                aria_label: "{label}",
+                                aria_expanded: "{is_expanded}",
+                                onclick: move |evt| {
+                                    evt.stop_propagation();
+                                    icon_toggle();
+                                },
+                                {icon}
+                            }
+                        }
+                    }
+                }
+            });
+            let expanded_node = expand
+                .filter(|_| is_expanded)
+                .and_then(|e| {
+                    let render = e.config.expanded_row_render.as_ref()?;
+                    let content = render(row, idx);
+                    let extra = e.config.expanded_row_class_name.clone().unwrap_or_default();
+                    Some(rsx! {
+                        div {
+                            key: "{key}-expanded",
+                            class: "adui-table-expanded-row {extra}",
+                            div { class: "adui-table-expanded-row-content", {content} }
+                        }
+                    })
+                });
+
+            let row_class = row_classes.join(" ");
 
             rsx! {
                 div {
                     key: "{key}",
-                    class: "adui-table-row {row_class}",
-                    class: if is_selected { "adui-table-row-selected" } else { "" },
+                    class: "{row_class}",
+                    style: "{row_style}",
+                    onclick: move |_| {
+                        if let Some(toggle) = row_toggle.as_mut() {
+                            toggle();
+                        }
+                    },
+                    ..row_attrs,
                     // Selection cell
                     if has_selection {
-                        div { class: "adui-table-cell adui-table-cell-selection",
+                        div {
+                            class: "adui-table-cell adui-table-cell-selection",
+                            onclick: move |evt| evt.stop_propagation(),
                             Checkbox {
                                 checked: is_selected,
                                 on_change: move |_checked| {
@@ -793,8 +1195,10 @@ fn render_rows(
                             }
                         }
                     }
+                    // Expand cell
+                    {expand_cell}
                     // Data cells
-                    {columns.iter().map(|col| {
+                    {ctx.columns.iter().map(|col| {
                         let mut cell_classes = vec!["adui-table-cell".to_string()];
                         if let Some(align) = col.align {
                             cell_classes.push(align.as_class().to_string());
@@ -820,11 +1224,114 @@ fn render_rows(
                         }
                     })}
                 }
+                {expanded_node}
             }
         })}
     }
 }
 
+/// Toggle `key` in the expanded key list, returning the new expanded flag
+/// for that key and the updated list.
+fn toggle_expanded_key(keys: &[String], key: &str) -> (bool, Vec<String>) {
+    if keys.iter().any(|k| k == key) {
+        let next = keys.iter().filter(|k| *k != key).cloned().collect();
+        (false, next)
+    } else {
+        let mut next = keys.to_vec();
+        next.push(key.to_string());
+        (true, next)
+    }
+}
+
+/// Common row attribute names, served without allocating. Dioxus needs
+/// `'static` attribute names; other names are interned by [`row_attr_name`].
+const ROW_PROP_ATTRIBUTES: &[&str] = &[
+    "id",
+    "title",
+    "role",
+    "tabindex",
+    "lang",
+    "dir",
+    "hidden",
+    "draggable",
+    "aria-label",
+    "aria-describedby",
+    "aria-disabled",
+    "aria-hidden",
+    "aria-level",
+    "aria-rowindex",
+    "aria-selected",
+    "data-id",
+    "data-index",
+    "data-key",
+    "data-row-key",
+    "data-status",
+    "data-testid",
+    "data-type",
+];
+
+/// Split the output of a [`RowPropsFn`] into spreadable attributes plus the
+/// `class` and `style` entries, which are merged with the row's own values.
+fn split_row_props(props: HashMap<String, String>) -> (Vec<Attribute>, Option<String>, String) {
+    let mut attrs = Vec::new();
+    let mut class = None;
+    let mut style = String::new();
+    let mut entries: Vec<(String, String)> = props.into_iter().collect();
+    entries.sort();
+    for (name, value) in entries {
+        match name.as_str() {
+            "class" | "className" => class = Some(value),
+            "style" => style = value,
+            _ => {
+                if let Some(name) = row_attr_name(&name) {
+                    attrs.push(Attribute::new(name, value, None, false));
+                }
+            }
+        }
+    }
+    (attrs, class, style)
+}
+
+/// Upper bound on distinct interned row attribute names, so names generated
+/// from data (e.g. `data-{id}`) cannot grow the table without limit.
+const MAX_INTERNED_ROW_ATTRS: usize = 256;
+
+/// `'static` spelling of a row attribute name. Common names come from
+/// [`ROW_PROP_ATTRIBUTES`]; any other valid name (including arbitrary
+/// `data-*` and `aria-*`) is interned once, up to [`MAX_INTERNED_ROW_ATTRS`].
+/// Inline event handlers (`on*`) and malformed names are rejected.
+fn row_attr_name(name: &str) -> Option<&'static str> {
+    if let Some(known) = ROW_PROP_ATTRIBUTES
+        .iter()
+        .copied()
+        .find(|known| *known == name)
+    {
+        return Some(known);
+    }
+    let valid = name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':'));
+    if !valid || name.to_ascii_lowercase().starts_with("on") {
+        return None;
+    }
+    thread_local! {
+        static INTERNED: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+    }
+    INTERNED.with(|interned| {
+        let mut interned = interned.borrow_mut();
+        if let Some(existing) = interned.get(name) {
+            return Some(*existing);
+        }
+        if interned.len() >= MAX_INTERNED_ROW_ATTRS {
+            return None;
+        }
+        let leaked: &'static str = Box::leak(name.to_string().into_boxed_str());
+        interned.insert(leaked);
+        Some(leaked)
+    })
+}
+
 /// Helper to extract a cell value as string.
 fn get_cell_text(row: &Value, key: &str) -> String {
     match row.get(key) {
@@ -944,4 +1451,57 @@ mod tests {
         let row_value = Value::Object(row);
         assert_eq!(get_cell_text(&row_value, "missing"), "");
     }
+
+    #[test]
+    fn toggle_expanded_key_adds_and_removes() {
+        let keys = vec!["a".to_string()];
+        let (expanded, next) = toggle_expanded_key(&keys, "b");
+        assert!(expanded);
+        assert_eq!(next, vec!["a".to_string(), "b".to_string()]);
+
+        let (expanded, next) = toggle_expanded_key(&next, "a");
+        assert!(!expanded);
+        assert_eq!(next, vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn row_expandable_predicate_is_applied() {
+        let config = ExpandableConfig {
+            row_expandable: Some(Rc::new(|record: &Value| record.get("children").is_some())),
+            ..Default::default()
+        };
+        assert!(config.is_row_expandable(&serde_json::json!({ "children": [] })));
+        assert!(!config.is_row_expandable(&serde_json::json!({ "name": "leaf" })));
+        assert!(ExpandableConfig::default().is_row_expandable(&Value::Null));
+    }
+
+    #[test]
+    fn split_row_props_separates_class_and_style() {
+        let mut props = HashMap::new();
+        props.insert("class".to_string(), "row-warning".to_string());
+        props.insert("style".to_string(), "color: red;".to_string());
+        props.insert("data-id".to_string(), "42".to_string());
+        props.insert("title".to_string(), "Row 42".to_string());
+
+        let (attrs, class, style) = split_row_props(props);
+        assert_eq!(class.as_deref(), Some("row-warning"));
+        assert_eq!(style, "color: red;");
+        let names: Vec<&str> = attrs.iter().map(|a| a.name).collect();
+        assert_eq!(names, vec!["data-id", "title"]);
+    }
+
+    #[test]
+    fn row_attr_name_passes_unknown_names_through() {
+        assert_eq!(row_attr_name("data-row-key"), Some("data-row-key"));
+        assert_eq!(row_attr_name("aria-label"), Some("aria-label"));
+        let first = row_attr_name("data-anything-else").unwrap();
+        assert_eq!(first, "data-anything-else");
+        assert!(std::ptr::eq(
+            first,
+            row_attr_name("data-anything-else").unwrap()
+        ));
+        assert_eq!(row_attr_name("aria-colcount"), Some("aria-colcount"));
+        assert_eq!(row_attr_name("onclick"), None);
+        assert_eq!(row_attr_name("bad name"), None);
+    }
 }
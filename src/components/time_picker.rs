@@ -1,8 +1,13 @@
-use crate::components::config_provider::{Locale, use_config};
+use crate::components::date_format::{
+    DEFAULT_TIME_FORMAT, format_has_token, format_time, parse_time,
+};
+use crate::components::date_picker::unix_now_seconds;
 use crate::components::floating::use_floating_close_handle;
+use crate::components::locale::use_locale;
 use crate::components::select_base::use_dropdown_layer;
 use dioxus::events::KeyboardEvent;
 use dioxus::prelude::*;
+use time::{OffsetDateTime, UtcOffset};
 
 /// Internal value type for TimePicker (HH:mm:ss).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -33,6 +38,20 @@ impl TimeValue {
         }
     }
 
+    /// Time of day at `unix_seconds` in the timezone `offset` away from UTC.
+    pub fn from_unix_timestamp(unix_seconds: i64, offset: UtcOffset) -> Option<Self> {
+        let at = OffsetDateTime::from_unix_timestamp(unix_seconds)
+            .ok()?
+            .to_offset(offset);
+        Some(Self::new(at.hour(), at.minute(), at.second()))
+    }
+
+    /// Current time of day in the timezone `offset` away from UTC.
+    pub fn now(offset: UtcOffset) -> Self {
+        Self::from_unix_timestamp(unix_now_seconds(), offset)
+            .expect("current time is within the supported range")
+    }
+
     /// Format as `HH:mm:ss` text.
     pub fn to_hms_string(&self) -> String {
         format!("{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
@@ -51,7 +70,8 @@ pub struct TimePickerProps {
     /// Placeholder shown when no time is selected.
     #[props(optional)]
     pub placeholder: Option<String>,
-    /// Display/parse format. MVP: primarily `HH:mm:ss`.
+    /// Display/parse pattern, e.g. `HH:mm` or `hh:mm A`. Defaults to
+    /// `HH:mm:ss`; columns whose unit is absent from the pattern are hidden.
     #[props(optional)]
     pub format: Option<String>,
     /// Step for hour column.
@@ -87,7 +107,7 @@ pub fn TimePicker(props: TimePickerProps) -> Element {
         value,
         default_value,
         placeholder,
-        format: _format,
+        format,
         hour_step,
         minute_step,
         second_step,
@@ -98,8 +118,7 @@ pub fn TimePicker(props: TimePickerProps) -> Element {
         on_change,
     } = props;
 
-    let config = use_config();
-    let locale = config.locale;
+    let locale = use_locale();
 
     let is_disabled = disabled.unwrap_or(false);
     let allow_clear_flag = allow_clear.unwrap_or(false);
@@ -116,16 +135,24 @@ pub fn TimePicker(props: TimePickerProps) -> Element {
     } else {
         *inner_state.read()
     };
-    let display_text = if value.is_some() || default_value.is_some() {
-        current_value.to_hms_string()
-    } else {
-        String::new()
+    let pattern = format.unwrap_or_else(|| DEFAULT_TIME_FORMAT.to_string());
+    let show_hour = format_has_token(&pattern, &["H", "HH", "h", "hh"]);
+    let show_minute = format_has_token(&pattern, &["m", "mm"]);
+    let show_second = format_has_token(&pattern, &["s", "ss"]);
+
+    // Text being typed into the input; replaces the formatted value until the
+    // input loses focus.
+    let draft: Signal<Option<String>> = use_signal(|| None);
+    let display_text = match draft.read().clone() {
+        Some(text) => text,
+        None if value.is_some() || default_value.is_some() => {
+            format_time(current_value, &pattern, &locale.date_picker)
+        }
+        None => String::new(),
     };
+    let date_locale = locale.date_picker.clone();
 
-    let default_placeholder = match locale {
-        Locale::ZhCN => "请选择时间".to_string(),
-        Locale::EnUS => "Select time".to_string(),
-    };
+    let default_placeholder = locale.time_picker.placeholder.clone();
     let placeholder_str = placeholder.unwrap_or(default_placeholder);
 
     let controlled = value.is_some();
@@ -248,10 +275,21 @@ pub fn TimePicker(props: TimePickerProps) -> Element {
                 },
                 input {
                     class: "adui-time-picker-input",
-                    readonly: true,
                     disabled: is_disabled,
                     value: "{display_text}",
                     placeholder: "{placeholder_str}",
+                    oninput: move |evt| {
+                        let text = evt.value();
+                        if let Some(parsed) = parse_time(&text, &pattern, &date_locale) {
+                            apply_time(parsed);
+                        }
+                        let mut draft = draft;
+                        draft.set(Some(text));
+                    },
+                    onblur: move |_| {
+                        let mut draft = draft;
+                        draft.set(None);
+                    },
                 }
                 if allow_clear_flag && !display_text.is_empty() && !is_disabled {
                     span {
@@ -281,46 +319,58 @@ pub fn TimePicker(props: TimePickerProps) -> Element {
                     style: "position: absolute; top: 100%; left: 0; min-width: 100%; z-index: {current_z};",
                     div { class: "adui-time-picker-panel",
                         // Hours column
-                        div { class: "adui-time-picker-column",
-                            for (h, class_attr, label) in hour_cells {
-                                span {
-                                    class: "{class_attr}",
-                                    onclick: move |_| {
-                                        close_handle.mark_internal_click();
-                                        let next = TimeValue::new(h, current_minute, current_second);
-                                        apply_time(next);
-                                    },
-                                    "{label}"
+                        if show_hour {
+                            div { class: "adui-time-picker-column",
+                                for (h, class_attr, label) in hour_cells {
+                                    span {
+                                        class: "{class_attr}",
+                                        onclick: move |_| {
+                                            close_handle.mark_internal_click();
+                                            let next = TimeValue::new(h, current_minute, current_second);
+                                            apply_time(next);
+                                            if !show_minute && !show_second {
+                                                close_handle.close();
+                                            }
+                                        },
+                                        "{label}"
+                                    }
                                 }
                             }
                         }
                         // Minutes column
-                        div { class: "adui-time-picker-column",
-                            for (m, class_attr, label) in minute_cells {
-                                span {
-                                    class: "{class_attr}",
-                                    onclick: move |_| {
-                                        close_handle.mark_internal_click();
-                                        let next = TimeValue::new(current_hour, m, current_second);
-                                        apply_time(next);
-                                    },
-                                    "{label}"
+                        if show_minute {
+                            div { class: "adui-time-picker-column",
+                                for (m, class_attr, label) in minute_cells {
+                                    span {
+                                        class: "{class_attr}",
+                                        onclick: move |_| {
+                                            close_handle.mark_internal_click();
+                                            let next = TimeValue::new(current_hour, m, current_second);
+                                            apply_time(next);
+                                            if !show_second {
+                                                close_handle.close();
+                                            }
+                                        },
+                                        "{label}"
+                                    }
                                 }
                             }
                         }
                         // Seconds column
-                        div { class: "adui-time-picker-column",
-                            for (s, class_attr, label) in second_cells {
-                                span {
-                                    class: "{class_attr}",
-                                    onclick: move |_| {
-                                        close_handle.mark_internal_click();
-                                        let next = TimeValue::new(current_hour, current_minute, s);
-                                        apply_time(next);
-                                        // 默认在选择秒后关闭面板。
-                                        close_handle.close();
-                                    },
-                                    "{label}"
+                        if show_second {
+                            div { class: "adui-time-picker-column",
+                                for (s, class_attr, label) in second_cells {
+                                    span {
+                                        class: "{class_attr}",
+                                        onclick: move |_| {
+                                            close_handle.mark_internal_click();
+                                            let next = TimeValue::new(current_hour, current_minute, s);
+                                            apply_time(next);
+                                            // 默认在选择秒后关闭面板。
+                                            close_handle.close();
+                                        },
+                                        "{label}"
+                                    }
                                 }
                             }
                         }
@@ -335,6 +385,21 @@ pub fn TimePicker(props: TimePickerProps) -> Element {
 mod tests {
     use super::*;
 
+    #[test]
+    fn time_value_from_unix_timestamp_applies_offset() {
+        // 2024-03-01T23:30:15Z
+        let at = 1_709_335_815;
+        assert_eq!(
+            TimeValue::from_unix_timestamp(at, UtcOffset::UTC),
+            Some(TimeValue::new(23, 30, 15))
+        );
+        let new_york = UtcOffset::from_hms(-5, 0, 0).unwrap();
+        assert_eq!(
+            TimeValue::from_unix_timestamp(at, new_york),
+            Some(TimeValue::new(18, 30, 15))
+        );
+    }
+
     #[test]
     fn time_value_to_string_roundtrip() {
         let v = TimeValue::new(9, 5, 7);
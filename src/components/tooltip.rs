@@ -1,4 +1,7 @@
 use crate::components::floating::use_floating_close_handle;
+use crate::components::floating_position::{
+    FloatingAlign, FloatingPlacement, FloatingSide, PositionOptions,
+};
 use crate::components::overlay::OverlayKind;
 use crate::components::select_base::use_floating_layer;
 use dioxus::events::{KeyboardEvent, MouseEvent};
@@ -15,6 +18,18 @@ pub enum TooltipPlacement {
     Right,
 }
 
+impl From<TooltipPlacement> for FloatingPlacement {
+    fn from(placement: TooltipPlacement) -> Self {
+        let side = match placement {
+            TooltipPlacement::Top => FloatingSide::Top,
+            TooltipPlacement::Bottom => FloatingSide::Bottom,
+            TooltipPlacement::Left => FloatingSide::Left,
+            TooltipPlacement::Right => FloatingSide::Right,
+        };
+        FloatingPlacement::new(side, FloatingAlign::Center)
+    }
+}
+
 /// Trigger mode for opening/closing the tooltip.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum TooltipTrigger {
@@ -92,6 +107,10 @@ pub fn Tooltip(props: TooltipProps) -> Element {
     // Register with the overlay manager to obtain a z-index slot.
     let floating = use_floating_layer(OverlayKind::Tooltip, current_open);
     let current_z = *floating.z_index.read();
+    let positioner = floating.position;
+    let measured = positioner.position();
+    let anchor_id = positioner.anchor_id();
+    let overlay_id = positioner.overlay_id();
 
     // Click-outside + Esc close helper is only needed for uncontrolled
     // click-triggered tooltips. For controlled cases the parent is expected to
@@ -119,15 +138,29 @@ pub fn Tooltip(props: TooltipProps) -> Element {
         list.join(" ")
     };
 
+    // After measuring, the flipped placement decides where the arrow goes.
+    let effective_placement = measured
+        .map(|pos| pos.placement)
+        .unwrap_or_else(|| placement.into());
+
     let overlay_class_attr = {
-        let mut list = vec!["adui-tooltip".to_string()];
+        let mut list = vec![
+            "adui-tooltip".to_string(),
+            format!(
+                "adui-tooltip-placement-{}",
+                effective_placement.side.as_str()
+            ),
+        ];
         if let Some(extra) = overlay_class {
             list.push(extra);
         }
         list.join(" ")
     };
 
-    let overlay_style_attr = {
+    let overlay_style_attr = if let Some(pos) = measured {
+        let extra = overlay_style.unwrap_or_default();
+        format!("{} z-index: {}; {}", pos.to_style(), current_z, extra)
+    } else {
         let placement_css = match placement {
             TooltipPlacement::Top => {
                 "bottom: 100%; left: 50%; transform: translateX(-50%); margin-bottom: 8px;"
@@ -154,6 +187,7 @@ pub fn Tooltip(props: TooltipProps) -> Element {
 
     rsx! {
         span {
+            id: "{anchor_id}",
             class: "{class_attr}",
             style: "position: relative; display: inline-block;",
             onmouseenter: move |_evt: MouseEvent| {
@@ -196,9 +230,13 @@ pub fn Tooltip(props: TooltipProps) -> Element {
             {children}
             if current_open {
                 div {
+                    id: "{overlay_id}",
                     class: "{overlay_class_attr}",
                     style: "{overlay_style_attr}",
                     tabindex: 0,
+                    onmounted: move |_| {
+                        positioner.update(placement.into(), PositionOptions::default());
+                    },
                     onkeydown: move |evt: KeyboardEvent| {
                         if matches!(evt.key(), Key::Escape) {
                             evt.prevent_default();
@@ -216,6 +254,7 @@ pub fn Tooltip(props: TooltipProps) -> Element {
                             handle.mark_internal_click();
                         }
                     },
+                    span { class: "adui-tooltip-arrow" }
                     div { class: "adui-tooltip-inner",
                         if let Some(node) = content_node {
                             {node}
@@ -282,6 +321,15 @@ mod tests {
         assert_eq!(original, cloned);
     }
 
+    #[test]
+    fn tooltip_placement_maps_to_centered_floating_placement() {
+        let top: FloatingPlacement = TooltipPlacement::Top.into();
+        assert_eq!(top.side, FloatingSide::Top);
+        assert_eq!(top.align, FloatingAlign::Center);
+        let right: FloatingPlacement = TooltipPlacement::Right.into();
+        assert_eq!(right.side, FloatingSide::Right);
+    }
+
     #[test]
     fn tooltip_trigger_default() {
         assert_eq!(TooltipTrigger::default(), TooltipTrigger::Hover);
@@ -30,6 +30,7 @@
 //! ```
 
 use crate::components::button::{Button, ButtonColor, ButtonVariant};
+use crate::components::locale::use_locale;
 use crate::components::overlay::{OverlayKey, OverlayKind, use_overlay};
 use crate::components::tooltip::TooltipPlacement;
 use crate::theme::use_theme;
@@ -247,6 +248,8 @@ pub fn Tour(props: TourProps) -> Element {
         });
     }
 
+    let locale = use_locale();
+
     if !open || steps.is_empty() {
         return rsx! {};
     }
@@ -268,16 +271,16 @@ pub fn Tour(props: TourProps) -> Element {
         .as_ref()
         .or(prev_button_text.as_ref())
         .cloned()
-        .unwrap_or_else(|| "Previous".to_string());
+        .unwrap_or_else(|| locale.tour.previous.clone());
     let next_text = step
         .next_button_text
         .as_ref()
         .or(next_button_text.as_ref())
         .cloned()
-        .unwrap_or_else(|| "Next".to_string());
+        .unwrap_or_else(|| locale.tour.next.clone());
     let finish_text = finish_button_text
         .clone()
-        .unwrap_or_else(|| "Finish".to_string());
+        .unwrap_or_else(|| locale.tour.finish.clone());
 
     // Placement CSS
     let placement = step.placement.unwrap_or(TooltipPlacement::Bottom);
@@ -3,6 +3,7 @@
 //! A double-column layout component that allows selecting items from a source
 //! list and moving them to a target list.
 
+use crate::components::locale::use_locale;
 use dioxus::prelude::*;
 use std::collections::HashSet;
 
@@ -204,9 +205,16 @@ pub fn Transfer(props: TransferProps) -> Element {
         .collect();
 
     // Titles
-    let (left_title, right_title) = titles.unwrap_or(("Source".into(), "Target".into()));
+    let locale = use_locale();
+    let (left_title, right_title) = titles.unwrap_or_else(|| {
+        (
+            locale.transfer.source_title.clone(),
+            locale.transfer.target_title.clone(),
+        )
+    });
     let (to_right_text, to_left_text) = operations.unwrap_or((">".into(), "<".into()));
-    let placeholder = search_placeholder.unwrap_or_else(|| "Search here".into());
+    let placeholder =
+        search_placeholder.unwrap_or_else(|| locale.transfer.search_placeholder.clone());
 
     // Build class list
     let mut class_list = vec!["adui-transfer".to_string()];
@@ -525,6 +533,7 @@ fn TransferList(props: TransferListProps) -> Element {
         show_select_all,
     } = props;
 
+    let locale = use_locale();
     let selected_set: HashSet<&str> = selected_keys.iter().map(|s| s.as_str()).collect();
     let selectable_count = items.iter().filter(|i| !i.disabled).count();
     let selected_count = selected_keys.len();
@@ -554,7 +563,7 @@ fn TransferList(props: TransferListProps) -> Element {
                     }
                 }
                 span { class: "adui-transfer-list-header-selected",
-                    "{selected_count}/{items.len()} items"
+                    "{selected_count}/{items.len()} {locale.transfer.items_unit}"
                 }
                 span { class: "adui-transfer-list-header-title", "{title}" }
             }
@@ -586,7 +595,7 @@ fn TransferList(props: TransferListProps) -> Element {
                         }
                     }
                     if items.is_empty() {
-                        li { class: "adui-transfer-list-empty", "No data" }
+                        li { class: "adui-transfer-list-empty", "{locale.transfer.not_found}" }
                     }
                 }
             }
@@ -748,9 +757,12 @@ mod tests {
 
     #[test]
     fn default_filter_with_description_preference() {
-        let item = TransferItem::new("1", "Title")
-            .with_description("Description text");
-        assert!(default_filter("description", &item, TransferDirection::Left));
+        let item = TransferItem::new("1", "Title").with_description("Description text");
+        assert!(default_filter(
+            "description",
+            &item,
+            TransferDirection::Left
+        ));
         assert!(default_filter("title", &item, TransferDirection::Left));
     }
 
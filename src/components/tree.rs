@@ -6,6 +6,8 @@
 //! - Checkable mode with checkbox indicators
 //! - Keyboard navigation
 //! - Optional show line mode
+//! - Drag and drop reordering with before/inside/after drop positions
+//! - Async lazy loading of children via `load_data`
 //!
 //! # Example
 //! ```rust,ignore
@@ -34,8 +36,12 @@
 //! ```
 
 use crate::components::config_provider::use_config;
-use crate::components::select_base::{OptionKey, TreeNode};
+use crate::components::icon::{Icon, IconKind};
+use crate::components::select_base::{
+    LoadDataFn, NodeLoadStatus, OptionKey, TreeNode, use_lazy_node_loader,
+};
 use crate::theme::use_theme;
+use dioxus::html::input_data::MouseButton;
 use dioxus::prelude::*;
 use std::rc::Rc;
 
@@ -235,9 +241,13 @@ pub struct TreeProps {
     /// Enable drag and drop for tree nodes.
     #[props(optional)]
     pub draggable: Option<DraggableConfig>,
-    /// Async load data function: (node) -> Vec<TreeNode>
+    /// Called when a dragged node is dropped on a valid target.
     #[props(optional)]
-    pub load_data: Option<Rc<dyn Fn(&TreeNode) -> Vec<TreeNode>>>,
+    pub on_drop: Option<EventHandler<TreeDropInfo>>,
+    /// Async loader for the children of a node, invoked when a childless
+    /// node is expanded. Loaded children are merged into `tree_data`.
+    #[props(optional)]
+    pub load_data: Option<LoadDataFn>,
     /// Custom field names for tree data structure.
     #[props(optional)]
     pub field_names: Option<FieldNames>,
@@ -253,9 +263,12 @@ pub struct TreeProps {
     /// Custom title render: (node) -> Element
     #[props(optional)]
     pub title_render: Option<Rc<dyn Fn(&TreeNode) -> Element>>,
-    /// Loaded keys for async loading state.
+    /// Controlled set of keys whose children have been loaded.
     #[props(optional)]
     pub loaded_keys: Option<Vec<String>>,
+    /// Called with all loaded keys after a `load_data` request succeeds.
+    #[props(optional)]
+    pub on_load: Option<EventHandler<Vec<String>>>,
 
     // --- Styling ---
     #[props(optional)]
@@ -300,6 +313,139 @@ pub struct FieldNames {
     pub children: Option<String>,
 }
 
+/// Where a dragged node lands relative to the drop target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DropPosition {
+    /// Sibling placed directly before the target.
+    Before,
+    /// Last child of the target.
+    Inside,
+    /// Sibling placed directly after the target.
+    After,
+}
+
+impl DropPosition {
+    fn as_class(&self) -> &'static str {
+        match self {
+            DropPosition::Before => "adui-tree-treenode-drop-before",
+            DropPosition::Inside => "adui-tree-treenode-drop-inside",
+            DropPosition::After => "adui-tree-treenode-drop-after",
+        }
+    }
+}
+
+/// Payload of the Tree `on_drop` event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeDropInfo {
+    /// Key of the node being dragged.
+    pub drag_key: String,
+    /// Key of the node it was dropped on.
+    pub drop_key: String,
+    /// Position relative to the drop node.
+    pub position: DropPosition,
+}
+
+/// Row height used to split a node into before/inside/after drop zones.
+/// Matches the `min-height` of `.adui-tree-treenode`.
+const TREE_NODE_HEIGHT: f64 = 28.0;
+
+/// Map a vertical offset inside a node row to a drop position: the top and
+/// bottom quarters drop into the gap, the middle drops inside.
+pub fn drop_position_for_offset(offset_y: f64, node_height: f64) -> DropPosition {
+    if offset_y < node_height * 0.25 {
+        DropPosition::Before
+    } else if offset_y > node_height * 0.75 {
+        DropPosition::After
+    } else {
+        DropPosition::Inside
+    }
+}
+
+/// Whether `drag_key` may be dropped on `drop_key`. A node cannot be dropped
+/// on itself or on one of its descendants.
+pub fn can_drop_tree_node(nodes: &[TreeNode], drag_key: &str, drop_key: &str) -> bool {
+    drag_key != drop_key
+        && find_tree_node(nodes, drop_key).is_some()
+        && !collect_descendant_keys(nodes, drag_key)
+            .iter()
+            .any(|k| k == drop_key)
+}
+
+/// Apply a drop to `nodes`, returning the reordered tree.
+///
+/// Returns `None` when either key is missing or the move would place a node
+/// inside its own subtree.
+pub fn move_tree_node(
+    nodes: &[TreeNode],
+    drag_key: &str,
+    drop_key: &str,
+    position: DropPosition,
+) -> Option<Vec<TreeNode>> {
+    if !can_drop_tree_node(nodes, drag_key, drop_key) {
+        return None;
+    }
+    let mut next = nodes.to_vec();
+    let dragged = take_tree_node(&mut next, drag_key)?;
+    insert_tree_node(&mut next, drop_key, position, dragged)
+        .is_none()
+        .then_some(next)
+}
+
+fn find_tree_node<'a>(nodes: &'a [TreeNode], key: &str) -> Option<&'a TreeNode> {
+    nodes.iter().find_map(|node| {
+        if node.key == key {
+            Some(node)
+        } else {
+            find_tree_node(&node.children, key)
+        }
+    })
+}
+
+fn take_tree_node(nodes: &mut Vec<TreeNode>, key: &str) -> Option<TreeNode> {
+    if let Some(idx) = nodes.iter().position(|n| n.key == key) {
+        return Some(nodes.remove(idx));
+    }
+    nodes
+        .iter_mut()
+        .find_map(|node| take_tree_node(&mut node.children, key))
+}
+
+/// Insert `node` next to or inside `drop_key`. Hands the node back when the
+/// target is not found.
+fn insert_tree_node(
+    nodes: &mut Vec<TreeNode>,
+    drop_key: &str,
+    position: DropPosition,
+    node: TreeNode,
+) -> Option<TreeNode> {
+    if let Some(idx) = nodes.iter().position(|n| n.key == drop_key) {
+        match position {
+            DropPosition::Before => nodes.insert(idx, node),
+            DropPosition::After => nodes.insert(idx + 1, node),
+            DropPosition::Inside => nodes[idx].children.push(node),
+        }
+        return None;
+    }
+    let mut node = node;
+    for child in nodes.iter_mut() {
+        match insert_tree_node(&mut child.children, drop_key, position, node) {
+            None => return None,
+            Some(back) => node = back,
+        }
+    }
+    Some(node)
+}
+
+/// In-flight drag started by a pointer press on a node.
+#[derive(Clone, Debug, PartialEq)]
+struct TreeDragState {
+    drag_key: String,
+    /// Set once the pointer has moved onto another node.
+    moved: bool,
+    /// Current valid drop target, if any.
+    over: Option<(String, DropPosition)>,
+}
+
 impl PartialEq for TreeProps {
     fn eq(&self, other: &Self) -> bool {
         // Compare all fields except function pointers
@@ -325,8 +471,10 @@ impl PartialEq for TreeProps {
             && self.class == other.class
             && self.style == other.style
             && self.draggable == other.draggable
+            && self.on_drop == other.on_drop
             && self.field_names == other.field_names
             && self.loaded_keys == other.loaded_keys
+            && self.on_load == other.on_load
         // Function pointers cannot be compared for equality
     }
 }
@@ -355,6 +503,11 @@ pub fn Tree(props: TreeProps) -> Element {
         show_icon,
         block_node,
         disabled,
+        draggable,
+        on_drop,
+        load_data,
+        loaded_keys,
+        on_load,
         class,
         style,
         ..
@@ -366,8 +519,9 @@ pub fn Tree(props: TreeProps) -> Element {
 
     let is_disabled = disabled || config.disabled;
 
-    // Prepare tree data
-    let nodes: Vec<TreeNode> = tree_data.unwrap_or_default();
+    // Prepare tree data, grafting in children fetched by `load_data`.
+    let loader = use_lazy_node_loader(load_data, loaded_keys, on_load);
+    let nodes: Vec<TreeNode> = loader.merge(&tree_data.unwrap_or_default());
 
     // Flatten tree for rendering
     let mut flat_nodes: Vec<FlatTreeNode> = Vec::new();
@@ -420,6 +574,13 @@ pub fn Tree(props: TreeProps) -> Element {
     // Active index for keyboard navigation
     let active_index: Signal<Option<usize>> = use_signal(|| None);
 
+    // --- Drag state ---
+    let drag_enabled = !is_disabled && draggable.as_ref().is_some_and(|d| d.enabled);
+    let drag_icon = draggable.as_ref().and_then(|d| d.icon.clone());
+    let node_draggable = draggable.as_ref().and_then(|d| d.node_draggable.clone());
+    let drag_state: Signal<Option<TreeDragState>> = use_signal(|| None);
+    let is_dragging = drag_state.read().as_ref().is_some_and(|s| s.moved);
+
     // Filter visible nodes based on expanded state
     let visible_nodes: Vec<FlatTreeNode> = {
         let mut result = Vec::new();
@@ -460,6 +621,12 @@ pub fn Tree(props: TreeProps) -> Element {
     if is_disabled {
         class_list.push("adui-tree-disabled".into());
     }
+    if drag_enabled {
+        class_list.push("adui-tree-draggable".into());
+    }
+    if is_dragging {
+        class_list.push("adui-tree-dragging".into());
+    }
     if let Some(extra) = class {
         class_list.push(extra);
     }
@@ -480,6 +647,18 @@ pub fn Tree(props: TreeProps) -> Element {
             style: "{style_attr}",
             role: "tree",
             tabindex: 0,
+            onpointerup: move |_| {
+                let mut state = drag_state;
+                if state.peek().is_some() {
+                    state.set(None);
+                }
+            },
+            onpointerleave: move |_| {
+                let mut state = drag_state;
+                if state.peek().is_some() {
+                    state.set(None);
+                }
+            },
             onkeydown: {
                 let visible_for_keydown = visible_nodes.clone();
                 let nodes_for_keydown = nodes.clone();
@@ -527,8 +706,14 @@ pub fn Tree(props: TreeProps) -> Element {
                             if let Some(idx) = *active.read() {
                                 if idx < visible_for_keydown.len() {
                                     let node = &visible_for_keydown[idx];
-                                    if node.has_children && !current_expanded_for_keydown.contains(&node.key) {
-                                        // Expand node
+                                    let expandable = node.has_children || loader.can_load(&node.key);
+                                    if expandable && !current_expanded_for_keydown.contains(&node.key) {
+                                        // Expand node, fetching its children if needed
+                                        if !node.has_children
+                                            && let Some(tree_node) = find_tree_node(&nodes_for_keydown, &node.key)
+                                        {
+                                            loader.load(tree_node);
+                                        }
                                         let mut next_expanded = current_expanded_for_keydown.clone();
                                         next_expanded.push(node.key.clone());
                                         if let Some(cb) = on_expand_cb {
@@ -547,7 +732,7 @@ pub fn Tree(props: TreeProps) -> Element {
                             if let Some(idx) = *active.read() {
                                 if idx < visible_for_keydown.len() {
                                     let node = &visible_for_keydown[idx];
-                                    if node.has_children && current_expanded_for_keydown.contains(&node.key) {
+                                    if current_expanded_for_keydown.contains(&node.key) {
                                         // Collapse node
                                         let next_expanded: Vec<String> = current_expanded_for_keydown
                                             .iter()
@@ -618,6 +803,14 @@ pub fn Tree(props: TreeProps) -> Element {
                     let label = node.label.clone();
                     let depth = node.depth;
                     let has_children = node.has_children;
+                    // Childless nodes stay expandable until `load_data` has run for them.
+                    let expandable = has_children || loader.can_load(&key);
+                    let load_status = if has_children { None } else { loader.status(&key) };
+                    let is_loading = load_status == Some(NodeLoadStatus::Loading);
+                    let load_error = match &load_status {
+                        Some(NodeLoadStatus::Failed(message)) => Some(message.clone()),
+                        _ => None,
+                    };
                     let node_disabled = node.disabled || is_disabled;
                     let is_last = node.is_last;
                     let ancestor_is_last = node.ancestor_is_last.clone();
@@ -645,6 +838,28 @@ pub fn Tree(props: TreeProps) -> Element {
                     let nodes_for_node = nodes.clone();
                     let flat_for_node = flat_nodes.clone();
 
+                    // Drag and drop
+                    let can_drag = drag_enabled
+                        && !node.disabled
+                        && node_draggable.as_ref().is_none_or(|check| {
+                            find_tree_node(&nodes, &key).is_some_and(|n| check(n))
+                        });
+                    let (is_drag_source, drop_position) = match drag_state.read().as_ref() {
+                        Some(state) if state.moved => (
+                            state.drag_key == key,
+                            state
+                                .over
+                                .as_ref()
+                                .filter(|(over_key, _)| over_key == &key)
+                                .map(|(_, position)| *position),
+                        ),
+                        _ => (false, None),
+                    };
+                    let drop_indent = match drop_position {
+                        Some(DropPosition::Inside) => (depth + 1) * 24,
+                        _ => depth * 24,
+                    };
+
                     rsx! {
                         li {
                             key: "{key}",
@@ -659,11 +874,82 @@ pub fn Tree(props: TreeProps) -> Element {
                                 if is_active {
                                     classes.push("adui-tree-treenode-active".into());
                                 }
+                                if is_drag_source {
+                                    classes.push("adui-tree-treenode-dragging".into());
+                                }
+                                if let Some(position) = drop_position {
+                                    classes.push(position.as_class().into());
+                                }
                                 classes.join(" ")
                             },
                             role: "treeitem",
                             "aria-selected": is_selected,
-                            "aria-expanded": if has_children { is_expanded.to_string() } else { String::new() },
+                            "aria-expanded": if expandable { is_expanded.to_string() } else { String::new() },
+                            "aria-busy": is_loading,
+                            onpointerdown: {
+                                let key_for_drag = key.clone();
+                                move |evt: PointerEvent| {
+                                    if !can_drag || evt.trigger_button() != Some(MouseButton::Primary) {
+                                        return;
+                                    }
+                                    let mut state = drag_state;
+                                    state.set(Some(TreeDragState {
+                                        drag_key: key_for_drag.clone(),
+                                        moved: false,
+                                        over: None,
+                                    }));
+                                }
+                            },
+                            onpointermove: {
+                                let key_for_drop = key.clone();
+                                let nodes_for_drop = nodes_for_node.clone();
+                                move |evt: PointerEvent| {
+                                    if !drag_enabled {
+                                        return;
+                                    }
+                                    let mut state = drag_state;
+                                    let Some(current) = state.peek().clone() else {
+                                        return;
+                                    };
+                                    if !evt.held_buttons().contains(MouseButton::Primary) {
+                                        state.set(None);
+                                        return;
+                                    }
+                                    let moved = current.moved || current.drag_key != key_for_drop;
+                                    let over = can_drop_tree_node(&nodes_for_drop, &current.drag_key, &key_for_drop)
+                                        .then(|| {
+                                            let offset_y = evt.element_coordinates().y;
+                                            (key_for_drop.clone(), drop_position_for_offset(offset_y, TREE_NODE_HEIGHT))
+                                        });
+                                    let next = TreeDragState {
+                                        drag_key: current.drag_key.clone(),
+                                        moved,
+                                        over,
+                                    };
+                                    if next != current {
+                                        state.set(Some(next));
+                                    }
+                                }
+                            },
+                            onpointerup: move |_| {
+                                let mut state = drag_state;
+                                let finished = state.peek().clone();
+                                if let Some(TreeDragState { drag_key, moved: true, over: Some((drop_key, position)) }) = finished {
+                                    state.set(None);
+                                    if let Some(cb) = on_drop {
+                                        cb.call(TreeDropInfo { drag_key, drop_key, position });
+                                    }
+                                }
+                            },
+                            if drop_position.is_some() {
+                                span {
+                                    class: "adui-tree-drop-indicator",
+                                    style: "left: {drop_indent}px;",
+                                }
+                            }
+                            if can_drag && let Some(icon) = drag_icon.clone() {
+                                span { class: "adui-tree-draggable-icon", {icon} }
+                            }
                             // Indent with optional lines
                             if show_line {
                                 {(0..depth).map(|i| {
@@ -698,7 +984,12 @@ pub fn Tree(props: TreeProps) -> Element {
                             span {
                                 class: {
                                     let mut classes = vec!["adui-tree-switcher".to_string()];
-                                    if has_children {
+                                    if is_loading {
+                                        classes.push("adui-tree-switcher-loading".into());
+                                    } else if load_error.is_some() {
+                                        classes.push("adui-tree-switcher-error".into());
+                                    }
+                                    if expandable {
                                         if is_expanded {
                                             classes.push("adui-tree-switcher-open".into());
                                         } else {
@@ -717,13 +1008,27 @@ pub fn Tree(props: TreeProps) -> Element {
                                 onclick: {
                                     let key_for_expand = key.clone();
                                     let current_expanded_for_expand = current_expanded_for_node.clone();
+                                    let nodes_for_expand = nodes_for_node.clone();
+                                    let retry = load_error.is_some();
                                     move |evt: MouseEvent| {
                                         evt.stop_propagation();
-                                        if !has_children {
+                                        if !expandable {
+                                            return;
+                                        }
+
+                                        let collapsing = current_expanded_for_expand.contains(&key_for_expand);
+                                        if !has_children
+                                            && (retry || !collapsing)
+                                            && let Some(tree_node) = find_tree_node(&nodes_for_expand, &key_for_expand)
+                                        {
+                                            loader.load(tree_node);
+                                        }
+                                        // Clicking a failed node retries the request without collapsing it.
+                                        if retry && collapsing {
                                             return;
                                         }
 
-                                        let next_expanded = if current_expanded_for_expand.contains(&key_for_expand) {
+                                        let next_expanded = if collapsing {
                                             current_expanded_for_expand
                                                 .iter()
                                                 .filter(|k| *k != &key_for_expand)
@@ -744,7 +1049,15 @@ pub fn Tree(props: TreeProps) -> Element {
                                         }
                                     }
                                 },
-                                if show_line {
+                                if is_loading {
+                                    Icon { kind: IconKind::Loading, size: 14.0, class: "adui-tree-switcher-loading-icon" }
+                                } else if let Some(message) = load_error.clone() {
+                                    span {
+                                        class: "adui-tree-switcher-error-icon",
+                                        title: "{message}",
+                                        "!"
+                                    }
+                                } else if show_line {
                                     // Vertical line (top half for non-first items, full for non-last items)
                                     if depth > 0 {
                                         // Top half of vertical line
@@ -763,7 +1076,7 @@ pub fn Tree(props: TreeProps) -> Element {
                                         }
                                     }
                                     // Show line style icons - bordered box
-                                    if has_children {
+                                    if expandable {
                                         span {
                                             style: "position: relative; z-index: 1; display: inline-flex; align-items: center; justify-content: center; width: 10px; height: 10px; border: 1px solid var(--adui-color-border, #d9d9d9); border-radius: 2px; background: var(--adui-color-bg-container, #fff); font-size: 10px; line-height: 1; cursor: pointer; color: var(--adui-color-text-secondary, rgba(0,0,0,0.65));",
                                             if is_expanded { "−" } else { "+" }
@@ -774,7 +1087,7 @@ pub fn Tree(props: TreeProps) -> Element {
                                             style: "position: absolute; left: 17px; top: 50%; width: 6px; height: 1px; background: var(--adui-color-border, #d9d9d9); transform: translateY(-50%);"
                                         }
                                     }
-                                } else if has_children {
+                                } else if expandable {
                                     {
                                         let rotate_deg = if is_expanded { 90 } else { 0 };
                                         rsx! {
@@ -870,7 +1183,7 @@ pub fn Tree(props: TreeProps) -> Element {
                                     span {
                                         class: "adui-tree-iconEle",
                                         style: "margin-right: 4px;",
-                                        if has_children {
+                                        if expandable {
                                             if is_expanded { "📂" } else { "📁" }
                                         } else {
                                             "📄"
@@ -1144,4 +1457,76 @@ mod tests {
         assert!(!descendants.contains(&"b".to_string()));
         assert!(!descendants.contains(&"root".to_string()));
     }
+
+    fn node(key: &str, children: Vec<TreeNode>) -> TreeNode {
+        TreeNode {
+            key: key.into(),
+            label: key.to_uppercase(),
+            disabled: false,
+            children,
+        }
+    }
+
+    fn shape(nodes: &[TreeNode]) -> String {
+        nodes
+            .iter()
+            .map(|n| {
+                if n.children.is_empty() {
+                    n.key.clone()
+                } else {
+                    format!("{}({})", n.key, shape(&n.children))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn sample_tree() -> Vec<TreeNode> {
+        vec![
+            node(
+                "a",
+                vec![node("a1", vec![]), node("a2", vec![node("a2x", vec![])])],
+            ),
+            node("b", vec![]),
+            node("c", vec![node("c1", vec![])]),
+        ]
+    }
+
+    #[test]
+    fn drop_position_splits_row_into_zones() {
+        assert_eq!(drop_position_for_offset(2.0, 28.0), DropPosition::Before);
+        assert_eq!(drop_position_for_offset(14.0, 28.0), DropPosition::Inside);
+        assert_eq!(drop_position_for_offset(26.0, 28.0), DropPosition::After);
+    }
+
+    #[test]
+    fn move_tree_node_before_and_after_siblings() {
+        let nodes = sample_tree();
+        let moved = move_tree_node(&nodes, "c", "a", DropPosition::Before).unwrap();
+        assert_eq!(shape(&moved), "c(c1),a(a1,a2(a2x)),b");
+
+        let moved = move_tree_node(&nodes, "a1", "b", DropPosition::After).unwrap();
+        assert_eq!(shape(&moved), "a(a2(a2x)),b,a1,c(c1)");
+    }
+
+    #[test]
+    fn move_tree_node_inside_appends_child() {
+        let nodes = sample_tree();
+        let moved = move_tree_node(&nodes, "b", "a2x", DropPosition::Inside).unwrap();
+        assert_eq!(shape(&moved), "a(a1,a2(a2x(b))),c(c1)");
+
+        let moved = move_tree_node(&nodes, "a2", "c", DropPosition::Inside).unwrap();
+        assert_eq!(shape(&moved), "a(a1),b,c(c1,a2(a2x))");
+    }
+
+    #[test]
+    fn move_tree_node_rejects_invalid_targets() {
+        let nodes = sample_tree();
+        assert!(move_tree_node(&nodes, "a", "a", DropPosition::After).is_none());
+        assert!(move_tree_node(&nodes, "a", "a2x", DropPosition::Inside).is_none());
+        assert!(move_tree_node(&nodes, "missing", "b", DropPosition::Before).is_none());
+        assert!(move_tree_node(&nodes, "b", "missing", DropPosition::Before).is_none());
+        assert!(can_drop_tree_node(&nodes, "a2x", "a"));
+        assert!(!can_drop_tree_node(&nodes, "a", "a1"));
+    }
 }
@@ -1,10 +1,11 @@
 use crate::components::config_provider::{ComponentSize, use_config};
 use crate::components::control::{ControlStatus, push_status_class};
 use crate::components::form::{FormItemControlContext, use_form_item_control};
+use crate::components::icon::{Icon, IconKind};
 use crate::components::select_base::{
-    DropdownLayer, OptionKey, TreeNode, handle_option_list_key_event, option_key_to_value,
-    option_keys_to_value, toggle_option_key, use_dropdown_layer, value_to_option_key,
-    value_to_option_keys,
+    DropdownLayer, LoadDataFn, NodeLoadStatus, OptionKey, TreeNode, find_option_node,
+    handle_option_list_key_event, option_key_to_value, option_keys_to_value, toggle_option_key,
+    use_dropdown_layer, use_lazy_node_loader, value_to_option_key, value_to_option_keys,
 };
 use dioxus::events::KeyboardEvent;
 use dioxus::prelude::*;
@@ -17,7 +18,8 @@ use serde_json::Value;
 /// - 简单 label 搜索（show_search，基于节点 label 的本地过滤）
 /// - 树结构默认全部展开，通过缩进展示层级；暂不支持折叠/半选状态
 /// - 与 Form 的值双向绑定，复用 Select 的表单集成逻辑
-#[derive(Props, Clone, PartialEq)]
+/// - 通过 `load_data` 异步加载无子节点的节点（与 Tree / Cascader 共用加载机制）
+#[derive(Props, Clone)]
 pub struct TreeSelectProps {
     /// 树形数据源。每个节点包含 key / label / disabled / children。
     #[props(optional)]
@@ -62,6 +64,39 @@ pub struct TreeSelectProps {
     /// 选中集合变更回调（单选约定 Vec 长度为 0 或 1）。
     #[props(optional)]
     pub on_change: Option<EventHandler<Vec<String>>>,
+    /// 异步加载节点子级，点击无子节点节点前的展开图标时触发。
+    #[props(optional)]
+    pub load_data: Option<LoadDataFn>,
+    /// 受控的已加载节点 key 集合。
+    #[props(optional)]
+    pub loaded_keys: Option<Vec<String>>,
+    /// `load_data` 成功后回调全部已加载 key。
+    #[props(optional)]
+    pub on_load: Option<EventHandler<Vec<String>>>,
+}
+
+impl PartialEq for TreeSelectProps {
+    fn eq(&self, other: &Self) -> bool {
+        // Compare all fields except function pointers
+        self.tree_data == other.tree_data
+            && self.value == other.value
+            && self.values == other.values
+            && self.multiple == other.multiple
+            && self.tree_checkable == other.tree_checkable
+            && self.show_search == other.show_search
+            && self.placeholder == other.placeholder
+            && self.disabled == other.disabled
+            && self.status == other.status
+            && self.size == other.size
+            && self.class == other.class
+            && self.style == other.style
+            && self.dropdown_class == other.dropdown_class
+            && self.dropdown_style == other.dropdown_style
+            && self.on_change == other.on_change
+            && self.loaded_keys == other.loaded_keys
+            && self.on_load == other.on_load
+        // Function pointers cannot be compared for equality
+    }
 }
 
 /// Internal flattened representation of a tree node for rendering and
@@ -107,10 +142,14 @@ pub fn TreeSelect(props: TreeSelectProps) -> Element {
         dropdown_class,
         dropdown_style,
         on_change,
+        load_data,
+        loaded_keys,
+        on_load,
     } = props;
 
     let config = use_config();
     let form_control = use_form_item_control();
+    let loader = use_lazy_node_loader(load_data, loaded_keys, on_load);
 
     let final_size = size.unwrap_or(config.size);
 
@@ -159,7 +198,7 @@ pub fn TreeSelect(props: TreeSelectProps) -> Element {
 
     // Document-level click handler for closing the dropdown when clicking
     // outside of the tree select. This is only compiled for wasm32 targets.
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         let mut open_for_global = open_state;
         let mut internal_flag = internal_click_flag;
@@ -202,7 +241,7 @@ pub fn TreeSelect(props: TreeSelectProps) -> Element {
     let current_z = *z_index.read();
 
     // Prepare flattened nodes and apply search filter if needed.
-    let nodes: Vec<TreeNode> = tree_data.unwrap_or_else(Vec::new);
+    let nodes: Vec<TreeNode> = loader.merge(&tree_data.unwrap_or_else(Vec::new));
     let mut flat_nodes: Vec<FlatNode> = Vec::new();
     flatten_tree(&nodes, 0, &mut flat_nodes);
 
@@ -444,6 +483,17 @@ pub fn TreeSelect(props: TreeSelectProps) -> Element {
                             let internal_click_for_item = internal_click_flag;
                             let depth = node.depth;
 
+                            // Childless nodes can be expanded through `load_data`.
+                            let tree_node = find_option_node(&nodes, &key).cloned();
+                            let lazy = tree_node
+                                .as_ref()
+                                .is_some_and(|n| n.children.is_empty() && loader.can_load(&n.key));
+                            let load_status = if lazy { loader.status(&key) } else { None };
+                            let load_error = match &load_status {
+                                Some(NodeLoadStatus::Failed(message)) => Some(message.clone()),
+                                _ => None,
+                            };
+
                             rsx! {
                                 li {
                                     class: {
@@ -462,6 +512,7 @@ pub fn TreeSelect(props: TreeSelectProps) -> Element {
                                     style: {format!("padding-left: {}px;", 12 + depth as i32 * 16)},
                                     role: "treeitem",
                                     "aria-selected": is_selected,
+                                    "aria-busy": load_status == Some(NodeLoadStatus::Loading),
                                     onclick: move |_| {
                                         if disabled_opt {
                                             return;
@@ -492,6 +543,27 @@ pub fn TreeSelect(props: TreeSelectProps) -> Element {
                                             open_signal.set(false);
                                         }
                                     },
+                                    if lazy {
+                                        span {
+                                            class: "adui-select-tree-switcher",
+                                            title: load_error.clone().unwrap_or_default(),
+                                            onclick: move |evt: MouseEvent| {
+                                                evt.stop_propagation();
+                                                let mut flag = internal_click_for_item;
+                                                flag.set(true);
+                                                if let Some(node) = tree_node.as_ref() {
+                                                    loader.load(node);
+                                                }
+                                            },
+                                            if load_status == Some(NodeLoadStatus::Loading) {
+                                                Icon { kind: IconKind::Loading, size: 12.0 }
+                                            } else if load_error.is_some() {
+                                                span { class: "adui-select-tree-switcher-error", "!" }
+                                            } else {
+                                                "▶"
+                                            }
+                                        }
+                                    }
                                     "{label}"
                                 }
                             }
@@ -1,10 +1,11 @@
 use crate::components::icon::{Icon, IconKind};
+use crate::components::locale::{TypographyLocale, use_locale};
 use crate::theme::use_theme;
 use dioxus::events::KeyboardEvent;
 use dioxus::prelude::*;
 use dioxus::prelude::{Key, Modifiers};
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use wasm_bindgen::{JsCast, closure::Closure};
 
 /// Text tone variants (aligned to Ant Design semantics subset).
@@ -153,17 +154,18 @@ fn TypographyBase(props: TypographyBaseProps) -> Element {
     let tokens = theme.tokens();
     let tone_color = resolve_color(&tokens, r#type, disabled);
     let decoration = text_decoration(underline, delete);
+    let locale = use_locale();
     let ellipsis_cfg = ellipsis_config.unwrap_or_default();
     let ellipsis_rows = ellipsis_cfg.rows.unwrap_or(1);
     let ellipsis_expandable = ellipsis_cfg.expandable;
     let ellipsis_expand_text = ellipsis_cfg
         .expand_text
         .clone()
-        .unwrap_or_else(|| "展开".to_string());
+        .unwrap_or_else(|| locale.typography.expand.clone());
     let ellipsis_collapse_text = ellipsis_cfg
         .collapse_text
         .clone()
-        .unwrap_or_else(|| "收起".to_string());
+        .unwrap_or_else(|| locale.typography.collapse.clone());
     let ellipsis_tooltip = ellipsis_cfg.tooltip.clone();
 
     let copy_status = use_signal(|| false);
@@ -256,6 +258,7 @@ fn TypographyBase(props: TypographyBaseProps) -> Element {
             editing,
             on_edit,
             on_edit_cancel,
+            &locale.typography,
         )
     } else {
         rsx! { span {
@@ -274,7 +277,7 @@ fn TypographyBase(props: TypographyBaseProps) -> Element {
                 title: tooltip_attr.clone().unwrap_or_default(),
                 {node}
                 if let Some(cfg) = copy_cfg.clone() {
-                    {render_copy_control(cfg, disabled, copy_status, on_copy)}
+                    {render_copy_control(cfg, disabled, copy_status, on_copy, &locale.typography)}
                 }
                 if let Some(cfg) = edit_cfg.clone() {
                     if let Some(control) = render_edit_trigger(
@@ -305,7 +308,7 @@ fn TypographyBase(props: TypographyBaseProps) -> Element {
                 title: tooltip_attr.clone().unwrap_or_default(),
                 {node}
                 if let Some(cfg) = copy_cfg.clone() {
-                    {render_copy_control(cfg, disabled, copy_status, on_copy)}
+                    {render_copy_control(cfg, disabled, copy_status, on_copy, &locale.typography)}
                 }
                 if let Some(cfg) = edit_cfg.clone() {
                     if let Some(control) = render_edit_trigger(
@@ -334,7 +337,7 @@ fn TypographyBase(props: TypographyBaseProps) -> Element {
             match level {
                 TitleLevel::H1 => {
                     rsx!(h1 { class: "{class_attr}", style: "{style_attr}", title: tooltip.clone(), {node}
-                        if let Some(cfg) = copy_cfg.clone() { {render_copy_control(cfg, disabled, copy_status, on_copy)} }
+                        if let Some(cfg) = copy_cfg.clone() { {render_copy_control(cfg, disabled, copy_status, on_copy, &locale.typography)} }
                         if let Some(cfg) = edit_cfg.clone() {
                             if let Some(control) = render_edit_trigger(cfg, disabled, editing, edit_value, on_edit_start) { {control} }
                         }
@@ -349,7 +352,7 @@ fn TypographyBase(props: TypographyBaseProps) -> Element {
                 }
                 TitleLevel::H2 => {
                     rsx!(h2 { class: "{class_attr}", style: "{style_attr}", title: tooltip.clone(), {node}
-                        if let Some(cfg) = copy_cfg.clone() { {render_copy_control(cfg, disabled, copy_status, on_copy)} }
+                        if let Some(cfg) = copy_cfg.clone() { {render_copy_control(cfg, disabled, copy_status, on_copy, &locale.typography)} }
                         if let Some(cfg) = edit_cfg.clone() {
                             if let Some(control) = render_edit_trigger(cfg, disabled, editing, edit_value, on_edit_start) { {control} }
                         }
@@ -364,7 +367,7 @@ fn TypographyBase(props: TypographyBaseProps) -> Element {
                 }
                 TitleLevel::H3 => {
                     rsx!(h3 { class: "{class_attr}", style: "{style_attr}", title: tooltip.clone(), {node}
-                        if let Some(cfg) = copy_cfg.clone() { {render_copy_control(cfg, disabled, copy_status, on_copy)} }
+                        if let Some(cfg) = copy_cfg.clone() { {render_copy_control(cfg, disabled, copy_status, on_copy, &locale.typography)} }
                         if let Some(cfg) = edit_cfg.clone() {
                             if let Some(control) = render_edit_trigger(cfg, disabled, editing, edit_value, on_edit_start) { {control} }
                         }
@@ -379,7 +382,7 @@ fn TypographyBase(props: TypographyBaseProps) -> Element {
                 }
                 TitleLevel::H4 => {
                     rsx!(h4 { class: "{class_attr}", style: "{style_attr}", title: tooltip.clone(), {node}
-                        if let Some(cfg) = copy_cfg.clone() { {render_copy_control(cfg, disabled, copy_status, on_copy)} }
+                        if let Some(cfg) = copy_cfg.clone() { {render_copy_control(cfg, disabled, copy_status, on_copy, &locale.typography)} }
                         if let Some(cfg) = edit_cfg.clone() {
                             if let Some(control) = render_edit_trigger(cfg, disabled, editing, edit_value, on_edit_start) { {control} }
                         }
@@ -395,7 +398,7 @@ fn TypographyBase(props: TypographyBaseProps) -> Element {
                 TitleLevel::H5 => {
                     rsx!(h5 { class: "{class_attr}", style: "{style_attr}", title: tooltip, {node}
                         if let Some(cfg) = copy_cfg {
-                            {render_copy_control(cfg, disabled, copy_status, on_copy)}
+                            {render_copy_control(cfg, disabled, copy_status, on_copy, &locale.typography)}
                         }
                         if let Some(cfg) = edit_cfg {
                             if let Some(control) = render_edit_trigger(cfg, disabled, editing, edit_value, on_edit_start) { {control} }
@@ -697,17 +700,18 @@ fn render_copy_control(
     disabled: bool,
     copy_state: Signal<bool>,
     on_copy: Option<EventHandler<String>>,
+    locale: &TypographyLocale,
 ) -> Element {
     let idle = cfg
         .tooltips
         .as_ref()
         .map(|pair| pair.0.clone())
-        .unwrap_or_else(|| "复制".into());
+        .unwrap_or_else(|| locale.copy.clone());
     let success = cfg
         .tooltips
         .as_ref()
         .map(|pair| pair.1.clone())
-        .unwrap_or_else(|| "已复制".into());
+        .unwrap_or_else(|| locale.copied.clone());
     let idle_icon = cfg.icon.clone().unwrap_or_else(|| {
         rsx!(Icon {
             kind: IconKind::Copy,
@@ -856,11 +860,12 @@ fn render_editing(
     editing: Signal<bool>,
     on_edit: Option<EventHandler<String>>,
     on_edit_cancel: Option<EventHandler<String>>,
+    locale: &TypographyLocale,
 ) -> Element {
     let placeholder = cfg
         .placeholder
         .clone()
-        .unwrap_or_else(|| "请输入".to_string());
+        .unwrap_or_else(|| locale.edit_placeholder.clone());
     let enter_icon = cfg.enter_icon.clone().unwrap_or_else(|| {
         rsx!(Icon {
             kind: IconKind::Check,
@@ -1011,7 +1016,7 @@ fn trigger_copy(text: String, handler: Option<EventHandler<String>>, mut copy_st
     if let Some(cb) = handler {
         cb.call(text.clone());
     }
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         if let Some(window) = web_sys::window() {
             let navigator = window.navigator();
@@ -1021,7 +1026,7 @@ fn trigger_copy(text: String, handler: Option<EventHandler<String>>, mut copy_st
         copy_state.set(true);
         schedule_copy_reset(copy_state);
     }
-    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
     {
         copy_state.set(true);
     }
@@ -1039,7 +1044,7 @@ fn key_triggers_activation(key: &Key) -> bool {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 fn schedule_copy_reset(state: Signal<bool>) {
     if let Some(window) = web_sys::window() {
         let mut state_clone = state;
@@ -1054,7 +1059,7 @@ fn schedule_copy_reset(state: Signal<bool>) {
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
 #[allow(dead_code)]
 fn schedule_copy_reset(_state: Signal<bool>) {}
 
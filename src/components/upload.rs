@@ -1,25 +1,36 @@
+use crate::components::locale::use_locale;
+use crate::components::upload_chunk::{ChunkUploadState, ChunkedUploadConfig, UploadQueue};
+use crate::components::upload_image::{
+    CropState, ImageCropConfig, ImageResizeConfig, PendingCrop, RenderPlan, UploadCropModal,
+    is_image_file, is_processable_image, object_url, revoke_object_url,
+};
 use dioxus::{
+    core::use_drop,
     html::events::{DragEvent, FormData},
     prelude::*,
 };
 use dioxus_html::HasFileData;
-use std::{collections::HashMap, rc::Rc, time::SystemTime};
-
-#[cfg(target_arch = "wasm32")]
-use std::cell::RefCell;
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::HashMap, rc::Rc, time::SystemTime};
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 use {
+    crate::components::sleep::sleep_ms,
+    crate::components::upload_chunk::{ChunkAction, content_range, parse_server_offset},
+    crate::components::upload_image::{load_image, render_image},
     js_sys::{Array, Uint8Array},
-    wasm_bindgen::{JsCast, closure::Closure},
-    wasm_bindgen_futures::spawn_local,
+    wasm_bindgen::{JsCast, JsValue, closure::Closure},
+    wasm_bindgen_futures::{JsFuture, spawn_local},
     web_sys::{Blob, FormData as WebFormData, ProgressEvent, XmlHttpRequest},
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum UploadStatus {
+    /// Waiting for a free slot in chunked mode.
     Ready,
     Uploading,
+    /// Paused chunked upload.
+    Paused,
     Done,
     Error,
 }
@@ -42,6 +53,8 @@ pub struct UploadFile {
     pub error: Option<String>,
     pub percent: Option<f32>,
     pub response: Option<String>,
+    /// Thumbnail for picture lists, e.g. an object URL of the local file.
+    pub thumb_url: Option<String>,
 }
 
 impl UploadFile {
@@ -55,6 +68,7 @@ impl UploadFile {
             error: None,
             percent: Some(100.0),
             response: None,
+            thumb_url: None,
         }
     }
 
@@ -68,6 +82,7 @@ impl UploadFile {
             error: None,
             percent: Some(0.0),
             response: None,
+            thumb_url: None,
         }
     }
 }
@@ -91,11 +106,13 @@ impl Default for UploadListConfig {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct UploadFileMeta {
     pub name: String,
     pub size: Option<u64>,
     pub mime: Option<String>,
+    /// Path inside a picked or dropped folder, e.g. `photos/2024/a.jpg`.
+    pub relative_path: Option<String>,
 }
 
 pub type BeforeUploadFn = Rc<dyn Fn(&UploadFileMeta) -> bool>;
@@ -109,7 +126,7 @@ pub enum UploadHttpMethod {
 }
 
 impl UploadHttpMethod {
-    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    #[cfg_attr(not(all(feature = "web", target_arch = "wasm32")), allow(dead_code))]
     fn as_str(&self) -> &'static str {
         match self {
             UploadHttpMethod::Post => "POST",
@@ -128,6 +145,64 @@ pub struct AcceptConfig {
     pub extensions: Option<Vec<String>>,
 }
 
+impl AcceptConfig {
+    /// Parse an HTML `accept` attribute such as `"image/*,.pdf"`.
+    pub fn from_attr(accept: &str) -> Self {
+        let mut mime_types = Vec::new();
+        let mut extensions = Vec::new();
+        for token in accept.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            if token.starts_with('.') {
+                extensions.push(token.to_string());
+            } else {
+                mime_types.push(token.to_string());
+            }
+        }
+        Self {
+            mime_types: (!mime_types.is_empty()).then_some(mime_types),
+            extensions: (!extensions.is_empty()).then_some(extensions),
+        }
+    }
+
+    /// Value for the `accept` attribute of the file input.
+    pub fn to_attr(&self) -> String {
+        self.mime_types
+            .iter()
+            .chain(self.extensions.iter())
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Whether a file passes the filter. Input dialogs only hint at
+    /// `accept`, so dropped, pasted and picked files are all checked here.
+    pub fn accepts(&self, meta: &UploadFileMeta) -> bool {
+        let mime_types = self.mime_types.as_deref().unwrap_or_default();
+        let extensions = self.extensions.as_deref().unwrap_or_default();
+        if mime_types.is_empty() && extensions.is_empty() {
+            return true;
+        }
+        let name = meta.name.to_ascii_lowercase();
+        let mime = meta
+            .mime
+            .as_deref()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let mime_ok = !mime.is_empty()
+            && mime_types.iter().any(|pattern| {
+                let pattern = pattern.trim().to_ascii_lowercase();
+                match pattern.strip_suffix("/*") {
+                    Some(group) => mime.split('/').next() == Some(group),
+                    None => pattern == mime,
+                }
+            });
+        mime_ok
+            || extensions
+                .iter()
+                .any(|ext| name.ends_with(&ext.trim().to_ascii_lowercase()))
+    }
+}
+
 /// Upload request options for custom request handler.
 #[derive(Clone)]
 pub struct UploadRequestOptions {
@@ -158,13 +233,29 @@ pub struct ItemActions {
 }
 
 /// Locale configuration for upload text.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct UploadLocale {
     pub uploading: Option<String>,
     pub remove_file: Option<String>,
     pub download_file: Option<String>,
     pub upload_error: Option<String>,
     pub preview_file: Option<String>,
+    #[serde(default)]
+    pub pause_file: Option<String>,
+    #[serde(default)]
+    pub resume_file: Option<String>,
+    #[serde(default)]
+    pub edit_image: Option<String>,
+    #[serde(default)]
+    pub rotate_left: Option<String>,
+    #[serde(default)]
+    pub rotate_right: Option<String>,
+    #[serde(default)]
+    pub create_request_failed: Option<String>,
+    #[serde(default)]
+    pub open_connection_failed: Option<String>,
+    #[serde(default)]
+    pub unsupported_target: Option<String>,
 }
 
 #[derive(Props, Clone)]
@@ -213,9 +304,13 @@ pub struct UploadProps {
     pub on_change: Option<EventHandler<UploadChangeInfo>>,
     #[props(optional)]
     pub on_remove: Option<EventHandler<UploadFile>>,
-    /// Callback when file is dropped (for drag-and-drop).
+    /// Called with every file dropped on the dragger, folders already
+    /// walked, before `accept_config` and `before_upload` filter them.
     #[props(optional)]
-    pub on_drop: Option<EventHandler<()>>,
+    pub on_drop: Option<EventHandler<Vec<UploadFileMeta>>>,
+    /// Upload images and files pasted anywhere on the page.
+    #[props(default)]
+    pub pastable: bool,
     /// Callback when file is previewed.
     #[props(optional)]
     pub on_preview: Option<EventHandler<UploadFile>>,
@@ -224,6 +319,17 @@ pub struct UploadProps {
     pub on_download: Option<EventHandler<UploadFile>>,
     #[props(optional)]
     pub show_upload_list: Option<UploadListConfig>,
+    /// Upload files in resumable chunks instead of a single multipart
+    /// request, see [`ChunkedUploadConfig`]. Requires `action`.
+    #[props(optional)]
+    pub chunked: Option<ChunkedUploadConfig>,
+    /// Resize/compress images in the browser before they are sent.
+    #[props(optional)]
+    pub image_resize: Option<ImageResizeConfig>,
+    /// Ask the user to crop/rotate each image before it is sent. Cancelling
+    /// the crop skips the file.
+    #[props(optional)]
+    pub image_crop: Option<ImageCropConfig>,
     /// Custom upload request handler: (options, info) -> void
     #[props(optional)]
     pub custom_request: Option<Rc<dyn Fn(UploadRequestOptions)>>,
@@ -274,9 +380,16 @@ impl PartialEq for UploadProps {
             && self.with_credentials == other.with_credentials
             && self.headers == other.headers
             && self.accept == other.accept
+            && self.accept_config == other.accept_config
+            && self.directory == other.directory
+            && self.pastable == other.pastable
             && self.file_list == other.file_list
             && self.default_file_list == other.default_file_list
             && self.show_upload_list == other.show_upload_list
+            && self.chunked == other.chunked
+            && self.image_resize == other.image_resize
+            && self.image_crop == other.image_crop
+            && self.locale == other.locale
             && self.description == other.description
             && self.class == other.class
             && self.style == other.style
@@ -290,6 +403,7 @@ impl PartialEq for UploadProps {
 pub fn Upload(props: UploadProps) -> Element {
     let UploadProps {
         action,
+        directory,
         multiple,
         disabled,
         list_type,
@@ -298,17 +412,26 @@ pub fn Upload(props: UploadProps) -> Element {
         with_credentials,
         headers,
         accept,
+        accept_config,
         file_list,
         default_file_list,
         before_upload,
         on_change,
         on_remove,
+        on_drop,
+        pastable,
         show_upload_list,
         description,
         class,
         style,
         children,
         dragger,
+        locale,
+        chunked,
+        image_resize,
+        image_crop,
+        preview_file,
+        is_image_url,
         ..
     } = props;
     let field_name = field_name.unwrap_or_else(|| "file".to_string());
@@ -323,15 +446,88 @@ pub fn Upload(props: UploadProps) -> Element {
         files_signal.set(controlled_list);
     }
 
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     let upload_requests =
         use_hook(|| Rc::new(RefCell::new(HashMap::<String, XmlHttpRequest>::new())));
-    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
     let _upload_requests = ();
 
     let list_config = show_upload_list.unwrap_or_default();
-    let abort_upload: Rc<dyn Fn(&str)> = {
-        #[cfg(target_arch = "wasm32")]
+    let bundle = use_locale();
+    let remove_label = locale
+        .as_ref()
+        .and_then(|l| l.remove_file.clone())
+        .or_else(|| bundle.upload.remove_file.clone())
+        .unwrap_or_default();
+    let pause_label = locale
+        .as_ref()
+        .and_then(|l| l.pause_file.clone())
+        .or_else(|| bundle.upload.pause_file.clone())
+        .unwrap_or_default();
+    let resume_label = locale
+        .as_ref()
+        .and_then(|l| l.resume_file.clone())
+        .or_else(|| bundle.upload.resume_file.clone())
+        .unwrap_or_default();
+    let crop_title = image_crop
+        .as_ref()
+        .and_then(|c| c.title.clone())
+        .or_else(|| locale.as_ref().and_then(|l| l.edit_image.clone()))
+        .or_else(|| bundle.upload.edit_image.clone())
+        .unwrap_or_default();
+    let rotate_left_label = locale
+        .as_ref()
+        .and_then(|l| l.rotate_left.clone())
+        .or_else(|| bundle.upload.rotate_left.clone())
+        .unwrap_or_default();
+    let rotate_right_label = locale
+        .as_ref()
+        .and_then(|l| l.rotate_right.clone())
+        .or_else(|| bundle.upload.rotate_right.clone())
+        .unwrap_or_default();
+    let error_text = UploadErrorText::resolve(locale.as_ref(), &bundle.upload);
+
+    // Object URLs of generated thumbnails, revoked on removal and unmount.
+    let thumb_urls = use_hook(|| Rc::new(RefCell::new(HashMap::<String, String>::new())));
+    {
+        let thumb_urls = thumb_urls.clone();
+        use_drop(move || {
+            for url in thumb_urls.borrow().values() {
+                revoke_object_url(url);
+            }
+        });
+    }
+    let mut crop_queue = use_signal(Vec::<PendingCrop>::new);
+
+    // Read on every render so a changed `concurrency` applies right away:
+    // a raised limit starts waiting files, running uploads are not
+    // interrupted.
+    let concurrency = chunked.as_ref().map(|c| c.concurrency).unwrap_or(1);
+    let chunk_jobs = use_hook(|| Rc::new(RefCell::new(ChunkJobs::new(concurrency))));
+    let unblocked = chunk_jobs.borrow_mut().queue.set_limit(concurrency);
+    let chunk_ctx = match (chunked.is_some(), action.clone()) {
+        (true, Some(action_url)) => Some(ChunkContext {
+            action: action_url,
+            method,
+            with_credentials,
+            headers: headers.clone().unwrap_or_default(),
+            files_signal,
+            controlled,
+            on_change,
+            jobs: chunk_jobs,
+            errors: error_text.clone(),
+        }),
+        _ => None,
+    };
+    if !unblocked.is_empty()
+        && let Some(ctx) = chunk_ctx.clone()
+    {
+        // Status updates write signals, so start them after this render.
+        spawn(async move { start_chunked(&ctx, unblocked) });
+    }
+
+    let abort_request: Rc<dyn Fn(&str)> = {
+        #[cfg(all(feature = "web", target_arch = "wasm32"))]
         {
             let store = upload_requests.clone();
             Rc::new(move |uid: &str| {
@@ -340,15 +536,35 @@ pub fn Upload(props: UploadProps) -> Element {
                 }
             })
         }
-        #[cfg(not(target_arch = "wasm32"))]
+        #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
         {
             let _ = &_upload_requests;
             Rc::new(|_: &str| {})
         }
     };
+    let abort_upload: Rc<dyn Fn(&str)> = {
+        let chunk_ctx = chunk_ctx.clone();
+        let thumb_urls = thumb_urls.clone();
+        Rc::new(move |uid: &str| {
+            abort_request(uid);
+            if let Some(ctx) = chunk_ctx.as_ref() {
+                cancel_chunked(ctx, uid);
+            }
+            if let Some(url) = thumb_urls.borrow_mut().remove(uid) {
+                revoke_object_url(&url);
+            }
+        })
+    };
     let input_id = format!("adui-upload-input-{}", unique_id());
-    let accept_attr = accept.unwrap_or_default();
-    let dragging = use_signal(|| false);
+    let accept_filter = accept_config
+        .clone()
+        .or_else(|| accept.as_deref().map(AcceptConfig::from_attr));
+    let accept_attr = accept
+        .or_else(|| accept_config.as_ref().map(AcceptConfig::to_attr))
+        .unwrap_or_default();
+    // Counts nested dragenter/dragleave pairs so hovering children of the
+    // dragger does not flicker the hover style.
+    let drag_depth = use_signal(|| 0u32);
     let class_attr = format!(
         "adui-upload adui-upload-type-{} {}",
         match list_type {
@@ -360,62 +576,103 @@ pub fn Upload(props: UploadProps) -> Element {
     );
 
     let headers = Rc::new(headers.clone().unwrap_or_default());
-    let process_files = {
+    // Adds the entry and starts the upload of a file that passed filters
+    // and image processing.
+    let start_file: StartFile = {
         let headers = headers.clone();
-        #[cfg(target_arch = "wasm32")]
+        let chunk_ctx = chunk_ctx.clone();
+        let thumb_urls = thumb_urls.clone();
+        let error_text = error_text.clone();
+        #[cfg(all(feature = "web", target_arch = "wasm32"))]
         let request_store = upload_requests.clone();
-        #[cfg(not(target_arch = "wasm32"))]
+        #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
         let request_store = ();
 
-        Rc::new(move |files: Vec<dioxus_html::FileData>| {
+        Rc::new(move |file: dioxus_html::FileData, meta: UploadFileMeta| {
+            let mut entry = if action.is_some() {
+                UploadFile::uploading(meta.name.clone(), meta.size)
+            } else {
+                UploadFile::done(meta.name.clone(), meta.size)
+            };
+            let uid = entry.uid.clone();
+            if list_type != UploadListType::Text
+                && is_image_file(&meta.name, meta.mime.as_deref())
+                && let Some(url) = object_url(&file)
+            {
+                thumb_urls.borrow_mut().insert(uid.clone(), url.clone());
+                entry.thumb_url = Some(url);
+            }
+
+            if let Some((changed, snapshot)) = mutate_files(files_signal, controlled, |list| {
+                list.push(entry.clone());
+                Some(entry.clone())
+            }) && let Some(handler) = on_change.as_ref()
+            {
+                handler.call(UploadChangeInfo {
+                    file: changed,
+                    file_list: snapshot,
+                });
+            }
+
+            if let (Some(ctx), Some(config)) = (chunk_ctx.as_ref(), chunked.as_ref()) {
+                let state = ChunkUploadState::new(meta.size.unwrap_or(0), config);
+                ctx.jobs
+                    .borrow_mut()
+                    .jobs
+                    .insert(uid.clone(), ChunkJob::new(file, state));
+                enqueue_chunked(ctx, uid);
+            } else if let Some(action_url) = action.clone() {
+                start_upload_task(
+                    file,
+                    meta,
+                    uid,
+                    action_url,
+                    field_name.clone(),
+                    method,
+                    with_credentials,
+                    (*headers).clone(),
+                    files_signal,
+                    controlled,
+                    on_change,
+                    request_store.clone(),
+                    error_text.clone(),
+                );
+            }
+        })
+    };
+
+    let process_files: FilesHandler = {
+        let start_file = start_file.clone();
+        let image_crop = image_crop.clone();
+        let image_resize = image_resize.clone();
+        Rc::new(move |files: Vec<PickedFile>| {
             if disabled || files.is_empty() {
                 return;
             }
-            for file in files {
-                let meta = UploadFileMeta {
-                    name: file.name(),
-                    size: Some(file.size()),
-                    mime: file.content_type(),
-                };
+            for PickedFile {
+                file,
+                relative_path,
+            } in files
+            {
+                let meta = picked_meta(&file, relative_path);
+                if let Some(filter) = accept_filter.as_ref()
+                    && !filter.accepts(&meta)
+                {
+                    continue;
+                }
                 if let Some(filter) = before_upload.as_ref()
                     && !(filter)(&meta)
                 {
                     continue;
                 }
 
-                let entry = if action.is_some() {
-                    UploadFile::uploading(meta.name.clone(), meta.size)
+                let image = is_processable_image(meta.mime.as_deref());
+                if image && image_crop.is_some() {
+                    queue_crop(file, meta, crop_queue, start_file.clone());
+                } else if image && let Some(config) = image_resize.as_ref() {
+                    render_then_start(file, meta, RenderPlan::resize(config), start_file.clone());
                 } else {
-                    UploadFile::done(meta.name.clone(), meta.size)
-                };
-                let uid = entry.uid.clone();
-
-                if let Some((changed, snapshot)) = mutate_files(files_signal, controlled, |list| {
-                    list.push(entry.clone());
-                    Some(entry.clone())
-                }) && let Some(handler) = on_change.as_ref()
-                {
-                    handler.call(UploadChangeInfo {
-                        file: changed,
-                        file_list: snapshot,
-                    });
-                }
-
-                if let Some(action_url) = action.clone() {
-                    start_upload_task(
-                        file.clone(),
-                        meta.clone(),
-                        uid,
-                        action_url,
-                        field_name.clone(),
-                        method,
-                        with_credentials,
-                        (*headers).clone(),
-                        files_signal,
-                        controlled,
-                        on_change,
-                        request_store.clone(),
-                    );
+                    start_file(file, meta);
                 }
             }
         })
@@ -427,52 +684,96 @@ pub fn Upload(props: UploadProps) -> Element {
             if disabled {
                 return;
             }
-            process_files(evt.files());
+            process_files(input_files(evt.files(), directory));
         }
     };
 
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
+    {
+        // The document listener only exists while pasting is enabled.
+        let enabled = pastable && !disabled;
+        let paste_target = use_hook(|| Rc::new(RefCell::new(None::<FilesHandler>)));
+        *paste_target.borrow_mut() = enabled.then(|| process_files.clone());
+        let mut listener = use_hook(|| CopyValue::new(None::<PasteListener>));
+        let attached = listener.peek().is_some();
+        if enabled && !attached {
+            *listener.write() = Some(PasteListener::attach(paste_target));
+        } else if !enabled && let Some(current) = listener.write().take() {
+            current.detach();
+        }
+        use_drop(move || {
+            if let Ok(mut slot) = listener.try_write()
+                && let Some(current) = slot.take()
+            {
+                current.detach();
+            }
+        });
+    }
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+    let _ = pastable;
+
     let mut selector_classes = vec!["adui-upload-selector".to_string()];
     if disabled {
         selector_classes.push("adui-upload-disabled".into());
     }
     if dragger {
         selector_classes.push("adui-upload-dragger".into());
-        if *dragging.read() {
+        if *drag_depth.read() > 0 {
             selector_classes.push("adui-upload-dragger-hover".into());
         }
     }
     let selector_class = selector_classes.join(" ");
-    let mut dragging_for_over = dragging;
-    let mut dragging_for_leave = dragging;
-    let mut dragging_for_drop = dragging;
-    let process_files_drop = process_files.clone();
+    let mut depth_for_enter = drag_depth;
+    let mut depth_for_leave = drag_depth;
+    let mut depth_for_drop = drag_depth;
+    let handle_drop: FilesHandler = {
+        let process_files = process_files.clone();
+        Rc::new(move |files: Vec<PickedFile>| {
+            if let Some(handler) = on_drop.as_ref() {
+                handler.call(
+                    files
+                        .iter()
+                        .map(|picked| picked_meta(&picked.file, picked.relative_path.clone()))
+                        .collect(),
+                );
+            }
+            process_files(files);
+        })
+    };
 
     rsx! {
         div { class: "{class_attr}", style: style.unwrap_or_default(),
             label {
                 r#for: input_id.clone(),
                 class: "{selector_class}",
+                ondragenter: move |evt: DragEvent| {
+                    if !dragger || disabled {
+                        return;
+                    }
+                    evt.prevent_default();
+                    depth_for_enter += 1;
+                },
                 ondragover: move |evt: DragEvent| {
                     if !dragger || disabled {
                         return;
                     }
                     evt.prevent_default();
-                    dragging_for_over.set(true);
                 },
                 ondragleave: move |evt: DragEvent| {
                     if !dragger || disabled {
                         return;
                     }
                     evt.prevent_default();
-                    dragging_for_leave.set(false);
+                    let depth = *depth_for_leave.peek();
+                    depth_for_leave.set(depth.saturating_sub(1));
                 },
                 ondrop: move |evt: DragEvent| {
                     if !dragger || disabled {
                         return;
                     }
                     evt.prevent_default();
-                    dragging_for_drop.set(false);
-                    process_files_drop(evt.files());
+                    depth_for_drop.set(0);
+                    dropped_files(&evt, handle_drop.clone());
                 },
                 {children}
                 if let Some(desc) = description {
@@ -482,28 +783,64 @@ pub fn Upload(props: UploadProps) -> Element {
             input {
                 id: input_id,
                 r#type: "file",
-                multiple: multiple,
+                multiple: multiple || directory,
                 disabled: disabled,
                 accept: accept_attr,
+                "webkitdirectory": directory.then_some("true"),
                 onchange: onchange,
                 style: "display:none",
             }
-            {render_upload_list(files_signal.read().clone(), list_type, list_config, files_signal, controlled, disabled, on_remove, on_change, abort_upload.clone())}
+            {render_upload_list(files_signal.read().clone(), list_type, list_config, remove_label, files_signal, controlled, disabled, on_remove, on_change, abort_upload.clone(), chunk_ctx.map(|ctx| ChunkControls { ctx, pause_label, resume_label }), ThumbnailSource { preview_file, is_image_url })}
+            if let (Some(pending), Some(config)) = (crop_queue.read().first().cloned(), image_crop.clone()) {
+                UploadCropModal {
+                    key: "{pending.url}",
+                    pending,
+                    config: config.clone(),
+                    title: crop_title,
+                    rotate_left: rotate_left_label,
+                    rotate_right: rotate_right_label,
+                    on_finish: move |result: Option<CropState>| {
+                        if crop_queue.peek().is_empty() {
+                            return;
+                        }
+                        let pending = crop_queue.write().remove(0);
+                        revoke_object_url(&pending.url);
+                        if let Some(state) = result {
+                            let plan = RenderPlan::crop(&state, &config, image_resize.as_ref());
+                            render_then_start(pending.file, pending.meta, plan, start_file.clone());
+                        }
+                    },
+                }
+            }
         }
     }
 }
 
+/// Drop zone variant of [`Upload`] (`Upload.Dragger` in Ant Design).
+///
+/// Dropped folders are walked recursively; every file keeps its path in
+/// [`UploadFileMeta::relative_path`].
+#[component]
+pub fn UploadDragger(props: UploadProps) -> Element {
+    rsx! {
+        Upload { dragger: true, ..props }
+    }
+}
+
 #[allow(clippy::too_many_arguments)] // Render helper mirrors Ant Design API surface for flexibility.
 fn render_upload_list(
     files: Vec<UploadFile>,
     list_type: UploadListType,
     config: UploadListConfig,
+    remove_label: String,
     files_signal: Signal<Vec<UploadFile>>,
     controlled: bool,
     disabled: bool,
     on_remove: Option<EventHandler<UploadFile>>,
     on_change: Option<EventHandler<UploadChangeInfo>>,
     abort_upload: Rc<dyn Fn(&str)>,
+    chunk_controls: Option<ChunkControls>,
+    thumbnail: ThumbnailSource,
 ) -> Element {
     if files.is_empty() {
         return rsx! { div {} };
@@ -518,8 +855,31 @@ fn render_upload_list(
                 let file_entry = file.clone();
                 let file_for_remove = file.clone();
                 let abort_upload = abort_upload.clone();
+                let chunk_action = chunk_controls.as_ref().and_then(|controls| {
+                    chunk_list_action(controls, &file_entry)
+                });
+                let thumb_src = thumbnail.src(&file_entry, list_type);
                 rsx!(li { key: "{file_entry.uid}", class: "adui-upload-list-item",
+                    if let Some(src) = thumb_src {
+                        img {
+                            class: "adui-upload-list-item-thumbnail",
+                            src: "{src}",
+                            alt: "{file_entry.name}",
+                        }
+                    }
                     span { class: "adui-upload-list-item-name", "{file_entry.name}" }
+                    if let Some((class, label, action)) = chunk_action {
+                        button {
+                            r#type: "button",
+                            class: "{class}",
+                            onclick: move |_| {
+                                if !disabled {
+                                    action();
+                                }
+                            },
+                            "{label}"
+                        }
+                    }
                     if config.show_remove_icon {
                         button {
                             r#type: "button",
@@ -546,7 +906,7 @@ fn render_upload_list(
                                     }
                                 }
                             },
-                            "删除"
+                            "{remove_label}"
                         }
                     }
                     if let Some(err) = file_entry.error.clone() {
@@ -558,6 +918,348 @@ fn render_upload_list(
     }
 }
 
+/// A file picked, dropped or pasted, with its path inside a folder.
+struct PickedFile {
+    file: dioxus_html::FileData,
+    relative_path: Option<String>,
+}
+
+type FilesHandler = Rc<dyn Fn(Vec<PickedFile>)>;
+
+fn picked_meta(file: &dioxus_html::FileData, relative_path: Option<String>) -> UploadFileMeta {
+    UploadFileMeta {
+        name: file.name(),
+        size: Some(file.size()),
+        mime: file.content_type().filter(|mime| !mime.is_empty()),
+        relative_path,
+    }
+}
+
+/// Relative path of a folder entry: `full_path` without its leading slash,
+/// or `None` for files that were not inside a folder.
+#[cfg_attr(not(all(feature = "web", target_arch = "wasm32")), allow(dead_code))]
+fn entry_relative_path(full_path: &str) -> Option<String> {
+    let path = full_path.trim_start_matches('/');
+    path.contains('/').then(|| path.to_string())
+}
+
+/// Files from the `<input>`, with `webkitRelativePath` in directory mode.
+fn input_files(files: Vec<dioxus_html::FileData>, directory: bool) -> Vec<PickedFile> {
+    files
+        .into_iter()
+        .map(|file| {
+            let relative_path = if directory {
+                web_relative_path(&file)
+            } else {
+                None
+            };
+            PickedFile {
+                file,
+                relative_path,
+            }
+        })
+        .collect()
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn web_relative_path(file: &dioxus_html::FileData) -> Option<String> {
+    let file = file.inner().downcast_ref::<web_sys::File>()?;
+    js_sys::Reflect::get(file, &JsValue::from_str("webkitRelativePath"))
+        .ok()?
+        .as_string()
+        .filter(|path| !path.is_empty())
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+fn web_relative_path(_file: &dioxus_html::FileData) -> Option<String> {
+    None
+}
+
+/// Hand the files of a drop to `handle`, walking dropped folders first.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn dropped_files(evt: &DragEvent, handle: FilesHandler) {
+    // Entries must be taken while the event is dispatched; the data transfer
+    // is emptied afterwards.
+    let entries = {
+        let binding = evt.data();
+        binding
+            .downcast::<web_sys::DragEvent>()
+            .and_then(|web_evt| web_evt.data_transfer())
+            .map(|transfer| {
+                let items = transfer.items();
+                (0..items.length())
+                    .filter_map(|index| items.get(index))
+                    .filter(|item| item.kind() == "file")
+                    .filter_map(|item| item.webkit_get_as_entry().ok().flatten())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    };
+    if entries.is_empty() {
+        handle(input_files(evt.files(), false));
+        return;
+    }
+    spawn_local(async move {
+        handle(walk_entries(entries).await);
+    });
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+fn dropped_files(evt: &DragEvent, handle: FilesHandler) {
+    handle(input_files(evt.files(), false));
+}
+
+/// Collect the files below `entries`, depth first in drop order.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+async fn walk_entries(entries: Vec<web_sys::FileSystemEntry>) -> Vec<PickedFile> {
+    let mut pending = entries;
+    pending.reverse();
+    let mut picked = Vec::new();
+    while let Some(entry) = pending.pop() {
+        if entry.is_directory() {
+            let dir = entry.unchecked_into::<web_sys::FileSystemDirectoryEntry>();
+            let mut children = read_directory(&dir).await;
+            children.reverse();
+            pending.extend(children);
+        } else if entry.is_file() {
+            let relative_path = entry_relative_path(&entry.full_path());
+            let entry = entry.unchecked_into::<web_sys::FileSystemFileEntry>();
+            if let Some(file) = entry_file(&entry).await.and_then(web_file_data) {
+                picked.push(PickedFile {
+                    file,
+                    relative_path,
+                });
+            }
+        }
+    }
+    picked
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+async fn entry_file(entry: &web_sys::FileSystemFileEntry) -> Option<web_sys::File> {
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        entry.file_with_callback_and_callback(&resolve, &reject);
+    });
+    JsFuture::from(promise).await.ok()?.dyn_into().ok()
+}
+
+/// All entries of a folder; `readEntries` returns them in batches until an
+/// empty one.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+async fn read_directory(dir: &web_sys::FileSystemDirectoryEntry) -> Vec<web_sys::FileSystemEntry> {
+    let reader = dir.create_reader();
+    let mut entries = Vec::new();
+    loop {
+        let promise = js_sys::Promise::new(&mut |resolve, reject| {
+            if reader
+                .read_entries_with_callback_and_callback(&resolve, &reject)
+                .is_err()
+            {
+                let _ = reject.call0(&JsValue::NULL);
+            }
+        });
+        let Ok(batch) = JsFuture::from(promise).await else {
+            break;
+        };
+        let batch = Array::from(&batch);
+        if batch.length() == 0 {
+            break;
+        }
+        entries.extend(batch.iter().map(|entry| entry.unchecked_into()));
+    }
+    entries
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn web_file_data(file: web_sys::File) -> Option<dioxus_html::FileData> {
+    let reader = web_sys::FileReader::new().ok()?;
+    Some(dioxus_html::FileData::new(dioxus::web::WebFileData::new(
+        file, reader,
+    )))
+}
+
+/// Document-level `paste` listener forwarding clipboard files to the
+/// handler currently stored in `target`.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+struct PasteListener {
+    callback: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+impl PasteListener {
+    fn attach(target: Rc<RefCell<Option<FilesHandler>>>) -> Self {
+        let callback = Closure::<dyn FnMut(web_sys::Event)>::new(move |evt: web_sys::Event| {
+            let Some(handle) = target.borrow().clone() else {
+                return;
+            };
+            let Some(list) = evt
+                .dyn_ref::<web_sys::ClipboardEvent>()
+                .and_then(|evt| evt.clipboard_data())
+                .and_then(|data| data.files())
+            else {
+                return;
+            };
+            let files: Vec<PickedFile> = (0..list.length())
+                .filter_map(|index| list.get(index))
+                .filter_map(web_file_data)
+                .map(|file| PickedFile {
+                    file,
+                    relative_path: None,
+                })
+                .collect();
+            if !files.is_empty() {
+                evt.prevent_default();
+                handle(files);
+            }
+        });
+        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+            let _ = document
+                .add_event_listener_with_callback("paste", callback.as_ref().unchecked_ref());
+        }
+        Self { callback }
+    }
+
+    fn detach(&self) {
+        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+            let _ = document.remove_event_listener_with_callback(
+                "paste",
+                self.callback.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
+
+type StartFile = Rc<dyn Fn(dioxus_html::FileData, UploadFileMeta)>;
+type FileString = Rc<dyn Fn(&UploadFile) -> String>;
+type FileCheck = Rc<dyn Fn(&UploadFile) -> bool>;
+
+/// Where list thumbnails come from, in order: `preview_file`, the generated
+/// `thumb_url`, then `url` when it points at an image.
+struct ThumbnailSource {
+    preview_file: Option<FileString>,
+    is_image_url: Option<FileCheck>,
+}
+
+impl ThumbnailSource {
+    fn src(&self, file: &UploadFile, list_type: UploadListType) -> Option<String> {
+        if list_type == UploadListType::Text {
+            return None;
+        }
+        if let Some(preview) = self.preview_file.as_ref() {
+            return Some(preview(file)).filter(|src| !src.is_empty());
+        }
+        if let Some(thumb) = file.thumb_url.clone() {
+            return Some(thumb);
+        }
+        let url = file.url.clone()?;
+        let is_image = match self.is_image_url.as_ref() {
+            Some(check) => check(file),
+            None => url.starts_with("data:image/") || is_image_file(&file.name, None),
+        };
+        is_image.then_some(url)
+    }
+}
+
+/// Measure an image and queue it for the crop modal.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn queue_crop(
+    file: dioxus_html::FileData,
+    meta: UploadFileMeta,
+    mut queue: Signal<Vec<PendingCrop>>,
+    start_file: StartFile,
+) {
+    let Some(url) = object_url(&file) else {
+        start_file(file, meta);
+        return;
+    };
+    spawn_local(async move {
+        match load_image(&url).await {
+            Some(image) => queue.write().push(PendingCrop {
+                file,
+                meta,
+                url,
+                width: f64::from(image.natural_width()),
+                height: f64::from(image.natural_height()),
+            }),
+            None => {
+                revoke_object_url(&url);
+                start_file(file, meta);
+            }
+        }
+    });
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+fn queue_crop(
+    file: dioxus_html::FileData,
+    meta: UploadFileMeta,
+    _queue: Signal<Vec<PendingCrop>>,
+    start_file: StartFile,
+) {
+    start_file(file, meta);
+}
+
+/// Redraw an image according to `plan` and upload the result, or the
+/// original when `plan` leaves it unchanged or the browser cannot decode it.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn render_then_start(
+    file: dioxus_html::FileData,
+    meta: UploadFileMeta,
+    plan: RenderPlan,
+    start_file: StartFile,
+) {
+    spawn_local(async move {
+        match render_image(&file, &plan).await.and_then(web_file_data) {
+            Some(rendered) => {
+                let rendered_meta = picked_meta(&rendered, meta.relative_path.clone());
+                start_file(rendered, rendered_meta);
+            }
+            None => start_file(file, meta),
+        }
+    });
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+fn render_then_start(
+    file: dioxus_html::FileData,
+    meta: UploadFileMeta,
+    _plan: RenderPlan,
+    start_file: StartFile,
+) {
+    start_file(file, meta);
+}
+
+/// Pause/resume button of a chunked upload in the list.
+#[derive(Clone)]
+struct ChunkControls {
+    ctx: ChunkContext,
+    pause_label: String,
+    resume_label: String,
+}
+
+type ChunkListAction = (&'static str, String, Rc<dyn Fn()>);
+
+fn chunk_list_action(controls: &ChunkControls, file: &UploadFile) -> Option<ChunkListAction> {
+    if !controls.ctx.jobs.borrow().jobs.contains_key(&file.uid) {
+        return None;
+    }
+    let ctx = controls.ctx.clone();
+    let uid = file.uid.clone();
+    match file.status {
+        UploadStatus::Ready | UploadStatus::Uploading => Some((
+            "adui-upload-list-item-pause",
+            controls.pause_label.clone(),
+            Rc::new(move || pause_chunked(&ctx, &uid)),
+        )),
+        UploadStatus::Paused | UploadStatus::Error => Some((
+            "adui-upload-list-item-resume",
+            controls.resume_label.clone(),
+            Rc::new(move || resume_chunked(&ctx, &uid)),
+        )),
+        UploadStatus::Done => None,
+    }
+}
+
 fn mutate_files(
     mut files_signal: Signal<Vec<UploadFile>>,
     controlled: bool,
@@ -592,7 +1294,7 @@ fn update_file_state(
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
 #[allow(clippy::too_many_arguments)] // Upload pipeline needs all parameters; grouped for wasi boundary.
 fn start_upload_task(
     file: dioxus_html::FileData,
@@ -607,6 +1309,7 @@ fn start_upload_task(
     controlled: bool,
     on_change: Option<EventHandler<UploadChangeInfo>>,
     request_store: Rc<RefCell<HashMap<String, XmlHttpRequest>>>,
+    errors: UploadErrorText,
 ) {
     spawn_local(async move {
         let bytes = match file.read_bytes().await {
@@ -625,7 +1328,7 @@ fn start_upload_task(
             Err(_) => {
                 update_file_state(files_signal, controlled, &uid, on_change, |entry| {
                     entry.status = UploadStatus::Error;
-                    entry.error = Some("无法创建请求".into());
+                    entry.error = Some(errors.create_request.clone());
                 });
                 return;
             }
@@ -634,7 +1337,7 @@ fn start_upload_task(
         if xhr.open_with_async(method.as_str(), &action, true).is_err() {
             update_file_state(files_signal, controlled, &uid, on_change, |entry| {
                 entry.status = UploadStatus::Error;
-                entry.error = Some("打开上传连接失败".into());
+                entry.error = Some(errors.open_connection.clone());
             });
             return;
         }
@@ -711,6 +1414,7 @@ fn start_upload_task(
         let error_uid = uid.clone();
         let error_on_change = on_change;
         let error_store = request_store.clone();
+        let error_text = errors.failed.clone();
         let error_closure =
             Closure::<dyn FnMut(_)>::wrap(Box::new(move |_event: web_sys::Event| {
                 error_store.borrow_mut().remove(&error_uid);
@@ -721,7 +1425,7 @@ fn start_upload_task(
                     error_on_change,
                     |entry| {
                         entry.status = UploadStatus::Error;
-                        entry.error = Some("上传失败".into());
+                        entry.error = Some(error_text.clone());
                     },
                 );
             }));
@@ -742,7 +1446,7 @@ fn start_upload_task(
     });
 }
 
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
 #[allow(clippy::too_many_arguments)] // Stub keeps the same signature as the web implementation for API parity.
 fn start_upload_task(
     _file: dioxus_html::FileData,
@@ -757,19 +1461,409 @@ fn start_upload_task(
     controlled: bool,
     on_change: Option<EventHandler<UploadChangeInfo>>,
     _request_store: (),
+    errors: UploadErrorText,
 ) {
     update_file_state(files_signal, controlled, &uid, on_change, |entry| {
         entry.status = UploadStatus::Error;
-        entry.error = Some("Upload is only supported on web targets".into());
+        entry.error = Some(errors.unsupported.clone());
     });
 }
 
+/// Localized messages for requests that fail before the server answers.
+#[cfg_attr(not(all(feature = "web", target_arch = "wasm32")), allow(dead_code))]
+#[derive(Clone, Debug, Default)]
+struct UploadErrorText {
+    create_request: String,
+    open_connection: String,
+    failed: String,
+    #[cfg_attr(all(feature = "web", target_arch = "wasm32"), allow(dead_code))]
+    unsupported: String,
+}
+
+impl UploadErrorText {
+    /// Prefer the `locale` prop, then the active locale bundle.
+    fn resolve(locale: Option<&UploadLocale>, bundle: &UploadLocale) -> Self {
+        let pick = |field: fn(&UploadLocale) -> &Option<String>| {
+            locale
+                .and_then(|l| field(l).clone())
+                .or_else(|| field(bundle).clone())
+                .unwrap_or_default()
+        };
+        Self {
+            create_request: pick(|l| &l.create_request_failed),
+            open_connection: pick(|l| &l.open_connection_failed),
+            failed: pick(|l| &l.upload_error),
+            unsupported: pick(|l| &l.unsupported_target),
+        }
+    }
+}
+
+/// Chunked uploads of one `Upload`, keyed by file uid.
+struct ChunkJobs {
+    queue: UploadQueue,
+    jobs: HashMap<String, ChunkJob>,
+}
+
+impl ChunkJobs {
+    fn new(concurrency: usize) -> Self {
+        Self {
+            queue: UploadQueue::new(concurrency),
+            jobs: HashMap::new(),
+        }
+    }
+}
+
+#[cfg_attr(not(all(feature = "web", target_arch = "wasm32")), allow(dead_code))]
+struct ChunkJob {
+    file: dioxus_html::FileData,
+    state: ChunkUploadState,
+    /// Bumped whenever a driver starts, so a driver left over from before a
+    /// pause stops instead of running alongside the new one.
+    run: u64,
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
+    request: Option<XmlHttpRequest>,
+}
+
+impl ChunkJob {
+    fn new(file: dioxus_html::FileData, state: ChunkUploadState) -> Self {
+        Self {
+            file,
+            state,
+            run: 0,
+            #[cfg(all(feature = "web", target_arch = "wasm32"))]
+            request: None,
+        }
+    }
+
+    fn abort_request(&mut self) {
+        #[cfg(all(feature = "web", target_arch = "wasm32"))]
+        if let Some(xhr) = self.request.take() {
+            let _ = xhr.abort();
+        }
+    }
+}
+
+/// Everything a chunk driver needs besides the file itself.
+#[cfg_attr(not(all(feature = "web", target_arch = "wasm32")), allow(dead_code))]
+#[derive(Clone)]
+struct ChunkContext {
+    action: String,
+    method: UploadHttpMethod,
+    with_credentials: bool,
+    headers: Vec<(String, String)>,
+    files_signal: Signal<Vec<UploadFile>>,
+    controlled: bool,
+    on_change: Option<EventHandler<UploadChangeInfo>>,
+    jobs: Rc<RefCell<ChunkJobs>>,
+    errors: UploadErrorText,
+}
+
+impl ChunkContext {
+    fn update(&self, uid: &str, updater: impl FnMut(&mut UploadFile)) {
+        update_file_state(
+            self.files_signal,
+            self.controlled,
+            uid,
+            self.on_change,
+            updater,
+        );
+    }
+}
+
+/// Start `uid` when a slot is free, otherwise leave it waiting as `Ready`.
+fn enqueue_chunked(ctx: &ChunkContext, uid: String) {
+    let start = ctx.jobs.borrow_mut().queue.push(uid.clone());
+    if start {
+        ctx.update(&uid, |entry| entry.status = UploadStatus::Uploading);
+        run_chunked(ctx.clone(), uid);
+    } else {
+        ctx.update(&uid, |entry| entry.status = UploadStatus::Ready);
+    }
+}
+
+/// Free the slot of `uid` and start the files waiting for it.
+fn release_chunked(ctx: &ChunkContext, uid: &str) {
+    let started = ctx.jobs.borrow_mut().queue.release(uid);
+    start_chunked(ctx, started);
+}
+
+/// Run files the queue moved into a free slot.
+fn start_chunked(ctx: &ChunkContext, started: Vec<String>) {
+    for next in started {
+        ctx.update(&next, |entry| entry.status = UploadStatus::Uploading);
+        run_chunked(ctx.clone(), next);
+    }
+}
+
+fn pause_chunked(ctx: &ChunkContext, uid: &str) {
+    let paused = match ctx.jobs.borrow_mut().jobs.get_mut(uid) {
+        Some(job) => {
+            let paused = job.state.pause();
+            if paused {
+                job.abort_request();
+            }
+            paused
+        }
+        None => false,
+    };
+    if paused {
+        ctx.update(uid, |entry| entry.status = UploadStatus::Paused);
+        release_chunked(ctx, uid);
+    }
+}
+
+fn resume_chunked(ctx: &ChunkContext, uid: &str) {
+    let resumed = ctx
+        .jobs
+        .borrow_mut()
+        .jobs
+        .get_mut(uid)
+        .is_some_and(|job| job.state.resume());
+    if resumed {
+        ctx.update(uid, |entry| entry.error = None);
+        enqueue_chunked(ctx, uid.to_string());
+    }
+}
+
+fn cancel_chunked(ctx: &ChunkContext, uid: &str) {
+    let job = ctx.jobs.borrow_mut().jobs.remove(uid);
+    if let Some(mut job) = job {
+        job.state.cancel();
+        job.abort_request();
+        release_chunked(ctx, uid);
+    }
+}
+
+/// Claim the job of `uid` for a new driver, returning its run id.
+fn claim_chunk_run(ctx: &ChunkContext, uid: &str) -> Option<u64> {
+    let mut jobs = ctx.jobs.borrow_mut();
+    let job = jobs.jobs.get_mut(uid)?;
+    job.run += 1;
+    Some(job.run)
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn run_chunked(ctx: ChunkContext, uid: String) {
+    let Some(run) = claim_chunk_run(&ctx, &uid) else {
+        return;
+    };
+    spawn_local(async move {
+        let mut response = None;
+        loop {
+            let step = match ctx.jobs.borrow_mut().jobs.get_mut(&uid) {
+                Some(job) if job.run == run => job.state.next_action(),
+                _ => return,
+            };
+            match step {
+                ChunkAction::Probe => {
+                    let result = probe_chunk_offset(&ctx, &uid).await;
+                    if let Some(job) = ctx.jobs.borrow_mut().jobs.get_mut(&uid) {
+                        job.state.on_probe(result);
+                    }
+                }
+                ChunkAction::Send { start, end } => {
+                    let result = send_chunk(&ctx, &uid, start, end).await;
+                    let percent = {
+                        let mut jobs = ctx.jobs.borrow_mut();
+                        let Some(job) = jobs.jobs.get_mut(&uid) else {
+                            return;
+                        };
+                        match result {
+                            Ok((offset, body)) => {
+                                job.state.on_chunk_ok(offset);
+                                response = body;
+                            }
+                            Err(err) => job.state.on_error(err),
+                        }
+                        job.state.percent(0)
+                    };
+                    ctx.update(&uid, |entry| entry.percent = Some(percent));
+                }
+                ChunkAction::Wait { delay_ms } => sleep_ms(delay_ms).await,
+                ChunkAction::Done => {
+                    ctx.jobs.borrow_mut().jobs.remove(&uid);
+                    ctx.update(&uid, |entry| {
+                        entry.status = UploadStatus::Done;
+                        entry.percent = Some(100.0);
+                        entry.response = response.clone();
+                        entry.error = None;
+                    });
+                    release_chunked(&ctx, &uid);
+                    return;
+                }
+                ChunkAction::Failed(err) => {
+                    // The job is kept so the file can be resumed.
+                    ctx.update(&uid, |entry| {
+                        entry.status = UploadStatus::Error;
+                        entry.error = Some(err.clone());
+                    });
+                    release_chunked(&ctx, &uid);
+                    return;
+                }
+                ChunkAction::Idle => return,
+            }
+        }
+    });
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+fn run_chunked(ctx: ChunkContext, uid: String) {
+    if claim_chunk_run(&ctx, &uid).is_none() {
+        return;
+    }
+    ctx.update(&uid, |entry| {
+        entry.status = UploadStatus::Error;
+        entry.error = Some(ctx.errors.unsupported.clone());
+    });
+    release_chunked(&ctx, &uid);
+}
+
+/// Open a request to the upload action with the shared headers applied.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn open_chunk_request(
+    ctx: &ChunkContext,
+    method: &str,
+    uid: &str,
+) -> Result<XmlHttpRequest, String> {
+    let xhr = XmlHttpRequest::new().map_err(|_| ctx.errors.create_request.clone())?;
+    xhr.open_with_async(method, &ctx.action, true)
+        .map_err(|_| ctx.errors.open_connection.clone())?;
+    xhr.set_with_credentials(ctx.with_credentials);
+    for (key, value) in ctx.headers.iter() {
+        let _ = xhr.set_request_header(key, value);
+    }
+    let _ = xhr.set_request_header("X-Upload-Id", uid);
+    Ok(xhr)
+}
+
+/// Send `xhr` and wait until it loads, fails or is aborted.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+async fn send_request(
+    xhr: &XmlHttpRequest,
+    body: Option<&Blob>,
+    failed: &str,
+) -> Result<(), String> {
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        let fail = reject.clone();
+        let on_load = Closure::once_into_js(move || {
+            let _ = resolve.call0(&JsValue::NULL);
+        });
+        let on_error = Closure::once_into_js(move || {
+            let _ = fail.call0(&JsValue::NULL);
+        });
+        let on_abort = Closure::once_into_js(move || {
+            let _ = reject.call0(&JsValue::NULL);
+        });
+        xhr.set_onload(Some(on_load.unchecked_ref()));
+        xhr.set_onerror(Some(on_error.unchecked_ref()));
+        xhr.set_onabort(Some(on_abort.unchecked_ref()));
+    });
+    xhr.send_with_opt_blob(body)
+        .map_err(|_| failed.to_string())?;
+    JsFuture::from(promise)
+        .await
+        .map(|_| ())
+        .map_err(|_| failed.to_string())
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn set_chunk_request(ctx: &ChunkContext, uid: &str, xhr: Option<XmlHttpRequest>) {
+    if let Some(job) = ctx.jobs.borrow_mut().jobs.get_mut(uid) {
+        job.request = xhr;
+    }
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn response_offset(xhr: &XmlHttpRequest) -> Option<u64> {
+    let upload_offset = xhr.get_response_header("Upload-Offset").ok().flatten();
+    let range = xhr.get_response_header("Range").ok().flatten();
+    parse_server_offset(upload_offset.as_deref(), range.as_deref())
+}
+
+/// Ask the server how many bytes of `uid` it has committed.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+async fn probe_chunk_offset(ctx: &ChunkContext, uid: &str) -> Result<Option<u64>, String> {
+    let xhr = open_chunk_request(ctx, "HEAD", uid)?;
+    set_chunk_request(ctx, uid, Some(xhr.clone()));
+    let result = send_request(&xhr, None, &ctx.errors.failed).await;
+    set_chunk_request(ctx, uid, None);
+    result?;
+    match xhr.status().unwrap_or(0) {
+        200..=299 => Ok(response_offset(&xhr)),
+        404 => Ok(None),
+        status => Err(format!("HTTP {status}")),
+    }
+}
+
+/// Send bytes `start..end` of `uid`, returning the server-reported offset
+/// and the response body.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+async fn send_chunk(
+    ctx: &ChunkContext,
+    uid: &str,
+    start: u64,
+    end: u64,
+) -> Result<(Option<u64>, Option<String>), String> {
+    let (blob, total, name) = {
+        let jobs = ctx.jobs.borrow();
+        let job = jobs
+            .jobs
+            .get(uid)
+            .ok_or_else(|| ctx.errors.failed.clone())?;
+        let file = job
+            .file
+            .inner()
+            .downcast_ref::<web_sys::File>()
+            .ok_or_else(|| ctx.errors.failed.clone())?;
+        let blob = file
+            .slice_with_f64_and_f64(start as f64, end as f64)
+            .map_err(|_| ctx.errors.failed.clone())?;
+        (blob, job.state.total(), file.name())
+    };
+
+    let xhr = open_chunk_request(ctx, ctx.method.as_str(), uid)?;
+    let _ = xhr.set_request_header("Content-Range", &content_range(start, end, total));
+    let encoded_name: String = js_sys::encode_uri_component(&name).into();
+    let _ = xhr.set_request_header("X-File-Name", &encoded_name);
+
+    let progress_ctx = ctx.clone();
+    let progress_uid = uid.to_string();
+    let on_progress =
+        Closure::<dyn FnMut(ProgressEvent)>::wrap(Box::new(move |event: ProgressEvent| {
+            let percent = progress_ctx
+                .jobs
+                .borrow()
+                .jobs
+                .get(&progress_uid)
+                .map(|job| job.state.percent(event.loaded() as u64));
+            if let Some(percent) = percent {
+                progress_ctx.update(&progress_uid, |entry| entry.percent = Some(percent));
+            }
+        }));
+    if let Ok(upload) = xhr.upload() {
+        upload.set_onprogress(Some(on_progress.as_ref().unchecked_ref()));
+    }
+
+    set_chunk_request(ctx, uid, Some(xhr.clone()));
+    let result = send_request(&xhr, Some(&blob), &ctx.errors.failed).await;
+    set_chunk_request(ctx, uid, None);
+    drop(on_progress);
+    result?;
+
+    let status = xhr.status().unwrap_or(0);
+    if (200..300).contains(&status) {
+        Ok((response_offset(&xhr), xhr.response_text().ok().flatten()))
+    } else {
+        Err(format!("HTTP {status}"))
+    }
+}
+
 fn unique_id() -> u128 {
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
     {
         (js_sys::Date::now() * 1000.0) as u128
     }
-    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
     {
         SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
@@ -791,6 +1885,97 @@ mod upload_tests {
         assert_ne!(UploadStatus::Ready, UploadStatus::Done);
     }
 
+    fn meta(name: &str, mime: Option<&str>) -> UploadFileMeta {
+        UploadFileMeta {
+            name: name.into(),
+            size: Some(1),
+            mime: mime.map(Into::into),
+            relative_path: None,
+        }
+    }
+
+    #[test]
+    fn accept_config_round_trips_attr() {
+        let config = AcceptConfig::from_attr("image/*, .PDF ,application/zip");
+        assert_eq!(
+            config.mime_types,
+            Some(vec!["image/*".to_string(), "application/zip".to_string()])
+        );
+        assert_eq!(config.extensions, Some(vec![".PDF".to_string()]));
+        assert_eq!(config.to_attr(), "image/*,application/zip,.PDF");
+        assert_eq!(AcceptConfig::from_attr(" , ").to_attr(), "");
+    }
+
+    #[test]
+    fn accept_config_filters_by_mime_and_extension() {
+        let config = AcceptConfig::from_attr("image/*,.pdf");
+        assert!(config.accepts(&meta("shot.png", Some("image/png"))));
+        assert!(config.accepts(&meta("Report.PDF", None)));
+        assert!(!config.accepts(&meta("notes.txt", Some("text/plain"))));
+        // Dropped files from folders often come without a MIME type.
+        assert!(!config.accepts(&meta("photo.jpg", None)));
+
+        let exact = AcceptConfig::from_attr("application/json");
+        assert!(exact.accepts(&meta("a.json", Some("application/json"))));
+        assert!(!exact.accepts(&meta("a.json", Some("application/jsonp"))));
+
+        let open = AcceptConfig {
+            mime_types: None,
+            extensions: Some(vec![]),
+        };
+        assert!(open.accepts(&meta("anything.bin", None)));
+    }
+
+    #[test]
+    fn thumbnail_source_prefers_preview_then_thumb_then_image_url() {
+        let mut file = UploadFile::done("photo.png", Some(10));
+        let plain = ThumbnailSource {
+            preview_file: None,
+            is_image_url: None,
+        };
+        assert_eq!(plain.src(&file, UploadListType::PictureCard), None);
+
+        file.url = Some("https://cdn.example.com/photo.png".into());
+        assert_eq!(
+            plain.src(&file, UploadListType::Picture).as_deref(),
+            Some("https://cdn.example.com/photo.png")
+        );
+        assert_eq!(plain.src(&file, UploadListType::Text), None);
+
+        file.thumb_url = Some("blob:local".into());
+        assert_eq!(
+            plain.src(&file, UploadListType::PictureCard).as_deref(),
+            Some("blob:local")
+        );
+
+        let custom = ThumbnailSource {
+            preview_file: Some(Rc::new(|f: &UploadFile| format!("/preview/{}", f.name))),
+            is_image_url: None,
+        };
+        assert_eq!(
+            custom.src(&file, UploadListType::PictureCard).as_deref(),
+            Some("/preview/photo.png")
+        );
+
+        let mut doc = UploadFile::done("report.pdf", None);
+        doc.url = Some("https://cdn.example.com/report".into());
+        assert_eq!(plain.src(&doc, UploadListType::Picture), None);
+        let forced = ThumbnailSource {
+            preview_file: None,
+            is_image_url: Some(Rc::new(|_: &UploadFile| true)),
+        };
+        assert!(forced.src(&doc, UploadListType::Picture).is_some());
+    }
+
+    #[test]
+    fn entry_relative_path_keeps_folder_paths() {
+        assert_eq!(entry_relative_path("/a.txt"), None);
+        assert_eq!(
+            entry_relative_path("/photos/2024/a.jpg"),
+            Some("photos/2024/a.jpg".to_string())
+        );
+    }
+
     #[test]
     fn upload_list_type_default() {
         assert_eq!(UploadListType::default(), UploadListType::Text);
@@ -819,7 +2004,7 @@ mod upload_tests {
 
     #[test]
     fn upload_http_method_as_str() {
-        #[cfg(target_arch = "wasm32")]
+        #[cfg(all(feature = "web", target_arch = "wasm32"))]
         {
             assert_eq!(UploadHttpMethod::Post.as_str(), "POST");
             assert_eq!(UploadHttpMethod::Put.as_str(), "PUT");
@@ -854,7 +2039,7 @@ mod upload_tests {
     }
 
     #[test]
-    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
     fn unique_id_generates_value() {
         let id1 = unique_id();
         // Small delay to ensure different timestamps
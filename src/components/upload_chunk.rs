@@ -0,0 +1,575 @@
+//! Chunked, resumable upload bookkeeping for `Upload`.
+//!
+//! Everything here is pure: the component drives a [`ChunkUploadState`] per
+//! file by asking for the [`ChunkAction`] to perform next and reporting the
+//! outcome back, and an [`UploadQueue`] decides which files may run at the
+//! same time.
+//!
+//! Wire protocol used by the web driver:
+//! - each chunk is sent as the raw request body with
+//!   `Content-Range: bytes <start>-<end - 1>/<total>` and `X-Upload-Id`;
+//! - the server may answer with `Upload-Offset: <n>` (or a `Range: bytes=0-<n - 1>`
+//!   header) to report how many bytes it has committed;
+//! - with [`ChunkedUploadConfig::resume`], a `HEAD` request carrying
+//!   `X-Upload-Id` asks for that offset before the first chunk, after a
+//!   failed chunk and when resuming. A 404 means nothing is stored yet.
+
+use std::collections::VecDeque;
+
+/// Opt-in chunking settings for `Upload`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChunkedUploadConfig {
+    /// Bytes per chunk. Defaults to 5 MiB.
+    pub chunk_size: u64,
+    /// Files uploading at the same time within one `Upload`. Others wait
+    /// with [`UploadStatus::Ready`](crate::components::upload::UploadStatus).
+    pub concurrency: usize,
+    /// Retries of a single chunk before the file fails.
+    pub max_retries: u32,
+    /// Delay before the first retry in milliseconds, doubled per attempt.
+    pub retry_delay_ms: u32,
+    /// Upper bound of the retry delay in milliseconds.
+    pub max_retry_delay_ms: u32,
+    /// Ask the server for its committed offset before sending.
+    pub resume: bool,
+}
+
+impl Default for ChunkedUploadConfig {
+    fn default() -> Self {
+        Self {
+            chunk_size: 5 * 1024 * 1024,
+            concurrency: 3,
+            max_retries: 3,
+            retry_delay_ms: 1000,
+            max_retry_delay_ms: 30_000,
+            resume: true,
+        }
+    }
+}
+
+impl ChunkedUploadConfig {
+    /// Delay before retry number `attempt` (starting at 1).
+    pub fn backoff_ms(&self, attempt: u32) -> u32 {
+        let factor = 1u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.retry_delay_ms
+            .saturating_mul(factor)
+            .min(self.max_retry_delay_ms)
+    }
+}
+
+/// Next step for the driver of a [`ChunkUploadState`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChunkAction {
+    /// Ask the server for its committed offset, then report it through
+    /// [`ChunkUploadState::on_probe`].
+    Probe,
+    /// Send bytes `start..end`, then report through
+    /// [`ChunkUploadState::on_chunk_ok`] or [`ChunkUploadState::on_error`].
+    Send { start: u64, end: u64 },
+    /// Sleep before asking for the next step.
+    Wait { delay_ms: u32 },
+    /// All bytes are committed.
+    Done,
+    /// Retries are exhausted.
+    Failed(String),
+    /// Paused, cancelled or waiting for a request; nothing to do.
+    Idle,
+}
+
+/// Lifecycle of a chunked upload.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChunkPhase {
+    Ready,
+    InFlight,
+    Backoff,
+    Paused,
+    Cancelled,
+    Done,
+    Failed,
+}
+
+/// Retry and offset bookkeeping of a single file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChunkUploadState {
+    config: ChunkedUploadConfig,
+    total: u64,
+    offset: u64,
+    /// End of the chunk in flight.
+    pending_end: u64,
+    /// Consecutive failures of the current chunk.
+    failures: u32,
+    needs_probe: bool,
+    /// Whether a chunk was committed, so empty files still send one request.
+    sent: bool,
+    phase: ChunkPhase,
+    backoff_ms: u32,
+    error: Option<String>,
+}
+
+impl ChunkUploadState {
+    pub fn new(total: u64, config: &ChunkedUploadConfig) -> Self {
+        Self {
+            config: config.clone(),
+            total,
+            offset: 0,
+            pending_end: 0,
+            failures: 0,
+            needs_probe: config.resume,
+            sent: false,
+            phase: ChunkPhase::Ready,
+            backoff_ms: 0,
+            error: None,
+        }
+    }
+
+    pub fn phase(&self) -> ChunkPhase {
+        self.phase
+    }
+
+    /// Bytes committed by the server.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Committed share of the file, plus `in_flight` bytes of the chunk
+    /// being sent, in percent.
+    pub fn percent(&self, in_flight: u64) -> f32 {
+        if self.total == 0 {
+            return if self.phase == ChunkPhase::Done {
+                100.0
+            } else {
+                0.0
+            };
+        }
+        let sent = (self.offset + in_flight).min(self.total);
+        (sent as f64 / self.total as f64 * 100.0) as f32
+    }
+
+    /// Decide what the driver does next.
+    pub fn next_action(&mut self) -> ChunkAction {
+        match self.phase {
+            ChunkPhase::Paused | ChunkPhase::Cancelled | ChunkPhase::InFlight => ChunkAction::Idle,
+            ChunkPhase::Done => ChunkAction::Done,
+            ChunkPhase::Failed => ChunkAction::Failed(self.error.clone().unwrap_or_default()),
+            ChunkPhase::Backoff => {
+                self.phase = ChunkPhase::Ready;
+                ChunkAction::Wait {
+                    delay_ms: self.backoff_ms,
+                }
+            }
+            ChunkPhase::Ready => {
+                if self.needs_probe {
+                    self.phase = ChunkPhase::InFlight;
+                    return ChunkAction::Probe;
+                }
+                if self.offset >= self.total && (self.sent || self.total > 0) {
+                    self.phase = ChunkPhase::Done;
+                    return ChunkAction::Done;
+                }
+                let start = self.offset;
+                let end = (start + self.config.chunk_size.max(1)).min(self.total);
+                self.pending_end = end;
+                self.phase = ChunkPhase::InFlight;
+                ChunkAction::Send { start, end }
+            }
+        }
+    }
+
+    /// Report the probe result: `Ok(None)` when the server has nothing
+    /// stored for this upload.
+    pub fn on_probe(&mut self, result: Result<Option<u64>, String>) {
+        if self.phase != ChunkPhase::InFlight {
+            return;
+        }
+        match result {
+            Ok(server_offset) => {
+                if let Some(offset) = server_offset {
+                    self.offset = offset.min(self.total);
+                    self.sent |= offset > 0;
+                }
+                self.needs_probe = false;
+                self.phase = ChunkPhase::Ready;
+            }
+            Err(err) => self.on_error(err),
+        }
+    }
+
+    /// Report a committed chunk. A server-reported offset wins over the
+    /// chunk end, also when it is smaller.
+    pub fn on_chunk_ok(&mut self, server_offset: Option<u64>) {
+        if self.phase != ChunkPhase::InFlight {
+            // Paused or cancelled meanwhile; still keep the progress.
+            self.offset = server_offset.unwrap_or(self.pending_end).min(self.total);
+            return;
+        }
+        self.offset = server_offset.unwrap_or(self.pending_end).min(self.total);
+        self.sent = true;
+        self.failures = 0;
+        self.phase = ChunkPhase::Ready;
+    }
+
+    /// Report a failed probe or chunk. Errors caused by pausing or
+    /// cancelling are ignored.
+    pub fn on_error(&mut self, err: String) {
+        if self.phase != ChunkPhase::InFlight {
+            return;
+        }
+        self.failures += 1;
+        if self.failures > self.config.max_retries {
+            self.phase = ChunkPhase::Failed;
+            self.error = Some(err);
+            return;
+        }
+        self.backoff_ms = self.config.backoff_ms(self.failures);
+        self.needs_probe = self.config.resume;
+        self.phase = ChunkPhase::Backoff;
+    }
+
+    /// Pause the upload. Returns whether it was running, in which case the
+    /// request in flight should be aborted.
+    pub fn pause(&mut self) -> bool {
+        match self.phase {
+            ChunkPhase::Ready | ChunkPhase::InFlight | ChunkPhase::Backoff => {
+                self.phase = ChunkPhase::Paused;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Resume a paused or failed upload, re-probing the server offset when
+    /// resuming is enabled. Returns whether the upload can run again.
+    pub fn resume(&mut self) -> bool {
+        match self.phase {
+            ChunkPhase::Paused | ChunkPhase::Failed => {
+                self.phase = ChunkPhase::Ready;
+                self.failures = 0;
+                self.error = None;
+                self.needs_probe = self.config.resume;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Stop the upload for good.
+    pub fn cancel(&mut self) {
+        if self.phase != ChunkPhase::Done {
+            self.phase = ChunkPhase::Cancelled;
+        }
+    }
+}
+
+/// Read the committed offset from `Upload-Offset`, or from a
+/// `Range: bytes=0-<last>` header.
+pub fn parse_server_offset(upload_offset: Option<&str>, range: Option<&str>) -> Option<u64> {
+    if let Some(value) = upload_offset.and_then(|v| v.trim().parse::<u64>().ok()) {
+        return Some(value);
+    }
+    let range = range?.trim().strip_prefix("bytes=")?;
+    let (_, last) = range.split_once('-')?;
+    last.trim().parse::<u64>().ok().map(|last| last + 1)
+}
+
+/// `Content-Range` header of the chunk `start..end`.
+pub fn content_range(start: u64, end: u64, total: u64) -> String {
+    if end <= start {
+        format!("bytes */{total}")
+    } else {
+        format!("bytes {start}-{}/{total}", end - 1)
+    }
+}
+
+/// Limits how many files upload at the same time.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UploadQueue {
+    limit: usize,
+    active: Vec<String>,
+    waiting: VecDeque<String>,
+}
+
+impl UploadQueue {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit: limit.max(1),
+            active: Vec::new(),
+            waiting: VecDeque::new(),
+        }
+    }
+
+    /// Add `uid`; returns whether it may start right away. Files start in
+    /// the order they were pushed.
+    pub fn push(&mut self, uid: impl Into<String>) -> bool {
+        let uid = uid.into();
+        if self.is_active(&uid) || self.waiting.contains(&uid) {
+            return self.is_active(&uid);
+        }
+        if self.active.len() < self.limit && self.waiting.is_empty() {
+            self.active.push(uid);
+            true
+        } else {
+            self.waiting.push_back(uid);
+            false
+        }
+    }
+
+    /// Change how many files may run at once, returning the waiting files a
+    /// raised limit lets start now. Files already running keep going.
+    pub fn set_limit(&mut self, limit: usize) -> Vec<String> {
+        self.limit = limit.max(1);
+        self.drain()
+    }
+
+    /// Free the slot or queue position of `uid`, returning the files that
+    /// may start now.
+    pub fn release(&mut self, uid: &str) -> Vec<String> {
+        self.active.retain(|u| u != uid);
+        self.waiting.retain(|u| u != uid);
+        self.drain()
+    }
+
+    /// Move waiting files into free slots, returning them in queue order.
+    fn drain(&mut self) -> Vec<String> {
+        let mut started = Vec::new();
+        while self.active.len() < self.limit {
+            let Some(next) = self.waiting.pop_front() else {
+                break;
+            };
+            self.active.push(next.clone());
+            started.push(next);
+        }
+        started
+    }
+
+    pub fn is_active(&self, uid: &str) -> bool {
+        self.active.iter().any(|u| u == uid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(chunk_size: u64, resume: bool) -> ChunkedUploadConfig {
+        ChunkedUploadConfig {
+            chunk_size,
+            max_retries: 2,
+            retry_delay_ms: 100,
+            max_retry_delay_ms: 250,
+            resume,
+            ..ChunkedUploadConfig::default()
+        }
+    }
+
+    #[test]
+    fn sends_chunks_until_done() {
+        let mut state = ChunkUploadState::new(25, &config(10, false));
+        assert_eq!(state.next_action(), ChunkAction::Send { start: 0, end: 10 });
+        assert_eq!(
+            state.next_action(),
+            ChunkAction::Idle,
+            "waits for the outcome"
+        );
+        state.on_chunk_ok(None);
+        assert_eq!(
+            state.next_action(),
+            ChunkAction::Send { start: 10, end: 20 }
+        );
+        state.on_chunk_ok(None);
+        assert_eq!(state.percent(0), 80.0);
+        assert_eq!(
+            state.next_action(),
+            ChunkAction::Send { start: 20, end: 25 }
+        );
+        state.on_chunk_ok(None);
+        assert_eq!(state.next_action(), ChunkAction::Done);
+        assert_eq!(state.percent(0), 100.0);
+    }
+
+    #[test]
+    fn probe_resumes_from_server_offset() {
+        let mut state = ChunkUploadState::new(30, &config(10, true));
+        assert_eq!(state.next_action(), ChunkAction::Probe);
+        state.on_probe(Ok(Some(17)));
+        assert_eq!(
+            state.next_action(),
+            ChunkAction::Send { start: 17, end: 27 }
+        );
+
+        // The server may report less than was sent.
+        state.on_chunk_ok(Some(20));
+        assert_eq!(
+            state.next_action(),
+            ChunkAction::Send { start: 20, end: 30 }
+        );
+
+        let mut fresh = ChunkUploadState::new(30, &config(10, true));
+        assert_eq!(fresh.next_action(), ChunkAction::Probe);
+        fresh.on_probe(Ok(None));
+        assert_eq!(fresh.next_action(), ChunkAction::Send { start: 0, end: 10 });
+    }
+
+    #[test]
+    fn retries_with_backoff_then_fails() {
+        let mut state = ChunkUploadState::new(20, &config(10, true));
+        state.next_action();
+        state.on_probe(Ok(None));
+        assert_eq!(state.next_action(), ChunkAction::Send { start: 0, end: 10 });
+
+        state.on_error("timeout".into());
+        assert_eq!(state.next_action(), ChunkAction::Wait { delay_ms: 100 });
+        assert_eq!(
+            state.next_action(),
+            ChunkAction::Probe,
+            "re-probe after an error"
+        );
+        state.on_probe(Ok(Some(4)));
+        assert_eq!(state.next_action(), ChunkAction::Send { start: 4, end: 14 });
+
+        state.on_error("timeout".into());
+        assert_eq!(state.next_action(), ChunkAction::Wait { delay_ms: 200 });
+        state.next_action();
+        state.on_probe(Err("offline".into()));
+        assert_eq!(state.phase(), ChunkPhase::Failed);
+        assert_eq!(state.next_action(), ChunkAction::Failed("offline".into()));
+    }
+
+    #[test]
+    fn success_resets_failure_count() {
+        let mut state = ChunkUploadState::new(30, &config(10, false));
+        state.next_action();
+        state.on_error("e".into());
+        state.next_action();
+        state.next_action();
+        state.on_chunk_ok(None);
+        assert_eq!(
+            state.next_action(),
+            ChunkAction::Send { start: 10, end: 20 }
+        );
+        state.on_error("e".into());
+        assert_eq!(state.next_action(), ChunkAction::Wait { delay_ms: 100 });
+    }
+
+    #[test]
+    fn pause_resume_and_cancel() {
+        let mut state = ChunkUploadState::new(20, &config(10, true));
+        state.next_action();
+        state.on_probe(Ok(None));
+        state.next_action();
+        assert!(state.pause());
+        // The aborted request reports an error, which is ignored.
+        state.on_error("aborted".into());
+        assert_eq!(state.phase(), ChunkPhase::Paused);
+        assert_eq!(state.next_action(), ChunkAction::Idle);
+
+        assert!(state.resume());
+        assert_eq!(state.next_action(), ChunkAction::Probe);
+        state.on_probe(Ok(Some(10)));
+        assert_eq!(
+            state.next_action(),
+            ChunkAction::Send { start: 10, end: 20 }
+        );
+
+        state.cancel();
+        assert_eq!(state.next_action(), ChunkAction::Idle);
+        assert!(!state.resume());
+    }
+
+    #[test]
+    fn failed_upload_can_resume() {
+        let mut state = ChunkUploadState::new(10, &config(10, false));
+        for _ in 0..3 {
+            state.next_action();
+            state.next_action();
+            state.on_error("e".into());
+        }
+        assert_eq!(state.phase(), ChunkPhase::Failed);
+        assert!(state.resume());
+        assert_eq!(state.next_action(), ChunkAction::Send { start: 0, end: 10 });
+    }
+
+    #[test]
+    fn empty_file_sends_one_request() {
+        let mut state = ChunkUploadState::new(0, &config(10, false));
+        assert_eq!(state.next_action(), ChunkAction::Send { start: 0, end: 0 });
+        state.on_chunk_ok(None);
+        assert_eq!(state.next_action(), ChunkAction::Done);
+        assert_eq!(content_range(0, 0, 0), "bytes */0");
+        assert_eq!(content_range(10, 20, 25), "bytes 10-19/25");
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let cfg = config(10, false);
+        assert_eq!(cfg.backoff_ms(1), 100);
+        assert_eq!(cfg.backoff_ms(2), 200);
+        assert_eq!(cfg.backoff_ms(3), 250);
+        assert_eq!(cfg.backoff_ms(40), 250);
+    }
+
+    #[test]
+    fn parses_server_offsets() {
+        assert_eq!(parse_server_offset(Some("1024"), None), Some(1024));
+        assert_eq!(parse_server_offset(None, Some("bytes=0-1023")), Some(1024));
+        assert_eq!(parse_server_offset(Some("x"), Some("bytes=0-9")), Some(10));
+        assert_eq!(parse_server_offset(None, Some("0-9")), None);
+        assert_eq!(parse_server_offset(None, None), None);
+    }
+
+    #[test]
+    fn queue_limits_concurrency() {
+        let mut queue = UploadQueue::new(2);
+        assert!(queue.push("a"));
+        assert!(queue.push("b"));
+        assert!(!queue.push("c"));
+        assert!(!queue.push("d"));
+        assert!(queue.push("a"), "pushing an active file is a no-op");
+
+        assert_eq!(queue.release("a"), vec!["c".to_string()]);
+        // Releasing a waiting file drops it from the queue.
+        assert!(queue.release("d").is_empty());
+        assert_eq!(queue.release("b"), Vec::<String>::new());
+        assert!(queue.is_active("c"));
+        assert!(UploadQueue::new(0).push("x"), "limit is at least one");
+    }
+
+    #[test]
+    fn queue_limit_can_change_while_running() {
+        let mut queue = UploadQueue::new(1);
+        assert!(queue.push("a"));
+        assert!(!queue.push("b"));
+        assert!(!queue.push("c"));
+
+        assert_eq!(
+            queue.set_limit(3),
+            vec!["b".to_string(), "c".to_string()],
+            "a raised limit starts waiting files in order"
+        );
+        assert!(!queue.push("d"));
+        assert_eq!(queue.release("a"), vec!["d".to_string()]);
+
+        assert!(queue.set_limit(1).is_empty());
+        assert!(!queue.push("e"));
+        assert!(queue.release("b").is_empty(), "running files drain first");
+        assert!(queue.release("c").is_empty());
+        assert_eq!(queue.release("d"), vec!["e".to_string()]);
+    }
+
+    #[test]
+    fn queue_keeps_files_in_push_order() {
+        let mut queue = UploadQueue::new(2);
+        assert!(queue.push("a"));
+        assert!(queue.push("b"));
+        assert!(!queue.push("c"));
+        // "c" is still waiting when the slot frees, so "d" queues behind it.
+        queue.active.retain(|u| u != "a");
+        assert!(!queue.push("d"));
+        assert_eq!(queue.release("b"), vec!["c".to_string(), "d".to_string()]);
+    }
+}
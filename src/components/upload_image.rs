@@ -0,0 +1,791 @@
+//! Client-side image processing for `Upload`: thumbnails, resize/compress
+//! and the crop modal.
+//!
+//! The geometry is pure and tested on the host. Canvas work and object URLs
+//! only exist on web targets; elsewhere images are uploaded untouched.
+//!
+//! Coordinates: the source image is rotated clockwise by quarter turns first,
+//! then cropped in the rotated space. [`rotation_offset`] is the translation
+//! that moves the rotated image back to the origin, so a canvas (or CSS
+//! transform) draws it with `translate(offset) rotate(turns * 90deg)`.
+
+use crate::components::button::Button;
+use crate::components::modal::Modal;
+use crate::components::slider::{Slider, SliderValue};
+use crate::components::upload::UploadFileMeta;
+use dioxus::prelude::*;
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+use {
+    js_sys::{Array, Promise},
+    wasm_bindgen::{JsCast, JsValue, closure::Closure},
+    wasm_bindgen_futures::JsFuture,
+    web_sys::{Blob, CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement},
+};
+
+/// Width of the crop stage in the modal, in CSS pixels.
+const CROP_STAGE_WIDTH: f64 = 400.0;
+
+/// Resize/compress step applied to images before they are uploaded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageResizeConfig {
+    pub max_width: Option<u32>,
+    pub max_height: Option<u32>,
+    /// Encoder quality between 0 and 1 for lossy formats.
+    pub quality: f64,
+    /// Output MIME type such as `image/webp`; keeps the source type when
+    /// `None`.
+    pub mime: Option<String>,
+}
+
+impl Default for ImageResizeConfig {
+    fn default() -> Self {
+        Self {
+            max_width: None,
+            max_height: None,
+            quality: 0.92,
+            mime: None,
+        }
+    }
+}
+
+/// Crop/rotate modal shown before an image is uploaded, like `antd-img-crop`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageCropConfig {
+    /// Width / height of the crop frame.
+    pub aspect: f64,
+    /// Show the rotate buttons.
+    pub rotate: bool,
+    /// Upper bound of the zoom slider.
+    pub max_zoom: f64,
+    /// Modal title; falls back to the locale.
+    pub title: Option<String>,
+    /// Encoder quality between 0 and 1 for lossy formats.
+    pub quality: f64,
+    /// Output MIME type; keeps the source type when `None`.
+    pub mime: Option<String>,
+}
+
+impl Default for ImageCropConfig {
+    fn default() -> Self {
+        Self {
+            aspect: 1.0,
+            rotate: true,
+            max_zoom: 3.0,
+            title: None,
+            quality: 0.92,
+            mime: None,
+        }
+    }
+}
+
+/// Crop rectangle in rotated image pixels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CropRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Scale `width`×`height` down to fit the bounds, keeping the aspect ratio.
+/// Never upscales and never returns a zero dimension.
+pub fn fit_within(
+    width: u32,
+    height: u32,
+    max_width: Option<u32>,
+    max_height: Option<u32>,
+) -> (u32, u32) {
+    if width == 0 || height == 0 {
+        return (width.max(1), height.max(1));
+    }
+    let mut scale = 1.0_f64;
+    if let Some(max) = max_width {
+        scale = scale.min(f64::from(max) / f64::from(width));
+    }
+    if let Some(max) = max_height {
+        scale = scale.min(f64::from(max) / f64::from(height));
+    }
+    let scaled = |value: u32| ((f64::from(value) * scale).round() as u32).max(1);
+    (scaled(width), scaled(height))
+}
+
+/// Size of the image after `quarter_turns` clockwise rotations.
+pub fn rotated_size(width: f64, height: f64, quarter_turns: u8) -> (f64, f64) {
+    if quarter_turns % 2 == 1 {
+        (height, width)
+    } else {
+        (width, height)
+    }
+}
+
+/// Translation that brings the rotated image back to the origin.
+pub fn rotation_offset(width: f64, height: f64, quarter_turns: u8) -> (f64, f64) {
+    match quarter_turns % 4 {
+        1 => (height, 0.0),
+        2 => (width, height),
+        3 => (0.0, width),
+        _ => (0.0, 0.0),
+    }
+}
+
+/// Largest `aspect` (width / height) size that fits inside the image.
+pub fn cover_size(width: f64, height: f64, aspect: f64) -> (f64, f64) {
+    let aspect = if aspect > 0.0 { aspect } else { 1.0 };
+    if width / height > aspect {
+        (height * aspect, height)
+    } else {
+        (width, width / aspect)
+    }
+}
+
+/// Whether the image can be redrawn on a canvas. Animated GIFs and SVGs are
+/// left alone.
+pub fn is_processable_image(mime: Option<&str>) -> bool {
+    mime.is_some_and(|mime| {
+        mime.starts_with("image/") && mime != "image/gif" && mime != "image/svg+xml"
+    })
+}
+
+/// Whether a file looks like an image, by MIME type or extension.
+pub fn is_image_file(name: &str, mime: Option<&str>) -> bool {
+    if let Some(mime) = mime.filter(|m| !m.is_empty()) {
+        return mime.starts_with("image/");
+    }
+    let ext = name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase());
+    matches!(
+        ext.as_deref(),
+        Some("png" | "jpg" | "jpeg" | "gif" | "webp" | "bmp" | "svg" | "avif" | "ico")
+    )
+}
+
+/// `name` with the extension matching `mime`, e.g. `a.png` → `a.webp`.
+pub fn output_file_name(name: &str, mime: &str) -> String {
+    let ext = match mime {
+        "image/jpeg" => "jpg",
+        "image/png" => "png",
+        "image/webp" => "webp",
+        "image/avif" => "avif",
+        _ => return name.to_string(),
+    };
+    let (stem, current) = name.rsplit_once('.').unwrap_or((name, ""));
+    let current = current.to_ascii_lowercase();
+    if current == ext || (ext == "jpg" && current == "jpeg") {
+        name.to_string()
+    } else {
+        format!("{stem}.{ext}")
+    }
+}
+
+/// Pan/zoom/rotation of the crop modal.
+///
+/// The crop frame has a fixed aspect ratio; at zoom 1 it is the largest such
+/// frame inside the rotated image, and zooming in shrinks it around
+/// `center`. The center is kept so the frame never leaves the image.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CropState {
+    width: f64,
+    height: f64,
+    aspect: f64,
+    quarter_turns: u8,
+    zoom: f64,
+    center: (f64, f64),
+}
+
+/// CSS transform placing the source image inside the crop stage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CropView {
+    pub scale: f64,
+    pub translate_x: f64,
+    pub translate_y: f64,
+    pub degrees: u16,
+}
+
+impl CropView {
+    /// Transform for an image with `transform-origin: 0 0`.
+    pub fn css(&self) -> String {
+        format!(
+            "translate({:.2}px, {:.2}px) scale({:.5}) rotate({}deg)",
+            self.translate_x, self.translate_y, self.scale, self.degrees
+        )
+    }
+}
+
+impl CropState {
+    pub fn new(width: f64, height: f64, aspect: f64) -> Self {
+        Self {
+            width: width.max(1.0),
+            height: height.max(1.0),
+            aspect: if aspect > 0.0 { aspect } else { 1.0 },
+            quarter_turns: 0,
+            zoom: 1.0,
+            center: (width / 2.0, height / 2.0),
+        }
+    }
+
+    pub fn quarter_turns(&self) -> u8 {
+        self.quarter_turns
+    }
+
+    pub fn zoom(&self) -> f64 {
+        self.zoom
+    }
+
+    fn rotated(&self) -> (f64, f64) {
+        rotated_size(self.width, self.height, self.quarter_turns)
+    }
+
+    fn frame(&self) -> (f64, f64) {
+        let (w, h) = self.rotated();
+        let (cw, ch) = cover_size(w, h, self.aspect);
+        (cw / self.zoom, ch / self.zoom)
+    }
+
+    fn clamp_center(&mut self) {
+        let (w, h) = self.rotated();
+        let (cw, ch) = self.frame();
+        self.center.0 = self.center.0.clamp(cw / 2.0, w - cw / 2.0);
+        self.center.1 = self.center.1.clamp(ch / 2.0, h - ch / 2.0);
+    }
+
+    /// Current crop in rotated image pixels.
+    pub fn crop(&self) -> CropRect {
+        let (width, height) = self.frame();
+        CropRect {
+            x: self.center.0 - width / 2.0,
+            y: self.center.1 - height / 2.0,
+            width,
+            height,
+        }
+    }
+
+    pub fn set_zoom(&mut self, zoom: f64, max_zoom: f64) {
+        self.zoom = zoom.clamp(1.0, max_zoom.max(1.0));
+        self.clamp_center();
+    }
+
+    /// Rotate a quarter turn; the frame is centered again.
+    pub fn rotate(&mut self, clockwise: bool) {
+        self.quarter_turns = (self.quarter_turns + if clockwise { 1 } else { 3 }) % 4;
+        let (w, h) = self.rotated();
+        self.center = (w / 2.0, h / 2.0);
+    }
+
+    /// Move the image by a pointer delta measured on a stage `stage_width`
+    /// pixels wide.
+    pub fn pan(&mut self, dx: f64, dy: f64, stage_width: f64) {
+        let scale = stage_width / self.frame().0;
+        self.center.0 -= dx / scale;
+        self.center.1 -= dy / scale;
+        self.clamp_center();
+    }
+
+    /// Where to draw the unrotated source so the crop fills the stage.
+    pub fn view(&self, stage_width: f64) -> CropView {
+        let crop = self.crop();
+        let scale = stage_width / crop.width;
+        let (ox, oy) = rotation_offset(self.width, self.height, self.quarter_turns);
+        CropView {
+            scale,
+            translate_x: (ox - crop.x) * scale,
+            translate_y: (oy - crop.y) * scale,
+            degrees: u16::from(self.quarter_turns) * 90,
+        }
+    }
+}
+
+/// An image waiting in the crop modal.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct PendingCrop {
+    pub file: dioxus_html::FileData,
+    pub meta: UploadFileMeta,
+    /// Object URL of the source, revoked once the crop is done.
+    pub url: String,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// How to redraw an image before upload.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct RenderPlan {
+    pub quarter_turns: u8,
+    pub crop: Option<CropRect>,
+    pub max_width: Option<u32>,
+    pub max_height: Option<u32>,
+    pub quality: f64,
+    pub mime: Option<String>,
+}
+
+impl RenderPlan {
+    pub fn resize(config: &ImageResizeConfig) -> Self {
+        Self {
+            max_width: config.max_width,
+            max_height: config.max_height,
+            quality: config.quality,
+            mime: config.mime.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Crop result, followed by the resize step when there is one.
+    pub fn crop(
+        state: &CropState,
+        config: &ImageCropConfig,
+        resize: Option<&ImageResizeConfig>,
+    ) -> Self {
+        Self {
+            quarter_turns: state.quarter_turns(),
+            crop: Some(state.crop()),
+            max_width: resize.and_then(|r| r.max_width),
+            max_height: resize.and_then(|r| r.max_height),
+            quality: resize.map_or(config.quality, |r| r.quality.min(config.quality)),
+            mime: config
+                .mime
+                .clone()
+                .or_else(|| resize.and_then(|r| r.mime.clone())),
+        }
+    }
+
+    /// Output canvas size for a crop of the rotated image.
+    #[cfg_attr(not(all(feature = "web", target_arch = "wasm32")), allow(dead_code))]
+    pub fn output_size(&self, crop: &CropRect) -> (u32, u32) {
+        fit_within(
+            crop.width.round() as u32,
+            crop.height.round() as u32,
+            self.max_width,
+            self.max_height,
+        )
+    }
+
+    /// Whether redrawing a `width`×`height` image of type `mime` would only
+    /// re-encode it: no rotation or crop, no downscale and the same type.
+    #[cfg_attr(not(all(feature = "web", target_arch = "wasm32")), allow(dead_code))]
+    pub fn keeps_source(&self, width: u32, height: u32, mime: &str) -> bool {
+        self.quarter_turns.is_multiple_of(4)
+            && self.crop.is_none()
+            && fit_within(width, height, self.max_width, self.max_height) == (width, height)
+            && self.mime.as_deref().is_none_or(|target| target == mime)
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub(crate) struct UploadCropModalProps {
+    pub pending: PendingCrop,
+    pub config: ImageCropConfig,
+    pub title: String,
+    pub rotate_left: String,
+    pub rotate_right: String,
+    /// Called with the final state, or `None` when cancelled.
+    pub on_finish: EventHandler<Option<CropState>>,
+}
+
+/// Crop modal for one image; the image is panned and zoomed beneath a
+/// fixed frame.
+#[component]
+pub(crate) fn UploadCropModal(props: UploadCropModalProps) -> Element {
+    let UploadCropModalProps {
+        pending,
+        config,
+        title,
+        rotate_left,
+        rotate_right,
+        on_finish,
+    } = props;
+    let aspect = config.aspect;
+    let max_zoom = config.max_zoom;
+    let mut state = use_signal(|| CropState::new(pending.width, pending.height, aspect));
+    let mut drag_from = use_signal(|| None::<(f64, f64)>);
+
+    let current = *state.read();
+    let stage_height = CROP_STAGE_WIDTH / current.aspect;
+    let view = current.view(CROP_STAGE_WIDTH).css();
+    let zoom = current.zoom();
+
+    rsx! {
+        Modal {
+            open: true,
+            title: Some(title),
+            width: Some(CROP_STAGE_WIDTH as f32 + 48.0),
+            mask_closable: false,
+            on_ok: move |_| on_finish.call(Some(*state.peek())),
+            on_cancel: move |_| on_finish.call(None),
+            div { class: "adui-upload-crop",
+                div {
+                    class: "adui-upload-crop-stage",
+                    style: "width: {CROP_STAGE_WIDTH}px; height: {stage_height}px;",
+                    onmousedown: move |evt: MouseEvent| {
+                        let point = evt.client_coordinates();
+                        drag_from.set(Some((point.x, point.y)));
+                    },
+                    onmousemove: move |evt: MouseEvent| {
+                        let Some((x, y)) = *drag_from.peek() else {
+                            return;
+                        };
+                        let point = evt.client_coordinates();
+                        state.write().pan(point.x - x, point.y - y, CROP_STAGE_WIDTH);
+                        drag_from.set(Some((point.x, point.y)));
+                    },
+                    onmouseup: move |_| drag_from.set(None),
+                    onmouseleave: move |_| drag_from.set(None),
+                    img {
+                        class: "adui-upload-crop-image",
+                        src: "{pending.url}",
+                        draggable: "false",
+                        style: "width: {pending.width}px; height: {pending.height}px; transform: {view};",
+                    }
+                }
+                div { class: "adui-upload-crop-controls",
+                    if config.rotate {
+                        Button {
+                            class: "adui-upload-crop-rotate-left",
+                            onclick: move |_| state.write().rotate(false),
+                            "↺ {rotate_left}"
+                        }
+                    }
+                    Slider {
+                        class: "adui-upload-crop-zoom",
+                        min: 1.0,
+                        max: max_zoom,
+                        step: 0.01,
+                        value: SliderValue::Single(zoom),
+                        on_change: move |value: SliderValue| {
+                            if let SliderValue::Single(zoom) = value {
+                                state.write().set_zoom(zoom, max_zoom);
+                            }
+                        },
+                    }
+                    if config.rotate {
+                        Button {
+                            class: "adui-upload-crop-rotate-right",
+                            onclick: move |_| state.write().rotate(true),
+                            "{rotate_right} ↻"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Object URL for showing `file` without reading it, e.g. as a thumbnail.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+pub(crate) fn object_url(file: &dioxus_html::FileData) -> Option<String> {
+    let file = file.inner().downcast_ref::<web_sys::File>()?;
+    web_sys::Url::create_object_url_with_blob(file).ok()
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+pub(crate) fn object_url(_file: &dioxus_html::FileData) -> Option<String> {
+    None
+}
+
+pub(crate) fn revoke_object_url(url: &str) {
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
+    let _ = web_sys::Url::revoke_object_url(url);
+    #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+    let _ = url;
+}
+
+/// Load an image, resolving once it is decoded.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+pub(crate) async fn load_image(url: &str) -> Option<HtmlImageElement> {
+    let image = HtmlImageElement::new().ok()?;
+    let promise = Promise::new(&mut |resolve, reject| {
+        let on_load = Closure::once_into_js(move || {
+            let _ = resolve.call0(&JsValue::NULL);
+        });
+        let on_error = Closure::once_into_js(move || {
+            let _ = reject.call0(&JsValue::NULL);
+        });
+        image.set_onload(Some(on_load.unchecked_ref()));
+        image.set_onerror(Some(on_error.unchecked_ref()));
+    });
+    image.set_src(url);
+    JsFuture::from(promise).await.ok()?;
+    Some(image)
+}
+
+/// Redraw `file` rotated, cropped and scaled according to `plan`, and
+/// encode it again. Returns `None` when the original should be uploaded
+/// as is: `plan` would not change it, or the browser cannot decode it.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+pub(crate) async fn render_image(
+    file: &dioxus_html::FileData,
+    plan: &RenderPlan,
+) -> Option<web_sys::File> {
+    let source = file.inner().downcast_ref::<web_sys::File>()?.clone();
+    let url = web_sys::Url::create_object_url_with_blob(&source).ok()?;
+    let image = load_image(&url).await;
+    revoke_object_url(&url);
+    let image = image?;
+    if plan.keeps_source(
+        image.natural_width(),
+        image.natural_height(),
+        &source.type_(),
+    ) {
+        return None;
+    }
+
+    let (width, height) = (
+        f64::from(image.natural_width()),
+        f64::from(image.natural_height()),
+    );
+    let (rotated_width, rotated_height) = rotated_size(width, height, plan.quarter_turns);
+    let crop = plan.crop.unwrap_or(CropRect {
+        x: 0.0,
+        y: 0.0,
+        width: rotated_width,
+        height: rotated_height,
+    });
+    let (out_width, out_height) = plan.output_size(&crop);
+    let mime = plan.mime.clone().unwrap_or_else(|| source.type_());
+
+    let canvas = web_sys::window()?
+        .document()?
+        .create_element("canvas")
+        .ok()?
+        .dyn_into::<HtmlCanvasElement>()
+        .ok()?;
+    canvas.set_width(out_width);
+    canvas.set_height(out_height);
+    let ctx = canvas
+        .get_context("2d")
+        .ok()??
+        .dyn_into::<CanvasRenderingContext2d>()
+        .ok()?;
+    if mime == "image/jpeg" {
+        // JPEG has no alpha; transparent pixels would turn black.
+        ctx.set_fill_style_str("#fff");
+        ctx.fill_rect(0.0, 0.0, f64::from(out_width), f64::from(out_height));
+    }
+    let scale = f64::from(out_width) / crop.width;
+    let (offset_x, offset_y) = rotation_offset(width, height, plan.quarter_turns);
+    ctx.scale(scale, scale).ok()?;
+    ctx.translate(offset_x - crop.x, offset_y - crop.y).ok()?;
+    ctx.rotate(f64::from(plan.quarter_turns) * std::f64::consts::FRAC_PI_2)
+        .ok()?;
+    ctx.draw_image_with_html_image_element(&image, 0.0, 0.0)
+        .ok()?;
+
+    let promise = Promise::new(&mut |resolve, reject| {
+        if canvas
+            .to_blob_with_type_and_encoder_options(
+                &resolve,
+                &mime,
+                &JsValue::from_f64(plan.quality),
+            )
+            .is_err()
+        {
+            let _ = reject.call0(&JsValue::NULL);
+        }
+    });
+    let blob = JsFuture::from(promise)
+        .await
+        .ok()?
+        .dyn_into::<Blob>()
+        .ok()?;
+    let options = web_sys::FilePropertyBag::new();
+    options.set_type(&blob.type_());
+    web_sys::File::new_with_blob_sequence_and_options(
+        &Array::of1(&blob),
+        &output_file_name(&source.name(), &blob.type_()),
+        &options,
+    )
+    .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_within_keeps_aspect_and_never_upscales() {
+        assert_eq!(fit_within(4000, 3000, Some(1000), None), (1000, 750));
+        assert_eq!(fit_within(4000, 3000, Some(1000), Some(500)), (667, 500));
+        assert_eq!(fit_within(800, 600, Some(1000), Some(1000)), (800, 600));
+        assert_eq!(fit_within(800, 600, None, None), (800, 600));
+        assert_eq!(fit_within(10_000, 1, Some(100), None), (100, 1));
+    }
+
+    #[test]
+    fn resize_plans_keep_small_sources() {
+        let plan = RenderPlan::resize(&ImageResizeConfig {
+            max_width: Some(1000),
+            max_height: Some(1000),
+            ..ImageResizeConfig::default()
+        });
+        assert!(plan.keeps_source(800, 600, "image/png"));
+        assert!(!plan.keeps_source(1200, 600, "image/png"));
+
+        let converting = RenderPlan {
+            mime: Some("image/webp".into()),
+            ..plan.clone()
+        };
+        assert!(!converting.keeps_source(800, 600, "image/png"));
+        assert!(converting.keeps_source(800, 600, "image/webp"));
+
+        let rotated = RenderPlan {
+            quarter_turns: 1,
+            ..plan
+        };
+        assert!(!rotated.keeps_source(800, 600, "image/png"));
+    }
+
+    #[test]
+    fn rotation_offsets_map_corners_back_to_origin() {
+        // A corner (x, y) rotated clockwise by 90° lands at (-y, x).
+        let (w, h) = (40.0, 30.0);
+        for turns in 0..4u8 {
+            let (ox, oy) = rotation_offset(w, h, turns);
+            let (rw, rh) = rotated_size(w, h, turns);
+            let rotate = |(x, y): (f64, f64)| match turns {
+                1 => (-y, x),
+                2 => (-x, -y),
+                3 => (y, -x),
+                _ => (x, y),
+            };
+            let corners = [(0.0, 0.0), (w, 0.0), (0.0, h), (w, h)].map(|c| {
+                let (x, y) = rotate(c);
+                (x + ox, y + oy)
+            });
+            let min_x = corners.iter().map(|c| c.0).fold(f64::MAX, f64::min);
+            let max_x = corners.iter().map(|c| c.0).fold(f64::MIN, f64::max);
+            let min_y = corners.iter().map(|c| c.1).fold(f64::MAX, f64::min);
+            let max_y = corners.iter().map(|c| c.1).fold(f64::MIN, f64::max);
+            assert_eq!(
+                (min_x, min_y, max_x, max_y),
+                (0.0, 0.0, rw, rh),
+                "turns {turns}"
+            );
+        }
+    }
+
+    #[test]
+    fn cover_size_fits_the_aspect_inside() {
+        assert_eq!(cover_size(400.0, 300.0, 1.0), (300.0, 300.0));
+        assert_eq!(cover_size(300.0, 400.0, 1.0), (300.0, 300.0));
+        assert_eq!(cover_size(400.0, 300.0, 16.0 / 9.0), (400.0, 225.0));
+        assert_eq!(cover_size(100.0, 100.0, 0.0), (100.0, 100.0));
+    }
+
+    #[test]
+    fn crop_state_zooms_and_pans_inside_the_image() {
+        let mut state = CropState::new(400.0, 300.0, 1.0);
+        let crop = state.crop();
+        assert_eq!(
+            crop,
+            CropRect {
+                x: 50.0,
+                y: 0.0,
+                width: 300.0,
+                height: 300.0
+            }
+        );
+
+        // Dragging right moves the image right, i.e. the frame left, but
+        // never past the edge.
+        state.pan(1000.0, 0.0, 300.0);
+        assert_eq!(state.crop().x, 0.0);
+
+        state.set_zoom(2.0, 3.0);
+        let crop = state.crop();
+        assert_eq!((crop.width, crop.height), (150.0, 150.0));
+        state.pan(-150.0, -150.0, 300.0);
+        let crop = state.crop();
+        assert_eq!((crop.x, crop.y), (150.0, 150.0));
+
+        state.set_zoom(10.0, 3.0);
+        assert_eq!(state.zoom(), 3.0);
+        state.set_zoom(0.1, 3.0);
+        assert_eq!(state.zoom(), 1.0);
+        let crop = state.crop();
+        assert!(crop.x >= 0.0 && crop.x + crop.width <= 400.0);
+    }
+
+    #[test]
+    fn crop_state_rotation_swaps_the_frame() {
+        let mut state = CropState::new(400.0, 300.0, 4.0 / 3.0);
+        assert_eq!(state.crop().width, 400.0);
+        state.rotate(true);
+        assert_eq!(state.quarter_turns(), 1);
+        let crop = state.crop();
+        assert_eq!((crop.width, crop.height), (300.0, 225.0));
+        assert_eq!((crop.x, crop.y), (0.0, 87.5));
+        state.rotate(false);
+        state.rotate(false);
+        assert_eq!(state.quarter_turns(), 3);
+    }
+
+    #[test]
+    fn crop_view_places_the_crop_at_the_stage_origin() {
+        let mut state = CropState::new(400.0, 300.0, 1.0);
+        let view = state.view(150.0);
+        assert_eq!(view.scale, 0.5);
+        assert_eq!((view.translate_x, view.translate_y), (-25.0, 0.0));
+        assert_eq!(view.degrees, 0);
+
+        state.rotate(true);
+        // Rotated image is 300x400; the centered square starts at y = 50.
+        let view = state.view(300.0);
+        assert_eq!(view.scale, 1.0);
+        assert_eq!((view.translate_x, view.translate_y), (300.0, -50.0));
+        assert_eq!(view.degrees, 90);
+        assert_eq!(
+            view.css(),
+            "translate(300.00px, -50.00px) scale(1.00000) rotate(90deg)"
+        );
+    }
+
+    #[test]
+    fn render_plan_combines_crop_and_resize() {
+        let mut state = CropState::new(4000.0, 3000.0, 1.0);
+        state.rotate(true);
+        let crop = ImageCropConfig {
+            mime: Some("image/webp".into()),
+            ..ImageCropConfig::default()
+        };
+        let resize = ImageResizeConfig {
+            max_width: Some(1024),
+            quality: 0.8,
+            ..ImageResizeConfig::default()
+        };
+        let plan = RenderPlan::crop(&state, &crop, Some(&resize));
+        assert_eq!(plan.quarter_turns, 1);
+        assert_eq!(plan.quality, 0.8);
+        assert_eq!(plan.mime.as_deref(), Some("image/webp"));
+        assert_eq!(plan.output_size(&plan.crop.unwrap()), (1024, 1024));
+
+        let plan = RenderPlan::resize(&resize);
+        let full = CropRect {
+            x: 0.0,
+            y: 0.0,
+            width: 4000.0,
+            height: 3000.0,
+        };
+        assert_eq!(plan.output_size(&full), (1024, 768));
+    }
+
+    #[test]
+    fn image_detection_and_file_names() {
+        assert!(is_processable_image(Some("image/png")));
+        assert!(!is_processable_image(Some("image/gif")));
+        assert!(!is_processable_image(Some("image/svg+xml")));
+        assert!(!is_processable_image(None));
+
+        assert!(is_image_file("a.JPG", None));
+        assert!(is_image_file("blob", Some("image/webp")));
+        assert!(!is_image_file("a.pdf", Some("application/pdf")));
+        assert!(!is_image_file("README", None));
+
+        assert_eq!(output_file_name("a.png", "image/webp"), "a.webp");
+        assert_eq!(output_file_name("a.jpeg", "image/jpeg"), "a.jpeg");
+        assert_eq!(
+            output_file_name("archive.tar.png", "image/jpeg"),
+            "archive.tar.jpg"
+        );
+        assert_eq!(output_file_name("noext", "image/png"), "noext.png");
+        assert_eq!(output_file_name("a.png", "image/x-icon"), "a.png");
+    }
+}
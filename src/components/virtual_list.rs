@@ -0,0 +1,366 @@
+//! Windowed rendering engine shared by Table, List and Select option lists.
+//!
+//! Only the rows intersecting the viewport (plus a small overscan) are
+//! mounted; spacer elements above and below the window preserve the full
+//! scroll height. Rows are assumed to have a uniform height.
+
+use dioxus::html::geometry::PixelsVector2D;
+use dioxus::html::{MountedData, ScrollBehavior};
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// Default number of extra rows rendered above and below the viewport.
+pub const DEFAULT_OVERSCAN: usize = 4;
+
+/// Visible window for a given scroll position.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct VirtualRange {
+    /// First rendered index (inclusive).
+    pub start: usize,
+    /// Last rendered index (exclusive).
+    pub end: usize,
+    /// Height of the spacer before the first rendered row.
+    pub offset_top: f64,
+    /// Height of the spacer after the last rendered row.
+    pub offset_bottom: f64,
+    /// Full scroll height of all rows.
+    pub total_height: f64,
+}
+
+impl VirtualRange {
+    /// Number of rendered rows.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Whether no rows are rendered.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// Alignment used when scrolling a row into view.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollAlign {
+    /// Scroll only when the row is outside the viewport, by the minimum amount.
+    #[default]
+    Auto,
+    /// Align the row with the top of the viewport.
+    Start,
+    /// Center the row in the viewport.
+    Center,
+    /// Align the row with the bottom of the viewport.
+    End,
+}
+
+/// Compute the rendered window for the current scroll position.
+pub fn compute_virtual_range(
+    item_count: usize,
+    item_height: f64,
+    viewport_height: f64,
+    scroll_top: f64,
+    overscan: usize,
+) -> VirtualRange {
+    let total_height = item_count as f64 * item_height;
+    if item_count == 0 || item_height <= 0.0 {
+        return VirtualRange {
+            total_height,
+            ..Default::default()
+        };
+    }
+
+    let max_scroll = (total_height - viewport_height).max(0.0);
+    let scroll_top = scroll_top.clamp(0.0, max_scroll);
+    let first_visible = ((scroll_top / item_height).floor() as usize).min(item_count - 1);
+    let visible_count = (viewport_height.max(0.0) / item_height).ceil() as usize + 1;
+
+    let start = first_visible.saturating_sub(overscan);
+    let end = (first_visible + visible_count + overscan).min(item_count);
+    let offset_top = start as f64 * item_height;
+    let offset_bottom = (item_count - end) as f64 * item_height;
+
+    VirtualRange {
+        start,
+        end,
+        offset_top,
+        offset_bottom,
+        total_height,
+    }
+}
+
+/// Scroll offset that brings `index` into view with the given alignment.
+pub fn scroll_offset_for_index(
+    index: usize,
+    item_count: usize,
+    item_height: f64,
+    viewport_height: f64,
+    current_scroll: f64,
+    align: ScrollAlign,
+) -> f64 {
+    if item_count == 0 {
+        return 0.0;
+    }
+    let index = index.min(item_count - 1);
+    let item_top = index as f64 * item_height;
+    let item_bottom = item_top + item_height;
+    let max_scroll = (item_count as f64 * item_height - viewport_height).max(0.0);
+
+    let target = match align {
+        ScrollAlign::Start => item_top,
+        ScrollAlign::End => item_bottom - viewport_height,
+        ScrollAlign::Center => item_top - (viewport_height - item_height) / 2.0,
+        ScrollAlign::Auto => {
+            if item_top < current_scroll {
+                item_top
+            } else if item_bottom > current_scroll + viewport_height {
+                item_bottom - viewport_height
+            } else {
+                current_scroll
+            }
+        }
+    };
+    target.clamp(0.0, max_scroll)
+}
+
+/// Geometry recorded by the last render, read by imperative scroll calls.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct VirtualGeometry {
+    item_count: usize,
+    item_height: f64,
+    viewport_height: f64,
+}
+
+/// Handle to a virtualized scroll container.
+///
+/// Create it with [`use_virtual_list`] and pass it to `Table`, `List` or
+/// [`VirtualList`] to drive scrolling imperatively.
+#[derive(Clone, Copy, PartialEq)]
+pub struct VirtualListHandle {
+    scroll_top: Signal<f64>,
+    geometry: CopyValue<VirtualGeometry>,
+    holder: CopyValue<Option<Rc<MountedData>>>,
+}
+
+impl VirtualListHandle {
+    /// Current scroll offset of the container.
+    pub fn scroll_top(&self) -> f64 {
+        *self.scroll_top.read()
+    }
+
+    /// Scroll the container to an absolute offset.
+    pub fn scroll_to(&self, offset: f64) {
+        let geometry = *self.geometry.read();
+        let max_scroll =
+            (geometry.item_count as f64 * geometry.item_height - geometry.viewport_height).max(0.0);
+        let offset = offset.clamp(0.0, max_scroll);
+        let mut scroll_top = self.scroll_top;
+        scroll_top.set(offset);
+        if let Some(holder) = self.holder.read().clone() {
+            spawn(async move {
+                let _ = holder
+                    .scroll(PixelsVector2D::new(0.0, offset), ScrollBehavior::Instant)
+                    .await;
+            });
+        }
+    }
+
+    /// Scroll so that the row at `index` is visible.
+    pub fn scroll_to_index(&self, index: usize, align: ScrollAlign) {
+        let geometry = *self.geometry.read();
+        let offset = scroll_offset_for_index(
+            index,
+            geometry.item_count,
+            geometry.item_height,
+            geometry.viewport_height,
+            self.scroll_top(),
+            align,
+        );
+        self.scroll_to(offset);
+    }
+
+    /// Record the geometry of the current render and return the window to draw.
+    pub(crate) fn window(
+        &self,
+        item_count: usize,
+        item_height: f64,
+        viewport_height: f64,
+        overscan: usize,
+    ) -> VirtualRange {
+        let mut geometry = self.geometry;
+        geometry.set(VirtualGeometry {
+            item_count,
+            item_height,
+            viewport_height,
+        });
+        compute_virtual_range(
+            item_count,
+            item_height,
+            viewport_height,
+            self.scroll_top(),
+            overscan,
+        )
+    }
+
+    /// Scroll event handler for the container element.
+    pub(crate) fn handle_scroll(&self, evt: &ScrollEvent) {
+        let top = evt.data().scroll_top();
+        let mut scroll_top = self.scroll_top;
+        if (*scroll_top.peek() - top).abs() > f64::EPSILON {
+            scroll_top.set(top);
+        }
+    }
+
+    /// Mounted event handler for the container element. A freshly mounted
+    /// container starts at the top, so the recorded offset is reset.
+    pub(crate) fn handle_mounted(&self, evt: &MountedEvent) {
+        let mut holder = self.holder;
+        holder.set(Some(evt.data()));
+        let mut scroll_top = self.scroll_top;
+        if *scroll_top.peek() != 0.0 {
+            scroll_top.set(0.0);
+        }
+    }
+}
+
+/// Create a handle for a virtualized scroll container.
+pub fn use_virtual_list() -> VirtualListHandle {
+    let scroll_top = use_signal(|| 0.0);
+    let geometry = use_hook(|| CopyValue::new(VirtualGeometry::default()));
+    let holder = use_hook(|| CopyValue::new(None));
+    VirtualListHandle {
+        scroll_top,
+        geometry,
+        holder,
+    }
+}
+
+/// Props for the VirtualList component.
+#[derive(Props, Clone, PartialEq)]
+pub struct VirtualListProps {
+    /// Total number of rows.
+    pub item_count: usize,
+    /// Height of a single row in pixels.
+    pub item_height: f32,
+    /// Height of the scroll viewport in pixels.
+    pub height: f32,
+    /// Extra rows rendered above and below the viewport.
+    #[props(default = DEFAULT_OVERSCAN)]
+    pub overscan: usize,
+    /// Renders the row at the given index.
+    pub render_item: Callback<usize, Element>,
+    /// External handle for imperative scrolling.
+    #[props(optional)]
+    pub handle: Option<VirtualListHandle>,
+    #[props(optional)]
+    pub class: Option<String>,
+    #[props(optional)]
+    pub style: Option<String>,
+}
+
+/// Scroll container that only mounts the rows in view.
+#[component]
+pub fn VirtualList(props: VirtualListProps) -> Element {
+    let VirtualListProps {
+        item_count,
+        item_height,
+        height,
+        overscan,
+        render_item,
+        handle,
+        class,
+        style,
+    } = props;
+
+    let internal = use_virtual_list();
+    let handle = handle.unwrap_or(internal);
+    let range = handle.window(item_count, item_height as f64, height as f64, overscan);
+
+    let class_attr = format!("adui-virtual-list {}", class.unwrap_or_default());
+    let style_attr = format!(
+        "height: {height}px; overflow-y: auto; {}",
+        style.unwrap_or_default()
+    );
+    let top_spacer = range.offset_top;
+    let bottom_spacer = range.offset_bottom;
+
+    rsx! {
+        div {
+            class: "{class_attr}",
+            style: "{style_attr}",
+            onmounted: move |evt| handle.handle_mounted(&evt),
+            onscroll: move |evt| handle.handle_scroll(&evt),
+            div { class: "adui-virtual-list-spacer", style: "height: {top_spacer}px;" }
+            for index in range.start..range.end {
+                div {
+                    key: "{index}",
+                    class: "adui-virtual-list-item",
+                    style: "height: {item_height}px;",
+                    {render_item.call(index)}
+                }
+            }
+            div { class: "adui-virtual-list-spacer", style: "height: {bottom_spacer}px;" }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_at_top_includes_viewport_and_overscan() {
+        let range = compute_virtual_range(50_000, 40.0, 400.0, 0.0, 4);
+        assert_eq!(range.start, 0);
+        // 10 visible rows + 1 partial + 4 overscan
+        assert_eq!(range.end, 15);
+        assert_eq!(range.offset_top, 0.0);
+        assert_eq!(range.total_height, 2_000_000.0);
+        assert_eq!(range.offset_bottom, (50_000 - 15) as f64 * 40.0);
+    }
+
+    #[test]
+    fn range_in_middle_is_windowed() {
+        let range = compute_virtual_range(50_000, 40.0, 400.0, 40_020.0, 4);
+        // First visible row is 1000.
+        assert_eq!(range.start, 996);
+        assert_eq!(range.end, 1015);
+        assert_eq!(range.offset_top, 996.0 * 40.0);
+        assert_eq!(
+            range.offset_top + range.len() as f64 * 40.0 + range.offset_bottom,
+            range.total_height
+        );
+    }
+
+    #[test]
+    fn range_clamps_past_the_end() {
+        let range = compute_virtual_range(100, 20.0, 200.0, 1_000_000.0, 2);
+        assert_eq!(range.end, 100);
+        assert_eq!(range.offset_bottom, 0.0);
+        assert!(range.start <= 90);
+    }
+
+    #[test]
+    fn range_handles_empty_and_short_lists() {
+        assert!(compute_virtual_range(0, 40.0, 400.0, 0.0, 4).is_empty());
+        let short = compute_virtual_range(3, 40.0, 400.0, 0.0, 4);
+        assert_eq!((short.start, short.end), (0, 3));
+    }
+
+    #[test]
+    fn scroll_offset_respects_alignment() {
+        let offset = |index, current, align| {
+            scroll_offset_for_index(index, 1000, 40.0, 400.0, current, align)
+        };
+        assert_eq!(offset(100, 0.0, ScrollAlign::Start), 4000.0);
+        assert_eq!(offset(100, 0.0, ScrollAlign::End), 3640.0);
+        assert_eq!(offset(100, 0.0, ScrollAlign::Center), 3820.0);
+        // Already visible: no movement.
+        assert_eq!(offset(5, 0.0, ScrollAlign::Auto), 0.0);
+        // Below the viewport: align to bottom; above: align to top.
+        assert_eq!(offset(20, 0.0, ScrollAlign::Auto), 440.0);
+        assert_eq!(offset(2, 400.0, ScrollAlign::Auto), 80.0);
+        // Clamped to the scrollable range.
+        assert_eq!(offset(999, 0.0, ScrollAlign::Start), 39_600.0);
+        assert_eq!(offset(0, 0.0, ScrollAlign::End), 0.0);
+    }
+}
@@ -36,6 +36,11 @@ pub use components::collapse::{
 pub use components::config_provider::{
     ComponentSize, ConfigContextValue, ConfigProvider, ConfigProviderProps, Locale, use_config,
 };
+pub use components::date_format::{
+    ParsedDateTime, format_date, format_date_time, format_time, parse_date, parse_date_time,
+    parse_time,
+};
+pub use components::date_panel::{PanelCell, PanelView, PickerMode, WeekRule, panel_cells};
 pub use components::date_picker::{
     DateGenerateConfig, DatePicker, DatePickerProps, DateRangeValue, DateValue, RangePicker,
     RangePickerProps, ShowTimeConfig,
@@ -58,10 +63,15 @@ pub use components::float_button::{
     BackTop, BadgeConfig, FloatButton, FloatButtonGroup, FloatButtonProps, FloatButtonPurePanel,
     FloatButtonPurePanelProps, FloatButtonShape, FloatButtonType,
 };
+pub use components::floating_position::{
+    FloatingAlign, FloatingPlacement, FloatingPosition, FloatingPositioner, FloatingRect,
+    FloatingSide, PositionOptions, compute_floating_position, use_floating_positioner,
+};
 pub use components::form::{
-    ControlSize, FeedbackIcons, Form, FormHandle, FormItem, FormItemProps, FormLayout, FormList,
-    FormListContext, FormListItemMeta, FormListProps, LabelAlign, RequiredMark,
-    ScrollToFirstErrorConfig, use_form, use_form_item_control, use_form_list,
+    ControlSize, FeedbackIcons, FieldMeta, Form, FormHandle, FormItem, FormItemProps, FormLayout,
+    FormList, FormListContext, FormListItemMeta, FormListProps, LabelAlign, NamePath, NameSegment,
+    RequiredMark, ScrollToFirstErrorConfig, typed_finish, use_form, use_form_item_control,
+    use_form_list,
 };
 pub use components::grid::{
     Col, ColProps, ColResponsive, ColSize, ResponsiveGutter, ResponsiveValue, Row, RowAlign,
@@ -75,15 +85,23 @@ pub use components::input::{
 pub use components::layout::{
     Content, Footer, Header, Layout, LayoutProps, Sider, SiderProps, SiderTheme,
 };
-pub use components::list::List;
+pub use components::list::{List, ListProps};
+pub use components::locale::{
+    ColorPickerLocale, DatePickerLocale, EmptyLocale, GlobalLocale, ImageLocale, LocaleBundle,
+    ModalLocale, PaginationLocale, PopconfirmLocale, QRCodeLocale, SchemaFormLocale,
+    TimePickerLocale, TourLocale, TransferLocale, TypographyLocale, find_locale_bundle,
+    format_locale_template, register_locale_bundle, use_locale,
+};
 pub use components::masonry::{Masonry, MasonryProps, MasonryResponsive};
 pub use components::menu::{Menu, MenuItemNode, MenuMode, MenuProps};
 pub use components::message::{MessageApi, MessageConfig, MessageType};
 pub use components::modal::{
-    ClosableConfig, FooterExtra, MaskConfig, Modal, ModalProps, ModalType,
+    ClosableConfig, FooterExtra, MaskConfig, Modal, ModalConfig, ModalOk, ModalOkFuture,
+    ModalProps, ModalRef, ModalType,
 };
 pub use components::notification::{
-    NotificationApi, NotificationConfig, NotificationPlacement, NotificationType,
+    NotificationApi, NotificationConfig, NotificationGlobalConfig, NotificationPlacement,
+    NotificationStack, NotificationType,
 };
 pub use components::pagination::{Pagination, PaginationProps};
 pub use components::popconfirm::{Popconfirm, PopconfirmProps};
@@ -92,9 +110,17 @@ pub use components::progress::{Progress, ProgressProps, ProgressStatus, Progress
 pub use components::qrcode::{QRCode, QRCodeErrorLevel, QRCodeProps, QRCodeStatus, QRCodeType};
 pub use components::radio::{Radio, RadioButton, RadioGroup, RadioGroupProps, RadioProps};
 pub use components::result::{Result, ResultProps, ResultStatus};
+pub use components::schema_form::{
+    SchemaField, SchemaForm, SchemaFormProps, SchemaWidget, schema_defaults, schema_fields,
+    schema_fields_with_locale,
+};
 pub use components::select::{
     PublicSelectOption as SelectOption, Select, SelectMode, SelectPlacement, SelectProps,
 };
+pub use components::select_base::{
+    LoadDataFn, LoadDataFuture, NodeLoadStatus, RemotePage, RemoteQuery, RemoteSearchFn,
+    RemoteSearchFuture, RemoteSearchState,
+};
 pub use components::skeleton::Skeleton;
 pub use components::space::{Space, SpaceAlign, SpaceDirection, SpaceProps, SpaceSize};
 pub use components::spin::{Spin, SpinProps, SpinSize};
@@ -105,10 +131,11 @@ pub use components::statistic::Statistic;
 pub use components::steps::{StepItem, StepStatus, Steps, StepsDirection, StepsProps};
 pub use components::switch::{Switch, SwitchProps, SwitchSize};
 pub use components::table::{
-    ColumnAlign, ColumnFilter, ColumnFixed, ColumnRenderFn, ExpandableConfig, RowClassNameFn,
-    RowPropsFn, RowSelection, SelectionType, SortOrder, StickyConfig, SummaryConfig, Table,
-    TableChangeEvent, TableColumn, TableLocale, TablePaginationState, TableProps, TableScroll,
-    TableSorterState,
+    ColumnAlign, ColumnFilter, ColumnFixed, ColumnRenderFn, ExpandableConfig, ExpandedRowRenderFn,
+    RowClassNameFn, RowExpandableFn, RowPropsFn, RowSelection, SelectionType, SortOrder,
+    StickyConfig, SummaryConfig, SummaryFixed, SummaryRenderFn, Table, TableChangeEvent,
+    TableColumn, TableLocale, TablePaginationState, TableProps, TableScroll, TableSorterState,
+    TableSummaryCell, TableSummaryCellProps, TableSummaryRow, TableSummaryRowProps,
 };
 pub use components::tabs::{TabEditAction, TabItem, TabPlacement, Tabs, TabsProps, TabsType};
 pub use components::tag::{Tag, TagColor, TagProps};
@@ -119,7 +146,8 @@ pub use components::timeline::{
 pub use components::tooltip::{Tooltip, TooltipPlacement, TooltipProps, TooltipTrigger};
 pub use components::tour::{Tour, TourProps, TourStep, TourType};
 pub use components::tree::{
-    DirectoryTree, DirectoryTreeProps, DraggableConfig, FieldNames, FlatTreeNode, Tree, TreeProps,
+    DirectoryTree, DirectoryTreeProps, DraggableConfig, DropPosition, FieldNames, FlatTreeNode,
+    Tree, TreeDropInfo, TreeProps, move_tree_node,
 };
 pub use components::tree_select::{TreeSelect, TreeSelectProps};
 pub use components::typography::{
@@ -127,13 +155,24 @@ pub use components::typography::{
     TypographyCopyable, TypographyEditable, TypographyEllipsis,
 };
 pub use components::upload::{
-    AcceptConfig, ItemActions, Upload, UploadChangeInfo, UploadFile, UploadHttpMethod,
-    UploadListConfig, UploadListType, UploadLocale, UploadProgressConfig, UploadProps,
-    UploadRequestOptions, UploadStatus,
+    AcceptConfig, ItemActions, Upload, UploadChangeInfo, UploadDragger, UploadFile, UploadFileMeta,
+    UploadHttpMethod, UploadListConfig, UploadListType, UploadLocale, UploadProgressConfig,
+    UploadProps, UploadRequestOptions, UploadStatus,
+};
+pub use components::upload_chunk::{
+    ChunkAction, ChunkPhase, ChunkUploadState, ChunkedUploadConfig, UploadQueue,
+};
+pub use components::upload_image::{
+    CropRect, CropState, CropView, ImageCropConfig, ImageResizeConfig,
+};
+pub use components::virtual_list::{
+    ScrollAlign, VirtualList, VirtualListHandle, VirtualListProps, VirtualRange, use_virtual_list,
 };
 pub use components::watermark::{Watermark, WatermarkFont, WatermarkProps};
 pub use theme::{
-    THEME_BASE_STYLE, Theme, ThemeHandle, ThemeMode, ThemeProvider, ThemeTokens, use_theme,
+    ButtonToken, ColorPalette, ComponentTokens, InputToken, MapToken, MenuToken, SeedToken,
+    THEME_BASE_STYLE, TableToken, Theme, ThemeAlgorithm, ThemeHandle, ThemeMode, ThemeProvider,
+    ThemeTokens, derive_map_token, derive_theme_tokens, generate_palette, use_theme,
 };
 
 // Foundation exports
@@ -1,6 +1,15 @@
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 
+pub mod algorithm;
+pub mod component_token;
+
+pub use algorithm::{
+    ColorPalette, MapToken, SeedToken, ThemeAlgorithm, derive_map_token, derive_theme_tokens,
+    generate_palette,
+};
+pub use component_token::{ButtonToken, ComponentTokens, InputToken, MenuToken, TableToken};
+
 /// 主题作用域基础样式与组件样式片段。
 macro_rules! adui_scope_style {
     () => {
@@ -67,6 +76,10 @@ macro_rules! adui_calendar_style {
     gap: 2px;
 }
 
+.adui-calendar-body-year {
+    grid-template-columns: repeat(3, 1fr);
+}
+
 .adui-calendar-date {
     box-sizing: border-box;
 }
@@ -86,6 +99,10 @@ macro_rules! adui_calendar_style {
     background-color: var(--adui-color-bg-base, #f5f5f5);
 }
 
+.adui-calendar-date-today .adui-calendar-date-value {
+    border-bottom: 2px solid var(--adui-color-primary, #1677ff);
+}
+
 .adui-calendar-date-selected .adui-calendar-date-cell {
     background-color: var(--adui-color-primary, #1677ff);
     color: #fff;
@@ -203,6 +220,7 @@ macro_rules! adui_button_style {
     cursor: pointer;
     transition: all var(--adui-motion-duration-fast, 0.18s) ease;
     box-shadow: var(--adui-btn-shadow, none);
+    font-weight: var(--adui-button-font-weight, 400);
     text-decoration: none;
     user-select: none;
 }
@@ -590,6 +608,10 @@ macro_rules! adui_date_picker_style {
     background-color: var(--adui-color-bg-base, #f5f5f5);
 }
 
+.adui-date-picker-cell-today {
+    box-shadow: inset 0 0 0 1px var(--adui-color-primary, #1677ff);
+}
+
 .adui-date-picker-cell-selected {
     background-color: var(--adui-color-primary, #1677ff);
     color: #fff;
@@ -599,11 +621,112 @@ macro_rules! adui_date_picker_style {
     background-color: rgba(22, 119, 255, 0.15);
 }
 
+.adui-date-picker-cell-in-range-hover {
+    background-color: rgba(22, 119, 255, 0.08);
+}
+
 .adui-date-picker-cell-range-start,
 .adui-date-picker-cell-range-end {
     background-color: var(--adui-color-primary, #1677ff);
     color: #fff;
 }
+
+.adui-date-picker-cell-disabled,
+.adui-date-picker-cell-disabled:hover {
+    cursor: not-allowed;
+    color: var(--adui-color-text-disabled, rgba(0,0,0,0.25));
+    background-color: rgba(0,0,0,0.04);
+}
+
+.adui-date-picker-dropdown-presets {
+    display: flex;
+    gap: 12px;
+}
+
+.adui-date-picker-presets {
+    margin: 0;
+    padding: 0 12px 0 0;
+    list-style: none;
+    border-right: 1px solid var(--adui-color-border, #d9d9d9);
+}
+
+.adui-date-picker-preset {
+    padding: 4px 8px;
+    border-radius: var(--adui-radius-sm, 4px);
+    white-space: nowrap;
+    cursor: pointer;
+}
+
+.adui-date-picker-preset:hover {
+    background-color: var(--adui-color-bg-base, #f5f5f5);
+}
+
+.adui-date-picker-panel {
+    flex: 1;
+}
+
+.adui-date-picker-selection {
+    display: flex;
+    flex-wrap: wrap;
+    gap: 4px;
+    flex: 1;
+    min-width: 120px;
+}
+
+.adui-date-picker-placeholder {
+    color: var(--adui-color-text-disabled, rgba(0,0,0,0.25));
+}
+
+.adui-date-picker-tag {
+    display: inline-flex;
+    align-items: center;
+    gap: 4px;
+    padding: 0 6px;
+    border-radius: var(--adui-radius-sm, 4px);
+    background-color: var(--adui-color-fill-secondary, rgba(0,0,0,0.06));
+    font-size: 12px;
+    line-height: 20px;
+}
+
+.adui-date-picker-tag-remove {
+    cursor: pointer;
+    color: var(--adui-color-text-secondary, var(--adui-color-text-muted));
+}
+
+.adui-date-picker-cell-hover:not(.adui-date-picker-cell-selected) {
+    background-color: var(--adui-color-bg-base, #f5f5f5);
+}
+
+.adui-date-picker-cell-outside {
+    color: var(--adui-color-text-secondary, var(--adui-color-text-muted));
+}
+
+.adui-date-picker-cell-week-number {
+    cursor: default;
+    color: var(--adui-color-text-secondary, var(--adui-color-text-muted));
+}
+
+.adui-date-picker-week-row-numbered,
+.adui-date-picker-week-panel {
+    grid-template-columns: repeat(8, 1fr);
+}
+
+.adui-date-picker-month-panel,
+.adui-date-picker-year-panel {
+    grid-template-columns: repeat(3, 1fr);
+    min-width: 200px;
+}
+
+.adui-date-picker-quarter-panel {
+    grid-template-columns: repeat(4, 1fr);
+    min-width: 200px;
+}
+
+.adui-date-picker-month-panel .adui-date-picker-cell,
+.adui-date-picker-quarter-panel .adui-date-picker-cell,
+.adui-date-picker-year-panel .adui-date-picker-cell {
+    padding: 8px 0;
+}
 "#
     };
 }
@@ -998,7 +1121,8 @@ macro_rules! adui_layout_style {
 	    align-items: center;
 	    padding: 6px 12px;
 	    cursor: pointer;
-	    border-radius: var(--adui-radius-sm, 4px);
+	    color: var(--adui-menu-item-color, inherit);
+	    border-radius: var(--adui-menu-item-border-radius, var(--adui-radius-sm, 4px));
 	    transition: background 0.16s ease, color 0.16s ease;
 	}
 
@@ -1008,12 +1132,12 @@ macro_rules! adui_layout_style {
 	}
 
 	.adui-menu-item:hover:not(.adui-menu-item-disabled) {
-	    background: rgba(0, 0, 0, 0.04);
+	    background: var(--adui-menu-item-hover-bg, rgba(0, 0, 0, 0.04));
 	}
 
 	.adui-menu-item-selected {
-	    background: rgba(22, 119, 255, 0.08);
-	    color: var(--adui-color-primary);
+	    background: var(--adui-menu-item-selected-bg, rgba(22, 119, 255, 0.08));
+	    color: var(--adui-menu-item-selected-color, var(--adui-color-primary));
 	}
 
 	.adui-menu-item-disabled {
@@ -1331,10 +1455,31 @@ macro_rules! adui_form_style {
     box-shadow: 0 0 0 2px rgba(255, 77, 79, 0.1);
 }
 
+.adui-form-item-help-warning {
+    color: var(--adui-color-warning);
+}
+
+.adui-form-item-has-warning .adui-form-item-control > *:first-child {
+    border-color: var(--adui-color-warning);
+}
+
 .adui-form-item-has-feedback .adui-form-item-label {
     position: relative;
 }
 
+.adui-form-item-has-feedback .adui-form-item-control {
+    position: relative;
+}
+
+.adui-form-item-feedback-icon {
+    position: absolute;
+    top: 8px;
+    inset-inline-end: 11px;
+    display: inline-flex;
+    color: var(--adui-color-primary);
+    pointer-events: none;
+}
+
 .adui-form-small .adui-form-item-label {
     min-height: 24px;
 }
@@ -1342,6 +1487,34 @@ macro_rules! adui_form_style {
 .adui-form-large .adui-form-item-label {
     min-height: 40px;
 }
+
+.adui-schema-form-section,
+.adui-schema-form-list {
+    margin-bottom: 24px;
+}
+
+.adui-schema-form-section-title,
+.adui-schema-form-list-title {
+    margin-bottom: 12px;
+    font-weight: 600;
+    color: var(--adui-color-text);
+}
+
+.adui-schema-form-section-description {
+    margin-bottom: 12px;
+    color: var(--adui-color-text-secondary, var(--adui-color-text-muted));
+}
+
+.adui-schema-form-list-item {
+    display: flex;
+    align-items: flex-start;
+    gap: 8px;
+}
+
+.adui-schema-form-list-item-body {
+    flex: 1;
+    min-width: 0;
+}
 "#
     };
 }
@@ -1353,7 +1526,7 @@ macro_rules! adui_control_style {
 .adui-input {
     box-sizing: border-box;
     width: 100%;
-    padding: 4px 11px;
+    padding: var(--adui-input-padding-block, 4px) var(--adui-input-padding-inline, 11px);
     border-radius: var(--adui-radius-sm, 4px);
     border: 1px solid var(--adui-color-border);
     background: var(--adui-color-bg-container);
@@ -1369,13 +1542,13 @@ macro_rules! adui_control_style {
 }
 
 .adui-input:hover {
-    border-color: var(--adui-color-border-hover);
+    border-color: var(--adui-input-hover-border-color, var(--adui-color-border-hover));
 }
 
 .adui-input:focus,
 .adui-input:focus-visible {
-    border-color: var(--adui-color-primary);
-    box-shadow: 0 0 0 2px rgba(22, 119, 255, 0.12);
+    border-color: var(--adui-input-active-border-color, var(--adui-color-primary));
+    box-shadow: var(--adui-input-active-shadow, 0 0 0 2px rgba(22, 119, 255, 0.12));
 }
 
 .adui-input[disabled] {
@@ -2164,6 +2337,49 @@ macro_rules! adui_control_style {
     color: var(--adui-color-text-disabled, rgba(0,0,0,0.25));
 }
 
+.adui-select-item-loading,
+.adui-select-item-empty {
+    padding: 8px 12px;
+    text-align: center;
+    font-size: var(--adui-font-size, 14px);
+    color: var(--adui-color-text-secondary, var(--adui-color-text-muted));
+}
+
+.adui-select-item-error {
+    color: var(--adui-color-error, #ff4d4f);
+}
+
+.adui-select-tree-switcher {
+    display: inline-flex;
+    align-items: center;
+    justify-content: center;
+    width: 16px;
+    margin-right: 4px;
+    font-size: 10px;
+    color: var(--adui-color-text-secondary, var(--adui-color-text-muted));
+}
+
+.adui-select-tree-switcher-error,
+.adui-cascader-menu-item-error-icon {
+    color: var(--adui-color-error, #ff4d4f);
+    font-weight: 600;
+}
+
+.adui-cascader-menu-item-content {
+    flex: 1;
+    min-width: 0;
+}
+
+.adui-cascader-menu-item-expand-icon,
+.adui-cascader-menu-item-loading-icon,
+.adui-cascader-menu-item-error-icon {
+    display: inline-flex;
+    align-items: center;
+    margin-left: 8px;
+    font-size: 12px;
+    color: var(--adui-color-text-secondary, var(--adui-color-text-muted));
+}
+
 .adui-dropdown-root {
     position: relative;
     display: inline-block;
@@ -2941,11 +3157,11 @@ macro_rules! adui_table_style {
 }
 
 .adui-table-bordered {
-    border-color: var(--adui-color-border);
+    border-color: var(--adui-table-border-color, var(--adui-color-border));
 }
 
 .adui-table-header {
-    border-bottom: 1px solid var(--adui-color-border);
+    border-bottom: 1px solid var(--adui-table-border-color, var(--adui-color-border));
 }
 
 .adui-table-row {
@@ -2953,14 +3169,15 @@ macro_rules! adui_table_style {
 }
 
 .adui-table-row-header {
-    background: rgba(0, 0, 0, 0.02);
+    background: var(--adui-table-header-bg, rgba(0, 0, 0, 0.02));
+    color: var(--adui-table-header-color, inherit);
     font-weight: 500;
 }
 
 .adui-table-cell {
     flex: 1;
-    padding: 8px 12px;
-    border-right: 1px solid var(--adui-color-border);
+    padding: var(--adui-table-cell-padding-block, 8px) var(--adui-table-cell-padding-inline, 12px);
+    border-right: 1px solid var(--adui-table-border-color, var(--adui-color-border));
     box-sizing: border-box;
 }
 
@@ -2972,6 +3189,14 @@ macro_rules! adui_table_style {
     background: rgba(0, 0, 0, 0.01);
 }
 
+.adui-table-body-inner .adui-table-row:hover {
+    background: var(--adui-table-row-hover-bg, rgba(0, 0, 0, 0.02));
+}
+
+.adui-table-body-inner .adui-table-row.adui-table-row-selected {
+    background: var(--adui-table-row-selected-bg, rgba(22, 119, 255, 0.06));
+}
+
 .adui-table-align-left { text-align: left; }
 .adui-table-align-center { text-align: center; }
 .adui-table-align-right { text-align: right; }
@@ -2984,6 +3209,74 @@ macro_rules! adui_table_style {
     padding: 8px 16px;
     text-align: right;
 }
+
+.adui-table-sticky {
+    overflow: visible;
+}
+
+.adui-table-header-sticky {
+    position: sticky;
+    z-index: 3;
+    background: var(--adui-color-bg-container);
+}
+
+.adui-table-cell-expand {
+    flex: 0 0 48px;
+    display: flex;
+    align-items: center;
+    justify-content: center;
+}
+
+.adui-table-row-expand-icon {
+    display: inline-flex;
+    align-items: center;
+    justify-content: center;
+    width: 17px;
+    height: 17px;
+    padding: 0;
+    color: inherit;
+    background: var(--adui-color-bg-container);
+    border: 1px solid var(--adui-color-border);
+    border-radius: 4px;
+    cursor: pointer;
+}
+
+.adui-table-row-expand-icon:hover {
+    color: var(--adui-color-primary-hover);
+    border-color: currentColor;
+}
+
+.adui-table-expanded-row {
+    background: rgba(0, 0, 0, 0.02);
+    border-bottom: 1px solid var(--adui-color-border);
+}
+
+.adui-table-expanded-row-content {
+    padding: 16px;
+}
+
+.adui-table-summary {
+    background: var(--adui-color-bg-container);
+    border-top: 1px solid var(--adui-color-border);
+    font-weight: 500;
+}
+
+.adui-table-summary-fixed {
+    position: sticky;
+    z-index: 2;
+}
+
+.adui-table-virtual .adui-table-body-inner > .adui-table-row {
+    height: var(--adui-table-row-height);
+    box-sizing: border-box;
+    overflow: hidden;
+}
+
+.adui-table-summary-fixed-top {
+    top: 0;
+    border-top: none;
+    border-bottom: 1px solid var(--adui-color-border);
+}
 "#
     };
 }
@@ -3188,6 +3481,35 @@ macro_rules! adui_tooltip_style {
     word-break: break-word;
 }
 
+.adui-tooltip-arrow {
+    position: absolute;
+    width: 8px;
+    height: 8px;
+    background: inherit;
+    transform: rotate(45deg);
+    pointer-events: none;
+}
+
+.adui-tooltip-placement-top .adui-tooltip-arrow {
+    bottom: -4px;
+    left: calc(var(--adui-arrow-offset, 50%) - 4px);
+}
+
+.adui-tooltip-placement-bottom .adui-tooltip-arrow {
+    top: -4px;
+    left: calc(var(--adui-arrow-offset, 50%) - 4px);
+}
+
+.adui-tooltip-placement-left .adui-tooltip-arrow {
+    right: -4px;
+    top: calc(var(--adui-arrow-offset, 50%) - 4px);
+}
+
+.adui-tooltip-placement-right .adui-tooltip-arrow {
+    left: -4px;
+    top: calc(var(--adui-arrow-offset, 50%) - 4px);
+}
+
 .adui-popover-root {
     position: relative;
     display: inline-block;
@@ -3545,6 +3867,66 @@ macro_rules! adui_tree_style {
     text-overflow: ellipsis;
 }
 
+/* Async loading */
+.adui-tree-switcher-loading {
+    color: var(--adui-color-primary, #1677ff);
+}
+
+.adui-tree-switcher-error-icon {
+    color: var(--adui-color-error, #ff4d4f);
+    font-weight: 600;
+}
+
+/* Drag and drop */
+.adui-tree-draggable .adui-tree-treenode {
+    user-select: none;
+}
+
+.adui-tree-dragging {
+    cursor: grabbing;
+}
+
+.adui-tree-dragging .adui-tree-treenode * {
+    pointer-events: none;
+}
+
+.adui-tree-treenode-dragging {
+    opacity: 0.5;
+}
+
+.adui-tree-draggable-icon {
+    display: inline-flex;
+    align-items: center;
+    justify-content: center;
+    width: 16px;
+    flex-shrink: 0;
+    cursor: grab;
+    color: var(--adui-color-text-tertiary, rgba(0, 0, 0, 0.45));
+}
+
+.adui-tree-drop-indicator {
+    position: absolute;
+    right: 0;
+    height: 2px;
+    z-index: 1;
+    background: var(--adui-color-primary, #1677ff);
+    border-radius: 1px;
+    pointer-events: none;
+}
+
+.adui-tree-treenode-drop-before .adui-tree-drop-indicator {
+    top: -1px;
+}
+
+.adui-tree-treenode-drop-after .adui-tree-drop-indicator,
+.adui-tree-treenode-drop-inside .adui-tree-drop-indicator {
+    bottom: -1px;
+}
+
+.adui-tree-treenode-drop-inside .adui-tree-node-content-wrapper {
+    background: var(--adui-color-primary-bg, #e6f4ff);
+}
+
 /* Show Line mode - no extra CSS pseudo-elements, lines are rendered inline */
 .adui-tree-show-line .adui-tree-treenode {
     /* Lines are rendered via inline spans */
@@ -3996,12 +4378,17 @@ macro_rules! adui_mentions_style {
     margin-top: 4px;
 }
 
-.adui-mentions-dropdown-loading {
+.adui-mentions-dropdown-loading,
+.adui-mentions-dropdown-empty {
     padding: 8px 12px;
     text-align: center;
     color: var(--adui-color-text-secondary, #8c8c8c);
 }
 
+.adui-mentions-dropdown-error {
+    color: var(--adui-color-error, #ff4d4f);
+}
+
 .adui-mentions-dropdown-list {
     list-style: none;
     margin: 0;
@@ -4253,6 +4640,164 @@ macro_rules! adui_image_style {
     };
 }
 
+macro_rules! adui_notification_style {
+    () => {
+        r#"
+.adui-notification-progress {
+    position: absolute;
+    left: 0;
+    right: 0;
+    bottom: 0;
+    height: 2px;
+    background: var(--adui-color-fill-secondary);
+}
+
+.adui-notification-progress-bar {
+    height: 100%;
+    background: var(--adui-color-primary);
+    transform-origin: left center;
+    animation-name: adui-notification-progress;
+    animation-timing-function: linear;
+    animation-fill-mode: forwards;
+}
+
+.adui-notification-pause-on-hover:hover .adui-notification-progress-bar {
+    animation-play-state: paused;
+}
+
+@keyframes adui-notification-progress {
+    from { transform: scaleX(1); }
+    to { transform: scaleX(0); }
+}
+
+.adui-notification-stack > .adui-notification {
+    transition: transform 0.2s ease;
+}
+
+.adui-notification-stack-collapsed > .adui-notification {
+    grid-area: 1 / 1;
+}
+"#
+    };
+}
+
+macro_rules! adui_upload_style {
+    () => {
+        r#"
+.adui-upload-selector {
+    display: inline-block;
+    cursor: pointer;
+}
+
+.adui-upload-dragger {
+    display: block;
+    width: 100%;
+    padding: 16px;
+    box-sizing: border-box;
+    text-align: center;
+    background: var(--adui-color-fill-tertiary, rgba(0, 0, 0, 0.02));
+    border: 1px dashed var(--adui-color-border, #d9d9d9);
+    border-radius: var(--adui-radius-lg, 8px);
+    transition: border-color 0.3s;
+}
+
+.adui-upload-dragger:hover,
+.adui-upload-dragger-hover {
+    border-color: var(--adui-color-primary, #1677ff);
+}
+
+.adui-upload-dragger-hover {
+    background: var(--adui-color-primary-bg, #e6f4ff);
+}
+
+.adui-upload-selector.adui-upload-disabled {
+    cursor: not-allowed;
+    opacity: 0.6;
+}
+
+.adui-upload-dragger.adui-upload-disabled:hover {
+    border-color: var(--adui-color-border, #d9d9d9);
+}
+
+.adui-upload-list-picture .adui-upload-list-item,
+.adui-upload-list-picture-card .adui-upload-list-item {
+    display: flex;
+    align-items: center;
+    gap: 8px;
+}
+
+.adui-upload-list-item-thumbnail {
+    width: 48px;
+    height: 48px;
+    object-fit: cover;
+    border-radius: var(--adui-radius-sm, 4px);
+}
+
+.adui-upload-list-picture-card {
+    display: flex;
+    flex-wrap: wrap;
+    gap: 8px;
+    padding: 0;
+    list-style: none;
+}
+
+.adui-upload-list-picture-card .adui-upload-list-item {
+    flex-direction: column;
+    justify-content: center;
+    width: 102px;
+    height: 102px;
+    padding: 8px;
+    box-sizing: border-box;
+    border: 1px solid var(--adui-color-border, #d9d9d9);
+    border-radius: var(--adui-radius-lg, 8px);
+    overflow: hidden;
+}
+
+.adui-upload-list-picture-card .adui-upload-list-item-thumbnail {
+    width: 100%;
+    height: 56px;
+}
+
+.adui-upload-list-picture-card .adui-upload-list-item-name {
+    max-width: 100%;
+    overflow: hidden;
+    text-overflow: ellipsis;
+    white-space: nowrap;
+    font-size: 12px;
+}
+
+.adui-upload-crop-stage {
+    position: relative;
+    margin: 0 auto;
+    overflow: hidden;
+    cursor: move;
+    background: var(--adui-color-fill-tertiary, rgba(0, 0, 0, 0.04));
+    user-select: none;
+}
+
+.adui-upload-crop-image {
+    position: absolute;
+    top: 0;
+    left: 0;
+    max-width: none;
+    transform-origin: 0 0;
+    pointer-events: none;
+}
+
+.adui-upload-crop-controls {
+    display: flex;
+    align-items: center;
+    gap: 12px;
+    margin-top: 16px;
+}
+
+.adui-upload-crop-zoom {
+    flex: 1;
+}
+"#
+    };
+}
+
 pub const SCOPE_STYLE: &str = adui_scope_style!();
 pub const BUTTON_STYLE: &str = adui_button_style!();
 pub const ICON_STYLE: &str = adui_icon_style!();
@@ -4290,6 +4835,8 @@ pub const TRANSFER_STYLE: &str = adui_transfer_style!();
 pub const CAROUSEL_STYLE: &str = adui_carousel_style!();
 pub const MENTIONS_STYLE: &str = adui_mentions_style!();
 pub const IMAGE_STYLE: &str = adui_image_style!();
+pub const NOTIFICATION_STYLE: &str = adui_notification_style!();
+pub const UPLOAD_STYLE: &str = adui_upload_style!();
 
 pub const THEME_BASE_STYLE: &str = concat!(
     adui_scope_style!(),
@@ -4333,6 +4880,8 @@ pub const THEME_BASE_STYLE: &str = concat!(
     adui_carousel_style!(),
     adui_mentions_style!(),
     adui_image_style!(),
+    adui_notification_style!(),
+    adui_upload_style!(),
 );
 
 /// Theme mode tracks the seed variant.
@@ -4392,6 +4941,9 @@ pub struct ThemeTokens {
     pub motion_duration_mid: f32,
     pub shadow: String,
     pub shadow_secondary: String,
+    /// Component-level overrides emitted as scoped CSS variables.
+    #[serde(default)]
+    pub components: ComponentTokens,
 }
 
 impl ThemeTokens {
@@ -4444,6 +4996,7 @@ impl ThemeTokens {
             motion_duration_mid: 0.24,
             shadow: "0 2px 0 rgba(5, 145, 255, 0.1)".into(),
             shadow_secondary: "0 6px 16px rgba(0,0,0,0.08)".into(),
+            components: ComponentTokens::default(),
         }
     }
 
@@ -4496,6 +5049,7 @@ impl ThemeTokens {
             motion_duration_mid: 0.24,
             shadow: "0 2px 0 rgba(23, 125, 220, 0.25)".into(),
             shadow_secondary: "0 6px 16px rgba(0,0,0,0.5)".into(),
+            components: ComponentTokens::default(),
         }
     }
 }
@@ -4535,6 +5089,20 @@ impl Theme {
             tokens: ThemeTokens::dark(),
         }
     }
+
+    /// Derive a full theme from seed tokens. The mode is `Dark` when the dark
+    /// algorithm is applied and `Custom` otherwise.
+    pub fn from_seed(seed: &SeedToken, algorithms: &[ThemeAlgorithm]) -> Self {
+        let mode = if algorithms.contains(&ThemeAlgorithm::Dark) {
+            ThemeMode::Dark
+        } else {
+            ThemeMode::Custom
+        };
+        Self {
+            mode,
+            tokens: derive_theme_tokens(seed, algorithms),
+        }
+    }
 }
 
 /// Handle for reading or mutating the active theme from components.
@@ -4562,6 +5130,12 @@ impl ThemeHandle {
         signal.set(Theme::for_mode(mode));
     }
 
+    /// Regenerate every token from seed values, replacing manual overrides.
+    pub fn set_seed(&self, seed: &SeedToken, algorithms: &[ThemeAlgorithm]) {
+        let mut signal = self.signal;
+        signal.set(Theme::from_seed(seed, algorithms));
+    }
+
     pub fn update_tokens(&self, mode: Option<ThemeMode>, update: impl FnOnce(&mut ThemeTokens)) {
         let mut signal = self.signal;
         signal.with_mut(|theme| {
@@ -4612,7 +5186,7 @@ pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
 }
 
 fn tokens_to_css_vars(tokens: &ThemeTokens) -> String {
-    format!(
+    let mut css = format!(
         "--adui-color-primary:{};\
         --adui-color-primary-hover:{};\
         --adui-color-primary-active:{};\
@@ -4705,7 +5279,9 @@ fn tokens_to_css_vars(tokens: &ThemeTokens) -> String {
         tokens.motion_duration_mid,
         tokens.shadow,
         tokens.shadow_secondary,
-    )
+    );
+    css.push_str(&tokens.components.to_css_vars());
+    css
 }
 
 #[cfg(test)]
@@ -4725,6 +5301,15 @@ mod tests {
         assert!(css.contains("--adui-shadow-secondary:0 6px 16px rgba(0,0,0,0.08);"));
     }
 
+    #[test]
+    fn tokens_to_css_vars_append_component_tokens() {
+        let mut tokens = ThemeTokens::light();
+        assert!(!tokens_to_css_vars(&tokens).contains("--adui-table-"));
+        tokens.components.table.header_bg = Some("#fafafa".into());
+        let css = tokens_to_css_vars(&tokens);
+        assert!(css.ends_with("--adui-table-header-bg:#fafafa;"));
+    }
+
     #[test]
     fn tokens_to_css_vars_reflect_custom_updates() {
         let mut tokens = ThemeTokens::light();
@@ -0,0 +1,634 @@
+//! Seed → map → alias token pipeline.
+//!
+//! Mirrors Ant Design's theme algorithms: a handful of seed values
+//! ([`SeedToken`]) are expanded by one or more [`ThemeAlgorithm`]s into
+//! [`MapToken`] (10-step palettes, neutral shades, size ladders), which is then
+//! flattened into the alias-level [`ThemeTokens`] consumed by components.
+
+use serde::{Deserialize, Serialize};
+
+use super::{ComponentTokens, ThemeTokens};
+
+/// Algorithms that turn seed tokens into map tokens. They can be combined,
+/// e.g. `[ThemeAlgorithm::Dark, ThemeAlgorithm::Compact]`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThemeAlgorithm {
+    /// Light palettes and regular sizes.
+    #[default]
+    Default,
+    /// Dark palettes blended against a dark background.
+    Dark,
+    /// Tighter control heights and paddings.
+    Compact,
+}
+
+/// Seed tokens: the minimal input a brand theme has to provide.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SeedToken {
+    pub color_primary: String,
+    pub color_success: String,
+    pub color_warning: String,
+    pub color_error: String,
+    /// Link color. Falls back to `color_primary` when `None`.
+    pub color_link: Option<String>,
+    /// Base text color. Defaults to black (light) or white (dark).
+    pub color_text_base: Option<String>,
+    /// Base background color. Defaults to white (light) or black (dark).
+    pub color_bg_base: Option<String>,
+    pub border_radius: f32,
+    pub font_size: f32,
+    pub control_height: f32,
+    pub line_width: f32,
+    /// Motion unit in seconds; fast/mid durations are multiples of it.
+    pub motion_unit: f32,
+}
+
+impl Default for SeedToken {
+    fn default() -> Self {
+        Self {
+            color_primary: "#1677ff".into(),
+            color_success: "#52c41a".into(),
+            color_warning: "#faad14".into(),
+            color_error: "#ff4d4f".into(),
+            color_link: None,
+            color_text_base: None,
+            color_bg_base: None,
+            border_radius: 6.0,
+            font_size: 14.0,
+            control_height: 32.0,
+            line_width: 1.0,
+            motion_unit: 0.08,
+        }
+    }
+}
+
+/// Ten shades of one color, lightest first. Index 5 is the base color in
+/// light themes.
+pub type ColorPalette = [String; 10];
+
+/// Map tokens produced by the algorithms.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MapToken {
+    pub dark: bool,
+    pub primary_palette: ColorPalette,
+    pub success_palette: ColorPalette,
+    pub warning_palette: ColorPalette,
+    pub error_palette: ColorPalette,
+    pub link_palette: ColorPalette,
+    pub color_text: String,
+    pub color_text_secondary: String,
+    pub color_text_tertiary: String,
+    pub color_text_quaternary: String,
+    pub color_bg_container: String,
+    pub color_bg_elevated: String,
+    pub color_bg_layout: String,
+    pub color_border: String,
+    pub color_border_secondary: String,
+    pub border_radius: f32,
+    pub border_radius_sm: f32,
+    pub border_radius_lg: f32,
+    pub control_height: f32,
+    pub control_height_sm: f32,
+    pub control_height_lg: f32,
+    pub font_size: f32,
+    pub font_size_sm: f32,
+    pub font_size_lg: f32,
+    pub line_height: f32,
+    pub line_width: f32,
+    pub motion_duration_fast: f32,
+    pub motion_duration_mid: f32,
+    /// Shadow tint derived from the primary seed.
+    pub shadow_color: String,
+}
+
+/// Background used to blend dark palettes, as in Ant Design.
+const DARK_PALETTE_BG: &str = "#141414";
+
+/// Run the algorithms over `seed` and produce map tokens.
+///
+/// An empty algorithm list behaves like `[ThemeAlgorithm::Default]`.
+pub fn derive_map_token(seed: &SeedToken, algorithms: &[ThemeAlgorithm]) -> MapToken {
+    let dark = algorithms.contains(&ThemeAlgorithm::Dark);
+    let compact = algorithms.contains(&ThemeAlgorithm::Compact);
+
+    let palette = |color: &str| generate_palette(color, dark);
+    let link_seed = seed.color_link.as_deref().unwrap_or(&seed.color_primary);
+
+    let text_base = seed
+        .color_text_base
+        .clone()
+        .unwrap_or_else(|| if dark { "#fff" } else { "#000" }.into());
+    let bg_base = seed
+        .color_bg_base
+        .clone()
+        .unwrap_or_else(|| if dark { "#000" } else { "#fff" }.into());
+    // Light themes darken the background for neutral shades, dark themes
+    // lighten it.
+    let solid = |amount: f64| {
+        let shift = if dark { amount } else { -amount };
+        shift_lightness(&bg_base, shift)
+    };
+    let text_alpha = if dark { 0.85 } else { 0.88 };
+
+    let control_height = if compact {
+        seed.control_height - 4.0
+    } else {
+        seed.control_height
+    };
+    let (radius_sm, radius_lg) = radius_ladder(seed.border_radius);
+    let font_size = seed.font_size;
+
+    let primary_rgb = parse_hex(&seed.color_primary).unwrap_or((22, 119, 255));
+
+    MapToken {
+        dark,
+        primary_palette: palette(&seed.color_primary),
+        success_palette: palette(&seed.color_success),
+        warning_palette: palette(&seed.color_warning),
+        error_palette: palette(&seed.color_error),
+        link_palette: palette(link_seed),
+        color_text: alpha_color(&text_base, text_alpha),
+        color_text_secondary: alpha_color(&text_base, 0.65),
+        color_text_tertiary: alpha_color(&text_base, 0.45),
+        color_text_quaternary: alpha_color(&text_base, 0.25),
+        color_bg_container: solid(if dark { 8.0 } else { 0.0 }),
+        color_bg_elevated: solid(if dark { 12.0 } else { 0.0 }),
+        color_bg_layout: solid(if dark { 0.0 } else { 4.0 }),
+        color_border: solid(if dark { 26.0 } else { 15.0 }),
+        color_border_secondary: solid(if dark { 19.0 } else { 6.0 }),
+        border_radius: seed.border_radius,
+        border_radius_sm: radius_sm,
+        border_radius_lg: radius_lg,
+        control_height,
+        control_height_sm: (control_height * 0.75).round(),
+        control_height_lg: (control_height * 1.25).round(),
+        font_size,
+        font_size_sm: font_size - 1.0,
+        font_size_lg: font_size + 2.0,
+        line_height: round_to((font_size + 8.0) / font_size, 4),
+        line_width: seed.line_width,
+        motion_duration_fast: round_to(seed.motion_unit * 2.0, 3),
+        motion_duration_mid: round_to(seed.motion_unit * 3.0, 3),
+        shadow_color: format!(
+            "rgba({}, {}, {}, {})",
+            primary_rgb.0,
+            primary_rgb.1,
+            primary_rgb.2,
+            if dark { 0.25 } else { 0.1 }
+        ),
+    }
+}
+
+impl MapToken {
+    /// Flatten map tokens into the alias tokens used by components.
+    pub fn alias_tokens(&self) -> ThemeTokens {
+        let ch = self.control_height;
+        let ch_sm = self.control_height_sm;
+        let ch_lg = self.control_height_lg;
+        ThemeTokens {
+            color_primary: self.primary_palette[5].clone(),
+            color_primary_hover: self.primary_palette[4].clone(),
+            color_primary_active: self.primary_palette[6].clone(),
+            color_success: self.success_palette[5].clone(),
+            color_success_hover: self.success_palette[4].clone(),
+            color_success_active: self.success_palette[6].clone(),
+            color_warning: self.warning_palette[5].clone(),
+            color_warning_hover: self.warning_palette[4].clone(),
+            color_warning_active: self.warning_palette[6].clone(),
+            color_error: self.error_palette[5].clone(),
+            color_error_hover: self.error_palette[4].clone(),
+            color_error_active: self.error_palette[6].clone(),
+            color_link: self.link_palette[5].clone(),
+            color_link_hover: self.link_palette[4].clone(),
+            color_link_active: self.link_palette[6].clone(),
+            color_text: self.color_text.clone(),
+            color_text_muted: self.color_text_secondary.clone(),
+            color_text_secondary: self.color_text_tertiary.clone(),
+            color_text_disabled: self.color_text_quaternary.clone(),
+            color_split: self.color_border_secondary.clone(),
+            color_bg_base: self.color_bg_layout.clone(),
+            color_bg_container: self.color_bg_container.clone(),
+            color_bg_layout: self.color_bg_layout.clone(),
+            color_border: self.color_border.clone(),
+            color_border_hover: self.primary_palette[2].clone(),
+            border_radius: self.border_radius,
+            border_radius_sm: self.border_radius_sm,
+            border_radius_lg: self.border_radius_lg,
+            control_height: ch,
+            control_height_small: ch_sm,
+            control_height_large: ch_lg,
+            padding_inline: (ch * 15.0 / 32.0).round(),
+            padding_inline_small: (ch_sm / 2.0).round(),
+            padding_inline_large: (ch_lg * 0.45).round(),
+            padding_block: (ch * 6.0 / 32.0).round(),
+            padding_block_small: (ch_sm / 6.0).round(),
+            padding_block_large: (ch_lg / 5.0).round(),
+            font_size: self.font_size,
+            font_size_small: self.font_size_sm,
+            font_size_large: self.font_size_lg,
+            line_height: self.line_height,
+            control_line_width: self.line_width,
+            motion_duration_fast: self.motion_duration_fast,
+            motion_duration_mid: self.motion_duration_mid,
+            shadow: format!("0 2px 0 {}", self.shadow_color),
+            shadow_secondary: if self.dark {
+                "0 6px 16px rgba(0,0,0,0.5)".into()
+            } else {
+                "0 6px 16px rgba(0,0,0,0.08)".into()
+            },
+            components: ComponentTokens::default(),
+        }
+    }
+}
+
+/// Seed → map → alias in one step.
+pub fn derive_theme_tokens(seed: &SeedToken, algorithms: &[ThemeAlgorithm]) -> ThemeTokens {
+    derive_map_token(seed, algorithms).alias_tokens()
+}
+
+/// Small/large radius ladder following Ant Design's `genRadius`.
+fn radius_ladder(base: f32) -> (f32, f32) {
+    let sm = if base >= 6.0 {
+        (base - 2.0).min(8.0)
+    } else {
+        base.min(4.0)
+    };
+    let lg = if base >= 16.0 {
+        16.0
+    } else if base > 4.0 {
+        base + 2.0
+    } else {
+        base
+    };
+    (sm, lg)
+}
+
+// ---- Palette generation (port of @ant-design/colors) -------------------------
+
+const HUE_STEP: f64 = 2.0;
+const SATURATION_STEP: f64 = 0.16;
+const SATURATION_STEP2: f64 = 0.05;
+const BRIGHTNESS_STEP1: f64 = 0.05;
+const BRIGHTNESS_STEP2: f64 = 0.15;
+const LIGHT_COLOR_COUNT: u32 = 5;
+const DARK_COLOR_COUNT: u32 = 4;
+/// (palette index, opacity) pairs used to blend dark palettes.
+const DARK_COLOR_MAP: [(usize, f64); 10] = [
+    (7, 0.15),
+    (6, 0.25),
+    (5, 0.3),
+    (5, 0.45),
+    (5, 0.65),
+    (5, 0.85),
+    (4, 0.9),
+    (3, 0.95),
+    (2, 0.97),
+    (1, 0.98),
+];
+
+/// Generate the 10-step palette for `color`. Dark palettes are blended
+/// against `#141414`. Unparseable input falls back to the default blue.
+pub fn generate_palette(color: &str, dark: bool) -> ColorPalette {
+    let rgb = parse_hex(color).unwrap_or((22, 119, 255));
+    let (h, s, v) = rgb_to_hsv(rgb);
+    let shade = |i: u32, light: bool| {
+        let hue = palette_hue(h, i, light);
+        let sat = palette_saturation(h, s, i, light);
+        let val = palette_value(v, i, light);
+        to_hex(hsv_to_rgb(hue, sat, val))
+    };
+
+    let mut patterns: Vec<String> = Vec::with_capacity(10);
+    for i in (1..=LIGHT_COLOR_COUNT).rev() {
+        patterns.push(shade(i, true));
+    }
+    patterns.push(to_hex(rgb));
+    for i in 1..=DARK_COLOR_COUNT {
+        patterns.push(shade(i, false));
+    }
+
+    if dark {
+        let bg = parse_hex(DARK_PALETTE_BG).unwrap_or((20, 20, 20));
+        std::array::from_fn(|idx| {
+            let (source, opacity) = DARK_COLOR_MAP[idx];
+            let fg = parse_hex(&patterns[source]).unwrap_or(rgb);
+            to_hex(mix(bg, fg, opacity))
+        })
+    } else {
+        std::array::from_fn(|idx| patterns[idx].clone())
+    }
+}
+
+fn palette_hue(h: f64, i: u32, light: bool) -> f64 {
+    let base = h.round();
+    let step = HUE_STEP * i as f64;
+    let hue = if (60.0..=240.0).contains(&base) {
+        if light { base - step } else { base + step }
+    } else if light {
+        base + step
+    } else {
+        base - step
+    };
+    hue.rem_euclid(360.0)
+}
+
+fn palette_saturation(h: f64, s: f64, i: u32, light: bool) -> f64 {
+    // Greys stay grey.
+    if h == 0.0 && s == 0.0 {
+        return s;
+    }
+    let mut sat = if light {
+        s - SATURATION_STEP * i as f64
+    } else if i == DARK_COLOR_COUNT {
+        s + SATURATION_STEP
+    } else {
+        s + SATURATION_STEP2 * i as f64
+    };
+    sat = sat.min(1.0);
+    if light && i == LIGHT_COLOR_COUNT && sat > 0.1 {
+        sat = 0.1;
+    }
+    sat = sat.max(0.06);
+    to_fixed(sat, 2)
+}
+
+fn palette_value(v: f64, i: u32, light: bool) -> f64 {
+    let value = if light {
+        v + BRIGHTNESS_STEP1 * i as f64
+    } else {
+        v - BRIGHTNESS_STEP2 * i as f64
+    };
+    to_fixed(value.min(1.0), 2)
+}
+
+// ---- Color helpers -----------------------------------------------------------
+
+type Rgb = (u8, u8, u8);
+
+/// Round like JavaScript's `Number(x.toFixed(digits))`.
+fn to_fixed(value: f64, digits: i32) -> f64 {
+    let factor = 10f64.powi(digits);
+    (value * factor).round() / factor
+}
+
+fn round_to<T: Into<f64>>(value: T, digits: i32) -> f32 {
+    to_fixed(value.into(), digits) as f32
+}
+
+/// Parse `#rgb` / `#rrggbb` (alpha channels are ignored).
+fn parse_hex(input: &str) -> Option<Rgb> {
+    let hex = input.trim().strip_prefix('#')?;
+    // Byte-offset slicing below is only safe on ASCII input.
+    if !hex.is_ascii() {
+        return None;
+    }
+    let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+    match hex.len() {
+        3 | 4 => {
+            let expand = |c: &str| channel(&c.repeat(2));
+            Some((
+                expand(&hex[0..1])?,
+                expand(&hex[1..2])?,
+                expand(&hex[2..3])?,
+            ))
+        }
+        6 | 8 => Some((
+            channel(&hex[0..2])?,
+            channel(&hex[2..4])?,
+            channel(&hex[4..6])?,
+        )),
+        _ => None,
+    }
+}
+
+fn to_hex((r, g, b): Rgb) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn alpha_color(color: &str, alpha: f64) -> String {
+    let (r, g, b) = parse_hex(color).unwrap_or((0, 0, 0));
+    format!("rgba({r},{g},{b},{alpha})")
+}
+
+fn mix(bg: Rgb, fg: Rgb, amount: f64) -> Rgb {
+    let channel = |a: u8, b: u8| ((b as f64 - a as f64) * amount + a as f64).round() as u8;
+    (
+        channel(bg.0, fg.0),
+        channel(bg.1, fg.1),
+        channel(bg.2, fg.2),
+    )
+}
+
+fn rgb_to_hsv((r, g, b): Rgb) -> (f64, f64, f64) {
+    let r = r as f64 / 255.0;
+    let g = g as f64 / 255.0;
+    let b = b as f64 / 255.0;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        ((g - b) / delta + if g < b { 6.0 } else { 0.0 }) * 60.0
+    } else if max == g {
+        ((b - r) / delta + 2.0) * 60.0
+    } else {
+        ((r - g) / delta + 4.0) * 60.0
+    };
+    let s = if max == 0.0 { 0.0 } else { delta / max };
+    (h, s, max)
+}
+
+fn hsv_to_rgb(h: f64, s: f64, v: f64) -> Rgb {
+    let h = (h.rem_euclid(360.0) / 360.0) * 6.0;
+    let i = h.floor();
+    let f = h - i;
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - f * s);
+    let t = v * (1.0 - (1.0 - f) * s);
+    let (r, g, b) = match (i as i32) % 6 {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+    let to_u8 = |c: f64| (c * 255.0).round().clamp(0.0, 255.0) as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
+fn rgb_to_hsl((r, g, b): Rgb) -> (f64, f64, f64) {
+    let r = r as f64 / 255.0;
+    let g = g as f64 / 255.0;
+    let b = b as f64 / 255.0;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    if max == min {
+        return (0.0, 0.0, l);
+    }
+    let d = max - min;
+    let s = if l > 0.5 {
+        d / (2.0 - max - min)
+    } else {
+        d / (max + min)
+    };
+    let h = if max == r {
+        (g - b) / d + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    (h * 60.0, s, l)
+}
+
+fn hsl_to_rgb(h: f64, s: f64, l: f64) -> Rgb {
+    let to_u8 = |c: f64| (c * 255.0).round().clamp(0.0, 255.0) as u8;
+    if s == 0.0 {
+        return (to_u8(l), to_u8(l), to_u8(l));
+    }
+    let hue_to_rgb = |p: f64, q: f64, mut t: f64| {
+        if t < 0.0 {
+            t += 1.0;
+        }
+        if t > 1.0 {
+            t -= 1.0;
+        }
+        if t < 1.0 / 6.0 {
+            p + (q - p) * 6.0 * t
+        } else if t < 0.5 {
+            q
+        } else if t < 2.0 / 3.0 {
+            p + (q - p) * (2.0 / 3.0 - t) * 6.0
+        } else {
+            p
+        }
+    };
+    let q = if l < 0.5 {
+        l * (1.0 + s)
+    } else {
+        l + s - l * s
+    };
+    let p = 2.0 * l - q;
+    let h = h / 360.0;
+    (
+        to_u8(hue_to_rgb(p, q, h + 1.0 / 3.0)),
+        to_u8(hue_to_rgb(p, q, h)),
+        to_u8(hue_to_rgb(p, q, h - 1.0 / 3.0)),
+    )
+}
+
+/// Lighten (positive) or darken (negative) a color by `amount` percent of
+/// HSL lightness, like TinyColor's `lighten` / `darken`.
+fn shift_lightness(color: &str, amount: f64) -> String {
+    let rgb = parse_hex(color).unwrap_or((255, 255, 255));
+    let (h, s, l) = rgb_to_hsl(rgb);
+    let next = (l + amount / 100.0).clamp(0.0, 1.0);
+    to_hex(hsl_to_rgb(h, s, next))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn light_palette_matches_ant_design_blue() {
+        let blue = generate_palette("#1677ff", false);
+        assert_eq!(blue[0], "#e6f4ff");
+        assert_eq!(blue[2], "#91caff");
+        assert_eq!(blue[4], "#4096ff");
+        assert_eq!(blue[5], "#1677ff");
+        assert_eq!(blue[6], "#0958d9");
+        assert_eq!(blue[9], "#001d66");
+    }
+
+    #[test]
+    fn light_palette_matches_ant_design_red() {
+        let red = generate_palette("#ff4d4f", false);
+        assert_eq!(red[4], "#ff7875");
+        assert_eq!(red[6], "#d9363e");
+    }
+
+    #[test]
+    fn dark_palette_blends_against_dark_background() {
+        let blue = generate_palette("#1677ff", true);
+        assert_eq!(blue[0], "#111a2c");
+        assert_eq!(blue[5], "#1668dc");
+    }
+
+    #[test]
+    fn default_seed_reproduces_light_preset_colors() {
+        let tokens = derive_theme_tokens(&SeedToken::default(), &[]);
+        let light = ThemeTokens::light();
+        assert_eq!(tokens.color_primary, light.color_primary);
+        assert_eq!(tokens.color_primary_hover, light.color_primary_hover);
+        assert_eq!(tokens.color_primary_active, light.color_primary_active);
+        assert_eq!(tokens.color_error_hover, light.color_error_hover);
+        assert_eq!(tokens.color_border, light.color_border);
+        assert_eq!(tokens.color_split, light.color_split);
+        assert_eq!(tokens.color_bg_layout, light.color_bg_layout);
+        assert_eq!(tokens.color_border_hover, light.color_border_hover);
+        assert_eq!(tokens.border_radius_sm, light.border_radius_sm);
+        assert_eq!(tokens.border_radius_lg, light.border_radius_lg);
+        assert_eq!(tokens.control_height_small, light.control_height_small);
+        assert_eq!(tokens.padding_inline, light.padding_inline);
+        assert_eq!(tokens.padding_block_large, light.padding_block_large);
+        assert_eq!(tokens.motion_duration_mid, light.motion_duration_mid);
+    }
+
+    #[test]
+    fn brand_seed_generates_hover_and_active_shades() {
+        let seed = SeedToken {
+            color_primary: "#722ed1".into(),
+            border_radius: 10.0,
+            ..SeedToken::default()
+        };
+        let tokens = derive_theme_tokens(&seed, &[ThemeAlgorithm::Default]);
+        assert_eq!(tokens.color_primary, "#722ed1");
+        assert_eq!(tokens.color_primary_hover, "#9254de");
+        assert_eq!(tokens.color_primary_active, "#531dab");
+        // Links follow the primary color unless seeded separately.
+        assert_eq!(tokens.color_link, "#722ed1");
+        assert_eq!(tokens.border_radius_sm, 8.0);
+        assert_eq!(tokens.border_radius_lg, 12.0);
+    }
+
+    #[test]
+    fn dark_algorithm_uses_dark_neutrals() {
+        let map = derive_map_token(&SeedToken::default(), &[ThemeAlgorithm::Dark]);
+        assert!(map.dark);
+        assert_eq!(map.color_bg_layout, "#000000");
+        assert_eq!(map.color_bg_container, "#141414");
+        assert_eq!(map.color_bg_elevated, "#1f1f1f");
+        assert_eq!(map.color_border_secondary, "#303030");
+        assert_eq!(map.color_text, "rgba(255,255,255,0.85)");
+    }
+
+    #[test]
+    fn compact_algorithm_shrinks_controls() {
+        let tokens = derive_theme_tokens(
+            &SeedToken::default(),
+            &[ThemeAlgorithm::Dark, ThemeAlgorithm::Compact],
+        );
+        assert_eq!(tokens.control_height, 28.0);
+        assert_eq!(tokens.control_height_small, 21.0);
+        assert!(tokens.padding_inline < ThemeTokens::light().padding_inline);
+        assert_eq!(tokens.color_bg_container, "#141414");
+    }
+
+    #[test]
+    fn parse_hex_accepts_short_form() {
+        assert_eq!(parse_hex("#fff"), Some((255, 255, 255)));
+        assert_eq!(parse_hex("#1677ffcc"), Some((22, 119, 255)));
+        assert_eq!(parse_hex("red"), None);
+    }
+    #[test]
+    fn parse_hex_rejects_non_ascii_input() {
+        assert_eq!(parse_hex("#中"), None);
+        assert_eq!(parse_hex("#ééé"), None);
+        assert_eq!(parse_hex("#ffé"), None);
+    }
+}
@@ -0,0 +1,185 @@
+//! Per-component design tokens.
+//!
+//! Every field is optional: `None` keeps the value derived from the global
+//! [`ThemeTokens`](super::ThemeTokens). Set fields are emitted by
+//! `ThemeProvider` as component-scoped CSS variables (`--adui-button-*`,
+//! `--adui-table-*`, ...) which the component styles read with a fallback to
+//! the global variables, so one component family can be restyled without
+//! touching the rest.
+
+use serde::{Deserialize, Serialize};
+
+/// Overrides for `Button`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ButtonToken {
+    /// Shadow of solid primary/success/warning buttons.
+    pub primary_shadow: Option<String>,
+    /// Shadow of solid default buttons.
+    pub default_shadow: Option<String>,
+    /// Shadow of solid danger buttons.
+    pub danger_shadow: Option<String>,
+    pub font_weight: Option<u16>,
+    pub default_bg: Option<String>,
+    pub default_color: Option<String>,
+    pub default_border_color: Option<String>,
+}
+
+/// Overrides for `Input` and other text controls sharing `.adui-input`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InputToken {
+    pub hover_border_color: Option<String>,
+    pub active_border_color: Option<String>,
+    pub active_shadow: Option<String>,
+    pub padding_block: Option<f32>,
+    pub padding_inline: Option<f32>,
+}
+
+/// Overrides for `Table`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableToken {
+    pub header_bg: Option<String>,
+    pub header_color: Option<String>,
+    pub border_color: Option<String>,
+    pub row_hover_bg: Option<String>,
+    pub row_selected_bg: Option<String>,
+    pub cell_padding_block: Option<f32>,
+    pub cell_padding_inline: Option<f32>,
+}
+
+/// Overrides for `Menu`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MenuToken {
+    pub item_color: Option<String>,
+    pub item_hover_bg: Option<String>,
+    pub item_selected_bg: Option<String>,
+    pub item_selected_color: Option<String>,
+    pub item_border_radius: Option<f32>,
+}
+
+/// Component-level tokens grouped by component family.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComponentTokens {
+    pub button: ButtonToken,
+    pub input: InputToken,
+    pub table: TableToken,
+    pub menu: MenuToken,
+}
+
+fn push_str(out: &mut String, name: &str, value: &Option<String>) {
+    if let Some(value) = value {
+        out.push_str(&format!("--adui-{name}:{value};"));
+    }
+}
+
+fn push_px(out: &mut String, name: &str, value: Option<f32>) {
+    if let Some(value) = value {
+        out.push_str(&format!("--adui-{name}:{value}px;"));
+    }
+}
+
+impl ComponentTokens {
+    /// CSS variable declarations for every field that is set.
+    pub fn to_css_vars(&self) -> String {
+        let mut out = String::new();
+
+        let button = &self.button;
+        push_str(&mut out, "button-primary-shadow", &button.primary_shadow);
+        push_str(&mut out, "button-default-shadow", &button.default_shadow);
+        push_str(&mut out, "button-danger-shadow", &button.danger_shadow);
+        push_str(
+            &mut out,
+            "button-font-weight",
+            &button.font_weight.map(|w| w.to_string()),
+        );
+        push_str(&mut out, "button-default-bg", &button.default_bg);
+        push_str(&mut out, "button-default-color", &button.default_color);
+        push_str(
+            &mut out,
+            "button-default-border-color",
+            &button.default_border_color,
+        );
+
+        let input = &self.input;
+        push_str(
+            &mut out,
+            "input-hover-border-color",
+            &input.hover_border_color,
+        );
+        push_str(
+            &mut out,
+            "input-active-border-color",
+            &input.active_border_color,
+        );
+        push_str(&mut out, "input-active-shadow", &input.active_shadow);
+        push_px(&mut out, "input-padding-block", input.padding_block);
+        push_px(&mut out, "input-padding-inline", input.padding_inline);
+
+        let table = &self.table;
+        push_str(&mut out, "table-header-bg", &table.header_bg);
+        push_str(&mut out, "table-header-color", &table.header_color);
+        push_str(&mut out, "table-border-color", &table.border_color);
+        push_str(&mut out, "table-row-hover-bg", &table.row_hover_bg);
+        push_str(&mut out, "table-row-selected-bg", &table.row_selected_bg);
+        push_px(
+            &mut out,
+            "table-cell-padding-block",
+            table.cell_padding_block,
+        );
+        push_px(
+            &mut out,
+            "table-cell-padding-inline",
+            table.cell_padding_inline,
+        );
+
+        let menu = &self.menu;
+        push_str(&mut out, "menu-item-color", &menu.item_color);
+        push_str(&mut out, "menu-item-hover-bg", &menu.item_hover_bg);
+        push_str(&mut out, "menu-item-selected-bg", &menu.item_selected_bg);
+        push_str(
+            &mut out,
+            "menu-item-selected-color",
+            &menu.item_selected_color,
+        );
+        push_px(&mut out, "menu-item-border-radius", menu.item_border_radius);
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_component_tokens_emit_nothing() {
+        assert!(ComponentTokens::default().to_css_vars().is_empty());
+    }
+
+    #[test]
+    fn set_fields_emit_scoped_variables() {
+        let mut tokens = ComponentTokens::default();
+        tokens.button.primary_shadow = Some("none".into());
+        tokens.table.header_bg = Some("#fafafa".into());
+        tokens.table.cell_padding_block = Some(12.0);
+        tokens.menu.item_selected_bg = Some("#e6f4ff".into());
+        let css = tokens.to_css_vars();
+        assert!(css.contains("--adui-button-primary-shadow:none;"));
+        assert!(css.contains("--adui-table-header-bg:#fafafa;"));
+        assert!(css.contains("--adui-table-cell-padding-block:12px;"));
+        assert!(css.contains("--adui-menu-item-selected-bg:#e6f4ff;"));
+        assert!(!css.contains("--adui-input-"));
+    }
+
+    #[test]
+    fn partial_json_deserializes_with_defaults() {
+        let tokens: ComponentTokens =
+            serde_json::from_str(r##"{"table":{"header_bg":"#f0f0f0"}}"##).unwrap();
+        assert_eq!(tokens.table.header_bg.as_deref(), Some("#f0f0f0"));
+        assert_eq!(tokens.button, ButtonToken::default());
+    }
+}
@@ -65,6 +65,8 @@ fn form_rule_creation() {
         pattern: None,
         message: Some("Invalid input".to_string()),
         validator: None,
+        warning_only: false,
+        validate_trigger: Default::default(),
     };
     assert_eq!(rule.required, true);
     assert_eq!(rule.min, Some(3));
@@ -220,6 +222,12 @@ fn select_props_defaults() {
         show_search: false,
         filter_option: None,
         token_separators: None,
+        on_search: None,
+        search_debounce: None,
+        remote_search: None,
+        loading: false,
+        not_found_content: None,
+        on_popup_scroll: None,
         status: None,
         size: None,
         variant: None,
@@ -242,6 +250,9 @@ fn select_props_defaults() {
         on_change: None,
         on_dropdown_visible_change: None,
         on_open_change: None,
+        r#virtual: true,
+        list_height: 256.0,
+        list_item_height: 32.0,
     };
     assert_eq!(props.mode, SelectMode::Single);
     assert_eq!(props.multiple, false);
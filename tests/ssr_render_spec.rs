@@ -0,0 +1,502 @@
+//! Host-side static rendering snapshots.
+//!
+//! Every component is rendered once in a headless `VirtualDom` on the host
+//! (no browser, no `web-sys` calls) and serialized with `dioxus-ssr`. The
+//! output is compared against `tests/snapshots/<name>.html`; run with
+//! `UPDATE_SNAPSHOTS=1 cargo test --test ssr_render_spec` to create or accept
+//! snapshots. A missing snapshot fails the test otherwise.
+
+use std::path::PathBuf;
+
+use adui_dioxus::components::carousel::{Carousel, CarouselSlide};
+use adui_dioxus::components::color_picker::ColorPicker;
+use adui_dioxus::components::image::Image;
+use adui_dioxus::components::input_number::InputNumber;
+use adui_dioxus::components::mentions::Mentions;
+use adui_dioxus::components::rate::Rate;
+use adui_dioxus::components::segmented::{Segmented, SegmentedOption};
+use adui_dioxus::components::slider::{Slider, SliderValue};
+use adui_dioxus::components::transfer::{Transfer, TransferItem};
+use adui_dioxus::*;
+use dioxus::dioxus_core::VirtualDom;
+use dioxus::prelude::*;
+
+// ---- Snapshot helpers ------------------------------------------------------
+
+/// Render `app` once and serialize the resulting tree.
+fn render(app: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    normalize_ids(&strip_style_elements(&dioxus_ssr::render(&dom)))
+}
+
+/// `ConfigProvider` and `App` inline the whole theme stylesheet; snapshots
+/// cover markup only, so `<style>` elements are dropped before comparing.
+fn strip_style_elements(html: &str) -> String {
+    let re = regex::Regex::new(r"(?s)<style\b[^>]*>.*?</style>").unwrap();
+    re.replace_all(html, "").into_owned()
+}
+
+/// Element ids come from process-wide counters or random sources, so they
+/// are replaced by `:r0:`, `:r1:`, ... in order of appearance.
+fn normalize_ids(html: &str) -> String {
+    let re = regex::Regex::new(r#" id="([^"]+)""#).unwrap();
+    let mut ids: Vec<String> = Vec::new();
+    for cap in re.captures_iter(html) {
+        let id = cap[1].to_string();
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    let mut ordered: Vec<(usize, &String)> = ids.iter().enumerate().collect();
+    ordered.sort_by_key(|(_, id)| std::cmp::Reverse(id.len()));
+    let mut out = html.to_string();
+    for (idx, id) in ordered {
+        out = out.replace(id.as_str(), &format!(":r{idx}:"));
+    }
+    // Row/Col scope their responsive rules with a process-wide counter.
+    let grid = regex::Regex::new(r"adui-(row|col)-\d+\b").unwrap();
+    grid.replace_all(&out, "adui-$1-N").into_owned()
+}
+
+fn assert_snapshot(name: &str, html: &str) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "snapshots"]
+        .iter()
+        .collect::<PathBuf>()
+        .join(format!("{name}.html"));
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some_and(|v| v == "1") {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, format!("{html}\n")).unwrap();
+        return;
+    }
+    let Ok(expected) = std::fs::read_to_string(&path) else {
+        panic!(
+            "snapshot `{name}` is missing; run with UPDATE_SNAPSHOTS=1 to create {}",
+            path.display()
+        );
+    };
+    assert_eq!(
+        expected.trim_end(),
+        html,
+        "snapshot `{name}` changed; rerun with UPDATE_SNAPSHOTS=1 to accept"
+    );
+}
+
+macro_rules! snapshot {
+    ($name:ident, { $($body:tt)* }) => {
+        #[test]
+        fn $name() {
+            fn app() -> Element {
+                // Components read the theme from context; any <style>
+                // elements they emit are stripped by `render`.
+                use_context_provider(|| Signal::new(Theme::light()));
+                rsx! { $($body)* }
+            }
+            let html = render(app);
+            assert!(
+                !html.is_empty(),
+                "`{}` failed to render on the host",
+                stringify!($name)
+            );
+            assert_snapshot(stringify!($name), &html);
+        }
+    };
+}
+
+// ---- Harness sanity --------------------------------------------------------
+
+#[test]
+fn style_elements_are_stripped() {
+    let html = "<div><style>.a { color: red; }\n.b {}</style><span>x</span><style></style></div>";
+    assert_eq!(strip_style_elements(html), "<div><span>x</span></div>");
+}
+
+// ---- Component snapshots ---------------------------------------------------
+
+fn option(key: &str, label: &str) -> SelectOption {
+    SelectOption {
+        key: key.into(),
+        label: label.into(),
+        disabled: false,
+    }
+}
+
+fn node(key: &str, label: &str, children: Vec<TreeNode>) -> TreeNode {
+    TreeNode {
+        key: key.into(),
+        label: label.into(),
+        disabled: false,
+        children,
+    }
+}
+
+snapshot!(affix, { Affix { div { "Pinned" } } });
+snapshot!(alert, {
+    Alert {
+        r#type: AlertType::Warning,
+        message: rsx!("Careful"),
+    }
+});
+snapshot!(anchor, {
+    Anchor {
+        items: vec![AnchorLinkItem::new("intro", "#intro", "Intro")],
+    }
+});
+snapshot!(app, { App { span { "Body" } } });
+
+/// Opens a confirm dialog on mount so `ModalHost` has something to render.
+#[component]
+fn OpenConfirm() -> Element {
+    let modal = use_modal();
+    use_hook(move || {
+        if let Some(api) = modal {
+            api.confirm(ModalConfig {
+                title: Some("Delete item?".into()),
+                content: Some("This cannot be undone.".into()),
+                ..Default::default()
+            });
+        }
+    });
+    rsx! {}
+}
+
+snapshot!(app_modal_confirm, { App { OpenConfirm {} } });
+snapshot!(auto_complete, {
+    AutoComplete {
+        options: vec![option("a", "Apple")],
+        placeholder: "Type",
+    }
+});
+snapshot!(avatar, {
+    AvatarGroup {
+        Avatar { "A" }
+        Avatar { shape: AvatarShape::Square, "B" }
+    }
+});
+snapshot!(badge, { Badge { count_number: 5, span { "Inbox" } } });
+snapshot!(ribbon, { Ribbon { text: "New", div { "Card" } } });
+snapshot!(breadcrumb, {
+    Breadcrumb {
+        items: vec![
+            BreadcrumbItem::new("home", "Home"),
+            BreadcrumbItem::new("list", "List"),
+        ],
+    }
+});
+snapshot!(button, {
+    ButtonGroup {
+        Button { r#type: ButtonType::Primary, "Submit" }
+        Button { danger: true, "Delete" }
+    }
+});
+snapshot!(calendar, {
+    Calendar {
+        value: CalendarDate::from_ymd(2024, 2, 14),
+    }
+});
+snapshot!(calendar_year, {
+    Calendar {
+        mode: CalendarMode::Year,
+        value: CalendarDate::from_ymd(2024, 2, 14),
+    }
+});
+snapshot!(card, { Card { title: rsx!("Title"), "Content" } });
+snapshot!(carousel, {
+    Carousel {
+        CarouselSlide { "One" }
+        CarouselSlide { "Two" }
+    }
+});
+snapshot!(cascader, {
+    Cascader {
+        options: vec![node("zj", "Zhejiang", vec![node("hz", "Hangzhou", vec![])])],
+    }
+});
+snapshot!(checkbox, {
+    CheckboxGroup {
+        Checkbox { value: "a", "A" }
+        Checkbox { value: "b", checked: true, "B" }
+    }
+});
+snapshot!(collapse, {
+    Collapse {
+        items: vec![CollapsePanel::new("1", rsx!("Header"), rsx!("Body"))],
+    }
+});
+snapshot!(color_picker, { ColorPicker { value: "#1677ff" } });
+snapshot!(config_provider, {
+    ConfigProvider { size: ComponentSize::Small, Button { "Small" } }
+});
+snapshot!(date_picker, {
+    DatePicker {
+        value: DateValue::from_ymd(2024, 2, 14),
+    }
+});
+snapshot!(date_picker_month, {
+    DatePicker {
+        picker: PickerMode::Month,
+        value: DateValue::from_ymd(2024, 2, 1),
+    }
+});
+snapshot!(date_picker_multiple, {
+    DatePicker {
+        multiple: true,
+        values: vec![
+            DateValue::from_ymd(2024, 2, 1).unwrap(),
+            DateValue::from_ymd(2024, 2, 14).unwrap(),
+        ],
+        allow_clear: true,
+    }
+});
+snapshot!(range_picker, {
+    RangePicker {
+        value: DateRangeValue {
+            start: DateValue::from_ymd(2024, 2, 1),
+            end: DateValue::from_ymd(2024, 2, 14),
+        },
+    }
+});
+snapshot!(descriptions, {
+    Descriptions {
+        items: vec![DescriptionsItem::new("name", rsx!("Name"), rsx!("Ada"))],
+    }
+});
+snapshot!(divider, {
+    Divider {
+        content: rsx!("Section"),
+    }
+});
+snapshot!(drawer, { Drawer { open: true, title: "Drawer", "Body" } });
+snapshot!(dropdown, {
+    Dropdown { items: vec![DropdownItem::new("edit", "Edit")], Button { "Menu" } }
+});
+snapshot!(empty, { Empty {} });
+snapshot!(flex, {
+    Flex { gap_size: FlexGap::Small,
+        span { "A" }
+        span { "B" }
+    }
+});
+snapshot!(float_button, {
+    FloatButtonGroup {
+        FloatButton { tooltip: "Help" }
+        BackTop {}
+    }
+});
+snapshot!(form, {
+    Form {
+        FormItem { label: "Name", name: "name", Input { placeholder: "Name" } }
+    }
+});
+snapshot!(grid, {
+    Row {
+        Col { span: 12, "Left" }
+        Col { span: 12, "Right" }
+    }
+});
+snapshot!(icon, {
+    Icon {
+        kind: IconKind::Search,
+    }
+});
+snapshot!(image, {
+    Image {
+        src: "https://example.com/a.png",
+        alt: "A",
+    }
+});
+snapshot!(input, {
+    div {
+        Input { placeholder: "Name" }
+        Password {}
+        Search {}
+        TextArea { rows: 2 }
+        OTP { length: 4 }
+    }
+});
+snapshot!(input_number, { InputNumber { value: 3.0 } });
+snapshot!(layout, {
+    Layout {
+        Header { "Header" }
+        Layout {
+            Sider { "Sider" }
+            Content { "Content" }
+        }
+        Footer { "Footer" }
+    }
+});
+snapshot!(list, { List { header: rsx!("Header"), div { "Row" } } });
+snapshot!(masonry, {
+    Masonry { columns: 2,
+        div { "A" }
+        div { "B" }
+    }
+});
+snapshot!(mentions, {
+    Mentions {
+        placeholder: "@someone",
+    }
+});
+snapshot!(menu, {
+    Menu {
+        items: vec![
+            MenuItemNode::leaf("home", "Home"),
+            MenuItemNode::leaf("about", "About"),
+        ],
+    }
+});
+snapshot!(modal, { Modal { open: true, title: "Confirm", "Are you sure?" } });
+snapshot!(pagination, {
+    Pagination {
+        total: 50,
+        current: 2,
+    }
+});
+snapshot!(popconfirm, { Popconfirm { title: "Sure?", Button { "Delete" } } });
+snapshot!(popover, { Popover { title: rsx!("Title"), content: rsx!("Body"), span { "Hover" } } });
+snapshot!(progress, { Progress { percent: 40.0 } });
+snapshot!(qrcode, {
+    QRCode {
+        value: "https://example.com",
+    }
+});
+snapshot!(radio, {
+    RadioGroup {
+        Radio { value: "a", "A" }
+        RadioButton { value: "b", "B" }
+    }
+});
+snapshot!(rate, { Rate { value: 3.0 } });
+snapshot!(result, {
+    adui_dioxus::Result {
+        status: ResultStatus::Success,
+        title: rsx!("Done"),
+    }
+});
+snapshot!(schema_form, {
+    SchemaForm {
+        schema: serde_json::json!({
+            "type": "object",
+            "required": ["name"],
+            "ui:order": ["name", "age", "role", "subscribe"],
+            "properties": {
+                "name": {"type": "string", "title": "Name", "ui:placeholder": "Your name"},
+                "age": {"type": "integer", "title": "Age", "minimum": 0},
+                "role": {"type": "string", "title": "Role", "enum": ["admin", "user"]},
+                "subscribe": {"type": "boolean", "title": "Subscribe"},
+                "address": {
+                    "type": "object",
+                    "title": "Address",
+                    "properties": {"city": {"type": "string", "title": "City"}}
+                },
+                "tags": {"type": "array", "title": "Tags", "items": {"type": "string"}}
+            }
+        }),
+        Button { html_type: ButtonHtmlType::Submit, "Submit" }
+    }
+});
+snapshot!(segmented, {
+    Segmented {
+        options: vec![
+            SegmentedOption::new("Day", "day"),
+            SegmentedOption::new("Week", "week"),
+        ],
+    }
+});
+snapshot!(select, {
+    Select {
+        options: vec![option("a", "Apple"), option("b", "Banana")],
+        placeholder: "Pick",
+    }
+});
+snapshot!(skeleton, { Skeleton {} });
+snapshot!(slider, {
+    Slider {
+        value: SliderValue::Single(30.0),
+    }
+});
+snapshot!(space, {
+    Space {
+        Button { "A" }
+        Button { "B" }
+    }
+});
+snapshot!(spin, { Spin { spinning: true } });
+snapshot!(splitter, {
+    Splitter {
+        first: rsx!("Left"),
+        second: rsx!("Right"),
+    }
+});
+snapshot!(statistic, {
+    Statistic {
+        title: rsx!("Users"),
+        value: 1128.0,
+    }
+});
+snapshot!(steps, {
+    Steps {
+        items: vec![
+            StepItem::new("1", rsx!("First")),
+            StepItem::new("2", rsx!("Second")),
+        ],
+    }
+});
+snapshot!(switch, { Switch { checked: true } });
+snapshot!(table, {
+    Table {
+        columns: vec![TableColumn::new("name", "Name")],
+        data: vec![serde_json::json!({ "key": "1", "name": "Ada" })],
+    }
+});
+snapshot!(tabs, {
+    Tabs {
+        items: vec![
+            TabItem::new("1", "One", Some(rsx!("First"))),
+            TabItem::new("2", "Two", Some(rsx!("Second"))),
+        ],
+    }
+});
+snapshot!(tag, { Tag { color: TagColor::Success, "Done" } });
+snapshot!(time_picker, {
+    TimePicker {
+        value: TimeValue::new(9, 30, 0),
+    }
+});
+snapshot!(timeline, {
+    Timeline {
+        items: vec![TimelineItem::new("1").content(rsx!("Created"))],
+    }
+});
+snapshot!(tooltip, { Tooltip { title: "Tip", span { "Hover" } } });
+snapshot!(tour, {
+    Tour {
+        open: true,
+        steps: vec![TourStep::new("1", "Welcome", "Start here")],
+    }
+});
+snapshot!(transfer, {
+    Transfer {
+        data_source: vec![TransferItem::new("1", "One"), TransferItem::new("2", "Two")],
+    }
+});
+snapshot!(tree, {
+    Tree {
+        tree_data: vec![node("root", "Root", vec![node("leaf", "Leaf", vec![])])],
+        default_expand_all: true,
+    }
+});
+snapshot!(tree_select, {
+    TreeSelect {
+        tree_data: vec![node("root", "Root", vec![])],
+    }
+});
+snapshot!(typography, {
+    div {
+        adui_dioxus::Title { level: TitleLevel::H2, "Heading" }
+        Paragraph { "Paragraph" }
+        adui_dioxus::Text { strong: true, "Text" }
+    }
+});
+snapshot!(upload, { Upload { Button { "Upload" } } });
+snapshot!(watermark, { Watermark { content: vec!["adui".to_string()], div { "Body" } } });